@@ -7,6 +7,7 @@ impl AssemblyScriptGenerator {
         w: &mut PrettyWriter<T>,
         module_name: &str,
         func_witx: &witx::Function,
+        options: &Options,
     ) -> Result<(), Error> {
         assert_eq!(func_witx.abi, witx::Abi::Preview1);
         let name = func_witx.name.as_str().to_string();
@@ -53,19 +54,78 @@ impl AssemblyScriptGenerator {
             let name = "result_ptr";
             results.push((name.to_string(), ok_type));
         }
+        let params_count = params_decomposed.len();
         for result in &results {
             let mut decomposed = result.1.decompose(&result.0, true);
             params_decomposed.append(&mut decomposed);
         }
 
+        // When tracing, the import is wrapped by an exported function with the same signature
+        let raw_name = if options.trace {
+            format!("{}_raw", name).as_fn()
+        } else {
+            name.as_fn()
+        };
+        let export = if options.trace { "" } else { "export " };
         w.write_line("// @ts-ignore: decorator")?
             .write_line("@unsafe")?
             .write_line("// @ts-ignore: decorator")?
             .write_line(format!("@external(\"{}\", \"{}\")", module_name, name))?
             .indent()?
-            .write(format!("export declare function {}(", name.as_fn()))?;
+            .write(format!("{}declare function {}(", export, raw_name))?;
+        Self::define_func_params(w, &params_decomposed)?;
+        w.write_line(format!("): {};", result.error_type.as_lang()))?;
+        w.eob()?;
+
+        if options.trace {
+            w.write_line("// @ts-ignore: decorator")?
+                .write_line("@unsafe")?
+                .indent()?
+                .write(format!("export function {}(", name.as_fn()))?;
+            Self::define_func_params(w, &params_decomposed)?;
+            w.write_line(format!("): {} {{", result.error_type.as_lang()))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!(
+                    "const res = {}({});",
+                    raw_name,
+                    params_decomposed
+                        .iter()
+                        .map(|param| param.name.as_var())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))?;
+                let mut message = format!("\"[{}] {}(", module_name, name);
+                for (i, param) in params_decomposed[..params_count].iter().enumerate() {
+                    if i > 0 {
+                        message.push_str(", ");
+                    }
+                    message.push_str(&format!(
+                        "{}=\" + {}.toString() + \"",
+                        param.name.as_var(),
+                        param.name.as_var()
+                    ));
+                }
+                message.push_str(") -> \" + res.toString()");
+                w.write_line(format!("console.log({});", message))?;
+                w.write_line("return res;")?;
+            }
+            w.write_line("}")?;
+            w.eob()?;
+        }
+
+        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
+        let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
+        assert_eq!(params_count_witx, params_decomposed.len() + 1);
+
+        Ok(())
+    }
 
-        if !params_decomposed.is_empty() || !results.is_empty() {
+    fn define_func_params<T: Write>(
+        w: &mut PrettyWriter<T>,
+        params_decomposed: &[ASTypeDecomposed],
+    ) -> Result<(), Error> {
+        if !params_decomposed.is_empty() {
             w.eol()?;
         }
         for (i, param) in params_decomposed.iter().enumerate() {
@@ -81,14 +141,6 @@ impl AssemblyScriptGenerator {
                 eol
             ))?;
         }
-
-        w.write_line(format!("): {};", result.error_type.as_lang()))?;
-        w.eob()?;
-
-        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
-        let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
-        assert_eq!(params_count_witx, params_decomposed.len() + 1);
-
         Ok(())
     }
 }
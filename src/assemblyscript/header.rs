@@ -67,6 +67,61 @@ export class WasiMutSlice<T> {
         this.length = array.byteLength;
     }
 }
+
+/**
+ * Pointer to the data of an `ArrayBuffer` or of a typed array
+ */
+export function wasiDataPtr<B>(buf: B): usize {
+    if (buf instanceof ArrayBuffer) {
+        return changetype<usize>(buf);
+    } else {
+        // @ts-ignore: ArrayBufferView
+        return buf.dataStart;
+    }
+}
+
+/**
+ * The value returned by a function, or the error code it returned instead
+ */
+export class WasiResult<T, E> {
+    error: E;
+    value: T;
+
+    constructor(error: E, value: T) {
+        this.error = error;
+        this.value = value;
+    }
+
+    static ok<T, E>(value: T): WasiResult<T, E> {
+        return new WasiResult<T, E>(<E>0, value);
+    }
+
+    static err<T, E>(error: E): WasiResult<T, E> {
+        if (isReference<T>()) {
+            return new WasiResult<T, E>(error, changetype<T>(0));
+        } else {
+            return new WasiResult<T, E>(error, <T>0);
+        }
+    }
+
+    isOk(): bool {
+        return this.error == <E>0;
+    }
+
+    isErr(): bool {
+        return this.error != <E>0;
+    }
+
+    /**
+     * Return the value, or abort if the function returned an error
+     */
+    unwrap(): T {
+        if (this.isErr()) {
+            throw new Error(\"WASI error \" + this.error.toString());
+        }
+        return this.value;
+    }
+}
 ",
         )?
         .eob()?;
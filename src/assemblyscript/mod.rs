@@ -1,9 +1,10 @@
-mod common;
+pub(crate) mod common;
 mod function;
 mod header;
 mod r#struct;
 mod tuple;
 mod union;
+mod wrapper;
 
 use std::io::Write;
 
@@ -39,6 +40,8 @@ impl<T: Write> Generator<T> for AssemblyScriptGenerator {
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
+        names::check_name_collisions(&module_witx, skip_imports, &NAME_CONVERSIONS)?;
+
         if !options.skip_header {
             Self::header(&mut w)?;
         }
@@ -71,9 +74,11 @@ impl<T: Write> Generator<T> for AssemblyScriptGenerator {
         }
 
         for func in module_witx.funcs() {
-            Self::define_func(&mut w, &module_name, func.as_ref())?;
+            Self::define_func(&mut w, &module_name, func.as_ref(), options)?;
         }
 
+        Self::define_wrappers(&mut w, &module_name, &module_witx)?;
+
         Ok(())
     }
 }
@@ -11,32 +11,122 @@ impl AssemblyScriptGenerator {
         w.write_line("// @ts-ignore: decorator")?
             .write_line("@unmanaged")?
             .write_line(format!("export class {} {{", name.as_type()))?;
+        let size = ASType::Struct(members.to_vec()).mem_size();
+        let members: Vec<_> = members
+            .iter()
+            .map(|member| (member.name.as_var(), member.type_.as_ref(), member.offset))
+            .collect();
+        Self::define_record_body(&mut w.new_block(), name, &members, size)?;
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+
+    /// Records are stored as opaque padding, and their members are accessed through their
+    /// offsets, so that the layout always matches the WebAssembly one, even for strings,
+    /// slices and nested records, that AssemblyScript would otherwise store as references.
+    pub fn define_record_body<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[(String, &ASType, usize)],
+        size: usize,
+    ) -> Result<(), Error> {
+        Self::define_storage(w, size)?;
+        w.eob()?;
+
+        let params = members
+            .iter()
+            .map(|(member_name, member_type, _)| {
+                format!("{}: {}", member_name, member_type.as_lang())
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        w.write_line(format!("constructor({}) {{", params))?;
         {
             let mut w = w.new_block();
-            for member in members {
-                let member_type = member.type_.as_ref();
-                w.write_line(format!(
-                    "{}: {};",
-                    member.name.as_var(),
-                    member_type.as_lang()
-                ))?;
-
-                let pad_len = member.padding;
-                for i in 0..(pad_len & 1) {
-                    w.write_line(format!("private __pad8_{}: u8;", i))?;
-                }
-                for i in 0..(pad_len & 3) / 2 {
-                    w.write_line(format!("private __pad16_{}: u16;", i))?;
-                }
-                for i in 0..(pad_len & 7) / 4 {
-                    w.write_line(format!("private __pad32_{}: u32;", i))?;
-                }
-                for i in 0..pad_len / 8 {
-                    w.write_line(format!("private __pad64_{}: u64;", i))?;
-                }
+            for (member_name, _, _) in members {
+                w.write_line(format!("this.{} = {};", member_name, member_name))?;
             }
         }
         w.write_line("}")?.eob()?;
+
+        w.write_line("/**")?
+            .write_line(" * The record stored at `ptr` in the guest memory, such as one written by the host")?
+            .write_line(" */")?
+            .write_line(format!("static at(ptr: usize): {} {{", name.as_type()))?
+            .indent()?
+            .write_line(format!("return changetype<{}>(ptr);", name.as_type()))?
+            .write_line("}")?;
+
+        for (member_name, member_type, offset) in members {
+            w.eob()?;
+            Self::define_record_member_accessors(w, member_name, member_type, *offset)?;
+        }
+        Ok(())
+    }
+
+    fn define_record_member_accessors<T: Write>(
+        w: &mut PrettyWriter<T>,
+        member_name: &str,
+        member_type: &ASType,
+        offset: usize,
+    ) -> Result<(), Error> {
+        let member_lang = member_type.as_lang();
+        let is_record = matches!(
+            member_type.leaf(),
+            ASType::Struct(_)
+                | ASType::Tuple(_)
+                | ASType::Union(_)
+                | ASType::Slice(_)
+                | ASType::String(_)
+                | ASType::ReadBuffer(_)
+                | ASType::WriteBuffer(_)
+        );
+        w.write_line(format!("get {}(): {} {{", member_name, member_lang))?;
+        if is_record {
+            w.new_block().write_line(format!(
+                "return changetype<{}>(changetype<usize>(this) + {});",
+                member_lang, offset
+            ))?;
+        } else {
+            w.new_block().write_line(format!(
+                "return load<{}>(changetype<usize>(this), {});",
+                member_lang, offset
+            ))?;
+        }
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!("set {}(val: {}) {{", member_name, member_lang))?;
+        if is_record {
+            w.new_block().write_line(format!(
+                "memory.copy(changetype<usize>(this) + {}, changetype<usize>(val), {});",
+                offset,
+                member_type.mem_size()
+            ))?;
+        } else {
+            w.new_block().write_line(format!(
+                "store<{}>(changetype<usize>(this), val, {});",
+                member_lang, offset
+            ))?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Opaque storage for `size` bytes, largest fields first so that no implicit padding
+    /// is inserted
+    fn define_storage<T: Write>(w: &mut PrettyWriter<T>, size: usize) -> Result<(), Error> {
+        for i in 0..size / 8 {
+            w.write_line(format!("private __pad64_{}: u64;", i))?;
+        }
+        for i in 0..(size & 7) / 4 {
+            w.write_line(format!("private __pad32_{}: u32;", i))?;
+        }
+        for i in 0..(size & 3) / 2 {
+            w.write_line(format!("private __pad16_{}: u16;", i))?;
+        }
+        for i in 0..(size & 1) {
+            w.write_line(format!("private __pad8_{}: u8;", i))?;
+        }
         Ok(())
     }
 }
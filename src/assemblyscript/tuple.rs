@@ -27,27 +27,13 @@ impl AssemblyScriptGenerator {
         w.write_line("// @ts-ignore: decorator")?
             .write_line("@unmanaged")?
             .write_line(format!("export class {} {{ // -- Tuple", name.as_type()))?;
-        {
-            let mut w = w.new_block();
-            for (i, member) in members.iter().enumerate() {
-                let member_type = member.type_.as_ref();
-                w.write_line(format!("v{}: {};", i, member_type.as_lang()))?;
-
-                let pad_len = member.padding;
-                for i in 0..(pad_len & 1) {
-                    w.write_line(format!("private __pad8_{}: u8;", i))?;
-                }
-                for i in 0..(pad_len & 3) / 2 {
-                    w.write_line(format!("private __pad16_{}: u16;", i))?;
-                }
-                for i in 0..(pad_len & 7) / 4 {
-                    w.write_line(format!("private __pad32_{}: u32;", i))?;
-                }
-                for i in 0..pad_len / 8 {
-                    w.write_line(format!("private __pad64_{}: u64;", i))?;
-                }
-            }
-        }
+        let size = ASType::Tuple(members.to_vec()).mem_size();
+        let members: Vec<_> = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| (format!("v{}", i), member.type_.as_ref(), member.offset))
+            .collect();
+        Self::define_record_body(&mut w.new_block(), name, &members, size)?;
         w.write_line("}")?.eob()?;
         Ok(())
     }
@@ -132,7 +132,8 @@ impl AssemblyScriptGenerator {
         {
             let mut w = w.new_block();
             w.write_line(format!("tag: {};", tag_repr.as_lang()))?;
-            let pad_len = union_.padding_after_tag;
+            // Members are accessed through their offset, so the padding covers them as well
+            let pad_len = union_.padding_after_tag + union_.max_member_size;
             for i in 0..(pad_len & 1) {
                 w.write_line(format!("private __pad8_{}: u8;", i))?;
             }
@@ -0,0 +1,384 @@
+use std::io::Write;
+use std::rc::Rc;
+
+use super::*;
+
+/// How a value returned through an out-parameter is stored and read back
+enum WrappedResult {
+    /// A scalar, loaded from a temporary buffer
+    Scalar { name: String, type_: Rc<ASType> },
+    /// A string, decoded from a temporary `WasiString`
+    String { name: String },
+    /// A record, allocated with `heap.alloc()` and returned as-is
+    Record { name: String, type_: Rc<ASType> },
+}
+
+impl WrappedResult {
+    fn new(name: String, type_: Rc<ASType>) -> Self {
+        match type_.leaf() {
+            ASType::String(_) => WrappedResult::String { name },
+            ASType::Struct(_)
+            | ASType::Tuple(_)
+            | ASType::Union(_)
+            | ASType::Slice(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => WrappedResult::Record { name, type_ },
+            _ => WrappedResult::Scalar { name, type_ },
+        }
+    }
+
+    fn as_lang(&self) -> String {
+        match self {
+            WrappedResult::Scalar { type_, .. } | WrappedResult::Record { type_, .. } => {
+                type_.as_lang()
+            }
+            WrappedResult::String { .. } => "string".to_string(),
+        }
+    }
+
+    fn define_storage<T: Write>(&self, w: &mut PrettyWriter<T>) -> Result<(), Error> {
+        match self {
+            WrappedResult::Scalar { name, type_ } => {
+                w.write_line(format!(
+                    "const {}_buf = new ArrayBuffer({});",
+                    name,
+                    type_.mem_size()
+                ))?;
+            }
+            WrappedResult::String { name } => {
+                w.write_line(format!(
+                    "const {}_buf = new ArrayBuffer({});",
+                    name,
+                    ASType::String(Rc::new(ASType::Char8)).mem_size()
+                ))?;
+            }
+            WrappedResult::Record { name, type_ } => {
+                w.write_line(format!(
+                    "const {} = changetype<{}>(heap.alloc({}));",
+                    name,
+                    type_.as_lang(),
+                    type_.mem_size()
+                ))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn as_arg(&self) -> String {
+        match self {
+            WrappedResult::Scalar { name, .. } | WrappedResult::String { name } => {
+                format!("changetype<usize>({}_buf)", name)
+            }
+            WrappedResult::Record { name, .. } => format!("changetype<usize>({})", name),
+        }
+    }
+
+    fn as_value(&self) -> String {
+        match self {
+            WrappedResult::Scalar { name, type_ } => {
+                format!("load<{}>(changetype<usize>({}_buf))", type_.as_lang(), name)
+            }
+            WrappedResult::String { name } => format!(
+                "changetype<WasiString>(changetype<usize>({}_buf)).toString()",
+                name
+            ),
+            WrappedResult::Record { name, .. } => name.to_string(),
+        }
+    }
+}
+
+impl AssemblyScriptGenerator {
+    /// High-level functions, accepting native strings and buffers, and returning typed
+    /// results. They are static methods of a class named after the module, so that their
+    /// names don't shadow the raw imports they call.
+    pub fn define_wrappers<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        module_witx: &witx::Module,
+    ) -> Result<(), Error> {
+        let funcs: Vec<_> = module_witx.funcs().collect();
+        if funcs.is_empty() {
+            return Ok(());
+        }
+        let class_name = module_name.as_namespace();
+
+        for func in &funcs {
+            Self::define_wrapper_results(w, &class_name, func)?;
+        }
+
+        Self::write_docs(
+            w,
+            &format!("High-level functions of the `{}` module", module_name),
+        )?;
+        w.write_line(format!("export class {} {{", class_name))?;
+        {
+            let mut w = w.new_block();
+            for (i, func) in funcs.iter().enumerate() {
+                if i > 0 {
+                    w.eob()?;
+                }
+                Self::define_wrapper(&mut w, func)?;
+            }
+        }
+        w.write_line("}")?;
+        w.eob()?;
+
+        Ok(())
+    }
+
+    /// The values returned by a function, the error type, and whether the values are a tuple
+    fn wrapper_results(func_witx: &witx::Function) -> (Vec<WrappedResult>, Rc<ASType>, bool) {
+        let result = match ASType::from(&func_witx.results[0].tref) {
+            ASType::Result(result) => result,
+            _ => unreachable!(),
+        };
+        let is_tuple = matches!(result.ok_type.leaf(), ASType::Tuple(_));
+        let results = match result.ok_type.leaf() {
+            ASType::Void => vec![],
+            ASType::Tuple(tuple_members) => tuple_members
+                .iter()
+                .enumerate()
+                .map(|(i, tuple_member)| {
+                    WrappedResult::new(format!("result{}", i), tuple_member.type_.clone())
+                })
+                .collect(),
+            _ => vec![WrappedResult::new(
+                "result".to_string(),
+                result.ok_type.clone(),
+            )],
+        };
+        (results, result.error_type.clone(), is_tuple)
+    }
+
+    fn wrapper_results_class(func_witx: &witx::Function) -> String {
+        format!("{}_results", func_witx.name.as_str()).as_type()
+    }
+
+    /// Functions returning a tuple get a class to hold its values
+    fn define_wrapper_results<T: Write>(
+        w: &mut PrettyWriter<T>,
+        class_name: &str,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let (results, _, is_tuple) = Self::wrapper_results(func_witx);
+        if !is_tuple {
+            return Ok(());
+        }
+        Self::write_docs(
+            w,
+            &format!(
+                "Values returned by `{}.{}()`",
+                class_name,
+                func_witx.name.as_str().as_fn()
+            ),
+        )?;
+        w.write_line(format!(
+            "export class {} {{",
+            Self::wrapper_results_class(func_witx)
+        ))?;
+        {
+            let mut w = w.new_block();
+            for (i, result) in results.iter().enumerate() {
+                w.write_line(format!("v{}: {};", i, result.as_lang()))?;
+            }
+            w.eob()?;
+            let params = results
+                .iter()
+                .enumerate()
+                .map(|(i, result)| format!("v{}: {}", i, result.as_lang()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            w.write_line(format!("constructor({}) {{", params))?;
+            {
+                let mut w = w.new_block();
+                for i in 0..results.len() {
+                    w.write_line(format!("this.v{} = v{};", i, i))?;
+                }
+            }
+            w.write_line("}")?;
+        }
+        w.write_line("}")?;
+        w.eob()?;
+        Ok(())
+    }
+
+    fn define_wrapper<T: Write>(
+        w: &mut PrettyWriter<T>,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let name = func_witx.name.as_str();
+        let (results, error_type, is_tuple) = Self::wrapper_results(func_witx);
+        let error_type = error_type.as_lang();
+
+        let mut generics = vec![];
+        let mut params = vec![];
+        let mut prelude = vec![];
+        let mut args = vec![];
+        for param_witx in &func_witx.params {
+            let param_name = param_witx.name.as_str().as_var();
+            let param_type = ASType::from(&param_witx.tref);
+            match param_type.leaf() {
+                ASType::Void => {}
+                ASType::String(_) => {
+                    params.push(format!("{}: string", param_name));
+                    prelude.push(format!(
+                        "const {}_buf = String.UTF8.encode({}, false);",
+                        param_name, param_name
+                    ));
+                    args.push(format!("changetype<usize>({}_buf)", param_name));
+                    args.push(format!("{}_buf.byteLength", param_name));
+                }
+                ASType::Slice(elements_type)
+                | ASType::ReadBuffer(elements_type)
+                | ASType::WriteBuffer(elements_type) => {
+                    // Any `ArrayBuffer` or typed array can be used as a buffer
+                    let generic = format!("B{}", generics.len());
+                    params.push(format!("{}: {}", param_name, generic));
+                    generics.push(generic);
+                    args.push(format!("wasiDataPtr({})", param_name));
+                    match elements_type.leaf() {
+                        ASType::U8 | ASType::Char8 => {
+                            args.push(format!("{}.byteLength", param_name))
+                        }
+                        _ => args.push(format!(
+                            "<usize>{}.byteLength / {}",
+                            param_name,
+                            elements_type.mem_size()
+                        )),
+                    }
+                }
+                _ => {
+                    params.push(format!("{}: {}", param_name, param_type.as_lang()));
+                    args.push(param_name);
+                }
+            }
+        }
+        args.extend(results.iter().map(WrappedResult::as_arg));
+
+        let value_type = if results.is_empty() {
+            None
+        } else if is_tuple {
+            Some(Self::wrapper_results_class(func_witx))
+        } else {
+            Some(results[0].as_lang())
+        };
+        let return_type = match &value_type {
+            None => error_type.clone(),
+            Some(value_type) => format!("WasiResult<{}, {}>", value_type, error_type),
+        };
+
+        let mut docs = func_witx.docs.trim_end().to_string();
+        if results
+            .iter()
+            .any(|result| matches!(result, WrappedResult::Record { .. }))
+        {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            docs.push_str(
+                "The returned records are allocated with `heap.alloc()`, and have to be released \
+                 with `heap.free()` by the caller.",
+            );
+        }
+        Self::write_docs(w, &docs)?;
+        let generics = if generics.is_empty() {
+            "".to_string()
+        } else {
+            format!("<{}>", generics.join(", "))
+        };
+        Self::write_list(
+            w,
+            &format!("static {}{}(", name.as_fn(), generics),
+            &params,
+            &format!("): {} {{", return_type),
+        )?;
+        {
+            let mut w = w.new_block();
+            for line in &prelude {
+                w.write_line(line)?;
+            }
+            for result in &results {
+                result.define_storage(&mut w)?;
+            }
+            Self::write_list(
+                &mut w,
+                &format!("const res = {}(", name.as_fn()),
+                &args,
+                ");",
+            )?;
+
+            match value_type {
+                None => {
+                    w.write_line("return res;")?;
+                }
+                Some(value_type) => Self::define_wrapper_return(
+                    &mut w,
+                    &results,
+                    &value_type,
+                    &error_type,
+                    is_tuple,
+                )?,
+            }
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Write a list of parameters or arguments on a single line if it fits, or one per line
+    fn write_list<T: Write>(
+        w: &mut PrettyWriter<T>,
+        prefix: &str,
+        items: &[String],
+        suffix: &str,
+    ) -> Result<(), Error> {
+        let line = format!("{}{}{}", prefix, items.join(", "), suffix);
+        if w.indent_level() as usize * 4 + line.len() <= 80 {
+            w.write_line(line)?;
+            return Ok(());
+        }
+        w.write_line(prefix)?;
+        for (i, item) in items.iter().enumerate() {
+            let eol = if i + 1 == items.len() { "" } else { "," };
+            w.write_line_continued(format!("{}{}", item, eol))?;
+        }
+        w.write_line(suffix)?;
+        Ok(())
+    }
+
+    fn define_wrapper_return<T: Write>(
+        w: &mut PrettyWriter<T>,
+        results: &[WrappedResult],
+        value_type: &str,
+        error_type: &str,
+        is_tuple: bool,
+    ) -> Result<(), Error> {
+        w.write_line("if (res != 0) {")?;
+        {
+            let mut w = w.new_block();
+            for result in results {
+                if let WrappedResult::Record { name, .. } = result {
+                    w.write_line(format!("heap.free(changetype<usize>({}));", name))?;
+                }
+            }
+            w.write_line(format!(
+                "return WasiResult.err<{}, {}>(res);",
+                value_type, error_type
+            ))?;
+        }
+        w.write_line("}")?;
+        let values: Vec<_> = results.iter().map(WrappedResult::as_value).collect();
+        let ok = format!("return WasiResult.ok<{}, {}>(", value_type, error_type);
+        if is_tuple {
+            Self::write_list(
+                w,
+                &format!("const values = new {}(", value_type),
+                &values,
+                ");",
+            )?;
+            w.write_line(format!("{}values);", ok))?;
+        } else {
+            Self::write_list(w, &ok, &values, ");")?;
+        }
+        Ok(())
+    }
+}
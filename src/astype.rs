@@ -1,3 +1,5 @@
+//! Intermediate representation of WITX types, annotated with their memory layout
+
 use std::rc::Rc;
 
 use witx::Layout as _;
@@ -14,12 +16,14 @@ pub struct ASStructMember {
     pub offset: usize,
     pub type_: Rc<ASType>,
     pub padding: usize,
+    pub docs: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ASEnumChoice {
     pub name: String,
     pub value: usize,
+    pub docs: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -32,6 +36,7 @@ pub struct ASEnum {
 pub struct ASUnionMember {
     pub name: String,
     pub type_: Rc<ASType>,
+    pub docs: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -55,6 +60,7 @@ pub struct ASOption {
     pub tag_repr: Rc<ASType>,
     pub type_: Rc<ASType>,
     pub offset: usize,
+    pub payload_size: usize,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -64,6 +70,7 @@ pub struct ASResult {
     pub ok_type: Rc<ASType>,
     pub result_offset: usize,
     pub padding_after_tag: usize,
+    pub max_result_size: usize,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -180,18 +187,17 @@ impl From<&witx::Type> for ASType {
                     };
                     tuple_members.push(member);
                 }
-                // Perform a second pass to compute padding between members
-                let n = if layout_witx.is_empty() {
-                    0
-                } else {
-                    layout_witx.len() - 1
-                };
-                for (i, member_witx) in layout_witx.iter().enumerate().take(n) {
+                // Perform a second pass to compute padding between members, and after the
+                // last member up to the size of the record
+                let full_size = record.mem_size(true);
+                for (i, member_witx) in layout_witx.iter().enumerate() {
                     let member_tref = &member_witx.member.tref;
                     let member_size = member_tref.mem_size(true);
-                    let member_padding =
-                        layout_witx[i + 1].offset - member_witx.offset - member_size;
-                    tuple_members[i].padding = member_padding;
+                    let next_offset = match layout_witx.get(i + 1) {
+                        None => full_size,
+                        Some(next_member_witx) => next_member_witx.offset,
+                    };
+                    tuple_members[i].padding = next_offset - member_witx.offset - member_size;
                 }
                 ASType::Tuple(tuple_members)
             }
@@ -209,21 +215,21 @@ impl From<&witx::Type> for ASType {
                         offset: member_offset,
                         type_: Rc::new(ASType::from(member_tref)),
                         padding: 0,
+                        docs: member_witx.member.docs.clone(),
                     };
                     struct_members.push(member);
                 }
-                // Perform a second pass to compute padding between members
-                let n = if layout_witx.is_empty() {
-                    0
-                } else {
-                    layout_witx.len() - 1
-                };
-                for (i, member_witx) in layout_witx.iter().enumerate().take(n) {
+                // Perform a second pass to compute padding between members, and after the
+                // last member up to the size of the record
+                let full_size = record.mem_size(true);
+                for (i, member_witx) in layout_witx.iter().enumerate() {
                     let member_tref = &member_witx.member.tref;
                     let member_size = member_tref.mem_size(true);
-                    let member_padding =
-                        layout_witx[i + 1].offset - member_witx.offset - member_size;
-                    struct_members[i].padding = member_padding;
+                    let next_offset = match layout_witx.get(i + 1) {
+                        None => full_size,
+                        Some(next_member_witx) => next_member_witx.offset,
+                    };
+                    struct_members[i].padding = next_offset - member_witx.offset - member_size;
                 }
                 ASType::Struct(struct_members)
             }
@@ -263,6 +269,7 @@ impl From<&witx::Type> for ASType {
                     let choice = ASEnumChoice {
                         name: choice_name,
                         value: idx,
+                        docs: choice_witx.docs.clone(),
                     };
                     choices.push(choice);
                 }
@@ -287,16 +294,16 @@ impl From<&witx::Type> for ASType {
             {
                 let tag_repr = ASType::from(variant.tag_repr);
                 let option_offset = variant.payload_offset(true);
-                assert_eq!(variant.cases.len(), 1);
-                let option_tref = &variant.cases[0].tref;
-                let option_type = match &option_tref {
+                let option_type = match variant.as_option() {
                     None => ASType::Void,
                     Some(type_witx) => ASType::from(type_witx),
                 };
+                let payload_size = variant.mem_size(true) - option_offset;
                 ASType::Option(ASOption {
                     tag_repr: Rc::new(tag_repr),
                     offset: option_offset,
                     type_: Rc::new(option_type),
+                    payload_size,
                 })
             }
             witx::Type::Variant(variant)
@@ -318,13 +325,14 @@ impl From<&witx::Type> for ASType {
                     None => ASType::Void,
                     Some(type_witx) => ASType::from(type_witx),
                 };
-                let full_size = variant.mem_size(true);
                 let tag_size = variant.tag_repr.mem_size(true);
-                let padding_after_tag = full_size - tag_size;
+                let padding_after_tag = result_offset - tag_size;
+                let max_result_size = variant.mem_size(true) - result_offset;
                 ASType::Result(ASResult {
                     tag_repr: Rc::new(tag_repr),
                     result_offset,
                     padding_after_tag,
+                    max_result_size,
                     error_type: Rc::new(error_type),
                     ok_type: Rc::new(ok_type),
                 })
@@ -344,12 +352,13 @@ impl From<&witx::Type> for ASType {
                     let member = ASUnionMember {
                         name: member_name,
                         type_: Rc::new(member_type),
+                        docs: member_witx.docs.clone(),
                     };
                     members.push(member);
                 }
                 let full_size = variant.mem_size(true);
                 let tag_size = variant.tag_repr.mem_size(true);
-                let padding_after_tag = full_size - tag_size;
+                let padding_after_tag = member_offset - tag_size;
                 let max_member_size = full_size - member_offset;
                 ASType::Union(ASUnion {
                     tag_repr: Rc::new(tag_repr),
@@ -411,6 +420,40 @@ impl ASType {
         }
     }
 
+    /// Size of a value in the guest memory, including the padding of records
+    pub fn mem_size(&self) -> usize {
+        match self {
+            ASType::Void => 0,
+            ASType::Alias(alias) => alias.type_.mem_size(),
+            ASType::Bool | ASType::Char8 | ASType::U8 | ASType::S8 => 1,
+            ASType::U16 | ASType::S16 => 2,
+            ASType::Char32
+            | ASType::USize
+            | ASType::F32
+            | ASType::U32
+            | ASType::S32
+            | ASType::Handle(_)
+            | ASType::ConstPtr(_)
+            | ASType::MutPtr(_) => 4,
+            ASType::F64 | ASType::U64 | ASType::S64 => 8,
+            ASType::Constants(constants) => constants.repr.mem_size(),
+            ASType::Enum(enum_) => enum_.repr.mem_size(),
+            ASType::Struct(members) => members.last().map_or(0, |member| {
+                member.offset + member.type_.mem_size() + member.padding
+            }),
+            ASType::Tuple(members) => members.last().map_or(0, |member| {
+                member.offset + member.type_.mem_size() + member.padding
+            }),
+            ASType::Union(union_) => union_.member_offset + union_.max_member_size,
+            ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => 8,
+            ASType::Option(option) => option.offset + option.payload_size,
+            ASType::Result(result) => result.result_offset + result.max_result_size,
+        }
+    }
+
     pub fn decompose(&self, name: &str, as_mut_pointers: bool) -> Vec<ASTypeDecomposed> {
         let leaf = self.leaf();
 
@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::*;
+use crate::names::NamingPolicy;
+use crate::{generate, generate_to_writer, is_wit_input, Config, OutputType, RustTrait};
+
+/// Generate code from a build script:
+///
+/// ```no_run
+/// witx_codegen::Builder::new()
+///     .witx("witx/crypto.witx")
+///     .rust()
+///     .out_file(format!("{}/crypto.rs", std::env::var("OUT_DIR").unwrap()))
+///     .generate()
+///     .unwrap();
+/// ```
+///
+/// Unless disabled with `rerun_if_changed(false)`, `cargo:rerun-if-changed` lines are
+/// printed for all the input files, including the WITX files they `use`.
+#[derive(Debug, Clone)]
+pub struct Builder {
+    config: Config,
+    rerun_if_changed: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder {
+    /// Creates a builder for Rust code, without any input files
+    pub fn new() -> Self {
+        Builder {
+            config: Config {
+                output_type: OutputType::Rust,
+                ..Default::default()
+            },
+            rerun_if_changed: true,
+        }
+    }
+
+    /// Adds a WITX file, a WIT file or a WIT package directory to the inputs
+    pub fn witx<P: AsRef<Path>>(mut self, path: P) -> Self {
+        let path = path.as_ref().to_string_lossy().to_string();
+        self.config.witx_files.push(path);
+        self
+    }
+
+    pub fn output_type(mut self, output_type: OutputType) -> Self {
+        self.config.output_type = output_type;
+        self
+    }
+
+    pub fn assemblyscript(self) -> Self {
+        self.output_type(OutputType::AssemblyScript)
+    }
+
+    pub fn rust(self) -> Self {
+        self.output_type(OutputType::Rust)
+    }
+
+    pub fn zig(self) -> Self {
+        self.output_type(OutputType::Zig)
+    }
+
+    pub fn cpp(self) -> Self {
+        self.output_type(OutputType::Cpp)
+    }
+
+    pub fn wat(self) -> Self {
+        self.output_type(OutputType::Wat)
+    }
+
+    /// Sets the output file. The standard output is used if no file is set.
+    pub fn out_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.config.output_file = Some(path.as_ref().to_string_lossy().to_string());
+        self
+    }
+
+    /// Sets the module name to use instead of reading it from the input files
+    pub fn module_name<S: Into<String>>(mut self, module_name: S) -> Self {
+        self.config.module_name = Some(module_name.into());
+        self
+    }
+
+    pub fn skip_imports(mut self, skip_imports: bool) -> Self {
+        self.config.flags.skip_imports = skip_imports;
+        self
+    }
+
+    pub fn skip_header(mut self, skip_header: bool) -> Self {
+        self.config.flags.skip_header = skip_header;
+        self
+    }
+
+    /// Calls mock implementations of the imports on native targets (Rust)
+    pub fn mocks(mut self, mocks: bool) -> Self {
+        self.config.flags.mocks = mocks;
+        self
+    }
+
+    /// Logs the calls to the imports (AssemblyScript, Rust, Zig)
+    pub fn trace(mut self, trace: bool) -> Self {
+        self.config.flags.trace = trace;
+        self
+    }
+
+    /// Generates functions returning `errno` instead of import declarations (WAT)
+    pub fn stub_errno(mut self, errno: Option<u64>) -> Self {
+        self.config.flags.stub_errno = errno;
+        self
+    }
+
+    /// Generates handle wrappers calling the close function when dropped (Rust, C++, Zig)
+    pub fn raii(mut self, raii: bool) -> Self {
+        self.config.flags.raii = raii;
+        self
+    }
+
+    /// Also exposes functions taking a handle as methods of the handle type (Rust, Zig)
+    pub fn methods(mut self, methods: bool) -> Self {
+        self.config.flags.methods = methods;
+        self
+    }
+
+    /// Selects the traits implemented for structures, tuples and unions (Rust)
+    pub fn derive(mut self, traits: &[RustTrait]) -> Self {
+        self.config.flags.derive = Some(traits.to_vec());
+        self
+    }
+
+    pub fn naming_policy(mut self, naming_policy: NamingPolicy) -> Self {
+        self.config.flags.naming_policy = Some(naming_policy);
+        self
+    }
+
+    /// Sets whether `cargo:rerun-if-changed` lines should be printed
+    pub fn rerun_if_changed(mut self, rerun_if_changed: bool) -> Self {
+        self.rerun_if_changed = rerun_if_changed;
+        self
+    }
+
+    /// Returns the configuration built so far
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Returns the input files, and the WITX files they transitively `use`
+    pub fn dependencies(&self) -> Result<Vec<PathBuf>, Error> {
+        let mut dependencies = vec![];
+        for witx_file in &self.config.witx_files {
+            for dependency in input_dependencies(Path::new(witx_file))? {
+                if !dependencies.contains(&dependency) {
+                    dependencies.push(dependency);
+                }
+            }
+        }
+        Ok(dependencies)
+    }
+
+    pub fn generate(self) -> Result<(), Error> {
+        if self.rerun_if_changed {
+            for dependency in self.dependencies()? {
+                println!("cargo:rerun-if-changed={}", dependency.display());
+            }
+        }
+        generate(&self.config)
+    }
+
+    /// Returns the generated code instead of writing it to the output file.
+    /// `cargo:rerun-if-changed` lines are not printed.
+    pub fn generate_to_string(&self) -> Result<String, Error> {
+        let mut output = vec![];
+        generate_to_writer(&self.config, &mut output)?;
+        Ok(String::from_utf8_lossy(&output).into_owned())
+    }
+}
+
+/// Returns an input file, and the WITX files it transitively `use`s.
+/// These are resolved like witnext does: `(use ... from $module)` loads
+/// `module.witx` from the directory of the file containing the declaration.
+fn input_dependencies(path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut dependencies = vec![];
+    if is_wit_input(path) {
+        // Cargo checks all the files of a directory
+        dependencies.push(path.to_path_buf());
+        return Ok(dependencies);
+    }
+    let mut visited = HashSet::new();
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(path) = pending.pop() {
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+        let source = fs::read_to_string(&path)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        for module_name in used_modules(&source) {
+            pending.push(dir.join(module_name).with_extension("witx"));
+        }
+        dependencies.push(path);
+    }
+    Ok(dependencies)
+}
+
+/// Names of the modules referenced by `(use ... from $module)` declarations
+fn used_modules(source: &str) -> Vec<&str> {
+    let tokens = source
+        .lines()
+        .map(|line| line.split(";;").next().unwrap())
+        .flat_map(|line| line.split(|c: char| c.is_whitespace() || c == '(' || c == ')'))
+        .filter(|token| !token.is_empty());
+    let mut modules = vec![];
+    let mut previous = "";
+    for token in tokens {
+        if previous == "from" {
+            if let Some(module_name) = token.strip_prefix('$') {
+                modules.push(module_name);
+            }
+        }
+        previous = token;
+    }
+    modules
+}
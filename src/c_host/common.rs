@@ -0,0 +1,184 @@
+use convert_case::Case;
+
+use crate::astype::*;
+use crate::names::{apply_naming_policy, NameConversions, NameKind};
+
+pub trait Normalize {
+    fn as_str(&self) -> &str;
+
+    /// Type names get the usual `_t` suffix
+    fn as_type(&self) -> String {
+        escape_reserved_word(&format!(
+            "{}_t",
+            apply_naming_policy(NameKind::Type, self.as_str(), Case::Snake)
+        ))
+    }
+
+    fn as_fn(&self) -> String {
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Function,
+            self.as_str(),
+            Case::Snake,
+        ))
+    }
+
+    fn as_var(&self) -> String {
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Variable,
+            self.as_str(),
+            Case::Snake,
+        ))
+    }
+
+    fn as_const(&self) -> String {
+        apply_naming_policy(NameKind::Constant, self.as_str(), Case::UpperSnake)
+    }
+
+    fn as_namespace(&self) -> String {
+        apply_naming_policy(NameKind::Namespace, self.as_str(), Case::Snake)
+    }
+}
+
+impl<T: AsRef<str>> Normalize for T {
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// The identifiers of this backend, checked for collisions
+pub const NAME_CONVERSIONS: NameConversions = NameConversions {
+    type_: |name| name.as_type(),
+    function: |name| name.as_fn(),
+    variable: |name| name.as_var(),
+    constant: |name| name.as_const(),
+    namespace: |name| name.as_namespace(),
+};
+
+/// Follows aliases up to the actual type
+pub fn resolve(type_: &ASType) -> &ASType {
+    match type_ {
+        ASType::Alias(alias) => resolve(alias.type_.as_ref()),
+        _ => type_,
+    }
+}
+
+pub trait ToLanguageRepresentation {
+    fn as_astype(&self) -> &ASType;
+
+    /// The C type of a value stored in the guest memory, where pointers are 32-bit offsets
+    fn as_lang(&self) -> String {
+        match self.as_astype() {
+            ASType::Alias(alias) => alias.name.as_type(),
+            ASType::Bool => "bool".to_string(),
+            ASType::Char32 => "uint32_t".to_string(),
+            ASType::Char8 => "uint8_t".to_string(),
+            ASType::F32 => "float".to_string(),
+            ASType::F64 => "double".to_string(),
+            ASType::Handle(_resource_name) => "wasi_handle_t".to_string(),
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => "wasi_guest_ptr_t".to_string(),
+            ASType::Option(_) => todo!(),
+            ASType::Result(_) => todo!(),
+            ASType::S8 => "int8_t".to_string(),
+            ASType::S16 => "int16_t".to_string(),
+            ASType::S32 => "int32_t".to_string(),
+            ASType::S64 => "int64_t".to_string(),
+            ASType::U8 => "uint8_t".to_string(),
+            ASType::U16 => "uint16_t".to_string(),
+            ASType::U32 => "uint32_t".to_string(),
+            ASType::U64 => "uint64_t".to_string(),
+            ASType::USize => "wasi_size_t".to_string(),
+            ASType::Void => "void".to_string(),
+            ASType::Constants(constants) => constants.repr.as_lang(),
+            ASType::Enum(enum_) => enum_.repr.as_lang(),
+            ASType::Struct(_) => unimplemented!(),
+            ASType::Tuple(_) => unimplemented!(),
+            ASType::Union(_) => unimplemented!(),
+            ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => "wasi_guest_slice_t".to_string(),
+        }
+    }
+}
+
+impl ToLanguageRepresentation for ASType {
+    fn as_astype(&self) -> &ASType {
+        self
+    }
+}
+
+/// Checks whether the given word is a reserved keyword.
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED.contains(&word)
+}
+
+/// Checks the given word against a list of reserved keywords.
+/// If the given word conflicts with a keyword, a trailing underscore will be
+/// appended.
+pub fn escape_reserved_word(word: &str) -> String {
+    if is_reserved_word(word) {
+        format!("{}_", word)
+    } else {
+        word.to_string()
+    }
+}
+
+/// C keywords, standard types, and names used by the generated code
+const RESERVED: &[&str] = &[
+    "auto",
+    "bool",
+    "break",
+    "case",
+    "char",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "exec_env",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "goto",
+    "host",
+    "if",
+    "inline",
+    "int",
+    "int16_t",
+    "int32_t",
+    "int64_t",
+    "int8_t",
+    "intptr_t",
+    "long",
+    "ptrdiff_t",
+    "register",
+    "restrict",
+    "return",
+    "short",
+    "signed",
+    "size_t",
+    "sizeof",
+    "ssize_t",
+    "static",
+    "struct",
+    "switch",
+    "true",
+    "typedef",
+    "uint16_t",
+    "uint32_t",
+    "uint64_t",
+    "uint8_t",
+    "uintptr_t",
+    "union",
+    "unsigned",
+    "void",
+    "volatile",
+    "wasi_guest_ptr_t",
+    "wasi_guest_slice_t",
+    "wasi_handle_t",
+    "wasi_size_t",
+    "while",
+];
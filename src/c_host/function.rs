@@ -0,0 +1,417 @@
+use std::io::Write;
+use std::rc::Rc;
+
+use witx::{CallMode, WasmType};
+
+use super::*;
+
+/// A function, with its parameters as they are seen by the host implementation
+struct HostFunc {
+    name: String,
+    docs: String,
+    params: Vec<(String, ASType)>,
+    results: Vec<(String, Rc<ASType>)>,
+    error_type: Rc<ASType>,
+    signature: witx::WasmSignature,
+}
+
+/// A parameter of a callback, and how the wrapper computes it from the core WebAssembly
+/// values
+struct NativeParam {
+    declaration: String,
+    arg: String,
+    /// Guest pointer, number of elements and size of an element, if the argument is a
+    /// pointer to validate and translate
+    range: Option<(String, String, usize)>,
+}
+
+impl HostFunc {
+    fn new(func_witx: &witx::Function) -> Self {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
+        let mut params = vec![];
+        for param_witx in &func_witx.params {
+            let param_name = param_witx.name.as_str();
+            let param_type = ASType::from(&param_witx.tref);
+            params.push((param_name.to_string(), param_type));
+        }
+
+        let results_witx = &func_witx.results;
+        assert_eq!(results_witx.len(), 1);
+        let result = match ASType::from(&results_witx[0].tref) {
+            ASType::Result(result) => result,
+            _ => unreachable!(),
+        };
+        let ok_type = result.ok_type.clone();
+        let mut results = vec![];
+        // A tuple in a result is expanded into additional parameters, transformed to
+        // pointers
+        if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
+            for (i, tuple_member) in tuple_members.iter().enumerate() {
+                let name = format!("result{}_ptr", i);
+                results.push((name, tuple_member.type_.clone()));
+            }
+        } else {
+            let name = "result_ptr";
+            results.push((name.to_string(), ok_type));
+        }
+
+        HostFunc {
+            name: func_witx.name.as_str().to_string(),
+            docs: func_witx.docs.clone(),
+            params,
+            results,
+            error_type: result.error_type,
+            signature: func_witx.wasm_signature(CallMode::DefinedImport),
+        }
+    }
+
+    /// Names of the core WebAssembly parameters
+    fn wasm_params_names(&self) -> Vec<String> {
+        let mut names = vec![];
+        for param in &self.params {
+            for decomposed in param.1.decompose(&param.0, false) {
+                names.push(decomposed.name.as_var());
+            }
+        }
+        for result in &self.results {
+            for decomposed in result.1.decompose(&result.0, true) {
+                names.push(decomposed.name.as_var());
+            }
+        }
+        assert_eq!(names.len(), self.signature.params.len());
+        names
+    }
+
+    /// Parameters of the callback implementing the function
+    fn native_params(&self) -> Vec<NativeParam> {
+        let mut native_params = vec![];
+        for (name, type_) in &self.params {
+            let ptr = format!("{}_ptr", name).as_var();
+            let len = format!("{}_len", name).as_var();
+            let (element_declaration, element_type) = match type_.leaf() {
+                ASType::Void => continue,
+                ASType::String(_) => ("const char".to_string(), &ASType::Char8),
+                ASType::Slice(elements_type) | ASType::ReadBuffer(elements_type) => (
+                    format!("const {}", elements_type.as_lang()),
+                    elements_type.as_ref(),
+                ),
+                ASType::WriteBuffer(elements_type) => {
+                    (elements_type.as_lang(), elements_type.as_ref())
+                }
+                _ => {
+                    native_params.push(native_value(&name.as_var(), type_));
+                    continue;
+                }
+            };
+            native_params.push(NativeParam {
+                declaration: format!("{} *{}", element_declaration, ptr),
+                arg: format!("({} *) {}_native", element_declaration, ptr),
+                range: Some((ptr, len.clone(), element_type.mem_size())),
+            });
+            native_params.push(NativeParam {
+                declaration: format!("uint32_t {}", len),
+                arg: len,
+                range: None,
+            });
+        }
+        for (name, type_) in &self.results {
+            if let ASType::Void = type_.leaf() {
+                continue;
+            }
+            let name = name.as_var();
+            native_params.push(NativeParam {
+                declaration: format!("{} *{}", type_.as_lang(), name),
+                arg: format!("({} *) {}_native", type_.as_lang(), name),
+                range: Some((name, "1".to_string(), type_.mem_size())),
+            });
+        }
+        native_params
+    }
+
+    /// The signature string of the native symbol
+    fn signature_string(&self) -> String {
+        let params: String = self
+            .signature
+            .params
+            .iter()
+            .map(|type_| signature_char(*type_))
+            .collect();
+        let results: String = self
+            .signature
+            .results
+            .iter()
+            .map(|type_| signature_char(*type_))
+            .collect();
+        format!("({}){}", params, results)
+    }
+}
+
+/// A parameter passed by value, or a record passed as a pointer
+fn native_value(name: &str, type_: &ASType) -> NativeParam {
+    match resolve(type_) {
+        ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => NativeParam {
+            declaration: format!("const {} *{}", type_.as_lang(), name),
+            arg: format!("(const {} *) {}_native", type_.as_lang(), name),
+            range: Some((name.to_string(), "1".to_string(), type_.mem_size())),
+        },
+        _ => NativeParam {
+            declaration: format!("{} {}", type_.as_lang(), name),
+            arg: format!("({}) {}", type_.as_lang(), name),
+            range: None,
+        },
+    }
+}
+
+fn signature_char(type_: WasmType) -> char {
+    match type_ {
+        WasmType::I32 => 'i',
+        WasmType::I64 => 'I',
+        WasmType::F32 => 'f',
+        WasmType::F64 => 'F',
+    }
+}
+
+fn wasm_type(type_: WasmType) -> &'static str {
+    match type_ {
+        WasmType::I32 => "uint32_t",
+        WasmType::I64 => "uint64_t",
+        WasmType::F32 => "float",
+        WasmType::F64 => "double",
+    }
+}
+
+impl CHostGenerator {
+    /// Defines the structure of callbacks implementing the functions, and declares the
+    /// registration function
+    pub fn define_host_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        funcs: &[Rc<witx::Function>],
+    ) -> Result<(), Error> {
+        let host_type = format!("{}_host", module_name);
+        Self::write_docs(
+            w,
+            &format!(
+                "Host implementation of the [{}] module\n\nGuest pointers are checked and translated to native pointers before the callbacks are\ncalled. Callbacks left to `NULL` raise an exception in the guest.",
+                module_name
+            ),
+        )?;
+        w.write_line(format!("typedef struct {} {{", host_type.as_fn()))?;
+        {
+            let mut w = w.new_block();
+            for (i, func) in funcs.iter().enumerate() {
+                let func = HostFunc::new(func);
+                if i > 0 {
+                    w.eob()?;
+                }
+                Self::write_docs(&mut w, &func.docs)?;
+                w.write_line(format!(
+                    "{} (*{})(",
+                    func.error_type.as_lang(),
+                    func.name.as_fn()
+                ))?;
+                let mut declarations = vec!["wasm_exec_env_t exec_env".to_string()];
+                declarations.extend(
+                    func.native_params()
+                        .into_iter()
+                        .map(|param| param.declaration),
+                );
+                Self::write_params(&mut w, &declarations, ");")?;
+            }
+        }
+        w.write_line(format!("}} {};", host_type.as_type()))?;
+        w.eob()?;
+
+        Self::write_docs(
+            &mut w.clone(),
+            &format!(
+                "Registers the native symbols of the [{}] module, calling the callbacks of `host`\n\n`host` must remain valid as long as the runtime is used.",
+                module_name
+            ),
+        )?;
+        w.write_line(format!(
+            "bool {}(const {} *host);",
+            format!("{}_register_natives", module_name).as_fn(),
+            host_type.as_type()
+        ))?;
+        w.eob()?;
+        Ok(())
+    }
+
+    /// Writes parameters, one per line
+    fn write_params<T: Write>(
+        w: &mut PrettyWriter<T>,
+        declarations: &[String],
+        end: &str,
+    ) -> Result<(), Error> {
+        for (i, declaration) in declarations.iter().enumerate() {
+            let eol = if i + 1 == declarations.len() {
+                end
+            } else {
+                ","
+            };
+            w.write_line_continued(format!("{}{}", declaration, eol))?;
+        }
+        Ok(())
+    }
+
+    /// Defines the wrappers of the callbacks, the table of native symbols, and the
+    /// registration function
+    pub fn define_wrappers<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        funcs: &[Rc<witx::Function>],
+    ) -> Result<(), Error> {
+        let host_type = format!("{}_host", module_name).as_type();
+        let symbols = format!("{}_native_symbols", module_name).as_fn();
+        let mut entries = vec![];
+        for func in funcs {
+            let func = HostFunc::new(func);
+            let wrapper = format!("{}_{}_wrapper", module_name, func.name).as_fn();
+            Self::define_wrapper(w, module_name, &host_type, &wrapper, &func)?;
+            entries.push(format!(
+                "{{ \"{}\", (void *) {}, \"{}\", NULL }},",
+                func.name,
+                wrapper,
+                func.signature_string()
+            ));
+        }
+
+        if !entries.is_empty() {
+            w.write_line(format!("static NativeSymbol {}[] = {{", symbols))?;
+            {
+                let mut w = w.new_block();
+                for entry in &entries {
+                    w.write_line(entry)?;
+                }
+            }
+            w.write_line("};")?;
+            w.eob()?;
+        }
+
+        w.write_line("bool")?;
+        w.write_line(format!(
+            "{}(const {} *host)",
+            format!("{}_register_natives", module_name).as_fn(),
+            host_type
+        ))?;
+        w.write_line("{")?;
+        {
+            let mut w = w.new_block();
+            if entries.is_empty() {
+                w.write_line("(void) host;")?;
+                w.write_line("return true;")?;
+            } else {
+                w.write_line(format!(
+                    "const size_t n = sizeof {} / sizeof {}[0];",
+                    symbols, symbols
+                ))?;
+                w.write_line("size_t i;")?;
+                w.eob()?;
+                w.write_line(
+                    "/* The callbacks are found through the attachments of the symbols */",
+                )?;
+                w.write_line("for (i = 0; i < n; i++) {")?;
+                w.new_block()
+                    .write_line(format!("{}[i].attachment = (void *) host;", symbols))?;
+                w.write_line("}")?;
+                w.write_line(format!(
+                    "return wasm_runtime_register_natives(\"{}\", {}, (uint32_t) n);",
+                    module_name, symbols
+                ))?;
+            }
+        }
+        w.write_line("}")?;
+        w.eob()?;
+        Ok(())
+    }
+
+    fn define_wrapper<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        host_type: &str,
+        wrapper: &str,
+        func: &HostFunc,
+    ) -> Result<(), Error> {
+        assert!(func.signature.results.len() <= 1);
+        let return_type = match func.signature.results.first() {
+            None => "void",
+            Some(type_) => wasm_type(*type_),
+        };
+        let failure = if func.signature.results.is_empty() {
+            "return;"
+        } else {
+            "return 0;"
+        };
+        w.write_line(format!("static {}", return_type))?;
+        w.write_line(format!("{}(", wrapper))?;
+        let mut declarations = vec!["wasm_exec_env_t exec_env".to_string()];
+        declarations.extend(
+            func.wasm_params_names()
+                .into_iter()
+                .zip(func.signature.params.iter())
+                .map(|(name, type_)| format!("{} {}", wasm_type(*type_), name)),
+        );
+        Self::write_params(w, &declarations, ")")?;
+        w.write_line("{")?;
+        {
+            let mut w = w.new_block();
+            let native_params = func.native_params();
+            let ranges: Vec<_> = native_params
+                .iter()
+                .filter_map(|param| param.range.as_ref())
+                .collect();
+            w.write_line(format!(
+                "const {} *host = wasm_runtime_get_function_attachment(exec_env);",
+                host_type
+            ))?;
+            for (ptr, _, _) in &ranges {
+                w.write_line(format!("void *{}_native = NULL;", ptr))?;
+            }
+            w.eob()?;
+
+            let callback = format!("host->{}", func.name.as_fn());
+            w.write_line(format!("if ({} == NULL) {{", callback))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),")?;
+                w.write_line_continued(format!(
+                    "\"{}.{} is not implemented\");",
+                    module_name, func.name
+                ))?;
+                w.write_line(failure)?;
+            }
+            w.write_line("}")?;
+
+            for (i, (ptr, len, element_size)) in ranges.iter().enumerate() {
+                let check = format!(
+                    "!wasi_guest_range(exec_env, {}, {}, {}, &{}_native)",
+                    ptr, len, element_size, ptr
+                );
+                let eol = if i + 1 == ranges.len() { ") {" } else { " ||" };
+                if i == 0 {
+                    w.write_line(format!("if ({}{}", check, eol))?;
+                } else {
+                    w.write_line_continued(format!("{}{}", check, eol))?;
+                }
+            }
+            if !ranges.is_empty() {
+                w.new_block().write_line(failure)?;
+                w.write_line("}")?;
+            }
+
+            let call = if func.signature.results.is_empty() {
+                format!("{}(", callback)
+            } else {
+                format!("return ({}) {}(", return_type, callback)
+            };
+            w.write_line(call)?;
+            let mut args = vec!["exec_env".to_string()];
+            args.extend(native_params.into_iter().map(|param| param.arg));
+            Self::write_params(&mut w, &args, ");")?;
+        }
+        w.write_line("}")?;
+        w.eob()?;
+        Ok(())
+    }
+}
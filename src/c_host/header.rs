@@ -0,0 +1,76 @@
+use std::io::Write;
+
+use super::*;
+
+impl CHostGenerator {
+    /// Declarations shared by all the modules
+    pub fn header<T: Write>(w: &mut PrettyWriter<T>) -> Result<(), Error> {
+        w.write_lines(
+            "
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#include \"wasm_export.h\"
+
+#ifndef WITX_CODEGEN_HOST_TYPES
+#define WITX_CODEGEN_HOST_TYPES
+
+typedef int32_t wasi_handle_t;
+typedef uint32_t wasi_size_t;
+
+/* A pointer in the guest memory */
+typedef uint32_t wasi_guest_ptr_t;
+
+/* A list stored in the guest memory */
+typedef struct wasi_guest_slice {
+    wasi_guest_ptr_t ptr;
+    wasi_size_t len;
+} wasi_guest_slice_t;
+
+#endif",
+        )?
+        .eob()?;
+        Ok(())
+    }
+
+    /// Includes and helpers of the wrappers
+    pub fn source_header<T: Write>(w: &mut PrettyWriter<T>) -> Result<(), Error> {
+        w.write_lines(
+            "
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <stdbool.h>
+#include <stdint.h>
+
+#include \"wasm_export.h\"
+
+/*
+ * Checks that `len` elements of `elem_size` bytes starting at `ptr` are in the guest memory,
+ * and translates `ptr` to a native pointer. Raises an exception in the guest otherwise.
+ */
+static inline bool
+wasi_guest_range(wasm_exec_env_t exec_env, uint32_t ptr, uint32_t len, uint32_t elem_size,
+                 void **native)
+{
+    wasm_module_inst_t inst = wasm_runtime_get_module_inst(exec_env);
+    uint64_t size = (uint64_t) len * elem_size;
+
+    if (size > UINT32_MAX || !wasm_runtime_validate_app_addr(inst, ptr, (uint32_t) size)) {
+        wasm_runtime_set_exception(inst, \"out of bounds memory access\");
+        return false;
+    }
+    *native = wasm_runtime_addr_app_to_native(inst, ptr);
+    return true;
+}",
+        )?
+        .eob()?;
+        Ok(())
+    }
+}
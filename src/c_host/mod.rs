@@ -0,0 +1,320 @@
+mod common;
+mod function;
+mod header;
+mod r#struct;
+mod tuple;
+mod union;
+
+use std::io::Write;
+use std::rc::Rc;
+
+use common::*;
+
+use super::*;
+use crate::astype::*;
+use crate::error::*;
+use crate::pretty_writer::PrettyWriter;
+
+/// Generates the header, or the source file, of the host bindings
+pub struct CHostGenerator {
+    module_name: Option<String>,
+    source: bool,
+}
+
+impl CHostGenerator {
+    pub fn new(module_name: Option<String>, source: bool) -> Self {
+        CHostGenerator {
+            module_name,
+            source,
+        }
+    }
+}
+
+impl<T: Write> Generator<T> for CHostGenerator {
+    fn generate(
+        &self,
+        writer: &mut T,
+        module_witx: witx::Module,
+        options: &Options,
+    ) -> Result<(), Error> {
+        let mut w = PrettyWriter::new(writer, "    ");
+        let module_name = match &self.module_name {
+            None => module_witx.name().as_str().to_string(),
+            Some(module_name) => module_name.to_string(),
+        };
+        let module_id = module_witx.module_id();
+        let skip_imports = options.skip_imports;
+
+        names::check_name_collisions(&module_witx, skip_imports, &NAME_CONVERSIONS)?;
+
+        if !options.skip_header {
+            if self.source {
+                Self::source_header(&mut w)?;
+            } else {
+                Self::header(&mut w)?;
+            }
+        }
+
+        let module_title_comments = format!(
+            "---------------------- Module: [{}] ----------------------",
+            module_name
+        );
+        Self::write_comments(&mut w, &module_title_comments)?;
+        w.eob()?;
+
+        let funcs: Vec<_> = module_witx.funcs().collect();
+        if self.source {
+            w.write_line(format!("#include \"{}_host.h\"", module_name))?;
+            w.eob()?;
+            Self::define_wrappers(&mut w, &module_name, &funcs)?;
+            return Ok(());
+        }
+
+        for type_ in module_witx.typenames() {
+            if skip_imports && &type_.module != module_id {
+                continue;
+            }
+            let constants_for_type: Vec<_> = module_witx
+                .constants()
+                .filter_map(|x| {
+                    if x.ty == type_.name {
+                        Some(ASConstant {
+                            name: x.name.as_str().to_string(),
+                            value: x.value,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
+        }
+
+        Self::define_host_struct(&mut w, &module_name, &funcs)?;
+
+        Ok(())
+    }
+}
+
+impl CHostGenerator {
+    fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        if docs.is_empty() {
+            return Ok(());
+        }
+        let lines: Vec<_> = docs.lines().collect();
+        if lines.len() == 1 {
+            w.write_line(format!("/** {} */", lines[0]))?;
+            return Ok(());
+        }
+        w.write_line("/**")?;
+        for docs_line in lines {
+            if docs_line.is_empty() {
+                w.write_line(" *")?;
+            } else {
+                w.write_line(format!(" * {}", docs_line))?;
+            }
+        }
+        w.write_line(" */")?;
+        Ok(())
+    }
+
+    fn write_comments<T: Write>(w: &mut PrettyWriter<T>, comments: &str) -> Result<(), Error> {
+        w.write_line(format!("/* {} */", comments))?;
+        Ok(())
+    }
+
+    fn define_as_alias<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        other_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "typedef {} {};",
+            other_name.as_type(),
+            name.as_type()
+        ))?;
+        Ok(())
+    }
+
+    fn define_as_atom<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        w.write_line(format!("typedef {} {};", type_.as_lang(), name.as_type()))?;
+        Ok(())
+    }
+
+    fn define_as_enum<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        enum_: &ASEnum,
+    ) -> Result<(), Error> {
+        let repr = enum_.repr.as_ref();
+        w.write_line(format!("typedef {} {};", repr.as_lang(), name.as_type()))?;
+        let constants: Vec<_> = enum_
+            .choices
+            .iter()
+            .map(|choice| ASConstant {
+                name: choice.name.clone(),
+                value: choice.value as u64,
+            })
+            .collect();
+        Self::define_constants_for_type(w, name, &constants)?;
+        Ok(())
+    }
+
+    fn define_as_constants<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        constants: &ASConstants,
+    ) -> Result<(), Error> {
+        let repr = constants.repr.as_ref();
+        w.write_line(format!("typedef {} {};", repr.as_lang(), name.as_type()))?;
+        Self::define_constants_for_type(w, name, &constants.constants)?;
+        Ok(())
+    }
+
+    fn define_as_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        match type_ {
+            ASType::Alias(_)
+            | ASType::Bool
+            | ASType::Char8
+            | ASType::Char32
+            | ASType::F32
+            | ASType::F64
+            | ASType::U8
+            | ASType::U16
+            | ASType::U32
+            | ASType::U64
+            | ASType::S8
+            | ASType::S16
+            | ASType::S32
+            | ASType::S64
+            | ASType::USize
+            | ASType::Handle(_)
+            | ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
+            ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
+            ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
+            ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
+            ASType::Tuple(members) => Self::define_as_tuple(w, name, members)?,
+            ASType::Struct(members) => Self::define_as_struct(w, name, members)?,
+            _ => {
+                dbg!(type_);
+                unimplemented!();
+            }
+        }
+        Ok(())
+    }
+
+    /// Constants are macros prefixed with the name of their type
+    fn define_constants_for_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        type_name: &str,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        if constants.is_empty() {
+            return Ok(());
+        }
+        w.eob()?;
+        let mut hex = false;
+        let mut single_bits: usize = 0;
+        for constant in constants {
+            if constant.value > 0xffff {
+                hex = true;
+            }
+            if constant.value.count_ones() == 1 {
+                single_bits += 1;
+            }
+        }
+        if constants.len() > 2 && single_bits == constants.len() {
+            hex = true;
+        }
+        for constant in constants {
+            let mut value_s = if hex {
+                format!("0x{:x}", constant.value)
+            } else {
+                format!("{}", constant.value)
+            };
+            if constant.value > u32::MAX as u64 {
+                value_s.push_str("ULL");
+            }
+            w.write_line(format!(
+                "#define {} (({}) {})",
+                format!("{}_{}", type_name, constant.name).as_const(),
+                type_name.as_type(),
+                value_s
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Writes padding bytes as a byte array
+    fn define_padding<T: Write>(
+        w: &mut PrettyWriter<T>,
+        pad_index: &mut usize,
+        pad_len: usize,
+    ) -> Result<(), Error> {
+        if pad_len > 0 {
+            w.write_line(format!("uint8_t __pad{}[{}];", pad_index, pad_len))?;
+            *pad_index += 1;
+        }
+        Ok(())
+    }
+
+    /// Checks that the native layout of a record matches the layout in the guest memory
+    fn define_layout_assertions<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        offsets: &[(String, usize)],
+        size: usize,
+    ) -> Result<(), Error> {
+        w.eob()?;
+        for (field, offset) in offsets {
+            w.write_line(format!(
+                "_Static_assert(offsetof({}, {}) == {}, \"Error layout\");",
+                name.as_type(),
+                field,
+                offset
+            ))?;
+        }
+        w.write_line(format!(
+            "_Static_assert(sizeof({}) == {}, \"Error layout\");",
+            name.as_type(),
+            size
+        ))?;
+        Ok(())
+    }
+
+    fn define_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        type_witx: &witx::NamedType,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        let docs = &type_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+        let type_name = type_witx.name.as_str();
+        let tref = &type_witx.tref;
+        match tref {
+            witx::TypeRef::Name(other_type) => {
+                Self::define_as_alias(w, type_name, other_type.name.as_str())?
+            }
+            witx::TypeRef::Value(type_witx) => {
+                let t = ASType::from(type_witx.as_ref());
+                Self::define_as_type(w, type_name, &t)?
+            }
+        }
+        Self::define_constants_for_type(w, type_name, constants)?;
+        w.eob()?;
+        Ok(())
+    }
+}
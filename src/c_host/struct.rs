@@ -0,0 +1,50 @@
+use std::io::Write;
+
+use super::*;
+
+impl CHostGenerator {
+    pub fn define_as_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[ASStructMember],
+    ) -> Result<(), Error> {
+        let fields: Vec<_> = members
+            .iter()
+            .map(|member| (member.name.as_var(), member))
+            .collect();
+        Self::define_record(
+            w,
+            name,
+            fields.iter().map(|(field, member)| {
+                (field.as_str(), &member.type_, member.offset, member.padding)
+            }),
+        )
+    }
+
+    /// Defines a packed structure with explicit padding, and checks its layout
+    pub fn define_record<'t, T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        fields: impl Iterator<Item = (&'t str, &'t Rc<ASType>, usize, usize)>,
+    ) -> Result<(), Error> {
+        let mut offsets = vec![];
+        let mut size = 0;
+        w.write_line(format!(
+            "typedef struct __attribute__((packed)) {} {{",
+            name.as_fn()
+        ))?;
+        {
+            let mut w = w.new_block();
+            let mut pad_index = 0;
+            for (field, type_, offset, padding) in fields {
+                w.write_line(format!("{} {};", type_.as_lang(), field))?;
+                Self::define_padding(&mut w, &mut pad_index, padding)?;
+                offsets.push((field.to_string(), offset));
+                size = offset + type_.mem_size() + padding;
+            }
+        }
+        w.write_line(format!("}} {};", name.as_type()))?;
+        Self::define_layout_assertions(w, name, &offsets, size)?;
+        Ok(())
+    }
+}
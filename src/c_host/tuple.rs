@@ -0,0 +1,24 @@
+use std::io::Write;
+
+use super::*;
+
+impl CHostGenerator {
+    pub fn define_as_tuple<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[ASTupleMember],
+    ) -> Result<(), Error> {
+        let fields: Vec<_> = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| (format!("v{}", i), member))
+            .collect();
+        Self::define_record(
+            w,
+            name,
+            fields.iter().map(|(field, member)| {
+                (field.as_str(), &member.type_, member.offset, member.padding)
+            }),
+        )
+    }
+}
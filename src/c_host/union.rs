@@ -0,0 +1,59 @@
+use std::io::Write;
+
+use super::*;
+
+impl CHostGenerator {
+    pub fn define_as_union<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        let tag_repr = union_.tag_repr.as_ref();
+        w.write_line(format!(
+            "typedef struct __attribute__((packed)) {} {{",
+            name.as_fn()
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("{} tag;", tag_repr.as_lang()))?;
+            Self::define_padding(&mut w, &mut 0, union_.padding_after_tag)?;
+            w.write_line("union __attribute__((packed)) {")?;
+            {
+                let mut w = w.new_block();
+                for member in &union_.members {
+                    let member_type = member.type_.as_ref();
+                    if let ASType::Void = member_type {
+                        continue;
+                    }
+                    w.write_line(format!(
+                        "{} {};",
+                        member_type.as_lang(),
+                        member.name.as_var()
+                    ))?;
+                }
+                // Makes the size of the union match the largest member, with its padding
+                if union_.max_member_size > 0 {
+                    w.write_line(format!("uint8_t __size[{}];", union_.max_member_size))?;
+                }
+            }
+            w.write_line("} member;")?;
+        }
+        w.write_line(format!("}} {};", name.as_type()))?;
+        Self::define_layout_assertions(
+            w,
+            name,
+            &[("member".to_string(), union_.member_offset)],
+            union_.member_offset + union_.max_member_size,
+        )?;
+        w.eob()?;
+
+        for (i, member) in union_.members.iter().enumerate() {
+            w.write_line(format!(
+                "#define {} {}",
+                format!("{}_{}", name, member.name).as_const(),
+                i
+            ))?;
+        }
+        Ok(())
+    }
+}
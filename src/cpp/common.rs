@@ -1,7 +1,9 @@
 use super::tuple::Tuple;
 use crate::astype::*;
+use crate::names::{apply_naming_policy, NameConversions, NameKind};
 use convert_case::{Case, Casing};
 
+#[allow(dead_code)]
 pub trait IsNullable {
     fn is_nullable(&self) -> bool;
 }
@@ -22,15 +24,16 @@ impl IsNullable for ASType {
     }
 }
 
+#[allow(dead_code)]
 pub trait Normalize {
     fn as_str(&self) -> &str;
 
     fn as_type(&self) -> String {
-        self.as_str().to_case(Case::Pascal)
+        apply_naming_policy(NameKind::Type, self.as_str(), Case::Pascal)
     }
 
     fn as_fn(&self) -> String {
-        self.as_str().to_case(Case::Snake)
+        apply_naming_policy(NameKind::Function, self.as_str(), Case::Snake)
     }
 
     fn as_fn_suffix(&self) -> String {
@@ -38,15 +41,15 @@ pub trait Normalize {
     }
 
     fn as_var(&self) -> String {
-        self.as_str().to_case(Case::Snake)
+        apply_naming_policy(NameKind::Variable, self.as_str(), Case::Snake)
     }
 
     fn as_const(&self) -> String {
-        self.as_str().to_case(Case::UpperSnake)
+        apply_naming_policy(NameKind::Constant, self.as_str(), Case::UpperSnake)
     }
 
     fn as_namespace(&self) -> String {
-        self.as_str().to_case(Case::Pascal)
+        apply_naming_policy(NameKind::Namespace, self.as_str(), Case::Pascal)
     }
 }
 
@@ -56,6 +59,15 @@ impl<T: AsRef<str>> Normalize for T {
     }
 }
 
+/// The identifiers of this backend, checked for collisions
+pub const NAME_CONVERSIONS: NameConversions = NameConversions {
+    type_: |name| name.as_type(),
+    function: |name| name.as_fn(),
+    variable: |name| name.as_var(),
+    constant: |name| name.as_const(),
+    namespace: |name| name.as_namespace(),
+};
+
 pub trait ToLanguageRepresentation {
     fn as_astype(&self) -> &ASType;
 
@@ -108,3 +120,106 @@ impl ToLanguageRepresentation for ASType {
         self
     }
 }
+
+/// Checks whether the given word is a reserved keyword.
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED.contains(&word)
+}
+
+/// C++ keywords, and the members of the classes owning handles
+const RESERVED: &[&str] = &[
+    "alignas",
+    "alignof",
+    "and",
+    "and_eq",
+    "asm",
+    "auto",
+    "bitand",
+    "bitor",
+    "bool",
+    "break",
+    "case",
+    "catch",
+    "char",
+    "char16_t",
+    "char32_t",
+    "char8_t",
+    "class",
+    "co_await",
+    "co_return",
+    "co_yield",
+    "compl",
+    "concept",
+    "const",
+    "const_cast",
+    "consteval",
+    "constexpr",
+    "constinit",
+    "continue",
+    "decltype",
+    "default",
+    "delete",
+    "do",
+    "double",
+    "dynamic_cast",
+    "else",
+    "enum",
+    "explicit",
+    "export",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "friend",
+    "get",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "mutable",
+    "namespace",
+    "new",
+    "noexcept",
+    "not",
+    "not_eq",
+    "nullptr",
+    "operator",
+    "or",
+    "or_eq",
+    "private",
+    "protected",
+    "public",
+    "register",
+    "reinterpret_cast",
+    "release",
+    "requires",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "static_assert",
+    "static_cast",
+    "struct",
+    "switch",
+    "template",
+    "this",
+    "thread_local",
+    "throw",
+    "true",
+    "try",
+    "typedef",
+    "typeid",
+    "typename",
+    "union",
+    "unsigned",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "wchar_t",
+    "while",
+    "xor",
+    "xor_eq",
+];
@@ -7,8 +7,52 @@ impl CppGenerator {
         // module_name: &str,
         func_witx: &witx::Function,
     ) -> Result<(), Error> {
-        assert_eq!(func_witx.abi, witx::Abi::Preview1);
         let name = func_witx.name.as_str().to_string();
+        let docs = &func_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+
+        let (params_decomposed, results_decomposed, fn_result_str) =
+            Self::decompose_func(func_witx);
+        w.indent()?
+            .write(format!("{} {}(", fn_result_str, name.as_fn()))?;
+
+        if !params_decomposed.is_empty() || !results_decomposed.is_empty() {
+            w.eol()?;
+        }
+        for (i, param) in params_decomposed.iter().enumerate() {
+            let eol = if i + 1 == params_decomposed.len() {
+                ""
+            } else {
+                ","
+            };
+            w.write_line_continued(format!(
+                "{} {}{}",
+                param.type_.as_lang(),
+                param.name.as_var(),
+                eol
+            ))?;
+        }
+
+        w.write_line(");")?;
+        w.eob()?;
+
+        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
+        let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
+        assert_eq!(
+            params_count_witx,
+            params_decomposed.len() + results_decomposed.len() + 1
+        );
+
+        Ok(())
+    }
+
+    /// The decomposed parameters and results of a function, and its return type
+    pub fn decompose_func(
+        func_witx: &witx::Function,
+    ) -> (Vec<ASTypeDecomposed>, Vec<ASTypeDecomposed>, String) {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
         let params_witx = &func_witx.params;
         let mut params = vec![];
         for param_witx in params_witx {
@@ -28,11 +72,6 @@ impl CppGenerator {
 
         let ok_type = result.ok_type.clone();
 
-        let docs = &func_witx.docs;
-        if !docs.is_empty() {
-            Self::write_docs(w, docs)?;
-        }
-
         let mut params_decomposed = vec![];
 
         for param in &params {
@@ -78,40 +117,11 @@ impl CppGenerator {
             _ => format!("std::tuple<{}>", results_set.join(", ")),
         };
 
-        w.indent()?.write(format!(
-            "Expected<{}, {}> {}(",
+        let fn_result_str = format!(
+            "Expected<{}, {}>",
             rust_fn_result_str,
-            result.error_type.as_lang(),
-            name.as_fn()
-        ))?;
-
-        if !params_decomposed.is_empty() || !results.is_empty() {
-            w.eol()?;
-        }
-        for (i, param) in params_decomposed.iter().enumerate() {
-            let eol = if i + 1 == params_decomposed.len() {
-                ""
-            } else {
-                ","
-            };
-            w.write_line_continued(format!(
-                "{} {}{}",
-                param.type_.as_lang(),
-                param.name.as_var(),
-                eol
-            ))?;
-        }
-
-        w.write_line(");")?;
-        w.eob()?;
-
-        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
-        let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
-        assert_eq!(
-            params_count_witx,
-            params_decomposed.len() + results_decomposed.len() + 1
+            result.error_type.as_lang()
         );
-
-        Ok(())
+        (params_decomposed, results_decomposed, fn_result_str)
     }
 }
@@ -0,0 +1,138 @@
+use std::io::Write;
+
+use super::*;
+use crate::resources::{self, CloseFunction, HandleMethods};
+
+impl CppGenerator {
+    pub fn define_as_handle<T: Write>(w: &mut PrettyWriter<T>, name: &str) -> Result<(), Error> {
+        w.write_line(format!("enum class {} : WasiHandle {{}};", name.as_type()))?;
+        Ok(())
+    }
+
+    /// A wrapper owning a handle, and closing it in its destructor. The other functions
+    /// taking the handle as their first parameter can be exposed as methods.
+    pub fn define_owned_handle<T: Write>(
+        w: &mut PrettyWriter<T>,
+        close_function: &CloseFunction,
+        methods: Option<&HandleMethods>,
+    ) -> Result<(), Error> {
+        let handle_type = close_function.handle_type.as_type();
+        let owned_type = format!("owned_{}", close_function.handle_type).as_type();
+        let close_fn = close_function.func.name.as_str().as_fn();
+
+        Self::write_docs(
+            w,
+            &format!(
+                "An owned `{}`, closed with `{}()` when destroyed",
+                handle_type, close_fn
+            ),
+        )?;
+        w.write_line(format!("class {} {{", owned_type))?;
+        w.write_line("public:")?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!(
+                "explicit {}({} handle) : handle_(handle) {{}}",
+                owned_type, handle_type
+            ))?
+            .eob()?;
+            w.write_line(format!("{}(const {} &) = delete;", owned_type, owned_type))?
+                .write_line(format!(
+                    "{} &operator=(const {} &) = delete;",
+                    owned_type, owned_type
+                ))?
+                .eob()?;
+            w.write_line(format!(
+                "{}({} &&other) noexcept : handle_(other.handle_), owned_(other.owned_) {{",
+                owned_type, owned_type
+            ))?
+            .indent()?
+            .write_line("other.owned_ = false;")?
+            .write_line("}")?
+            .eob()?;
+            w.write_line(format!("~{}() {{", owned_type))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("if (owned_) {")?;
+                w.new_block()
+                    .write_line(format!("(void) {}(handle_);", close_fn))?;
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+            w.write_line(format!("{} get() const {{", handle_type))?
+                .indent()?
+                .write_line("return handle_;")?
+                .write_line("}")?
+                .eob()?;
+            Self::write_docs(
+                &mut w,
+                "Release the ownership of the handle, without closing it",
+            )?;
+            w.write_line(format!("{} release() {{", handle_type))?;
+            w.new_block()
+                .write_line("owned_ = false;")?
+                .write_line("return handle_;")?;
+            w.write_line("}")?;
+            // Closing the handle is left to the destructor
+            let funcs = methods
+                .iter()
+                .flat_map(|methods| methods.funcs.iter())
+                .filter(|func| func.name != close_function.func.name);
+            for func in funcs {
+                w.eob()?;
+                Self::define_owned_handle_method(&mut w, &close_function.handle_type, func)?;
+            }
+        }
+        w.eob()?.write_line("private:")?;
+        w.new_block()
+            .write_line(format!("{} handle_;", handle_type))?
+            .write_line("bool owned_ = true;")?;
+        w.write_line("};")?.eob()?;
+        Ok(())
+    }
+
+    fn define_owned_handle_method<T: Write>(
+        w: &mut PrettyWriter<T>,
+        handle_type: &str,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let docs = &func_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+        let (params_decomposed, _, fn_result_str) = Self::decompose_func(func_witx);
+        // Methods that would be named like a keyword or an accessor keep the name of the function
+        let method_name = match resources::method_name(handle_type, func_witx).as_fn() {
+            method_name if is_reserved_word(&method_name) => func_witx.name.as_str().as_fn(),
+            method_name => method_name,
+        };
+        // The first parameter is the handle itself
+        let params = &params_decomposed[1..];
+        if params.is_empty() {
+            w.write_line(format!("{} {}() const {{", fn_result_str, method_name))?;
+        } else {
+            w.write_line(format!("{} {}(", fn_result_str, method_name))?;
+            for (i, param) in params.iter().enumerate() {
+                let eol = if i + 1 == params.len() { "" } else { "," };
+                w.write_line_continued(format!(
+                    "{} {}{}",
+                    param.type_.as_lang(),
+                    param.name.as_var(),
+                    eol
+                ))?;
+            }
+            w.write_line(") const {")?;
+        }
+        let args = std::iter::once("handle_".to_string())
+            .chain(params.iter().map(|param| param.name.as_var()))
+            .collect::<Vec<_>>();
+        // Qualified, since the method or a parameter can have the same name as the function
+        w.new_block().write_line(format!(
+            "return ::{}({});",
+            func_witx.name.as_str().as_fn(),
+            args.join(", ")
+        ))?;
+        w.write_line("}")?;
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 mod common;
 mod function;
+mod handle;
 mod header;
 mod r#struct;
 mod tuple;
@@ -37,6 +38,8 @@ impl<T: Write> Generator<T> for CppGenerator {
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
+        names::check_name_collisions(&module_witx, skip_imports, &NAME_CONVERSIONS)?;
+
         if !options.skip_header {
             Self::header(&mut w)?;
         }
@@ -83,6 +86,20 @@ impl<T: Write> Generator<T> for CppGenerator {
             Self::define_func(&mut w, /* &module_name, */ func.as_ref())?;
         }
 
+        if options.raii {
+            let methods = if options.methods {
+                resources::handle_methods(&module_witx)
+            } else {
+                vec![]
+            };
+            for close_function in resources::close_functions(&module_witx) {
+                let methods_for_type = methods
+                    .iter()
+                    .find(|methods| methods.handle_type == close_function.handle_type);
+                Self::define_owned_handle(&mut w, &close_function, methods_for_type)?;
+            }
+        }
+
         // w.write_line("}")?;
 
         Ok(())
@@ -189,11 +206,11 @@ impl CppGenerator {
             | ASType::S32
             | ASType::S64
             | ASType::USize
-            | ASType::Handle(_)
             | ASType::Slice(_)
             | ASType::String(_)
             | ASType::ReadBuffer(_)
             | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
+            ASType::Handle(_) => Self::define_as_handle(w, name)?,
             ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
             ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
             ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
@@ -5,12 +5,54 @@ use witx::WitxError;
 #[derive(Debug)]
 pub enum Error {
     Witx(WitxError),
+    Wit(anyhow::Error),
     Io(std::io::Error),
+    Lint(usize),
+    Config {
+        path: String,
+        message: String,
+    },
+    NameCollision {
+        scope: String,
+        first: String,
+        second: String,
+        normalized: String,
+    },
+    UnsupportedOption {
+        option: String,
+        output_type: String,
+    },
+    InvalidOption {
+        option: String,
+        message: String,
+    },
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", &self)
+        match self {
+            Error::NameCollision {
+                scope,
+                first,
+                second,
+                normalized,
+            } => write!(
+                f,
+                "[{}] and [{}] are both translated to [{}] in {}",
+                first, second, normalized, scope
+            ),
+            Error::Config { path, message } => write!(f, "{}: {}", path, message),
+            Error::UnsupportedOption {
+                option,
+                output_type,
+            } => write!(
+                f,
+                "--{} is not supported by the {} output type",
+                option, output_type
+            ),
+            Error::InvalidOption { option, message } => write!(f, "--{}: {}", option, message),
+            _ => write!(f, "{:?}", &self),
+        }
     }
 }
 
@@ -0,0 +1,170 @@
+use convert_case::{Case, Casing};
+
+use crate::astype::*;
+use crate::names::{apply_naming_policy, NameConversions, NameKind};
+
+pub trait Normalize {
+    fn as_str(&self) -> &str;
+
+    fn as_type(&self) -> String {
+        apply_naming_policy(NameKind::Type, self.as_str(), Case::Pascal)
+    }
+
+    fn as_fn(&self) -> String {
+        apply_naming_policy(NameKind::Function, self.as_str(), Case::Pascal)
+    }
+
+    /// Struct fields are exported
+    fn as_field(&self) -> String {
+        self.as_str().to_case(Case::Pascal)
+    }
+
+    fn as_var(&self) -> String {
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Variable,
+            self.as_str(),
+            Case::Camel,
+        ))
+    }
+
+    fn as_const(&self) -> String {
+        apply_naming_policy(NameKind::Constant, self.as_str(), Case::Pascal)
+    }
+
+    fn as_namespace(&self) -> String {
+        apply_naming_policy(NameKind::Namespace, self.as_str(), Case::Pascal)
+    }
+}
+
+impl<T: AsRef<str>> Normalize for T {
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// The identifiers of this backend, checked for collisions
+pub const NAME_CONVERSIONS: NameConversions = NameConversions {
+    type_: |name| name.as_type(),
+    function: |name| name.as_fn(),
+    variable: |name| name.as_var(),
+    constant: |name| name.as_const(),
+    namespace: |name| name.as_namespace(),
+};
+
+/// Follows aliases up to the actual type
+pub fn resolve(type_: &ASType) -> &ASType {
+    match type_ {
+        ASType::Alias(alias) => resolve(alias.type_.as_ref()),
+        _ => type_,
+    }
+}
+
+/// Bytes are exchanged as `[]byte`, other lists as pointers and lengths
+pub fn is_byte(type_: &ASType) -> bool {
+    matches!(resolve(type_), ASType::U8 | ASType::Char8)
+}
+
+/// Go package names are lowercase, with no separators
+pub fn package_name(module_name: &str) -> String {
+    module_name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+pub trait ToLanguageRepresentation {
+    fn as_astype(&self) -> &ASType;
+
+    fn as_lang(&self) -> String {
+        match self.as_astype() {
+            ASType::Alias(alias) => alias.name.as_type(),
+            ASType::Bool => "bool".to_string(),
+            ASType::Char32 => "Char32".to_string(),
+            ASType::Char8 => "Char8".to_string(),
+            ASType::F32 => "float32".to_string(),
+            ASType::F64 => "float64".to_string(),
+            ASType::Handle(_resource_name) => "WasiHandle".to_string(),
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => "WasiPtr".to_string(),
+            ASType::Option(_) => todo!(),
+            ASType::Result(_) => todo!(),
+            ASType::S8 => "int8".to_string(),
+            ASType::S16 => "int16".to_string(),
+            ASType::S32 => "int32".to_string(),
+            ASType::S64 => "int64".to_string(),
+            ASType::U8 => "uint8".to_string(),
+            ASType::U16 => "uint16".to_string(),
+            ASType::U32 => "uint32".to_string(),
+            ASType::U64 => "uint64".to_string(),
+            ASType::USize => "WasiSize".to_string(),
+            ASType::Void => unimplemented!(),
+            ASType::Constants(constants) => constants.repr.as_lang(),
+            ASType::Enum(enum_) => enum_.repr.as_lang(),
+            ASType::Struct(_) => unimplemented!(),
+            ASType::Tuple(_) => unimplemented!(),
+            ASType::Union(_) => unimplemented!(),
+            ASType::Slice(elements_type)
+            | ASType::ReadBuffer(elements_type)
+            | ASType::WriteBuffer(elements_type) => {
+                if is_byte(elements_type) {
+                    "[]byte".to_string()
+                } else {
+                    "WasiSlice".to_string()
+                }
+            }
+            ASType::String(_) => "string".to_string(),
+        }
+    }
+}
+
+impl ToLanguageRepresentation for ASType {
+    fn as_astype(&self) -> &ASType {
+        self
+    }
+}
+
+/// Checks whether the given word is a reserved keyword.
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED.contains(&word)
+}
+
+/// Checks the given word against a list of reserved keywords.
+/// If the given word conflicts with a keyword, a trailing underscore will be
+/// appended.
+pub fn escape_reserved_word(word: &str) -> String {
+    if is_reserved_word(word) {
+        format!("{}_", word)
+    } else {
+        word.to_string()
+    }
+}
+
+/// Go keywords, and names used by the generated functions
+const RESERVED: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "ctx",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
@@ -0,0 +1,347 @@
+use std::io::Write;
+use std::rc::Rc;
+
+use witx::{CallMode, WasmType};
+
+use super::*;
+
+/// A function, with its parameters and results as they are seen by the host implementation
+struct HostFunc {
+    name: String,
+    docs: String,
+    params: Vec<(String, ASType)>,
+    results: Vec<(String, Rc<ASType>)>,
+    signature: witx::WasmSignature,
+}
+
+impl HostFunc {
+    fn new(func_witx: &witx::Function) -> Self {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
+        let mut params = vec![];
+        for param_witx in &func_witx.params {
+            let param_name = param_witx.name.as_str();
+            let param_type = ASType::from(&param_witx.tref);
+            params.push((param_name.to_string(), param_type));
+        }
+
+        let results_witx = &func_witx.results;
+        assert_eq!(results_witx.len(), 1);
+        let result = match ASType::from(&results_witx[0].tref) {
+            ASType::Result(result) => result,
+            _ => unreachable!(),
+        };
+        let ok_type = result.ok_type.clone();
+        let mut results = vec![];
+        // A tuple in a result is expanded into additional parameters, transformed to
+        // pointers
+        if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
+            for (i, tuple_member) in tuple_members.iter().enumerate() {
+                let name = format!("result{}_ptr", i);
+                results.push((name, tuple_member.type_.clone()));
+            }
+        } else {
+            let name = "result_ptr";
+            results.push((name.to_string(), ok_type));
+        }
+
+        HostFunc {
+            name: func_witx.name.as_str().to_string(),
+            docs: func_witx.docs.clone(),
+            params,
+            results,
+            signature: func_witx.wasm_signature(CallMode::DefinedImport),
+        }
+    }
+
+    /// Results that are actually returned by the host implementation
+    fn returned_results(&self) -> Vec<&(String, Rc<ASType>)> {
+        self.results
+            .iter()
+            .filter(|result| !matches!(result.1.leaf(), ASType::Void))
+            .collect()
+    }
+
+    /// Names of the core WebAssembly parameters, as they are exported
+    fn wasm_params_names(&self) -> Vec<String> {
+        let mut names = vec![];
+        for param in &self.params {
+            for decomposed in param.1.decompose(&param.0, false) {
+                names.push(decomposed.name);
+            }
+        }
+        for result in &self.results {
+            for decomposed in result.1.decompose(&result.0, true) {
+                names.push(decomposed.name);
+            }
+        }
+        assert_eq!(names.len(), self.signature.params.len());
+        names
+    }
+
+    /// Whether returning the results requires allocating memory in the guest
+    fn allocates(&self) -> bool {
+        self.results.iter().any(|result| allocates(&result.1))
+    }
+}
+
+impl GoHostGenerator {
+    /// Defines the interface to implement by the host
+    pub fn define_impl_interface<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        funcs: &[Rc<witx::Function>],
+    ) -> Result<(), Error> {
+        Self::write_docs(
+            w,
+            &format!(
+                "{} is the host implementation of the [{}] module\n\nThe methods receive the parameters decoded from the guest memory, and return the\nresults. Errors are returned to the guest by returning a *WasiError.",
+                module_name.as_type(),
+                module_name
+            ),
+        )?;
+        w.write_line(format!("type {} interface {{", module_name.as_type()))?;
+        {
+            let mut w = w.new_block();
+            for (i, func) in funcs.iter().enumerate() {
+                let func = HostFunc::new(func);
+                if i > 0 {
+                    w.eob()?;
+                }
+                Self::write_docs(&mut w, &func.docs)?;
+                let mut params = vec!["ctx context.Context".to_string()];
+                params.extend(
+                    func.params
+                        .iter()
+                        .filter(|param| !matches!(param.1.leaf(), ASType::Void))
+                        .map(|param| format!("{} {}", param.0.as_var(), param.1.as_lang())),
+                );
+                let mut results: Vec<_> = func
+                    .returned_results()
+                    .iter()
+                    .map(|result| result.1.as_lang())
+                    .collect();
+                results.push("error".to_string());
+                let results = if results.len() == 1 {
+                    results[0].clone()
+                } else {
+                    format!("({})", results.join(", "))
+                };
+                w.write_line(format!(
+                    "{}({}) {}",
+                    func.name.as_fn(),
+                    params.join(", "),
+                    results
+                ))?;
+            }
+        }
+        w.write_line("}")?;
+        w.eob()?;
+        Ok(())
+    }
+
+    /// Defines a function instantiating a host module, with functions calling the host
+    /// implementation
+    pub fn define_instantiate<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        funcs: &[Rc<witx::Function>],
+    ) -> Result<(), Error> {
+        let alloc = funcs.iter().any(|func| HostFunc::new(func).allocates());
+        let mut docs = format!(
+            "Instantiate adds the [{}] module to the runtime, calling the methods of impl",
+            module_name
+        );
+        if alloc {
+            docs.push_str(
+                "\n\nalloc allocates the memory of the strings and byte slices returned to the guest.",
+            );
+        }
+        Self::write_docs(w, &docs)?;
+        w.write_line(format!(
+            "func Instantiate(ctx context.Context, r wazero.Runtime, impl {}{}) (api.Module, error) {{",
+            module_name.as_type(),
+            if alloc { ", alloc GuestAllocator" } else { "" }
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("b := r.NewHostModuleBuilder(\"{}\")", module_name))?;
+            for func in funcs {
+                Self::define_host_function(&mut w, &HostFunc::new(func))?;
+            }
+            w.write_line("return b.Instantiate(ctx)")?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    fn define_host_function<T: Write>(
+        w: &mut PrettyWriter<T>,
+        func: &HostFunc,
+    ) -> Result<(), Error> {
+        // Core WebAssembly values are read from the stack, in the order of the signature
+        let wasm_values: Vec<_> = func
+            .signature
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, _)| format!("stack[{}]", i))
+            .collect();
+        let mut wasm_values = wasm_values.iter();
+
+        let mut args = vec!["ctx".to_string()];
+        for param in &func.params {
+            let count = param.1.decompose(&param.0, false).len();
+            let values: Vec<_> = wasm_values.by_ref().take(count).collect();
+            if let Some(arg) = decode_param(&param.1, &values) {
+                args.push(arg);
+            }
+        }
+        let returned_results = func.returned_results();
+        let mut statements = vec![];
+        let mut names = vec![];
+        for (i, result) in func.results.iter().enumerate() {
+            let count = result.1.decompose(&result.0, true).len();
+            let values: Vec<_> = wasm_values.by_ref().take(count).collect();
+            if count == 0 {
+                continue;
+            }
+            let name = if returned_results.len() > 1 {
+                format!("v{}", i)
+            } else {
+                "v".to_string()
+            };
+            let ptr = format!("api.DecodeU32({})", values[0]);
+            statements.extend(write_value(&result.1, &ptr, 0, &name));
+            names.push(name);
+        }
+        names.push("err".to_string());
+
+        w.write_line("b.NewFunctionBuilder().")?;
+        {
+            let mut w = w.new_block();
+            w.write_line("WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {")?;
+            {
+                let mut w = w.new_block();
+                if func.allocates() {
+                    w.write_line("m := allocatingMemory(ctx, mod, alloc)")?;
+                } else if args.iter().chain(statements.iter()).any(|s| uses_memory(s)) {
+                    w.write_line("m := guestMemory{Memory: mod.Memory()}")?;
+                }
+                let call = format!(
+                    "{} := impl.{}({})",
+                    names.join(", "),
+                    func.name.as_fn(),
+                    args.join(", ")
+                );
+                if call.len() <= 80 {
+                    w.write_line(call)?;
+                } else {
+                    w.write_line(format!(
+                        "{} := impl.{}(",
+                        names.join(", "),
+                        func.name.as_fn()
+                    ))?;
+                    for arg in &args {
+                        w.new_block().write_line(format!("{},", arg))?;
+                    }
+                    w.write_line(")")?;
+                }
+                w.write_line("if err != nil {")?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line("stack[0] = errno(err)")?;
+                    w.write_line("return")?;
+                }
+                w.write_line("}")?;
+                for statement in &statements {
+                    w.write_line(statement)?;
+                }
+                w.write_line("stack[0] = 0")?;
+            }
+            w.write_line(format!(
+                "}}), {}, {}).",
+                value_types(&func.signature.params),
+                value_types(&func.signature.results)
+            ))?;
+            let params_names: Vec<_> = func
+                .wasm_params_names()
+                .iter()
+                .map(|name| format!("\"{}\"", name))
+                .collect();
+            if !params_names.is_empty() {
+                w.write_line(format!("WithParameterNames({}).", params_names.join(", ")))?;
+            }
+            w.write_line(format!("Export(\"{}\")", func.name))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if an expression or a statement accesses the guest memory
+fn uses_memory(s: &str) -> bool {
+    s.starts_with("m.") || s.contains("(m.") || s.contains(" m.") || s.contains("(m, ")
+}
+
+/// The Go expression decoding a parameter from its core WebAssembly values
+fn decode_param(type_: &ASType, values: &[&String]) -> Option<String> {
+    let expr = match type_.leaf() {
+        ASType::Void => return None,
+        ASType::String(_) => format!(
+            "m.string(api.DecodeU32({}), api.DecodeU32({}))",
+            values[0], values[1]
+        ),
+        ASType::Slice(elements_type) | ASType::ReadBuffer(elements_type)
+            if is_byte(elements_type) =>
+        {
+            format!(
+                "m.copyBytes(api.DecodeU32({}), api.DecodeU32({}))",
+                values[0], values[1]
+            )
+        }
+        // Output buffers are views of the guest memory, that the host writes to
+        ASType::WriteBuffer(elements_type) if is_byte(elements_type) => format!(
+            "m.bytes(api.DecodeU32({}), api.DecodeU32({}))",
+            values[0], values[1]
+        ),
+        ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => format!(
+            "WasiSlice{{Ptr: api.DecodeU32({}), Len: api.DecodeU32({})}}",
+            values[0], values[1]
+        ),
+        _ => match resolve(type_) {
+            ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => {
+                read_value(type_, &format!("api.DecodeU32({})", values[0]), 0)
+            }
+            ASType::Bool => format!("api.DecodeU32({}) != 0", values[0]),
+            ASType::F32 => format!("api.DecodeF32({})", values[0]),
+            ASType::F64 => format!("api.DecodeF64({})", values[0]),
+            ASType::U64 => values[0].to_string(),
+            ASType::S64 => format!("int64({})", values[0]),
+            ASType::U32 => format!("api.DecodeU32({})", values[0]),
+            ASType::Enum(enum_) if is_64_bit(&enum_.repr) => {
+                format!("{}({})", type_.as_lang(), values[0])
+            }
+            ASType::Constants(constants) if is_64_bit(&constants.repr) => {
+                format!("{}({})", type_.as_lang(), values[0])
+            }
+            _ => format!("{}(api.DecodeU32({}))", type_.as_lang(), values[0]),
+        },
+    };
+    Some(expr)
+}
+
+fn is_64_bit(type_: &ASType) -> bool {
+    matches!(resolve(type_), ASType::U64 | ASType::S64)
+}
+
+fn value_types(types: &[WasmType]) -> String {
+    let types: Vec<_> = types
+        .iter()
+        .map(|type_| match type_ {
+            WasmType::I32 => "api.ValueTypeI32",
+            WasmType::I64 => "api.ValueTypeI64",
+            WasmType::F32 => "api.ValueTypeF32",
+            WasmType::F64 => "api.ValueTypeF64",
+        })
+        .collect();
+    format!("[]api.ValueType{{{}}}", types.join(", "))
+}
@@ -0,0 +1,175 @@
+use std::io::Write;
+
+use super::*;
+
+impl GoHostGenerator {
+    pub fn header<T: Write>(w: &mut PrettyWriter<T>, module_name: &str) -> Result<(), Error> {
+        w.write_line("// Code generated by witx-codegen. DO NOT EDIT.")?;
+        w.eob()?;
+        w.write_line(format!("package {}", package_name(module_name)))?;
+        w.write_lines(
+            "
+import (
+\t\"context\"
+\t\"errors\"
+\t\"fmt\"
+
+\t\"github.com/tetratelabs/wazero\"
+\t\"github.com/tetratelabs/wazero/api\"
+)",
+        )?;
+        w.write_lines(
+            "
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiSize = uint32
+type Char8 = uint8
+type Char32 = uint32",
+        )?;
+        w.write_lines(
+            "
+// WasiSlice is a list of values in the guest memory
+type WasiSlice struct {
+\tPtr WasiPtr
+\tLen WasiSize
+}",
+        )?;
+        w.write_lines(
+            "
+// WasiError is returned by a host implementation to return an error code to the guest
+type WasiError struct {
+\tErrno uint32
+}",
+        )?;
+        w.write_lines(
+            "
+func (e *WasiError) Error() string {
+\treturn fmt.Sprintf(\"WASI error %d\", e.Errno)
+}",
+        )?;
+        w.write_lines(
+            "
+// errno returns the error code of a WasiError, and panics on other errors, so that the
+// guest traps
+func errno(err error) uint64 {
+\tvar wasiErr *WasiError
+\tif errors.As(err, &wasiErr) {
+\t\treturn api.EncodeU32(wasiErr.Errno)
+\t}
+\tpanic(err)
+}",
+        )?;
+        w.write_lines(
+            "
+func boolToU8(v bool) uint8 {
+\tif v {
+\t\treturn 1
+\t}
+\treturn 0
+}",
+        )?;
+        w.write_lines(
+            "
+// errOutOfBounds is raised when the guest passes a pointer outside of its memory
+var errOutOfBounds = errors.New(\"out of bounds memory access\")",
+        )?;
+        w.write_lines(
+            "
+// GuestAllocator allocates size bytes aligned to align in the memory of the guest module,
+// and returns their address
+type GuestAllocator func(ctx context.Context, mod api.Module, size, align uint32) (uint32, error)",
+        )?;
+        w.write_lines(
+            "
+// guestMemory accesses the memory of the guest, and panics on invalid pointers
+type guestMemory struct {
+\tapi.Memory
+\t// alloc is only set in functions returning strings or byte slices
+\talloc func(size, align uint32) uint32
+}",
+        )?;
+        w.write_lines(
+            "
+// allocatingMemory returns a guestMemory that can allocate memory in the guest, and
+// panics if the allocation fails
+func allocatingMemory(ctx context.Context, mod api.Module, alloc GuestAllocator) guestMemory {
+\treturn guestMemory{mod.Memory(), func(size, align uint32) uint32 {
+\t\tptr, err := alloc(ctx, mod, size, align)
+\t\tif err != nil {
+\t\t\tpanic(err)
+\t\t}
+\t\treturn ptr
+\t}}
+}",
+        )?;
+        w.write_lines(
+            "
+func (m guestMemory) check(ok bool) {
+\tif !ok {
+\t\tpanic(errOutOfBounds)
+\t}
+}",
+        )?;
+        for (suffix, type_, read, write) in [
+            ("U8", "uint8", "ReadByte", "WriteByte"),
+            ("U16", "uint16", "ReadUint16Le", "WriteUint16Le"),
+            ("U32", "uint32", "ReadUint32Le", "WriteUint32Le"),
+            ("U64", "uint64", "ReadUint64Le", "WriteUint64Le"),
+            ("F32", "float32", "ReadFloat32Le", "WriteFloat32Le"),
+            ("F64", "float64", "ReadFloat64Le", "WriteFloat64Le"),
+        ] {
+            w.write_lines(format!(
+                "
+func (m guestMemory) {}(ptr uint32) {} {{
+\tv, ok := m.{}(ptr)
+\tm.check(ok)
+\treturn v
+}}",
+                suffix.to_lowercase(),
+                type_,
+                read
+            ))?;
+            w.write_lines(format!(
+                "
+func (m guestMemory) put{}(ptr uint32, v {}) {{
+\tm.check(m.{}(ptr, v))
+}}",
+                suffix, type_, write
+            ))?;
+        }
+        w.write_lines(
+            "
+// bytes returns a view of the guest memory
+func (m guestMemory) bytes(ptr, len uint32) []byte {
+\tb, ok := m.Read(ptr, len)
+\tm.check(ok)
+\treturn b
+}",
+        )?;
+        w.write_lines(
+            "
+func (m guestMemory) copyBytes(ptr, len uint32) []byte {
+\treturn append([]byte(nil), m.bytes(ptr, len)...)
+}",
+        )?;
+        w.write_lines(
+            "
+func (m guestMemory) string(ptr, len uint32) string {
+\treturn string(m.bytes(ptr, len))
+}",
+        )?;
+        w.write_lines(
+            "
+// putBytes copies data to memory allocated in the guest, and stores its address and
+// length at ptr
+func (m guestMemory) putBytes(ptr uint32, data []byte) {
+\tdataPtr := m.alloc(uint32(len(data)), 1)
+\tm.check(m.Write(dataPtr, data))
+\tm.putU32(ptr, dataPtr)
+\tm.putU32(ptr+4, uint32(len(data)))
+}",
+        )?;
+        w.eob()?;
+        Ok(())
+    }
+}
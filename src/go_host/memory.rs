@@ -0,0 +1,126 @@
+//! Expressions reading and writing values in the guest memory, through a `guestMemory`
+//! named `m`. Strings and byte slices are written to memory allocated by the guest, so the
+//! `guestMemory` has to be created with `allocatingMemory()` to write them.
+
+use super::*;
+
+/// An offset from a base pointer
+pub fn offset(base: &str, offset: usize) -> String {
+    if offset == 0 {
+        base.to_string()
+    } else {
+        format!("{}+{}", base, offset)
+    }
+}
+
+/// The `guestMemory` accessor suffix for a scalar, and the type it returns
+fn accessor(type_: &ASType) -> (&'static str, &'static str) {
+    match resolve(type_) {
+        ASType::Bool | ASType::U8 | ASType::S8 | ASType::Char8 => ("U8", "uint8"),
+        ASType::U16 | ASType::S16 => ("U16", "uint16"),
+        ASType::U32
+        | ASType::S32
+        | ASType::Char32
+        | ASType::USize
+        | ASType::Handle(_)
+        | ASType::ConstPtr(_)
+        | ASType::MutPtr(_) => ("U32", "uint32"),
+        ASType::U64 | ASType::S64 => ("U64", "uint64"),
+        ASType::F32 => ("F32", "float32"),
+        ASType::F64 => ("F64", "float64"),
+        ASType::Enum(enum_) => accessor(&enum_.repr),
+        ASType::Constants(constants) => accessor(&constants.repr),
+        type_ => {
+            dbg!(type_);
+            unimplemented!()
+        }
+    }
+}
+
+/// The name of a record or union, that has its own read and write functions
+fn record_name(type_: &ASType) -> String {
+    match type_ {
+        ASType::Alias(alias) => alias.name.as_type(),
+        _ => {
+            dbg!(type_);
+            unimplemented!()
+        }
+    }
+}
+
+/// An expression reading a value stored at `base + at`
+pub fn read_value(type_: &ASType, base: &str, at: usize) -> String {
+    let ptr_at = offset(base, at);
+    let len_at = offset(base, at + 4);
+    match resolve(type_) {
+        ASType::Bool => format!("m.u8({}) != 0", ptr_at),
+        ASType::String(_) => format!("m.string(m.u32({}), m.u32({}))", ptr_at, len_at),
+        ASType::WriteBuffer(elements_type) if is_byte(elements_type) => {
+            format!("m.bytes(m.u32({}), m.u32({}))", ptr_at, len_at)
+        }
+        ASType::Slice(elements_type) | ASType::ReadBuffer(elements_type)
+            if is_byte(elements_type) =>
+        {
+            format!("m.copyBytes(m.u32({}), m.u32({}))", ptr_at, len_at)
+        }
+        ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => format!(
+            "WasiSlice{{Ptr: m.u32({}), Len: m.u32({})}}",
+            ptr_at, len_at
+        ),
+        ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => {
+            format!("read{}(m, {})", record_name(type_), ptr_at)
+        }
+        _ => format!(
+            "{}(m.{}({}))",
+            type_.as_lang(),
+            accessor(type_).0.to_lowercase(),
+            ptr_at
+        ),
+    }
+}
+
+/// Whether writing a value requires allocating memory in the guest, for the content of
+/// strings and byte slices
+pub fn allocates(type_: &ASType) -> bool {
+    match resolve(type_) {
+        ASType::String(_) => true,
+        ASType::Slice(elements_type)
+        | ASType::ReadBuffer(elements_type)
+        | ASType::WriteBuffer(elements_type) => is_byte(elements_type),
+        ASType::Struct(members) => members.iter().any(|member| allocates(&member.type_)),
+        ASType::Tuple(members) => members.iter().any(|member| allocates(&member.type_)),
+        ASType::Union(union_) => union_.members.iter().any(|member| allocates(&member.type_)),
+        _ => false,
+    }
+}
+
+/// Statements writing `value` at `base + at`
+pub fn write_value(type_: &ASType, base: &str, at: usize, value: &str) -> Vec<String> {
+    let ptr_at = offset(base, at);
+    match resolve(type_) {
+        ASType::Void => vec![],
+        ASType::Bool => vec![format!("m.putU8({}, boolToU8({}))", ptr_at, value)],
+        ASType::String(_) => vec![format!("m.putBytes({}, []byte({}))", ptr_at, value)],
+        ASType::Slice(elements_type)
+        | ASType::ReadBuffer(elements_type)
+        | ASType::WriteBuffer(elements_type)
+            if is_byte(elements_type) =>
+        {
+            vec![format!("m.putBytes({}, {})", ptr_at, value)]
+        }
+        ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => vec![
+            format!("m.putU32({}, {}.Ptr)", ptr_at, value),
+            format!("m.putU32({}, {}.Len)", offset(base, at + 4), value),
+        ],
+        ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => vec![format!(
+            "write{}(m, {}, {})",
+            record_name(type_),
+            ptr_at,
+            value
+        )],
+        _ => {
+            let (suffix, repr) = accessor(type_);
+            vec![format!("m.put{}({}, {}({}))", suffix, ptr_at, repr, value)]
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::io::Write;
+
+use super::*;
+
+impl GoHostGenerator {
+    pub fn define_as_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[ASStructMember],
+    ) -> Result<(), Error> {
+        let fields: Vec<_> = members
+            .iter()
+            .map(|member| (member.name.as_field(), member.type_.as_lang()))
+            .collect();
+        Self::define_go_struct(w, name, &fields)?;
+
+        let mut values = vec![];
+        let mut write = vec![];
+        for member in members {
+            let member_type = member.type_.as_ref();
+            values.push((
+                member.name.as_field(),
+                read_value(member_type, "ptr", member.offset),
+            ));
+            let value = format!("v.{}", member.name.as_field());
+            write.extend(write_value(member_type, "ptr", member.offset, &value));
+        }
+        let read = Self::struct_literal(name, &values);
+        Self::define_read_write(w, name, &read, &write)?;
+        Ok(())
+    }
+
+    /// Defines a struct type, or an empty struct if there are no fields
+    pub fn define_go_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<(), Error> {
+        if fields.is_empty() {
+            w.write_line(format!("type {} struct{{}}", name.as_type()))?;
+            return Ok(());
+        }
+        w.write_line(format!("type {} struct {{", name.as_type()))?;
+        Self::write_aligned(&mut w.new_block(), fields)?;
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Statements returning a struct literal, with a field per line
+    pub fn struct_literal(name: &str, values: &[(String, String)]) -> Vec<String> {
+        if values.is_empty() {
+            return vec![format!("return {}{{}}", name.as_type())];
+        }
+        let width = values.iter().map(|value| value.0.len() + 1).max().unwrap();
+        let mut statements = vec![format!("return {}{{", name.as_type())];
+        for (field, value) in values {
+            statements.push(format!(
+                "\t{:width$} {},",
+                format!("{}:", field),
+                value,
+                width = width
+            ));
+        }
+        statements.push("}".to_string());
+        statements
+    }
+}
@@ -0,0 +1,33 @@
+use std::io::Write;
+
+use super::*;
+
+impl GoHostGenerator {
+    pub fn define_as_tuple<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[ASTupleMember],
+    ) -> Result<(), Error> {
+        let fields: Vec<_> = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| (format!("V{}", i), member.type_.as_lang()))
+            .collect();
+        Self::define_go_struct(w, name, &fields)?;
+
+        let mut values = vec![];
+        let mut write = vec![];
+        for (i, member) in members.iter().enumerate() {
+            let member_type = member.type_.as_ref();
+            values.push((
+                format!("V{}", i),
+                read_value(member_type, "ptr", member.offset),
+            ));
+            let value = format!("v.V{}", i);
+            write.extend(write_value(member_type, "ptr", member.offset, &value));
+        }
+        let read = Self::struct_literal(name, &values);
+        Self::define_read_write(w, name, &read, &write)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::Write;
+
+use super::*;
+
+impl GoHostGenerator {
+    pub fn define_as_union<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        let tag_repr = union_.tag_repr.as_ref();
+        let member_offset = union_.member_offset;
+
+        // The tag, and a field for each member with a content; only the field matching the
+        // tag is meaningful
+        let mut fields = vec![("Tag".to_string(), tag_repr.as_lang())];
+        for member in &union_.members {
+            let member_type = member.type_.as_ref();
+            if let ASType::Void = member_type {
+                continue;
+            }
+            fields.push((member.name.as_field(), member_type.as_lang()));
+        }
+        Self::define_go_struct(w, name, &fields)?;
+        w.eob()?;
+
+        let tag_names: Vec<_> = union_
+            .members
+            .iter()
+            .map(|member| format!("{}_{}", name, member.name).as_const())
+            .collect();
+        w.write_line("const (")?;
+        {
+            let rows: Vec<_> = tag_names
+                .iter()
+                .enumerate()
+                .map(|(i, tag_name)| (tag_name.clone(), format!("= {}", i)))
+                .collect();
+            Self::write_aligned(&mut w.new_block(), &rows)?;
+        }
+        w.write_line(")")?;
+
+        let mut read = vec![
+            format!(
+                "v := {}{{Tag: {}}}",
+                name.as_type(),
+                read_value(tag_repr, "ptr", 0)
+            ),
+            "switch v.Tag {".to_string(),
+        ];
+        let mut write = write_value(tag_repr, "ptr", 0, "v.Tag");
+        let mut write_cases = vec![];
+        for (member, tag_name) in union_.members.iter().zip(&tag_names) {
+            let member_type = member.type_.as_ref();
+            read.push(format!("case {}:", tag_name));
+            if let ASType::Void = member_type {
+                continue;
+            }
+            read.push(format!(
+                "\tv.{} = {}",
+                member.name.as_field(),
+                read_value(member_type, "ptr", member_offset)
+            ));
+            let value = format!("v.{}", member.name.as_field());
+            write_cases.push(format!("case {}:", tag_name));
+            for statement in write_value(member_type, "ptr", member_offset, &value) {
+                write_cases.push(format!("\t{}", statement));
+            }
+        }
+        read.push("default:".to_string());
+        read.push(format!(
+            "\tpanic(fmt.Sprintf(\"invalid tag for {}: %d\", v.Tag))",
+            name.as_type()
+        ));
+        read.push("}".to_string());
+        read.push("return v".to_string());
+        if !write_cases.is_empty() {
+            write.push("switch v.Tag {".to_string());
+            write.extend(write_cases);
+            write.push("}".to_string());
+        }
+        Self::define_read_write(w, name, &read, &write)?;
+        Ok(())
+    }
+}
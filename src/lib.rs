@@ -2,22 +2,39 @@
 
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 
 use structopt::StructOpt;
 use strum::VariantNames;
 use strum_macros::{Display, EnumString, VariantNames};
 
 mod assemblyscript;
-mod astype;
+pub mod astype;
+mod builder;
+mod c_host;
 mod cpp;
 mod doc;
 mod error;
+mod go_host;
+mod lint;
+mod names;
 mod overview;
 mod pretty_writer;
+mod project;
+mod python_host;
+mod resources;
 mod rust;
+mod typescript_host;
+mod wat;
+mod wit;
 mod zig;
 
+pub use crate::builder::Builder;
 pub use crate::error::*;
+pub use crate::lint::Lint;
+pub use crate::names::{NameCase, NamingPolicy, NamingRule};
+pub use crate::project::Project;
+pub use crate::rust::RustTrait;
 
 /// Generator output types
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Display, EnumString, VariantNames)]
@@ -31,6 +48,14 @@ pub enum OutputType {
     #[strum(serialize = "doc", serialize = "markdown")]
     Doc,
     Cpp,
+    Wit,
+    Wat,
+    PythonHost,
+    TypescriptHost,
+    GoHost,
+    CHostHeader,
+    CHostSource,
+    Lint,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, StructOpt)]
@@ -43,7 +68,7 @@ pub struct Config {
     #[structopt(short, long)]
     pub output_file: Option<String>,
 
-    /// WITX files
+    /// WITX files, WIT files or WIT package directories
     #[structopt()]
     pub witx_files: Vec<String>,
 
@@ -65,6 +90,17 @@ impl Default for Config {
             flags: Options {
                 skip_header: false,
                 skip_imports: false,
+                allow: vec![],
+                deny: vec![],
+                naming: None,
+                naming_policy: None,
+                mocks: false,
+                trace: false,
+                stub_errno: None,
+                raii: false,
+                methods: false,
+                derive: None,
+                input_file: None,
             },
         }
     }
@@ -80,6 +116,50 @@ pub struct Options {
     /// Do not generate a header
     #[structopt(short = "H", long)]
     skip_header: bool,
+
+    /// Lint to ignore in lint mode
+    #[structopt(short = "A", long, number_of_values = 1, possible_values = Lint::VARIANTS)]
+    allow: Vec<Lint>,
+
+    /// Lint to report as an error in lint mode
+    #[structopt(short = "D", long, number_of_values = 1, possible_values = Lint::VARIANTS)]
+    deny: Vec<Lint>,
+
+    /// TOML file with the naming conventions to use instead of the backend defaults
+    #[structopt(long)]
+    naming: Option<String>,
+
+    /// Naming conventions set by a project file, overriding `naming`
+    #[structopt(skip)]
+    naming_policy: Option<NamingPolicy>,
+
+    /// Call mock implementations of the imports on native targets (Rust)
+    #[structopt(long)]
+    mocks: bool,
+
+    /// Log the calls to the imports (AssemblyScript, Rust and Zig only)
+    #[structopt(long)]
+    trace: bool,
+
+    /// Generate functions returning this error code instead of import declarations (WAT)
+    #[structopt(long)]
+    stub_errno: Option<u64>,
+
+    /// Generate handle wrappers calling the close function when dropped (Rust, C++, Zig)
+    #[structopt(long)]
+    raii: bool,
+
+    /// Also expose functions taking a handle as methods of the handle type (Rust, Zig, and C++ with --raii)
+    #[structopt(long)]
+    methods: bool,
+
+    /// Traits to implement for structures, tuples and unions, all by default (Rust)
+    #[structopt(long, use_delimiter = true, possible_values = RustTrait::VARIANTS)]
+    derive: Option<Vec<RustTrait>>,
+
+    /// Path of the input file being generated, to report its diagnostics
+    #[structopt(skip)]
+    input_file: Option<String>,
 }
 
 /// Abstract generator interface
@@ -90,6 +170,11 @@ pub trait Generator<T: Write> {
         module_witx: witx::Module,
         options: &Options,
     ) -> Result<(), Error>;
+
+    /// Called once all the input files have been generated
+    fn finish(&self, _writer: &mut T) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 fn get_generator<T: Write>(module: Option<&str>, output: OutputType) -> Box<dyn Generator<T>> {
@@ -102,35 +187,132 @@ fn get_generator<T: Write>(module: Option<&str>, output: OutputType) -> Box<dyn
         OutputType::Overview => Box::new(overview::OverviewGenerator::new(m)),
         OutputType::Doc => Box::new(doc::DocGenerator::new(m)),
         OutputType::Cpp => Box::new(cpp::CppGenerator::new(m)),
+        OutputType::Wit => Box::new(wit::WitGenerator::new(m)),
+        OutputType::Wat => Box::new(wat::WatGenerator::new(m)),
+        OutputType::PythonHost => Box::new(python_host::PythonHostGenerator::new(m)),
+        OutputType::TypescriptHost => Box::new(typescript_host::TypeScriptHostGenerator::new(m)),
+        OutputType::GoHost => Box::new(go_host::GoHostGenerator::new(m)),
+        OutputType::CHostHeader => Box::new(c_host::CHostGenerator::new(m, false)),
+        OutputType::CHostSource => Box::new(c_host::CHostGenerator::new(m, true)),
+        OutputType::Lint => Box::new(lint::LintGenerator::new(m)),
+    }
+}
+
+/// Returns `true` if the input is a WIT file or package directory, rather than a WITX file
+fn is_wit_input(path: &Path) -> bool {
+    path.is_dir() || matches!(path.extension(), Some(ext) if ext == "wit")
+}
+
+/// Load a module from a WITX file, or from a WIT file or package directory
+fn load_module(path: &str) -> Result<witx::Module, Error> {
+    let path = Path::new(path);
+    if is_wit_input(path) {
+        wit::load(path)
+    } else {
+        Ok(witx::load(path)?)
     }
 }
 
 /// Generate sources from WITX files using the provided config
 pub fn generate(cfg: &Config) -> Result<(), Error> {
-    // generate all or generate no header no imports
+    check_options(cfg)?;
+    let naming_policy = naming_policy(cfg)?;
 
     // Setup writer based on output file config
     let mut writer: Box<dyn Write> = match cfg.output_file.as_deref() {
         None | Some("-") => Box::new(std::io::stdout()),
-        Some(file) => Box::new(File::create(file).unwrap()),
+        Some(file) => Box::new(File::create(file)?),
     };
 
+    generate_with_naming_policy(cfg, &naming_policy, &mut writer)
+}
+
+/// Generate sources from WITX files into a writer, ignoring the output file of the config
+pub fn generate_to_writer<W: Write>(cfg: &Config, writer: &mut W) -> Result<(), Error> {
+    check_options(cfg)?;
+    generate_with_naming_policy(cfg, &naming_policy(cfg)?, writer)
+}
+
+/// Rejects the options that the output type would ignore
+fn check_options(cfg: &Config) -> Result<(), Error> {
+    let output_type = cfg.output_type;
+    let supported = [
+        (
+            "trace",
+            cfg.flags.trace,
+            matches!(
+                output_type,
+                OutputType::AssemblyScript | OutputType::Rust | OutputType::Zig
+            ),
+        ),
+        // C++ handles only have methods through the classes owning them
+        (
+            "methods",
+            cfg.flags.methods,
+            matches!(output_type, OutputType::Rust | OutputType::Zig)
+                || (output_type == OutputType::Cpp && cfg.flags.raii),
+        ),
+        (
+            "stub-errno",
+            cfg.flags.stub_errno.is_some(),
+            output_type == OutputType::Wat,
+        ),
+    ];
+    for (option, enabled, supported) in supported {
+        if enabled && !supported {
+            return Err(Error::UnsupportedOption {
+                option: option.to_string(),
+                output_type: cfg.output_type.to_string(),
+            });
+        }
+    }
+    // Error codes are returned as an `i32`
+    if let Some(errno) = cfg.flags.stub_errno {
+        if errno > i32::MAX as u64 {
+            return Err(Error::InvalidOption {
+                option: "stub-errno".to_string(),
+                message: format!("{} doesn't fit in an i32", errno),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn naming_policy(cfg: &Config) -> Result<NamingPolicy, Error> {
+    match (&cfg.flags.naming_policy, &cfg.flags.naming) {
+        (Some(naming_policy), _) => Ok(naming_policy.clone()),
+        (None, None) => Ok(NamingPolicy::default()),
+        (None, Some(path)) => NamingPolicy::load(path, &cfg.output_type.to_string()),
+    }
+}
+
+fn generate_with_naming_policy<W: Write>(
+    cfg: &Config,
+    naming_policy: &NamingPolicy,
+    mut writer: &mut W,
+) -> Result<(), Error> {
+    // generate all or generate no header no imports
+
     let mut flags = cfg.flags.clone();
 
-    for witx_file in &cfg.witx_files {
-        // Parse WITX file
-        let witx = witx::load(witx_file).unwrap();
+    // Create generator for the specified output type
+    let generator = get_generator(cfg.module_name.as_deref(), cfg.output_type);
 
-        // Create generator for the specified output type
-        let generator = get_generator(cfg.module_name.as_deref(), cfg.output_type);
+    for witx_file in &cfg.witx_files {
+        // Parse WITX file, or lower a WIT package to WITX
+        let witx = load_module(witx_file)?;
+        flags.input_file = Some(witx_file.clone());
 
         // Generate output file
-        generator.generate(&mut writer, witx, &flags).unwrap();
+        names::with_naming_policy(naming_policy, || {
+            generator.generate(&mut writer, witx, &flags)
+        })?;
 
         // Generate definitions only once if we have multiple input files
         flags.skip_imports = true;
         flags.skip_header = true;
     }
+    generator.finish(&mut writer)?;
 
     Ok(())
 }
@@ -0,0 +1,107 @@
+use std::fmt;
+use std::path::Path;
+
+/// A position in an input file, starting at 1:1
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The text of a WITX input file, to locate the definitions reported by the lints.
+///
+/// WIT inputs are lowered to WITX before being checked, so their definitions have no
+/// location. Neither have the definitions of the files included with `use`.
+pub struct Source {
+    text: Option<String>,
+}
+
+impl Source {
+    pub fn load(path: Option<&str>) -> Self {
+        let text = path
+            .filter(|path| !crate::is_wit_input(Path::new(path)))
+            .and_then(|path| std::fs::read_to_string(path).ok());
+        Source { text }
+    }
+
+    pub fn type_location(&self, type_name: &str) -> Option<Location> {
+        let offset = self.find(0, &["(typename", &format!("${}", type_name)])?;
+        self.location_at(offset)
+    }
+
+    /// Locates a field, a case or a flag of a type, by its name following `keyword`, if any
+    pub fn member_location(
+        &self,
+        type_name: &str,
+        keyword: Option<&str>,
+        name: &str,
+    ) -> Option<Location> {
+        let type_offset = self.find(0, &["(typename", &format!("${}", type_name)])?;
+        let name = format!("${}", name);
+        let offset = match keyword {
+            None => self.find(type_offset + 1, &[&name])?,
+            Some(keyword) => self.find(type_offset, &[keyword, &name])?,
+        };
+        self.location_at(offset)
+    }
+
+    pub fn constant_location(&self, type_name: &str, name: &str) -> Option<Location> {
+        let offset = self.find(
+            0,
+            &[
+                "(@witx",
+                "const",
+                &format!("${}", type_name),
+                &format!("${}", name),
+            ],
+        )?;
+        self.location_at(offset)
+    }
+
+    pub fn func_location(&self, name: &str) -> Option<Location> {
+        let offset = self.find(
+            0,
+            &["(@interface", "func", "(export", &format!("\"{}\"", name)],
+        )?;
+        self.location_at(offset)
+    }
+
+    /// Finds a sequence of tokens separated by whitespace after `from`, and returns the
+    /// offset of the last one
+    fn find(&self, from: usize, tokens: &[&str]) -> Option<usize> {
+        let text = self.text.as_deref()?;
+        let (first, rest) = tokens.split_first()?;
+        'candidates: for (start, _) in text[from..].match_indices(first) {
+            let mut token_start = from + start;
+            let mut end = token_start + first.len();
+            for token in rest {
+                let remaining = text[end..].trim_start();
+                token_start = text.len() - remaining.len();
+                if token_start == end || !remaining.starts_with(token) {
+                    continue 'candidates;
+                }
+                end = token_start + token.len();
+            }
+            let is_id_char = |c: char| c.is_alphanumeric() || c == '_';
+            if !text[end..].starts_with(is_id_char) {
+                return Some(token_start);
+            }
+        }
+        None
+    }
+
+    fn location_at(&self, offset: usize) -> Option<Location> {
+        let before = &self.text.as_deref()?[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        })
+    }
+}
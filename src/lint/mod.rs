@@ -0,0 +1,432 @@
+mod location;
+
+use std::io::Write;
+
+use convert_case::Case;
+use location::{Location, Source};
+use strum_macros::{Display, EnumString, VariantNames};
+
+use super::*;
+use crate::astype::*;
+use crate::error::*;
+use crate::names::{apply_naming_policy, apply_naming_policy_or_preserve, NameKind};
+use crate::pretty_writer::PrettyWriter;
+
+/// Checks performed in lint mode
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Display, EnumString, VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Lint {
+    /// Structures and tuples with padding between members
+    Padding,
+    /// Types, enumeration cases and functions without documentation
+    Undocumented,
+    /// Names that turn into a reserved word of a target language once normalized
+    ReservedWord,
+    /// Constants whose value doesn't fit in the representation of their type
+    ConstantOverflow,
+    /// Functions that don't return an `expected` type with an errno error type
+    ErrnoResult,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Display)]
+#[strum(serialize_all = "snake_case")]
+enum LintLevel {
+    Allow,
+    Warning,
+    Error,
+}
+
+impl Options {
+    fn lint_level(&self, lint: Lint) -> LintLevel {
+        if self.deny.contains(&lint) {
+            LintLevel::Error
+        } else if self.allow.contains(&lint) {
+            LintLevel::Allow
+        } else {
+            LintLevel::Warning
+        }
+    }
+}
+
+struct Diagnostic {
+    lint: Lint,
+    location: Option<Location>,
+    message: String,
+}
+
+pub struct LintGenerator {
+    module_name: Option<String>,
+}
+
+impl LintGenerator {
+    pub fn new(module_name: Option<String>) -> Self {
+        LintGenerator { module_name }
+    }
+}
+
+impl<T: Write> Generator<T> for LintGenerator {
+    fn generate(
+        &self,
+        writer: &mut T,
+        module_witx: witx::Module,
+        options: &Options,
+    ) -> Result<(), Error> {
+        let mut w = PrettyWriter::new(writer, "    ");
+        let module_name = match &self.module_name {
+            None => module_witx.name().as_str().to_string(),
+            Some(module_name) => module_name.to_string(),
+        };
+        let module_id = module_witx.module_id();
+        let skip_imports = options.skip_imports;
+        let input = options.input_file.as_deref().unwrap_or(&module_name);
+        let source = Source::load(options.input_file.as_deref());
+
+        let mut diagnostics = vec![];
+        for type_ in module_witx.typenames() {
+            if skip_imports && &type_.module != module_id {
+                continue;
+            }
+            Self::check_type(&mut diagnostics, &source, type_.as_ref());
+        }
+        for constant in module_witx.constants() {
+            let type_ = match module_witx.typenames().find(|x| x.name == constant.ty) {
+                None => continue,
+                Some(type_) => type_,
+            };
+            if skip_imports && &type_.module != module_id {
+                continue;
+            }
+            Self::check_constant(&mut diagnostics, &source, type_.as_ref(), constant);
+        }
+        for func in module_witx.funcs() {
+            Self::check_func(&mut diagnostics, &source, func.as_ref());
+        }
+
+        let (mut warnings, mut errors) = (0, 0);
+        for diagnostic in &diagnostics {
+            let level = options.lint_level(diagnostic.lint);
+            match level {
+                LintLevel::Allow => continue,
+                LintLevel::Warning => warnings += 1,
+                LintLevel::Error => errors += 1,
+            }
+            let position = match diagnostic.location {
+                None => input.to_string(),
+                Some(location) => format!("{}:{}", input, location),
+            };
+            w.write_line(format!(
+                "{}: {}: {} [{}]",
+                position, level, diagnostic.message, diagnostic.lint
+            ))?;
+        }
+        if warnings + errors > 0 {
+            w.write_line(format!(
+                "{}: {} warning(s), {} error(s) emitted",
+                input, warnings, errors
+            ))?;
+        }
+        if errors > 0 {
+            return Err(Error::Lint(errors));
+        }
+        Ok(())
+    }
+}
+
+impl LintGenerator {
+    fn report(
+        diagnostics: &mut Vec<Diagnostic>,
+        lint: Lint,
+        location: Option<Location>,
+        message: String,
+    ) {
+        diagnostics.push(Diagnostic {
+            lint,
+            location,
+            message,
+        });
+    }
+
+    fn check_type(diagnostics: &mut Vec<Diagnostic>, source: &Source, type_witx: &witx::NamedType) {
+        let type_name = type_witx.name.as_str();
+        let location = source.type_location(type_name);
+        if type_witx.docs.trim().is_empty() {
+            Self::report(
+                diagnostics,
+                Lint::Undocumented,
+                location,
+                format!("type `{}` is not documented", type_name),
+            );
+        }
+        Self::check_reserved_words(
+            diagnostics,
+            location,
+            type_name,
+            "type",
+            &[
+                assemblyscript::common::Normalize::as_type(&type_name),
+                rust::common::Normalize::as_type(&type_name),
+                zig::common::Normalize::as_type(&type_name),
+            ],
+        );
+
+        let type_witx = match &type_witx.tref {
+            witx::TypeRef::Name(_) => return,
+            witx::TypeRef::Value(type_witx) => type_witx.as_ref(),
+        };
+        if let witx::Type::Variant(variant) = type_witx {
+            if !variant.is_bool()
+                && variant.as_expected().is_none()
+                && variant.as_option().is_none()
+            {
+                for case in &variant.cases {
+                    if case.docs.trim().is_empty() {
+                        let case_name = case.name.as_str();
+                        Self::report(
+                            diagnostics,
+                            Lint::Undocumented,
+                            source
+                                .member_location(type_name, Some("(case"), case_name)
+                                .or(location),
+                            format!("case `{}` of `{}` is not documented", case_name, type_name),
+                        );
+                    }
+                }
+            }
+        }
+
+        match ASType::from(type_witx) {
+            ASType::Struct(members) => {
+                for member in &members {
+                    let member_location = source
+                        .member_location(type_name, Some("(field"), &member.name)
+                        .or(location);
+                    Self::check_reserved_words(
+                        diagnostics,
+                        member_location,
+                        &member.name,
+                        "member",
+                        &var_names(&member.name),
+                    );
+                    if member.padding > 0 {
+                        Self::report(
+                            diagnostics,
+                            Lint::Padding,
+                            member_location,
+                            format!(
+                                "type `{}` has {} byte(s) of padding after member `{}`",
+                                type_name, member.padding, member.name
+                            ),
+                        );
+                    }
+                }
+            }
+            ASType::Tuple(members) => {
+                for (i, member) in members.iter().enumerate() {
+                    if member.padding > 0 {
+                        Self::report(
+                            diagnostics,
+                            Lint::Padding,
+                            location,
+                            format!(
+                                "tuple `{}` has {} byte(s) of padding after member #{}",
+                                type_name, member.padding, i
+                            ),
+                        );
+                    }
+                }
+            }
+            ASType::Enum(enum_) => {
+                for choice in &enum_.choices {
+                    let choice_name = choice.name.as_str();
+                    Self::check_reserved_words(
+                        diagnostics,
+                        source
+                            .member_location(type_name, None, choice_name)
+                            .or(location),
+                        choice_name,
+                        "enumeration case",
+                        &[
+                            assemblyscript::common::Normalize::as_const(&choice_name),
+                            rust::common::Normalize::as_const(&choice_name),
+                            zig::common::Normalize::as_const(&choice_name),
+                        ],
+                    );
+                }
+            }
+            ASType::Union(union_) => {
+                for member in &union_.members {
+                    Self::check_reserved_words(
+                        diagnostics,
+                        source
+                            .member_location(type_name, Some("(case"), &member.name)
+                            .or(location),
+                        &member.name,
+                        "union member",
+                        &var_names(&member.name),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_reserved_words(
+        diagnostics: &mut Vec<Diagnostic>,
+        location: Option<Location>,
+        name: &str,
+        kind: &str,
+        normalized: &[String; 3],
+    ) {
+        let [as_name, rust_name, zig_name] = normalized;
+        let checks = [
+            (
+                "AssemblyScript",
+                as_name,
+                assemblyscript::common::is_reserved_word(as_name),
+            ),
+            ("Rust", rust_name, rust::common::is_reserved_word(rust_name)),
+            ("Zig", zig_name, zig::common::is_reserved_word(zig_name)),
+        ];
+        for (language, normalized_name, is_reserved) in checks.iter() {
+            if *is_reserved {
+                Self::report(
+                    diagnostics,
+                    Lint::ReservedWord,
+                    location,
+                    format!(
+                        "{} `{}` becomes `{}`, which is a reserved word in {}",
+                        kind, name, normalized_name, language
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_constant(
+        diagnostics: &mut Vec<Diagnostic>,
+        source: &Source,
+        type_witx: &witx::NamedType,
+        constant: &witx::Constant,
+    ) {
+        let location = source.constant_location(type_witx.name.as_str(), constant.name.as_str());
+        let type_ = match &type_witx.tref {
+            witx::TypeRef::Name(other_type) => ASType::from(&other_type.tref),
+            witx::TypeRef::Value(type_witx) => ASType::from(type_witx.as_ref()),
+        };
+        if let Some(max_value) = max_value_for(&type_) {
+            if constant.value > max_value {
+                Self::report(
+                    diagnostics,
+                    Lint::ConstantOverflow,
+                    location,
+                    format!(
+                        "constant `{}` of `{}` has value 0x{:x}, which exceeds the maximum \
+                         value of its representation (0x{:x})",
+                        constant.name.as_str(),
+                        type_witx.name.as_str(),
+                        constant.value,
+                        max_value
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_func(diagnostics: &mut Vec<Diagnostic>, source: &Source, func_witx: &witx::Function) {
+        let name = func_witx.name.as_str();
+        let location = source.func_location(name);
+        if func_witx.docs.trim().is_empty() {
+            Self::report(
+                diagnostics,
+                Lint::Undocumented,
+                location,
+                format!("function `{}` is not documented", name),
+            );
+        }
+        Self::check_reserved_words(diagnostics, location, name, "function", &fn_names(name));
+        for param in &func_witx.params {
+            let param_name = param.name.as_str();
+            Self::check_reserved_words(
+                diagnostics,
+                location,
+                param_name,
+                "parameter",
+                &var_names(param_name),
+            );
+        }
+
+        let results_witx = &func_witx.results;
+        let result = match results_witx.as_slice() {
+            [result_witx] => ASType::from(&result_witx.tref),
+            _ => {
+                Self::report(
+                    diagnostics,
+                    Lint::ErrnoResult,
+                    location,
+                    format!(
+                        "function `{}` returns {} values instead of a single `expected` value",
+                        name,
+                        results_witx.len()
+                    ),
+                );
+                return;
+            }
+        };
+        match result {
+            ASType::Result(result) => {
+                if !matches!(resolve_aliases(&result.error_type), ASType::Enum(_)) {
+                    Self::report(
+                        diagnostics,
+                        Lint::ErrnoResult,
+                        location,
+                        format!(
+                            "the error type of function `{}` is not an errno enumeration",
+                            name
+                        ),
+                    );
+                }
+            }
+            _ => Self::report(
+                diagnostics,
+                Lint::ErrnoResult,
+                location,
+                format!("function `{}` doesn't return an `expected` value", name),
+            ),
+        }
+    }
+}
+
+/// Names of a variable in AssemblyScript, Rust and Zig, before the backends escape them
+fn var_names(name: &str) -> [String; 3] {
+    let name = apply_naming_policy(NameKind::Variable, name, Case::Snake);
+    [name.clone(), name.clone(), name]
+}
+
+/// Names of a function in AssemblyScript, Rust and Zig, before the backends escape them
+fn fn_names(name: &str) -> [String; 3] {
+    [
+        apply_naming_policy(NameKind::Function, name, Case::Camel),
+        apply_naming_policy(NameKind::Function, name, Case::Snake),
+        apply_naming_policy_or_preserve(NameKind::Function, name),
+    ]
+}
+
+fn resolve_aliases(type_: &ASType) -> &ASType {
+    match type_ {
+        ASType::Alias(alias) => resolve_aliases(&alias.type_),
+        _ => type_,
+    }
+}
+
+/// Largest value that can be stored in a type, if it has an integer representation
+fn max_value_for(type_: &ASType) -> Option<u64> {
+    match resolve_aliases(type_) {
+        ASType::U8 | ASType::S8 | ASType::Char8 => Some(0xff),
+        ASType::U16 | ASType::S16 => Some(0xffff),
+        ASType::U32 | ASType::S32 | ASType::Char32 | ASType::USize => Some(0xffff_ffff),
+        ASType::U64 | ASType::S64 => Some(u64::MAX),
+        ASType::Enum(enum_) => max_value_for(&enum_.repr),
+        ASType::Constants(constants) => max_value_for(&constants.repr),
+        _ => None,
+    }
+}
@@ -1,9 +1,18 @@
 #![forbid(unsafe_code)]
 
+use std::path::Path;
+
 use anyhow::Error;
 use structopt::StructOpt;
 
 fn main() -> Result<(), Error> {
+    // Without arguments, run the jobs of the project file, if there is one
+    let project_file = Path::new(witx_codegen::Project::FILE_NAME);
+    if std::env::args_os().len() == 1 && project_file.exists() {
+        witx_codegen::Project::load(project_file)?.generate()?;
+        return Ok(());
+    }
+
     // Load options from CLI
     let cfg = witx_codegen::Config::from_args();
 
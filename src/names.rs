@@ -0,0 +1,287 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+
+use convert_case::{Case, Casing};
+use serde::Deserialize;
+
+use crate::astype::*;
+use crate::error::*;
+
+/// Kinds of identifiers that WITX names are normalized into
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NameKind {
+    Type,
+    Function,
+    Variable,
+    Constant,
+    Namespace,
+}
+
+/// Casing applied to identifiers
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NameCase {
+    /// Keep the name as written in the WITX file
+    Preserve,
+    /// `snake_case`
+    Snake,
+    /// `UPPER_SNAKE_CASE`
+    UpperSnake,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `flatcase`
+    Flat,
+    /// `UPPERFLATCASE`
+    UpperFlat,
+}
+
+impl NameCase {
+    fn apply(self, name: &str) -> String {
+        let case = match self {
+            NameCase::Preserve => return name.to_string(),
+            NameCase::Snake => Case::Snake,
+            NameCase::UpperSnake => Case::UpperSnake,
+            NameCase::Camel => Case::Camel,
+            NameCase::Pascal => Case::Pascal,
+            NameCase::Flat => Case::Flat,
+            NameCase::UpperFlat => Case::UpperFlat,
+        };
+        name.to_case(case)
+    }
+}
+
+/// How a kind of identifier is derived from a WITX name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamingRule {
+    /// Casing, if different from the backend's own convention
+    pub case: Option<NameCase>,
+    pub prefix: String,
+    pub suffix: String,
+}
+
+/// Naming conventions for all kinds of identifiers
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamingPolicy {
+    pub types: NamingRule,
+    pub functions: NamingRule,
+    pub variables: NamingRule,
+    pub constants: NamingRule,
+    pub namespaces: NamingRule,
+}
+
+impl NamingPolicy {
+    /// Loads the naming policy for a backend from a TOML file with one table per output type:
+    ///
+    /// ```toml
+    /// [cpp.types]
+    /// case = "snake"
+    /// suffix = "_t"
+    /// ```
+    pub fn load(path: &str, output_type: &str) -> Result<Self, Error> {
+        let config_error = |message: String| Error::Config {
+            path: path.to_string(),
+            message,
+        };
+        let source = fs::read_to_string(path).map_err(|e| config_error(e.to_string()))?;
+        let mut policies: BTreeMap<String, NamingPolicy> =
+            toml::from_str(&source).map_err(|e| config_error(e.to_string()))?;
+        Ok(policies.remove(output_type).unwrap_or_default())
+    }
+
+    fn rule(&self, kind: NameKind) -> &NamingRule {
+        match kind {
+            NameKind::Type => &self.types,
+            NameKind::Function => &self.functions,
+            NameKind::Variable => &self.variables,
+            NameKind::Constant => &self.constants,
+            NameKind::Namespace => &self.namespaces,
+        }
+    }
+}
+
+thread_local! {
+    static NAMING_POLICY: RefCell<NamingPolicy> = RefCell::new(NamingPolicy::default());
+}
+
+/// Runs `f` with the naming policy used by `apply_naming_policy()` set to `policy`
+pub fn with_naming_policy<R>(policy: &NamingPolicy, f: impl FnOnce() -> R) -> R {
+    let previous = NAMING_POLICY.with(|current| current.replace(policy.clone()));
+    let result = f();
+    NAMING_POLICY.with(|current| current.replace(previous));
+    result
+}
+
+/// Converts a WITX name into an identifier of the given kind, using `default_case`
+/// unless the current naming policy overrides it
+pub fn apply_naming_policy(kind: NameKind, name: &str, default_case: Case) -> String {
+    apply_naming_rule(kind, name, |name| name.to_case(default_case))
+}
+
+/// Converts a WITX name into an identifier of the given kind, keeping the name as written
+/// unless the current naming policy overrides it
+pub fn apply_naming_policy_or_preserve(kind: NameKind, name: &str) -> String {
+    apply_naming_rule(kind, name, |name| name.to_string())
+}
+
+fn apply_naming_rule(kind: NameKind, name: &str, default: impl Fn(&str) -> String) -> String {
+    NAMING_POLICY.with(|policy| {
+        let policy = policy.borrow();
+        let rule = policy.rule(kind);
+        let name = match rule.case {
+            None => default(name),
+            Some(case) => case.apply(name),
+        };
+        format!("{}{}{}", rule.prefix, name, rule.suffix)
+    })
+}
+
+/// A set of identifiers that must remain distinct after normalization
+pub struct Scope {
+    description: String,
+    names: HashMap<String, String>,
+}
+
+impl Scope {
+    pub fn new(description: impl Into<String>) -> Self {
+        Scope {
+            description: description.into(),
+            names: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, original: &str, normalized: String) -> Result<(), Error> {
+        match self.names.get(&normalized) {
+            Some(previous) if previous != original => Err(Error::NameCollision {
+                scope: self.description.clone(),
+                first: previous.clone(),
+                second: original.to_string(),
+                normalized,
+            }),
+            Some(_) => Ok(()),
+            None => {
+                self.names.insert(normalized, original.to_string());
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The conversions of WITX names into each kind of identifier, as done by a backend
+#[derive(Clone, Copy)]
+pub struct NameConversions {
+    pub type_: fn(&str) -> String,
+    pub function: fn(&str) -> String,
+    pub variable: fn(&str) -> String,
+    pub constant: fn(&str) -> String,
+    pub namespace: fn(&str) -> String,
+}
+
+impl NameConversions {
+    pub fn convert(&self, kind: NameKind, name: &str) -> String {
+        let conversion = match kind {
+            NameKind::Type => self.type_,
+            NameKind::Function => self.function,
+            NameKind::Variable => self.variable,
+            NameKind::Constant => self.constant,
+            NameKind::Namespace => self.namespace,
+        };
+        conversion(name)
+    }
+}
+
+/// Renames a parameter that would shadow the function it belongs to
+pub fn unshadowed_var(var: String, fn_name: &str) -> String {
+    if var == fn_name {
+        format!("{}_", var)
+    } else {
+        var
+    }
+}
+
+/// Checks that distinct WITX names are not mapped to the same identifier by a
+/// backend, which would produce code that doesn't compile.
+pub fn check_name_collisions(
+    module_witx: &witx::Module,
+    skip_imports: bool,
+    conversions: &NameConversions,
+) -> Result<(), Error> {
+    let normalize = |kind, name: &str| conversions.convert(kind, name);
+    let module_id = module_witx.module_id();
+    let mut types = Scope::new("types");
+    let mut namespaces = Scope::new("namespaces");
+    for type_ in module_witx.typenames() {
+        if skip_imports && &type_.module != module_id {
+            continue;
+        }
+        let type_name = type_.name.as_str();
+        types.insert(type_name, normalize(NameKind::Type, type_name))?;
+
+        let mut constants = Scope::new(format!("constants of type [{}]", type_name));
+        for constant in module_witx.constants().filter(|x| x.ty == type_.name) {
+            let constant_name = constant.name.as_str();
+            constants.insert(constant_name, normalize(NameKind::Constant, constant_name))?;
+        }
+
+        let type_ = match &type_.tref {
+            witx::TypeRef::Name(_) => ASType::Void,
+            witx::TypeRef::Value(type_witx) => ASType::from(type_witx.as_ref()),
+        };
+        match type_ {
+            ASType::Enum(enum_) => {
+                namespaces.insert(type_name, normalize(NameKind::Namespace, type_name))?;
+                let mut choices = Scope::new(format!("cases of enumeration [{}]", type_name));
+                for choice in &enum_.choices {
+                    choices.insert(&choice.name, normalize(NameKind::Constant, &choice.name))?;
+                }
+            }
+            ASType::Constants(constants_) => {
+                for constant in &constants_.constants {
+                    constants.insert(
+                        &constant.name,
+                        normalize(NameKind::Constant, &constant.name),
+                    )?;
+                }
+            }
+            ASType::Struct(members) => {
+                let mut fields = Scope::new(format!("members of structure [{}]", type_name));
+                for member in &members {
+                    fields.insert(&member.name, normalize(NameKind::Variable, &member.name))?;
+                }
+            }
+            ASType::Union(union_) => {
+                let mut fields = Scope::new(format!("members of union [{}]", type_name));
+                for member in &union_.members {
+                    fields.insert(&member.name, normalize(NameKind::Variable, &member.name))?;
+                }
+            }
+            _ => {}
+        }
+        if !constants.names.is_empty() {
+            namespaces.insert(type_name, normalize(NameKind::Namespace, type_name))?;
+        }
+    }
+
+    let mut functions = Scope::new("functions");
+    for func in module_witx.funcs() {
+        let func_name = func.name.as_str();
+        functions.insert(func_name, normalize(NameKind::Function, func_name))?;
+
+        // A parameter named like its function after normalization is renamed, and the new
+        // name must not be the one of another parameter
+        let fn_name = normalize(NameKind::Function, func_name);
+        let mut params = Scope::new(format!("parameters of function [{}]", func_name));
+        for param_witx in &func.params {
+            let param_type = ASType::from(&param_witx.tref);
+            for param in param_type.decompose(param_witx.name.as_str(), false) {
+                let var = unshadowed_var(normalize(NameKind::Variable, &param.name), &fn_name);
+                params.insert(&param.name, var)?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::astype::*;
 
+#[allow(dead_code)]
 pub trait Normalize {
     fn as_str(&self) -> &str;
 
@@ -0,0 +1,146 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::*;
+use crate::names::NamingPolicy;
+use crate::{generate, Config, Lint, Options, OutputType, RustTrait};
+
+/// A generation job, as described in a project file
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct Job {
+    inputs: Vec<String>,
+    output_type: String,
+    output: Option<String>,
+    module_name: Option<String>,
+    #[serde(default)]
+    skip_imports: bool,
+    #[serde(default)]
+    skip_header: bool,
+    #[serde(default)]
+    mocks: bool,
+    #[serde(default)]
+    trace: bool,
+    stub_errno: Option<u64>,
+    #[serde(default)]
+    raii: bool,
+    #[serde(default)]
+    methods: bool,
+    derive: Option<Vec<String>>,
+    #[serde(default)]
+    allow: Vec<String>,
+    #[serde(default)]
+    deny: Vec<String>,
+    naming: Option<NamingPolicy>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProjectFile {
+    #[serde(default, rename = "job")]
+    jobs: Vec<Job>,
+}
+
+/// A set of generation jobs loaded from a project file:
+///
+/// ```toml
+/// [[job]]
+/// inputs = ["witx/crypto.witx"]
+/// output-type = "rust"
+/// output = "src/crypto.rs"
+///
+/// [job.naming.types]
+/// suffix = "Type"
+/// ```
+///
+/// Relative paths are relative to the directory of the project file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Project {
+    pub jobs: Vec<Config>,
+}
+
+impl Project {
+    /// Name of the project file loaded when no arguments are given
+    pub const FILE_NAME: &'static str = "witx-codegen.toml";
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let config_error = |message: String| Error::Config {
+            path: path.display().to_string(),
+            message,
+        };
+        let source = fs::read_to_string(path).map_err(|e| config_error(e.to_string()))?;
+        let project_file: ProjectFile =
+            toml::from_str(&source).map_err(|e| config_error(e.to_string()))?;
+
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let relative_to_project = |file: &str| base_dir.join(file).to_string_lossy().to_string();
+        let parse_lints = |lints: &[String]| {
+            lints
+                .iter()
+                .map(|lint| {
+                    lint.parse::<Lint>()
+                        .map_err(|_| config_error(format!("Unknown lint [{}]", lint)))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let parse_traits = |traits: &[String]| {
+            traits
+                .iter()
+                .map(|trait_| {
+                    trait_
+                        .parse::<RustTrait>()
+                        .map_err(|_| config_error(format!("Unknown trait [{}]", trait_)))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let mut jobs = vec![];
+        for job in project_file.jobs {
+            let output_type = job
+                .output_type
+                .parse::<OutputType>()
+                .map_err(|_| config_error(format!("Unknown output type [{}]", job.output_type)))?;
+            let output_file = match job.output.as_deref() {
+                None | Some("-") => job.output,
+                Some(output) => Some(relative_to_project(output)),
+            };
+            jobs.push(Config {
+                module_name: job.module_name,
+                output_file,
+                witx_files: job.inputs.iter().map(|x| relative_to_project(x)).collect(),
+                output_type,
+                flags: Options {
+                    skip_imports: job.skip_imports,
+                    skip_header: job.skip_header,
+                    allow: parse_lints(&job.allow)?,
+                    deny: parse_lints(&job.deny)?,
+                    naming: None,
+                    naming_policy: job.naming,
+                    mocks: job.mocks,
+                    trace: job.trace,
+                    stub_errno: job.stub_errno,
+                    raii: job.raii,
+                    methods: job.methods,
+                    derive: match &job.derive {
+                        None => None,
+                        Some(traits) => Some(parse_traits(traits)?),
+                    },
+                    input_file: None,
+                },
+            });
+        }
+        Ok(Project { jobs })
+    }
+
+    /// Runs all the jobs of the project
+    pub fn generate(&self) -> Result<(), Error> {
+        for job in &self.jobs {
+            generate(job)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use convert_case::Case;
+
+use crate::astype::*;
+use crate::names::{apply_naming_policy, NameConversions, NameKind};
+
+pub trait Normalize {
+    fn as_str(&self) -> &str;
+
+    fn as_type(&self) -> String {
+        apply_naming_policy(NameKind::Type, self.as_str(), Case::Pascal)
+    }
+
+    fn as_fn(&self) -> String {
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Function,
+            self.as_str(),
+            Case::Snake,
+        ))
+    }
+
+    fn as_var(&self) -> String {
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Variable,
+            self.as_str(),
+            Case::Snake,
+        ))
+    }
+
+    fn as_const(&self) -> String {
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Constant,
+            self.as_str(),
+            Case::UpperSnake,
+        ))
+    }
+
+    fn as_namespace(&self) -> String {
+        apply_naming_policy(NameKind::Namespace, self.as_str(), Case::UpperSnake)
+    }
+}
+
+impl<T: AsRef<str>> Normalize for T {
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// The identifiers of this backend, checked for collisions
+pub const NAME_CONVERSIONS: NameConversions = NameConversions {
+    type_: |name| name.as_type(),
+    function: |name| name.as_fn(),
+    variable: |name| name.as_var(),
+    constant: |name| name.as_const(),
+    namespace: |name| name.as_namespace(),
+};
+
+/// Follows aliases up to the actual type
+pub fn resolve(type_: &ASType) -> &ASType {
+    match type_ {
+        ASType::Alias(alias) => resolve(alias.type_.as_ref()),
+        _ => type_,
+    }
+}
+
+/// Enumerations and flags are Python classes, stored as their integer representation
+pub fn is_int_class(type_: &ASType) -> bool {
+    matches!(resolve(type_), ASType::Enum(_) | ASType::Constants(_))
+}
+
+pub trait ToLanguageRepresentation {
+    fn as_astype(&self) -> &ASType;
+
+    /// The `ctypes` type used to store a value
+    fn as_lang(&self) -> String {
+        match self.as_astype() {
+            ASType::Alias(alias) if is_int_class(&alias.type_) => alias.type_.as_lang(),
+            ASType::Alias(alias) => alias.name.as_type(),
+            ASType::Bool => "ctypes.c_bool".to_string(),
+            ASType::Char32 => "Char32".to_string(),
+            ASType::Char8 => "Char8".to_string(),
+            ASType::F32 => "ctypes.c_float".to_string(),
+            ASType::F64 => "ctypes.c_double".to_string(),
+            ASType::Handle(_resource_name) => "WasiHandle".to_string(),
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => "WasiPtr".to_string(),
+            ASType::Option(_) => todo!(),
+            ASType::Result(_) => todo!(),
+            ASType::S8 => "ctypes.c_int8".to_string(),
+            ASType::S16 => "ctypes.c_int16".to_string(),
+            ASType::S32 => "ctypes.c_int32".to_string(),
+            ASType::S64 => "ctypes.c_int64".to_string(),
+            ASType::U8 => "ctypes.c_uint8".to_string(),
+            ASType::U16 => "ctypes.c_uint16".to_string(),
+            ASType::U32 => "ctypes.c_uint32".to_string(),
+            ASType::U64 => "ctypes.c_uint64".to_string(),
+            ASType::USize => "WasiSize".to_string(),
+            ASType::Void => "None".to_string(),
+            ASType::Constants(constants) => constants.repr.as_lang(),
+            ASType::Enum(enum_) => enum_.repr.as_lang(),
+            ASType::Struct(_) => unimplemented!(),
+            ASType::Tuple(_) => unimplemented!(),
+            ASType::Union(_) => unimplemented!(),
+            ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => {
+                "WasiSlice".to_string()
+            }
+            ASType::String(_) => "WasiString".to_string(),
+        }
+    }
+}
+
+impl ToLanguageRepresentation for ASType {
+    fn as_astype(&self) -> &ASType {
+        self
+    }
+}
+
+/// Checks whether the given word is a reserved keyword.
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED.contains(&word)
+}
+
+/// Checks the given word against a list of reserved keywords.
+/// If the given word conflicts with a keyword, a trailing underscore will be
+/// appended.
+pub fn escape_reserved_word(word: &str) -> String {
+    if is_reserved_word(word) {
+        format!("{}_", word)
+    } else {
+        word.to_string()
+    }
+}
+
+/// Python keywords, and names used by the generated functions
+const RESERVED: &[&str] = &[
+    "False", "None", "True", "alloc", "and", "as", "assert", "async", "await", "break", "caller",
+    "class", "continue", "def", "del", "elif", "else", "except", "finally", "for", "from",
+    "global", "host", "if", "import", "in", "is", "lambda", "linker", "nonlocal", "not", "or",
+    "pass", "raise", "result", "return", "self", "try", "while", "with", "yield",
+];
@@ -0,0 +1,356 @@
+use std::io::Write;
+use std::rc::Rc;
+
+use witx::{CallMode, WasmType};
+
+use super::*;
+
+/// Longer function definitions have a parameter per line
+const MAX_LINE_LEN: usize = 79;
+
+/// A function, with its parameters and results as they are seen by the host implementation
+struct HostFunc {
+    name: String,
+    docs: String,
+    params: Vec<(String, ASType)>,
+    results: Vec<(String, Rc<ASType>)>,
+    result_is_tuple: bool,
+    signature: witx::WasmSignature,
+}
+
+impl HostFunc {
+    fn new(func_witx: &witx::Function) -> Self {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
+        let mut params = vec![];
+        for param_witx in &func_witx.params {
+            let param_name = param_witx.name.as_str();
+            let param_type = ASType::from(&param_witx.tref);
+            params.push((param_name.to_string(), param_type));
+        }
+
+        let results_witx = &func_witx.results;
+        assert_eq!(results_witx.len(), 1);
+        let result = match ASType::from(&results_witx[0].tref) {
+            ASType::Result(result) => result,
+            _ => unreachable!(),
+        };
+        let ok_type = result.ok_type.clone();
+        let mut results = vec![];
+        // A tuple in a result is expanded into additional parameters, transformed to
+        // pointers
+        let result_is_tuple = if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
+            for (i, tuple_member) in tuple_members.iter().enumerate() {
+                let name = format!("result{}_ptr", i);
+                results.push((name, tuple_member.type_.clone()));
+            }
+            true
+        } else {
+            let name = "result_ptr";
+            results.push((name.to_string(), ok_type));
+            false
+        };
+
+        HostFunc {
+            name: func_witx.name.as_str().to_string(),
+            docs: func_witx.docs.clone(),
+            params,
+            results,
+            result_is_tuple,
+            signature: func_witx.wasm_signature(CallMode::DefinedImport),
+        }
+    }
+
+    /// Names of the core WebAssembly parameters
+    fn wasm_params_names(&self) -> Vec<String> {
+        let mut names = vec![];
+        for param in &self.params {
+            for decomposed in param.1.decompose(&param.0, false) {
+                names.push(decomposed.name.as_var());
+            }
+        }
+        for result in &self.results {
+            for decomposed in result.1.decompose(&result.0, true) {
+                names.push(decomposed.name.as_var());
+            }
+        }
+        assert_eq!(names.len(), self.signature.params.len());
+        names
+    }
+
+    /// Whether the function returns strings or bytes, copied to memory allocated by the guest
+    fn allocates(&self) -> bool {
+        self.results.iter().any(|result| allocates(&result.1))
+    }
+}
+
+impl PythonHostGenerator {
+    /// Defines a class with a method for each function, to be overridden by the host
+    pub fn define_host_class<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        funcs: &[Rc<witx::Function>],
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "class {}:",
+            format!("{}_host", module_name).as_type()
+        ))?;
+        {
+            let mut w = w.new_block();
+            Self::write_docs(
+                &mut w,
+                &format!(
+                    "Host implementation of the [{}] module\n\nThe methods receive the parameters decoded from the guest memory, and return\nthe results. Errors are returned to the guest by raising a WasiError.",
+                    module_name
+                ),
+            )?;
+            for func in funcs {
+                let func = HostFunc::new(func);
+                w.eob()?;
+                let mut params = vec!["self".to_string()];
+                params.extend(func.params.iter().map(|param| param.0.as_var()));
+                Self::write_def(&mut w, &func.name.as_fn(), &params)?;
+                {
+                    let mut w = w.new_block();
+                    Self::write_docs(&mut w, &func.docs)?;
+                    w.write_line(format!("raise NotImplementedError(\"{}\")", func.name))?;
+                }
+            }
+        }
+        w.eob()?;
+        w.eob()?;
+        Ok(())
+    }
+
+    /// Defines a function registering the functions of the module on a `wasmtime.Linker`
+    pub fn define_add_to_linker<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        funcs: &[Rc<witx::Function>],
+    ) -> Result<(), Error> {
+        let allocates = funcs.iter().any(|func| HostFunc::new(func).allocates());
+        w.write_line(format!(
+            "def {}(linker, host{}):",
+            format!("add_{}_to_linker", module_name).as_fn(),
+            if allocates { ", alloc" } else { "" }
+        ))?;
+        {
+            let mut w = w.new_block();
+            let mut docs = format!(
+                "Defines the functions of the [{}] module in a wasmtime.Linker\n\nThe functions decode their parameters from the memory exported by the guest,\nand call the methods of host.",
+                module_name
+            );
+            if allocates {
+                docs.push_str(
+                    "\n\nReturned strings and bytes are copied to memory allocated in the guest by\nalloc(caller, size, align), which returns the address of the allocation.",
+                );
+            }
+            Self::write_docs(&mut w, &docs)?;
+            w.eob()?;
+            for type_ in ["i32", "i64", "f32", "f64"] {
+                w.write_line(format!("{} = wasmtime.ValType.{}()", type_, type_))?;
+            }
+            for func in funcs {
+                w.eob()?;
+                Self::define_host_func(&mut w, module_name, &HostFunc::new(func))?;
+            }
+        }
+        w.eob()?;
+        Ok(())
+    }
+
+    fn define_host_func<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        func: &HostFunc,
+    ) -> Result<(), Error> {
+        let wasm_params_names = func.wasm_params_names();
+        let mut closure_params = vec!["caller".to_string()];
+        closure_params.extend(wasm_params_names.iter().cloned());
+        Self::write_def(w, &func.name.as_fn(), &closure_params)?;
+        {
+            let mut w = w.new_block();
+            let results: Vec<_> = func
+                .results
+                .iter()
+                .enumerate()
+                .filter(|(_, result)| !matches!(result.1.leaf(), ASType::Void))
+                .collect();
+            let assignment = if results.is_empty() { "" } else { "result = " };
+            w.write_line("try:")?;
+            {
+                let mut w = w.new_block();
+                let args: Vec<_> = func
+                    .params
+                    .iter()
+                    .filter_map(|param| decode_param(&param.0, &param.1))
+                    .collect();
+                if args.is_empty() {
+                    w.write_line(format!("{}host.{}()", assignment, func.name.as_fn()))?;
+                } else {
+                    w.write_line(format!("{}host.{}(", assignment, func.name.as_fn()))?;
+                    for arg in &args {
+                        w.write_line_continued(format!("{},", arg))?;
+                    }
+                    w.write_line(")")?;
+                }
+            }
+            w.write_line("except WasiError as e:")?;
+            w.new_block().write_line("return e.errno")?;
+            for (i, result) in results {
+                let value = if func.result_is_tuple {
+                    format!("result[{}]", i)
+                } else {
+                    "result".to_string()
+                };
+                w.write_line(write_result(&result.0, &result.1, &value))?;
+            }
+            w.write_line("return 0")?;
+        }
+        w.eob()?;
+
+        let val_types = |types: &[WasmType]| {
+            types
+                .iter()
+                .map(|type_| wasm_type(*type_))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        w.write_line("linker.define_func(")?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("\"{}\",", module_name))?;
+            w.write_line(format!("\"{}\",", func.name))?;
+            w.write_line(format!(
+                "wasmtime.FuncType([{}], [{}]),",
+                val_types(&func.signature.params),
+                val_types(&func.signature.results)
+            ))?;
+            w.write_line(format!("{},", func.name.as_fn()))?;
+            w.write_line("access_caller=True,")?;
+        }
+        w.write_line(")")?;
+        Ok(())
+    }
+}
+
+impl PythonHostGenerator {
+    /// Writes a function definition, with a parameter per line if it doesn't fit on a single line
+    fn write_def<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        params: &[String],
+    ) -> Result<(), Error> {
+        let def = format!("def {}({}):", name, params.join(", "));
+        if w.indent_level() as usize * 4 + def.len() <= MAX_LINE_LEN {
+            w.write_line(def)?;
+            return Ok(());
+        }
+        w.write_line(format!("def {}(", name))?;
+        for param in params {
+            w.write_line_continued(format!("{},", param))?;
+        }
+        w.write_line("):")?;
+        Ok(())
+    }
+}
+
+/// The Python expression decoding a parameter from its core WebAssembly values
+fn decode_param(param_name: &str, type_: &ASType) -> Option<String> {
+    let name = param_name.as_var();
+    let ptr = format!("{}_ptr", param_name).as_var();
+    let len = format!("{}_len", param_name).as_var();
+    let expr = match type_.leaf() {
+        ASType::Void => return None,
+        ASType::String(_) => format!("read_string(caller, {}, {})", ptr, len),
+        ASType::Slice(elements_type) | ASType::ReadBuffer(elements_type) => {
+            if is_byte(elements_type) {
+                format!("read_bytes(caller, {}, {})", ptr, len)
+            } else {
+                format!(
+                    "read_list(caller, {}, {}, {})",
+                    ptr,
+                    len,
+                    elements_type.as_lang()
+                )
+            }
+        }
+        ASType::WriteBuffer(elements_type) => {
+            if is_byte(elements_type) {
+                format!("GuestBuffer(caller, {}, {})", ptr, len)
+            } else {
+                format!(
+                    "GuestBuffer(caller, {}, {} * ctypes.sizeof({}))",
+                    ptr,
+                    len,
+                    elements_type.as_lang()
+                )
+            }
+        }
+        _ => match resolve(type_) {
+            ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => {
+                format!("read_value(caller, {}, {})", name, type_.as_lang())
+            }
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => format!("{} & 0xFFFFFFFF", name),
+            ASType::Bool => format!("bool({})", name),
+            ASType::F32 | ASType::F64 => name,
+            ASType::Enum(_) | ASType::Constants(_) => format!(
+                "{}({}({}).value)",
+                int_class_name(type_),
+                type_.as_lang(),
+                name
+            ),
+            _ => format!("{}({}).value", type_.as_lang(), name),
+        },
+    };
+    Some(expr)
+}
+
+/// The Python statement writing a result to the guest memory
+fn write_result(result_name: &str, type_: &ASType, value: &str) -> String {
+    let ptr = result_name.as_var();
+    match resolve(type_) {
+        ASType::String(_) => format!("write_string(caller, alloc, {}, {})", ptr, value),
+        ASType::Slice(elements_type) | ASType::ReadBuffer(elements_type)
+            if is_byte(elements_type) =>
+        {
+            format!("write_allocated_bytes(caller, alloc, {}, {})", ptr, value)
+        }
+        _ => format!(
+            "write_value(caller, {}, {}, {})",
+            ptr,
+            type_.as_lang(),
+            value
+        ),
+    }
+}
+
+/// Whether writing a value requires allocating memory in the guest. Strings and bytes
+/// nested in structures are `ctypes` values, whose address is set by the host.
+fn allocates(type_: &ASType) -> bool {
+    match resolve(type_) {
+        ASType::String(_) => true,
+        ASType::Slice(elements_type) | ASType::ReadBuffer(elements_type) => is_byte(elements_type),
+        _ => false,
+    }
+}
+
+/// The name of the class of an enumeration or of flags
+fn int_class_name(type_: &ASType) -> String {
+    match type_ {
+        ASType::Alias(alias) => alias.name.as_type(),
+        _ => unimplemented!(),
+    }
+}
+
+fn is_byte(type_: &ASType) -> bool {
+    matches!(resolve(type_), ASType::U8 | ASType::Char8)
+}
+
+fn wasm_type(type_: WasmType) -> &'static str {
+    match type_ {
+        WasmType::I32 => "i32",
+        WasmType::I64 => "i64",
+        WasmType::F32 => "f32",
+        WasmType::F64 => "f64",
+    }
+}
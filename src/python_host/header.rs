@@ -0,0 +1,122 @@
+use std::io::Write;
+
+use super::*;
+
+impl PythonHostGenerator {
+    pub fn header<T: Write>(w: &mut PrettyWriter<T>) -> Result<(), Error> {
+        w.write_lines(
+            "
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#",
+        )?;
+        w.write_lines(
+            "
+import ctypes
+import enum
+
+import wasmtime
+
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [(\"ptr\", WasiPtr), (\"len\", WasiSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [(\"ptr\", WasiPtr), (\"len\", WasiSize)]
+
+
+class WasiError(Exception):
+    \"\"\"Raised by a host implementation to return an error code to the guest\"\"\"
+
+    def __init__(self, errno):
+        super().__init__(errno)
+        self.errno = errno
+
+
+def read_bytes(caller, ptr, length):
+    \"\"\"Copies length bytes from the guest memory\"\"\"
+    ptr &= 0xFFFFFFFF
+    return bytes(caller[\"memory\"].read(caller, ptr, ptr + length))
+
+
+def write_bytes(caller, ptr, data):
+    \"\"\"Copies data to the guest memory\"\"\"
+    caller[\"memory\"].write(caller, data, ptr & 0xFFFFFFFF)
+
+
+def read_string(caller, ptr, length):
+    \"\"\"Decodes an UTF-8 string from the guest memory\"\"\"
+    return read_bytes(caller, ptr, length).decode(\"utf-8\")
+
+
+def read_value(caller, ptr, ctype):
+    \"\"\"Reads a value of the given ctypes type from the guest memory\"\"\"
+    value = ctype.from_buffer_copy(read_bytes(caller, ptr, ctypes.sizeof(ctype)))
+    if isinstance(value, (ctypes.Structure, ctypes.Union)):
+        return value
+    return value.value
+
+
+def read_list(caller, ptr, length, ctype):
+    \"\"\"Reads an array of values of the given ctypes type from the guest memory\"\"\"
+    array = (ctype * length).from_buffer_copy(
+        read_bytes(caller, ptr, ctypes.sizeof(ctype) * length)
+    )
+    return list(array)
+
+
+def write_value(caller, ptr, ctype, value):
+    \"\"\"Writes a value of the given ctypes type to the guest memory\"\"\"
+    if not isinstance(value, ctype):
+        value = ctype(value)
+    write_bytes(caller, ptr, bytes(value))
+
+
+def write_allocated_bytes(caller, alloc, ptr, data):
+    \"\"\"Copies data to memory allocated in the guest, and stores its address and
+    length at ptr\"\"\"
+    data_ptr = alloc(caller, len(data), 1)
+    write_bytes(caller, data_ptr, data)
+    write_value(caller, ptr, WasiSlice, WasiSlice(data_ptr, len(data)))
+
+
+def write_string(caller, alloc, ptr, value):
+    \"\"\"Copies an UTF-8 string to memory allocated in the guest, and stores its
+    address and length at ptr\"\"\"
+    write_allocated_bytes(caller, alloc, ptr, value.encode(\"utf-8\"))
+
+
+class GuestBuffer:
+    \"\"\"A buffer in the guest memory, that the host can write to\"\"\"
+
+    def __init__(self, caller, ptr, length):
+        self.caller = caller
+        self.ptr = ptr
+        self.length = length
+
+    def __len__(self):
+        return self.length
+
+    def read(self):
+        return read_bytes(self.caller, self.ptr, self.length)
+
+    def write(self, data, offset=0):
+        if offset + len(data) > self.length:
+            raise ValueError(\"The data doesn't fit in the guest buffer\")
+        write_bytes(self.caller, self.ptr + offset, data)",
+        )?;
+        w.eob()?;
+        w.eob()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+mod common;
+mod function;
+mod header;
+mod r#struct;
+mod tuple;
+mod union;
+
+use std::io::Write;
+
+use common::*;
+
+use super::*;
+use crate::astype::*;
+use crate::error::*;
+use crate::pretty_writer::PrettyWriter;
+
+pub struct PythonHostGenerator {
+    module_name: Option<String>,
+}
+
+impl PythonHostGenerator {
+    pub fn new(module_name: Option<String>) -> Self {
+        PythonHostGenerator { module_name }
+    }
+}
+
+impl<T: Write> Generator<T> for PythonHostGenerator {
+    fn generate(
+        &self,
+        writer: &mut T,
+        module_witx: witx::Module,
+        options: &Options,
+    ) -> Result<(), Error> {
+        let mut w = PrettyWriter::new(writer, "    ");
+        let module_name = match &self.module_name {
+            None => module_witx.name().as_str().to_string(),
+            Some(module_name) => module_name.to_string(),
+        };
+        let module_id = module_witx.module_id();
+        let skip_imports = options.skip_imports;
+
+        names::check_name_collisions(&module_witx, skip_imports, &NAME_CONVERSIONS)?;
+
+        if !options.skip_header {
+            Self::header(&mut w)?;
+        }
+
+        let module_title_comments = format!(
+            "---------------------- Module: [{}] ----------------------",
+            module_name
+        );
+        Self::write_comments(&mut w, &module_title_comments)?;
+        w.eob()?;
+        w.eob()?;
+
+        for type_ in module_witx.typenames() {
+            if skip_imports && &type_.module != module_id {
+                continue;
+            }
+            let constants_for_type: Vec<_> = module_witx
+                .constants()
+                .filter_map(|x| {
+                    if x.ty == type_.name {
+                        Some(ASConstant {
+                            name: x.name.as_str().to_string(),
+                            value: x.value,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
+        }
+
+        let funcs: Vec<_> = module_witx.funcs().collect();
+        Self::define_host_class(&mut w, &module_name, &funcs)?;
+        Self::define_add_to_linker(&mut w, &module_name, &funcs)?;
+
+        Ok(())
+    }
+}
+
+impl PythonHostGenerator {
+    fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        if docs.is_empty() {
+            return Ok(());
+        }
+        let mut lines = docs.trim_end().lines();
+        let first_line = lines.next().unwrap_or_default();
+        if docs.trim_end().lines().count() == 1 {
+            w.write_line(format!("\"\"\"{}\"\"\"", first_line))?;
+            return Ok(());
+        }
+        w.write_line(format!("\"\"\"{}", first_line))?;
+        for docs_line in lines {
+            if docs_line.is_empty() {
+                w.eob()?;
+            } else {
+                w.write_line(docs_line)?;
+            }
+        }
+        w.write_line("\"\"\"")?;
+        Ok(())
+    }
+
+    fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        if docs.is_empty() {
+            return Ok(());
+        }
+        for docs_line in docs.lines() {
+            if docs_line.is_empty() {
+                w.write_line("#")?;
+            } else {
+                w.write_line(format!("# {}", docs_line))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn define_as_alias<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        other_name: &str,
+    ) -> Result<(), Error> {
+        // Enumerations and flags are aliased to their class, not to their representation
+        w.write_line(format!("{} = {}", name.as_type(), other_name.as_type()))?;
+        Ok(())
+    }
+
+    fn define_as_atom<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        w.write_line(format!("{} = {}", name.as_type(), type_.as_lang()))?;
+        Ok(())
+    }
+
+    fn define_as_enum<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        enum_: &ASEnum,
+    ) -> Result<(), Error> {
+        w.write_line(format!("class {}(enum.IntEnum):", name.as_type()))?;
+        {
+            let mut w = w.new_block();
+            if enum_.choices.is_empty() {
+                w.write_line("pass")?;
+            }
+            for choice in &enum_.choices {
+                w.write_line(format!("{} = {}", choice.name.as_const(), choice.value))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn define_as_constants<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        constants: &ASConstants,
+    ) -> Result<(), Error> {
+        w.write_line(format!("class {}(enum.IntFlag):", name.as_type()))?;
+        {
+            let mut w = w.new_block();
+            if constants.constants.is_empty() {
+                w.write_line("pass")?;
+            }
+            Self::define_constants(&mut w, &constants.constants)?;
+        }
+        Ok(())
+    }
+
+    fn define_as_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        match type_ {
+            ASType::Alias(_)
+            | ASType::Bool
+            | ASType::Char8
+            | ASType::Char32
+            | ASType::F32
+            | ASType::F64
+            | ASType::U8
+            | ASType::U16
+            | ASType::U32
+            | ASType::U64
+            | ASType::S8
+            | ASType::S16
+            | ASType::S32
+            | ASType::S64
+            | ASType::USize
+            | ASType::Handle(_)
+            | ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
+            ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
+            ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
+            ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
+            ASType::Tuple(members) => Self::define_as_tuple(w, name, members)?,
+            ASType::Struct(members) => Self::define_as_struct(w, name, members)?,
+            _ => {
+                dbg!(type_);
+                unimplemented!();
+            }
+        }
+        Ok(())
+    }
+
+    fn define_constants<T: Write>(
+        w: &mut PrettyWriter<T>,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        let mut hex = false;
+        let mut single_bits: usize = 0;
+        for constant in constants {
+            if constant.value > 0xffff {
+                hex = true;
+            }
+            if constant.value.count_ones() == 1 {
+                single_bits += 1;
+            }
+        }
+        if constants.len() > 2 && single_bits == constants.len() {
+            hex = true;
+        }
+        for constant in constants {
+            let value_s = if hex {
+                format!("0x{:x}", constant.value)
+            } else {
+                format!("{}", constant.value)
+            };
+            w.write_line(format!("{} = {}", constant.name.as_const(), value_s))?;
+        }
+        Ok(())
+    }
+
+    fn define_constants_for_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        type_name: &str,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        if constants.is_empty() {
+            return Ok(());
+        }
+        w.write_line(format!("class {}:", type_name.as_namespace()))?;
+        Self::define_constants(&mut w.new_block(), constants)?;
+        w.eob()?;
+        w.eob()?;
+        Ok(())
+    }
+
+    fn define_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        type_witx: &witx::NamedType,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        let docs = &type_witx.docs;
+        if !docs.is_empty() {
+            Self::write_comments(w, docs)?;
+        }
+        let type_name = type_witx.name.as_str();
+        let tref = &type_witx.tref;
+        match tref {
+            witx::TypeRef::Name(other_type) => {
+                Self::define_as_alias(w, type_name, other_type.name.as_str())?
+            }
+            witx::TypeRef::Value(type_witx) => {
+                let t = ASType::from(type_witx.as_ref());
+                Self::define_as_type(w, type_name, &t)?
+            }
+        }
+        w.eob()?;
+        w.eob()?;
+        Self::define_constants_for_type(w, type_name, constants)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::Write;
+
+use super::*;
+
+impl PythonHostGenerator {
+    pub fn define_as_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[ASStructMember],
+    ) -> Result<(), Error> {
+        let mut fields = vec![];
+        for member in members {
+            fields.push((member.name.as_var(), member.type_.as_lang()));
+            if member.padding > 0 {
+                fields.push(Self::padding_field(fields.len(), member.padding));
+            }
+        }
+        Self::define_ctypes_class(w, name, "ctypes.Structure", &fields)
+    }
+
+    /// A field filling `len` bytes of padding
+    pub fn padding_field(i: usize, len: usize) -> (String, String) {
+        (format!("__pad{}", i), format!("ctypes.c_uint8 * {}", len))
+    }
+
+    /// Defines a packed `ctypes` class, with explicit padding fields
+    pub fn define_ctypes_class<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        base: &str,
+        fields: &[(String, String)],
+    ) -> Result<(), Error> {
+        w.write_line(format!("class {}({}):", name.as_type(), base))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("_pack_ = 1")?;
+            if fields.is_empty() {
+                w.write_line("_fields_ = []")?;
+                return Ok(());
+            }
+            w.write_line("_fields_ = [")?;
+            {
+                let mut w = w.new_block();
+                for (field_name, field_type) in fields {
+                    w.write_line(format!("(\"{}\", {}),", field_name, field_type))?;
+                }
+            }
+            w.write_line("]")?;
+        }
+        Ok(())
+    }
+}
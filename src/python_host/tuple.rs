@@ -0,0 +1,20 @@
+use std::io::Write;
+
+use super::*;
+
+impl PythonHostGenerator {
+    pub fn define_as_tuple<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[ASTupleMember],
+    ) -> Result<(), Error> {
+        let mut fields = vec![];
+        for (i, member) in members.iter().enumerate() {
+            fields.push((format!("v{}", i), member.type_.as_lang()));
+            if member.padding > 0 {
+                fields.push(Self::padding_field(fields.len(), member.padding));
+            }
+        }
+        Self::define_ctypes_class(w, name, "ctypes.Structure", &fields)
+    }
+}
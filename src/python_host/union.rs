@@ -0,0 +1,56 @@
+use std::io::Write;
+
+use super::*;
+
+impl PythonHostGenerator {
+    pub fn define_as_union<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        let inner_name = format!("{}_member", name);
+        let mut inner_fields = vec![];
+        for member in &union_.members {
+            if !matches!(member.type_.as_ref(), ASType::Void) {
+                inner_fields.push((member.name.as_var(), member.type_.as_lang()));
+            }
+        }
+        // The union always has the size of its largest member, including its trailing padding
+        inner_fields.push((
+            "__size".to_string(),
+            format!("ctypes.c_uint8 * {}", union_.max_member_size),
+        ));
+        Self::define_ctypes_class(w, &inner_name, "ctypes.Union", &inner_fields)?;
+        w.eob()?;
+        w.eob()?;
+
+        let mut fields = vec![("tag".to_string(), union_.tag_repr.as_lang())];
+        if union_.padding_after_tag > 0 {
+            fields.push(Self::padding_field(fields.len(), union_.padding_after_tag));
+        }
+        fields.push(("member".to_string(), inner_name.as_type()));
+        Self::define_ctypes_class(w, name, "ctypes.Structure", &fields)?;
+        {
+            let mut w = w.new_block();
+            for (i, member) in union_.members.iter().enumerate() {
+                w.eob()?;
+                Self::define_union_member_accessor(&mut w, i, member)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn define_union_member_accessor<T: Write>(
+        w: &mut PrettyWriter<T>,
+        i: usize,
+        member: &ASUnionMember,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "def {}(self):",
+            format!("is_{}", member.name).as_fn()
+        ))?;
+        w.new_block()
+            .write_line(format!("return self.tag == {}", i))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use std::rc::Rc;
+
+use crate::astype::*;
+
+/// Doc comment line designating a function as the one closing the handle it takes
+const CLOSE_ANNOTATION: &str = "@close";
+
+/// A function releasing handles of a given type
+pub struct CloseFunction {
+    /// Name of the handle type
+    pub handle_type: String,
+    pub func: Rc<witx::Function>,
+}
+
+/// Functions whose first parameter is a handle of a given type
+pub struct HandleMethods {
+    /// Name of the handle type
+    pub handle_type: String,
+    pub funcs: Vec<Rc<witx::Function>>,
+}
+
+/// The handle type of the first parameter of a function, if it is a handle
+fn first_handle_type(func: &witx::Function) -> Option<String> {
+    match &func.params.first()?.tref {
+        witx::TypeRef::Name(named) if matches!(ASType::from(&named.tref), ASType::Handle(_)) => {
+            Some(named.name.as_str().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// The handle type closed by a function, if it takes a single handle and returns nothing
+fn closed_handle_type(func: &witx::Function) -> Option<String> {
+    if func.params.len() != 1 {
+        return None;
+    }
+    let handle_type = first_handle_type(func)?;
+    match func.results.as_slice() {
+        [result] => match ASType::from(&result.tref) {
+            ASType::Result(result) if matches!(result.ok_type.leaf(), ASType::Void) => {
+                Some(handle_type)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Finds the function closing each handle type of a module.
+///
+/// A function taking a single handle and returning nothing closes it if its documentation
+/// contains a `@close` line. Otherwise, `<type>_close` is used, or any function whose name
+/// ends with `close`.
+pub fn close_functions(module_witx: &witx::Module) -> Vec<CloseFunction> {
+    let mut candidates: Vec<(usize, CloseFunction)> = vec![];
+    for func in module_witx.funcs() {
+        let handle_type = match closed_handle_type(&func) {
+            None => continue,
+            Some(handle_type) => handle_type,
+        };
+        let name = func.name.as_str();
+        let priority = if func
+            .docs
+            .lines()
+            .any(|line| line.trim() == CLOSE_ANNOTATION)
+        {
+            0
+        } else if name == format!("{}_close", handle_type) {
+            1
+        } else if name == "close" || name.ends_with("_close") {
+            2
+        } else {
+            continue;
+        };
+        match candidates
+            .iter_mut()
+            .find(|(_, candidate)| candidate.handle_type == handle_type)
+        {
+            Some(candidate) if candidate.0 <= priority => {}
+            Some(candidate) => *candidate = (priority, CloseFunction { handle_type, func }),
+            None => candidates.push((priority, CloseFunction { handle_type, func })),
+        }
+    }
+    candidates
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Groups the functions of a module by the handle type of their first parameter
+pub fn handle_methods(module_witx: &witx::Module) -> Vec<HandleMethods> {
+    let mut methods: Vec<HandleMethods> = vec![];
+    for func in module_witx.funcs() {
+        let handle_type = match first_handle_type(&func) {
+            None => continue,
+            Some(handle_type) => handle_type,
+        };
+        match methods
+            .iter_mut()
+            .find(|methods| methods.handle_type == handle_type)
+        {
+            Some(methods) => methods.funcs.push(func),
+            None => methods.push(HandleMethods {
+                handle_type,
+                funcs: vec![func],
+            }),
+        }
+    }
+    methods
+}
+
+/// The name of a function as a method, without the handle type prefix:
+/// `symmetric_state_absorb()` becomes `SymmetricState::absorb()`
+pub fn method_name(handle_type: &str, func: &witx::Function) -> String {
+    let name = func.name.as_str();
+    match name.strip_prefix(handle_type) {
+        Some(suffix) if suffix.len() > 1 && suffix.starts_with('_') => suffix[1..].to_string(),
+        _ => name.to_string(),
+    }
+}
@@ -7,9 +7,34 @@ impl RustGenerator {
         w: &mut PrettyWriter<T>,
         module_name: &str,
         func_witx: &witx::Function,
+        options: &Options,
     ) -> Result<(), Error> {
-        assert_eq!(func_witx.abi, witx::Abi::Preview1);
         let name = func_witx.name.as_str().to_string();
+        let (params_decomposed, results_decomposed, result) = Self::decompose_func(func_witx);
+
+        let docs = &func_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+
+        Self::define_func_raw(
+            w,
+            module_name,
+            &name,
+            &params_decomposed,
+            &results_decomposed,
+            &result,
+            options,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the parameters of the raw import, followed by the pointers to the results
+    pub(super) fn decompose_func(
+        func_witx: &witx::Function,
+    ) -> (Vec<ASTypeDecomposed>, Vec<ASTypeDecomposed>, ASResult) {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
         let params_witx = &func_witx.params;
         let mut params = vec![];
         for param_witx in params_witx {
@@ -29,11 +54,6 @@ impl RustGenerator {
 
         let ok_type = result.ok_type.clone();
 
-        let docs = &func_witx.docs;
-        if !docs.is_empty() {
-            Self::write_docs(w, docs)?;
-        }
-
         let mut params_decomposed = vec![];
 
         for param in &params {
@@ -59,15 +79,6 @@ impl RustGenerator {
             results_decomposed.append(&mut decomposed);
         }
 
-        Self::define_func_raw(
-            w,
-            module_name,
-            &name,
-            &params_decomposed,
-            &results_decomposed,
-            &result,
-        )?;
-
         let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
         let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
         assert_eq!(
@@ -75,7 +86,7 @@ impl RustGenerator {
             params_decomposed.len() + results_decomposed.len() + 1
         );
 
-        Ok(())
+        (params_decomposed, results_decomposed, result)
     }
 
     fn define_func_raw<T: Write>(
@@ -85,7 +96,16 @@ impl RustGenerator {
         params_decomposed: &[ASTypeDecomposed],
         results_decomposed: &[ASTypeDecomposed],
         result: &ASResult,
+        options: &Options,
     ) -> Result<(), Error> {
+        // A parameter named like the function would shadow the raw import
+        let fn_name = name.as_fn();
+        let var = |param: &ASTypeDecomposed| names::unshadowed_var(param.name.as_var(), &fn_name);
+        let mock_module = if options.mocks {
+            Some(Self::mock_module_name(module_name))
+        } else {
+            None
+        };
         let results_decomposed_deref = results_decomposed
             .iter()
             .map(|result_ptr_type| match result_ptr_type.type_.as_ref() {
@@ -105,16 +125,12 @@ impl RustGenerator {
             1 => results_set[0].clone(),
             _ => format!("({})", results_set.join(", ")),
         };
-        w.indent()?.write(format!("pub fn {}(", name.as_fn()))?;
+        w.indent()?.write(format!("pub fn {}(", fn_name))?;
         if !params_decomposed.is_empty() || !results_decomposed.is_empty() {
             w.eol()?;
         }
         for param in params_decomposed {
-            w.write_line_continued(format!(
-                "{}: {},",
-                param.name.as_var(),
-                param.type_.as_lang(),
-            ))?;
+            w.write_line_continued(format!("{}: {},", var(param), param.type_.as_lang()))?;
         }
         w.write_line(format!(") -> Result<{}, Error> {{", rust_fn_result_str))?;
         {
@@ -122,18 +138,25 @@ impl RustGenerator {
 
             // Inner (raw) definition
             {
+                if options.mocks {
+                    w.write_line("#[cfg(target_arch = \"wasm32\")]")?;
+                }
                 w.write_line(format!("#[link(wasm_import_module = \"{}\")]", module_name))?;
                 w.write_line("extern \"C\" {")?;
                 {
                     let mut w = w.new_block();
-                    w.indent()?.write(format!("fn {}(", name.as_fn()))?;
+                    // The import name must remain the WITX name, whatever the naming policy
+                    if fn_name != name {
+                        w.write_line(format!("#[link_name = \"{}\"]", name))?;
+                    }
+                    w.indent()?.write(format!("fn {}(", fn_name))?;
                     if !params_decomposed.is_empty() {
                         w.eol()?;
                     }
                     for param in params_decomposed.iter().chain(results_decomposed.iter()) {
                         w.write_line_continued(format!(
                             "{}: {},",
-                            param.name.as_var(),
+                            var(param),
                             param.type_.as_lang(),
                         ))?;
                     }
@@ -142,6 +165,31 @@ impl RustGenerator {
                 w.write_line("}")?;
             }
 
+            // Native targets call the mock implementation instead
+            if let Some(mock_module) = &mock_module {
+                w.write_line("#[cfg(not(target_arch = \"wasm32\"))]")?;
+                w.indent()?.write(format!("unsafe fn {}(", fn_name))?;
+                if !params_decomposed.is_empty() || !results_decomposed.is_empty() {
+                    w.eol()?;
+                }
+                for param in params_decomposed.iter().chain(results_decomposed.iter()) {
+                    w.write_line_continued(format!("{}: {},", var(param), param.type_.as_lang()))?;
+                }
+                w.write_line(format!(") -> {} {{", result.error_type.as_lang()))?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line(format!(
+                        "{}::with_imports(|imports| imports.{}(",
+                        mock_module, fn_name
+                    ))?;
+                    for param in params_decomposed.iter().chain(results_decomposed.iter()) {
+                        w.write_line_continued(format!("{},", var(param)))?;
+                    }
+                    w.write_line("))")?;
+                }
+                w.write_line("}")?;
+            }
+
             // Wrapper
             for result in &results_decomposed_deref {
                 w.write_line(format!(
@@ -150,14 +198,30 @@ impl RustGenerator {
                 ))?;
             }
 
-            w.write_line(format!("let res = unsafe {{ {}(", name.as_fn()))?;
+            w.write_line(format!("let res = unsafe {{ {}(", fn_name))?;
             for param in params_decomposed {
-                w.write_line_continued(format!("{},", param.name.as_var()))?;
+                w.write_line_continued(format!("{},", var(param)))?;
             }
             for result in results_decomposed_deref.iter() {
                 w.write_line_continued(format!("{}.as_mut_ptr(),", result.name.as_var()))?;
             }
             w.write_line(")};")?;
+            if options.trace {
+                let args_format = params_decomposed
+                    .iter()
+                    .map(|param| format!("{}={{:?}}", param.name.as_var()))
+                    .collect::<Vec<_>>();
+                w.write_line(format!(
+                    "eprintln!(\"[{}] {}({}) -> {{:?}}\", {}res);",
+                    module_name,
+                    name,
+                    args_format.join(", "),
+                    params_decomposed
+                        .iter()
+                        .map(|param| format!("{}, ", var(param)))
+                        .collect::<String>()
+                ))?;
+            }
             w.write_lines(
                 "if res != 0 {
     return Err(Error::WasiError(res as _));
@@ -0,0 +1,67 @@
+use std::io::Write;
+
+use super::*;
+use crate::resources::CloseFunction;
+
+impl RustGenerator {
+    pub fn define_as_handle<T: Write>(w: &mut PrettyWriter<T>, name: &str) -> Result<(), Error> {
+        w.write_line("#[repr(transparent)]")?
+            .write_line("#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]")?
+            .write_line(format!("pub struct {}(pub WasiHandle);", name.as_type()))?;
+        Ok(())
+    }
+
+    /// A wrapper owning a handle, and closing it when dropped
+    pub fn define_owned_handle<T: Write>(
+        w: &mut PrettyWriter<T>,
+        close_function: &CloseFunction,
+    ) -> Result<(), Error> {
+        let handle_type = close_function.handle_type.as_type();
+        let owned_type = format!("owned_{}", close_function.handle_type).as_type();
+        let close_fn = close_function.func.name.as_str().as_fn();
+
+        w.write_line(format!(
+            "/// An owned `{}`, closed with `{}()` when dropped",
+            handle_type, close_fn
+        ))?
+        .write_line("#[derive(Debug)]")?
+        .write_line(format!("pub struct {}({});", owned_type, handle_type))?
+        .eob()?;
+
+        w.write_line(format!("impl {} {{", owned_type))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("/// Takes the ownership of a handle")?
+                .write_line(format!("pub fn new(handle: {}) -> Self {{", handle_type))?
+                .indent()?
+                .write_line(format!("{}(handle)", owned_type))?
+                .write_line("}")?
+                .eob()?;
+            w.write_line("/// Returns the handle, that remains owned by the wrapper")?
+                .write_line(format!("pub fn handle(&self) -> {} {{", handle_type))?
+                .indent()?
+                .write_line("self.0")?
+                .write_line("}")?
+                .eob()?;
+            w.write_line("/// Releases the ownership of the handle, without closing it")?
+                .write_line(format!("pub fn into_inner(self) -> {} {{", handle_type))?;
+            w.new_block()
+                .write_line("let handle = self.0;")?
+                .write_line("std::mem::forget(self);")?
+                .write_line("handle")?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!("impl Drop for {} {{", owned_type))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("fn drop(&mut self) {")?
+                .indent()?
+                .write_line(format!("let _ = {}(self.0);", close_fn))?
+                .write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+}
@@ -33,14 +33,14 @@ pub type WasiMutPtr<T> = *mut T;
 pub type WasiStringBytesPtr = WasiPtr<Char8>;
 
 #[repr(C)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct WasiSlice<T> {
     ptr: WasiPtr<T>,
     len: usize,
 }
 
 #[repr(C)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct WasiMutSlice<T> {
     ptr: WasiMutPtr<T>,
     len: usize,
@@ -84,7 +84,7 @@ impl<T> WasiMutSlice<T> {
 }
 
 #[repr(C)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct WasiString {
     ptr: WasiStringBytesPtr,
     len: usize,
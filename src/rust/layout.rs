@@ -0,0 +1,147 @@
+use std::io::Write;
+
+use super::*;
+
+/// Alignment of a generated Rust type on `wasm32`
+pub fn rust_align(type_: &ASType) -> usize {
+    match type_ {
+        ASType::Alias(alias) => rust_align(&alias.type_),
+        ASType::Struct(members) => {
+            let members: Vec<_> = members
+                .iter()
+                .map(|member| (member.offset, member.type_.as_ref(), member.padding))
+                .collect();
+            record_align(&members)
+        }
+        ASType::Tuple(members) => {
+            let members: Vec<_> = members
+                .iter()
+                .map(|member| (member.offset, member.type_.as_ref(), member.padding))
+                .collect();
+            record_align(&members)
+        }
+        ASType::Union(union_) => union_
+            .members
+            .iter()
+            .map(|member| rust_align(&member.type_))
+            .fold(rust_align(&union_.tag_repr), usize::max),
+        ASType::Option(option) => rust_align(&option.tag_repr).max(rust_align(&option.type_)),
+        ASType::Result(result) => rust_align(&result.tag_repr)
+            .max(rust_align(&result.ok_type))
+            .max(rust_align(&result.error_type)),
+        ASType::Constants(constants) => rust_align(&constants.repr),
+        ASType::Enum(enum_) => rust_align(&enum_.repr),
+        // Pointer and length
+        ASType::Slice(_) | ASType::String(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => 4,
+        _ => type_.mem_size().max(1),
+    }
+}
+
+/// Alignment of a structure or a tuple: the one of its largest field, or 1 if it is packed
+fn record_align(members: &[(usize, &ASType, usize)]) -> usize {
+    natural_align(members).unwrap_or(1)
+}
+
+/// Sizes of the fields filling `pad_len` bytes of padding, in declaration order
+fn padding_fields(pad_len: usize) -> impl Iterator<Item = (usize, usize)> {
+    IntoIterator::into_iter([
+        (1, pad_len & 1),
+        (2, (pad_len & 3) / 2),
+        (4, (pad_len & 7) / 4),
+        (8, pad_len / 8),
+    ])
+    .flat_map(|(size, count)| (0..count).map(move |i| (size, i)))
+}
+
+/// The alignment of a `repr(C)` record, if it places the members, followed by their padding
+/// fields, at the offsets computed for the WebAssembly layout
+fn natural_align(members: &[(usize, &ASType, usize)]) -> Option<usize> {
+    let mut offset = 0;
+    let mut align = 1;
+    for (member_offset, member_type, padding) in members {
+        let member_align = rust_align(member_type);
+        if *member_offset != offset || offset % member_align != 0 {
+            return None;
+        }
+        align = align.max(member_align);
+        offset += member_type.mem_size();
+        for (size, _) in padding_fields(*padding) {
+            if offset % size != 0 {
+                return None;
+            }
+            align = align.max(size);
+            offset += size;
+        }
+    }
+    if offset % align != 0 {
+        return None;
+    }
+    Some(align)
+}
+
+/// Whether a type contains pointers or sizes, whose layout is only the WebAssembly one on
+/// `wasm32`
+fn has_pointers(type_: &ASType) -> bool {
+    match type_ {
+        ASType::Alias(alias) => has_pointers(&alias.type_),
+        ASType::Struct(members) => members.iter().any(|member| has_pointers(&member.type_)),
+        ASType::Tuple(members) => members.iter().any(|member| has_pointers(&member.type_)),
+        ASType::Union(union_) => union_
+            .members
+            .iter()
+            .any(|member| has_pointers(&member.type_)),
+        ASType::ConstPtr(_)
+        | ASType::MutPtr(_)
+        | ASType::Slice(_)
+        | ASType::String(_)
+        | ASType::ReadBuffer(_)
+        | ASType::WriteBuffer(_)
+        | ASType::USize => true,
+        _ => false,
+    }
+}
+
+/// Whether a record can be `repr(C)`, or has to be packed to match the WebAssembly layout
+pub fn has_natural_layout(members: &[(usize, &ASType, usize)]) -> bool {
+    natural_align(members).is_some()
+}
+
+impl RustGenerator {
+    /// Padding fields, smallest first so that each of them is aligned
+    pub fn define_padding<T: Write>(w: &mut PrettyWriter<T>, pad_len: usize) -> Result<(), Error> {
+        for (size, i) in padding_fields(pad_len) {
+            w.write_line(format!("__pad{}_{}: u{},", size * 8, i, size * 8))?;
+        }
+        Ok(())
+    }
+
+    /// Compile-time checks that a type that isn't packed has the WebAssembly size and alignment.
+    ///
+    /// Types without pointers have the same layout on 64-bit targets, where `u64` and `f64` are
+    /// also 8-byte aligned, so that they are checked by native builds as well.
+    pub fn define_layout_assertions<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        let cfg = if has_pointers(type_) {
+            "#[cfg(target_arch = \"wasm32\")]"
+        } else {
+            "#[cfg(any(target_arch = \"wasm32\", target_pointer_width = \"64\"))]"
+        };
+        for (function, value) in [
+            ("size_of", type_.mem_size()),
+            ("align_of", rust_align(type_)),
+        ] {
+            w.write_line(cfg)?
+                .write_line(format!(
+                    "const _: () = assert!(std::mem::{}::<{}>() == {});",
+                    function,
+                    name.as_type(),
+                    value
+                ))?;
+        }
+        w.eob()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use std::io::Write;
+
+use super::*;
+use crate::resources::{self, HandleMethods};
+
+impl RustGenerator {
+    /// Functions taking a handle as their first parameter, as methods of the handle type
+    pub fn define_handle_methods<T: Write>(
+        w: &mut PrettyWriter<T>,
+        methods: &HandleMethods,
+    ) -> Result<(), Error> {
+        w.write_line(format!("impl {} {{", methods.handle_type.as_type()))?;
+        {
+            let mut w = w.new_block();
+            for (i, func) in methods.funcs.iter().enumerate() {
+                if i > 0 {
+                    w.eob()?;
+                }
+                Self::define_handle_method(&mut w, &methods.handle_type, func)?;
+            }
+        }
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+
+    fn define_handle_method<T: Write>(
+        w: &mut PrettyWriter<T>,
+        handle_type: &str,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let (params_decomposed, results_decomposed, _) = Self::decompose_func(func_witx);
+        let results_set = results_decomposed
+            .iter()
+            .map(|result_ptr_type| match result_ptr_type.type_.as_ref() {
+                ASType::MutPtr(result_type) => result_type.as_lang(),
+                _ => panic!("Result type is not a pointer"),
+            })
+            .collect::<Vec<_>>();
+        let rust_fn_result_str = match results_set.len() {
+            0 => "()".to_string(),
+            1 => results_set[0].clone(),
+            _ => format!("({})", results_set.join(", ")),
+        };
+
+        let docs = &func_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+        let method_name = resources::method_name(handle_type, func_witx).as_fn();
+        // The first parameter is the handle itself
+        let params = &params_decomposed[1..];
+        if params.is_empty() {
+            w.write_line(format!(
+                "pub fn {}(self) -> Result<{}, Error> {{",
+                method_name, rust_fn_result_str
+            ))?;
+        } else {
+            w.write_line(format!("pub fn {}(", method_name))?
+                .write_line_continued("self,")?;
+            for param in params {
+                w.write_line_continued(format!(
+                    "{}: {},",
+                    param.name.as_var(),
+                    param.type_.as_lang(),
+                ))?;
+            }
+            w.write_line(format!(") -> Result<{}, Error> {{", rust_fn_result_str))?;
+        }
+        let args = std::iter::once("self".to_string())
+            .chain(params.iter().map(|param| param.name.as_var()))
+            .collect::<Vec<_>>();
+        // A parameter with the same name as the function would shadow it
+        let fn_name = func_witx.name.as_str().as_fn();
+        let fn_path = if params.iter().any(|param| param.name.as_var() == fn_name) {
+            format!("self::{}", fn_name)
+        } else {
+            fn_name
+        };
+        {
+            let mut w = w.new_block();
+            let call = format!("{}({})", fn_path, args.join(", "));
+            if w.indent_level() as usize * 4 + call.len() <= 80 {
+                w.write_line(call)?;
+            } else {
+                w.write_line(format!("{}(", fn_path))?;
+                for arg in &args {
+                    w.write_line_continued(format!("{},", arg))?;
+                }
+                w.write_line(")")?;
+            }
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+}
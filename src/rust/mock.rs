@@ -0,0 +1,100 @@
+use std::io::Write;
+
+use convert_case::{Case, Casing};
+
+use super::*;
+
+impl RustGenerator {
+    /// Name of the module with the mock implementation of the imports of a WITX module
+    pub fn mock_module_name(module_name: &str) -> String {
+        format!("{}_mock", module_name.to_case(Case::Snake))
+    }
+
+    /// Defines a trait with a method per import, and a thread-local implementation
+    /// of that trait, called instead of the imports on native targets
+    pub fn define_mock_imports<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        module_witx: &witx::Module,
+    ) -> Result<(), Error> {
+        w.write_line("#[cfg(not(target_arch = \"wasm32\"))]")?;
+        w.write_line(format!(
+            "pub mod {} {{",
+            Self::mock_module_name(module_name)
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("#[allow(unused_imports)]")?;
+            w.write_line("use super::*;")?;
+            w.eob()?;
+
+            w.write_line(format!(
+                "/// Implementation of the imports of the [{}] module, for native targets.",
+                module_name
+            ))?;
+            w.write_line("/// Imports that are not implemented panic.")?;
+            w.write_line("pub trait Imports {")?;
+            {
+                let mut w = w.new_block();
+                for (i, func) in module_witx.funcs().enumerate() {
+                    if i > 0 {
+                        w.eob()?;
+                    }
+                    let name = func.name.as_str();
+                    let (params_decomposed, results_decomposed, result) =
+                        Self::decompose_func(func.as_ref());
+                    w.write_line(format!("fn {}(", name.as_fn()))?;
+                    w.write_line_continued("&mut self,")?;
+                    for param in params_decomposed.iter().chain(results_decomposed.iter()) {
+                        w.write_line_continued(format!(
+                            "_{}: {},",
+                            param.name.as_var(),
+                            param.type_.as_lang(),
+                        ))?;
+                    }
+                    w.write_line(format!(") -> {} {{", result.error_type.as_lang()))?;
+                    w.new_block()
+                        .write_line(format!("unimplemented!(\"{}\")", name))?;
+                    w.write_line("}")?;
+                }
+            }
+            w.write_line("}")?;
+            w.eob()?;
+
+            w.write_lines(
+                "thread_local! {
+    static IMPORTS: std::cell::RefCell<Option<Box<dyn Imports>>> = std::cell::RefCell::new(None);
+}",
+            )?;
+            w.eob()?;
+            w.write_lines(
+                "/// Sets the implementation of the imports for the current thread
+pub fn set_imports<I: Imports + 'static>(imports: I) {
+    IMPORTS.with(|x| *x.borrow_mut() = Some(Box::new(imports)));
+}",
+            )?;
+            w.eob()?;
+            w.write_lines(
+                "/// Removes the implementation of the imports for the current thread, and returns it
+pub fn take_imports() -> Option<Box<dyn Imports>> {
+    IMPORTS.with(|x| x.borrow_mut().take())
+}",
+            )?;
+            w.eob()?;
+            w.write_lines(
+                "pub(super) fn with_imports<R>(f: impl FnOnce(&mut dyn Imports) -> R) -> R {
+    IMPORTS.with(|x| {
+        let mut imports = x.borrow_mut();
+        let imports = imports
+            .as_deref_mut()
+            .expect(\"The imports are not implemented for this thread\");
+        f(imports)
+    })
+}",
+            )?;
+        }
+        w.write_line("}")?;
+        w.eob()?;
+        Ok(())
+    }
+}
@@ -1,13 +1,20 @@
-mod common;
+pub(crate) mod common;
 mod function;
+mod handle;
 mod header;
+mod layout;
+mod method;
+mod mock;
 mod r#struct;
+mod traits;
 mod tuple;
 mod union;
 
 use std::io::Write;
 
 use common::*;
+use layout::*;
+pub use traits::RustTrait;
 
 use super::*;
 use crate::astype::*;
@@ -39,6 +46,8 @@ impl<T: Write> Generator<T> for RustGenerator {
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
+        names::check_name_collisions(&module_witx, skip_imports, &NAME_CONVERSIONS)?;
+
         if !options.skip_header {
             Self::header(&mut w)?;
         }
@@ -67,11 +76,27 @@ impl<T: Write> Generator<T> for RustGenerator {
                     }
                 })
                 .collect();
-            Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
+            Self::define_type(&mut w, type_.as_ref(), &constants_for_type, options)?;
         }
 
         for func in module_witx.funcs() {
-            Self::define_func(&mut w, &module_name, func.as_ref())?;
+            Self::define_func(&mut w, &module_name, func.as_ref(), options)?;
+        }
+
+        if options.methods {
+            for methods in resources::handle_methods(&module_witx) {
+                Self::define_handle_methods(&mut w, &methods)?;
+            }
+        }
+
+        if options.raii {
+            for close_function in resources::close_functions(&module_witx) {
+                Self::define_owned_handle(&mut w, &close_function)?;
+            }
+        }
+
+        if options.mocks {
+            Self::define_mock_imports(&mut w, &module_name, &module_witx)?;
         }
 
         Ok(())
@@ -167,6 +192,7 @@ impl RustGenerator {
         w: &mut PrettyWriter<T>,
         name: &str,
         type_: &ASType,
+        options: &Options,
     ) -> Result<(), Error> {
         match type_ {
             ASType::Alias(_)
@@ -184,16 +210,16 @@ impl RustGenerator {
             | ASType::S32
             | ASType::S64
             | ASType::USize
-            | ASType::Handle(_)
             | ASType::Slice(_)
             | ASType::String(_)
             | ASType::ReadBuffer(_)
             | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
+            ASType::Handle(_) => Self::define_as_handle(w, name)?,
             ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
-            ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
+            ASType::Union(union_) => Self::define_as_union(w, name, union_, options)?,
             ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
-            ASType::Tuple(members) => Self::define_as_tuple(w, name, members)?,
-            ASType::Struct(members) => Self::define_as_struct(w, name, members)?,
+            ASType::Tuple(members) => Self::define_as_tuple(w, name, members, options)?,
+            ASType::Struct(members) => Self::define_as_struct(w, name, members, options)?,
             _ => {
                 dbg!(type_);
                 unimplemented!();
@@ -252,6 +278,7 @@ impl RustGenerator {
         w: &mut PrettyWriter<T>,
         type_witx: &witx::NamedType,
         constants: &[ASConstant],
+        options: &Options,
     ) -> Result<(), Error> {
         let docs = &type_witx.docs;
         if !docs.is_empty() {
@@ -265,7 +292,7 @@ impl RustGenerator {
             }
             witx::TypeRef::Value(type_witx) => {
                 let t = ASType::from(type_witx.as_ref());
-                Self::define_as_type(w, type_name, &t)?
+                Self::define_as_type(w, type_name, &t, options)?
             }
         }
         w.eob()?;
@@ -0,0 +1,313 @@
+use std::io::Write;
+
+use strum_macros::{Display, EnumString, VariantNames};
+
+use super::*;
+
+/// Standard traits implemented for Rust structures, tuples and unions
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Display, EnumString, VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RustTrait {
+    Debug,
+    PartialEq,
+    /// Only for types without floating-point members; implies `partial-eq`
+    Eq,
+    /// Only for types without floating-point members
+    Hash,
+    Default,
+}
+
+impl Options {
+    /// Whether a trait has to be implemented. All of them are, unless a selection was made.
+    fn rust_trait(&self, trait_: RustTrait) -> bool {
+        let selected = |trait_| match &self.derive {
+            None => true,
+            Some(traits) => traits.contains(&trait_),
+        };
+        match trait_ {
+            RustTrait::PartialEq => selected(RustTrait::PartialEq) || selected(RustTrait::Eq),
+            _ => selected(trait_),
+        }
+    }
+}
+
+/// Whether a type contains floating-point values, that prevent `Eq` and `Hash` from being
+/// implemented
+fn has_floats(type_: &ASType) -> bool {
+    match type_ {
+        ASType::F32 | ASType::F64 => true,
+        ASType::Alias(alias) => has_floats(&alias.type_),
+        ASType::Struct(members) => members.iter().any(|member| has_floats(&member.type_)),
+        ASType::Tuple(members) => members.iter().any(|member| has_floats(&member.type_)),
+        ASType::Union(union_) => union_
+            .members
+            .iter()
+            .any(|member| has_floats(&member.type_)),
+        ASType::Slice(element_type)
+        | ASType::ReadBuffer(element_type)
+        | ASType::WriteBuffer(element_type) => has_floats(element_type),
+        _ => false,
+    }
+}
+
+impl RustGenerator {
+    /// Trait implementations for structures and tuples.
+    ///
+    /// They may be packed, so members are copied out with `{ self.x }` instead of being
+    /// borrowed, which is why the traits can't simply be derived.
+    pub fn define_record_traits<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[(String, &ASType)],
+        options: &Options,
+    ) -> Result<(), Error> {
+        let type_name = name.as_type();
+        let eq_hash = !members
+            .iter()
+            .any(|(_, member_type)| has_floats(member_type));
+
+        if options.rust_trait(RustTrait::Debug) {
+            w.write_line(format!("impl std::fmt::Debug for {} {{", type_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(
+                    "fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {",
+                )?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line(format!("f.debug_struct(\"{}\")", type_name))?;
+                    for (member_name, _) in members {
+                        w.write_line_continued(format!(
+                            ".field(\"{}\", &{{ self.{} }})",
+                            member_name, member_name
+                        ))?;
+                    }
+                    w.write_line_continued(".finish()")?;
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+        }
+
+        if options.rust_trait(RustTrait::PartialEq) {
+            w.write_line(format!("impl PartialEq for {} {{", type_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("fn eq(&self, other: &Self) -> bool {")?;
+                {
+                    let mut w = w.new_block();
+                    if members.is_empty() {
+                        w.write_line("true")?;
+                    }
+                    for (i, (member_name, _)) in members.iter().enumerate() {
+                        let comparison = format!(
+                            "&{{ self.{} }} == &{{ other.{} }}",
+                            member_name, member_name
+                        );
+                        if i == 0 {
+                            w.write_line(comparison)?;
+                        } else {
+                            w.write_line_continued(format!("&& {}", comparison))?;
+                        }
+                    }
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+        }
+
+        if eq_hash && options.rust_trait(RustTrait::Eq) {
+            w.write_line(format!("impl Eq for {} {{}}", type_name))?
+                .eob()?;
+        }
+
+        if eq_hash && options.rust_trait(RustTrait::Hash) {
+            w.write_line(format!("impl std::hash::Hash for {} {{", type_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("fn hash<H: std::hash::Hasher>(&self, state: &mut H) {")?;
+                {
+                    let mut w = w.new_block();
+                    for (member_name, _) in members {
+                        w.write_line(format!(
+                            "std::hash::Hash::hash(&{{ self.{} }}, state);",
+                            member_name
+                        ))?;
+                    }
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+        }
+
+        if options.rust_trait(RustTrait::Default) {
+            Self::define_zeroed_default(w, name)?;
+        }
+        Ok(())
+    }
+
+    /// Trait implementations for tagged unions, looking only at the active member
+    pub fn define_union_traits<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        union_: &ASUnion,
+        options: &Options,
+    ) -> Result<(), Error> {
+        let type_name = name.as_type();
+        let eq_hash = !union_
+            .members
+            .iter()
+            .any(|member| has_floats(&member.type_));
+        let member_ref = |member: &ASUnionMember| {
+            format!(
+                "unsafe {{ &(*self.member.as_ptr()).{} }}",
+                member.name.as_var()
+            )
+        };
+
+        if options.rust_trait(RustTrait::Debug) {
+            w.write_line(format!("impl std::fmt::Debug for {} {{", type_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(
+                    "fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {",
+                )?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line("match self.tag {")?;
+                    {
+                        let mut w = w.new_block();
+                        for (i, member) in union_.members.iter().enumerate() {
+                            match member.type_.as_ref() {
+                                ASType::Void => w.write_line(format!(
+                                    "{} => f.write_str(\"{}\"),",
+                                    i,
+                                    member.name.as_type()
+                                ))?,
+                                _ => w.write_line(format!(
+                                    "{} => f.debug_tuple(\"{}\").field({}).finish(),",
+                                    i,
+                                    member.name.as_type(),
+                                    member_ref(member)
+                                ))?,
+                            };
+                        }
+                        w.write_line(format!(
+                            "tag => f.debug_struct(\"{}\").field(\"tag\", &tag).finish(),",
+                            type_name
+                        ))?;
+                    }
+                    w.write_line("}")?;
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+        }
+
+        if options.rust_trait(RustTrait::PartialEq) {
+            w.write_line(format!("impl PartialEq for {} {{", type_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("fn eq(&self, other: &Self) -> bool {")?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line("if self.tag != other.tag {")?;
+                    w.new_block().write_line("return false;")?;
+                    w.write_line("}")?;
+                    w.write_line("match self.tag {")?;
+                    {
+                        let mut w = w.new_block();
+                        for (i, member) in union_.members.iter().enumerate() {
+                            if !matches!(member.type_.as_ref(), ASType::Void) {
+                                w.write_line(format!(
+                                    "{} => self.as_{}() == other.as_{}(),",
+                                    i,
+                                    member.name.as_fn_suffix(),
+                                    member.name.as_fn_suffix()
+                                ))?;
+                            }
+                        }
+                        w.write_line("_ => true,")?;
+                    }
+                    w.write_line("}")?;
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+        }
+
+        if eq_hash && options.rust_trait(RustTrait::Eq) {
+            w.write_line(format!("impl Eq for {} {{}}", type_name))?
+                .eob()?;
+        }
+
+        if eq_hash && options.rust_trait(RustTrait::Hash) {
+            w.write_line(format!("impl std::hash::Hash for {} {{", type_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("fn hash<H: std::hash::Hasher>(&self, state: &mut H) {")?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line("std::hash::Hash::hash(&self.tag, state);")?;
+                    w.write_line("match self.tag {")?;
+                    {
+                        let mut w = w.new_block();
+                        for (i, member) in union_.members.iter().enumerate() {
+                            if !matches!(member.type_.as_ref(), ASType::Void) {
+                                w.write_line(format!(
+                                    "{} => std::hash::Hash::hash({}, state),",
+                                    i,
+                                    member_ref(member)
+                                ))?;
+                            }
+                        }
+                        w.write_line("_ => {}")?;
+                    }
+                    w.write_line("}")?;
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+        }
+
+        if options.rust_trait(RustTrait::Default) {
+            Self::define_zeroed_default(w, name)?;
+        }
+        Ok(())
+    }
+
+    /// Traits derived by the Rust enum holding the content of a union
+    pub fn union_value_derives(union_: &ASUnion, options: &Options) -> String {
+        let eq_hash = !union_
+            .members
+            .iter()
+            .any(|member| has_floats(&member.type_));
+        let mut derives = vec!["Copy", "Clone"];
+        if options.rust_trait(RustTrait::Debug) {
+            derives.push("Debug");
+        }
+        if options.rust_trait(RustTrait::PartialEq) {
+            derives.push("PartialEq");
+        }
+        if eq_hash && options.rust_trait(RustTrait::Eq) {
+            derives.push("Eq");
+        }
+        if eq_hash && options.rust_trait(RustTrait::Hash) {
+            derives.push("Hash");
+        }
+        format!("#[derive({})]", derives.join(", "))
+    }
+
+    /// WITX types only contain integers, floats and pointers, that are all valid when zeroed
+    fn define_zeroed_default<T: Write>(w: &mut PrettyWriter<T>, name: &str) -> Result<(), Error> {
+        w.write_line(format!("impl Default for {} {{", name.as_type()))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("fn default() -> Self {")?;
+            w.new_block().write_line("unsafe { std::mem::zeroed() }")?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+}
@@ -23,9 +23,19 @@ impl RustGenerator {
         w: &mut PrettyWriter<T>,
         name: &str,
         members: &[ASTupleMember],
+        options: &Options,
     ) -> Result<(), Error> {
-        w.write_line("#[repr(C, packed)]")?
-            .write_line("#[derive(Copy, Clone, Debug)]")?
+        let layout: Vec<_> = members
+            .iter()
+            .map(|member| (member.offset, member.type_.as_ref(), member.padding))
+            .collect();
+        let natural_layout = has_natural_layout(&layout);
+        if natural_layout {
+            w.write_line("#[repr(C)]")?;
+        } else {
+            w.write_line("#[repr(C, packed)]")?;
+        }
+        w.write_line("#[derive(Copy, Clone)]")?
             .write_line(format!("pub struct {} {{ // -- Tuple", name.as_type()))?;
         {
             let mut w = w.new_block();
@@ -33,22 +43,20 @@ impl RustGenerator {
                 let member_type = member.type_.as_ref();
                 w.write_line(format!("pub v{}: {},", i, member_type.as_lang()))?;
 
-                let pad_len = member.padding;
-                for i in 0..(pad_len & 1) {
-                    w.write_line(format!("__pad8_{}: u8,", i))?;
-                }
-                for i in 0..(pad_len & 3) / 2 {
-                    w.write_line(format!("__pad16_{}: u16,", i))?;
-                }
-                for i in 0..(pad_len & 7) / 4 {
-                    w.write_line(format!("__pad32_{}: u32,", i))?;
-                }
-                for i in 0..pad_len / 8 {
-                    w.write_line(format!("__pad64_{}: u64,", i))?;
-                }
+                Self::define_padding(&mut w, member.padding)?;
             }
         }
         w.write_line("}")?.eob()?;
+        if natural_layout {
+            Self::define_layout_assertions(w, name, &ASType::Tuple(members.to_vec()))?;
+        }
+
+        let members: Vec<_> = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| (format!("v{}", i), member.type_.as_ref()))
+            .collect();
+        Self::define_record_traits(w, name, &members, options)?;
         Ok(())
     }
 }
@@ -41,17 +41,55 @@ impl RustGenerator {
             }
             w.write_line("}")?.eob()?;
 
-            // get_*
+            // into_*
             w.write_line(format!(
-                "pub fn into_{}(self) -> {} {{",
+                "pub fn into_{}(self) -> Option<{}> {{",
                 name.as_fn_suffix(),
                 member.type_.as_lang()
             ))?;
             {
                 let mut w = w.new_block();
-                w.write_line(format!("assert_eq!(self.tag, {});", i))?;
+                w.write_line(format!("if self.tag != {} {{", i))?;
+                w.new_block().write_line("return None;")?;
+                w.write_line("}")?;
                 w.write_line(format!(
-                    "unsafe {{ self.member.assume_init().{} }}",
+                    "Some(unsafe {{ self.member.assume_init().{} }})",
+                    member.name.as_var()
+                ))?;
+            }
+            w.write_line("}")?.eob()?;
+
+            // as_*
+            w.write_line(format!(
+                "pub fn as_{}(&self) -> Option<&{}> {{",
+                name.as_fn_suffix(),
+                member.type_.as_lang()
+            ))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("if self.tag != {} {{", i))?;
+                w.new_block().write_line("return None;")?;
+                w.write_line("}")?;
+                w.write_line(format!(
+                    "Some(unsafe {{ &(*self.member.as_ptr()).{} }})",
+                    member.name.as_var()
+                ))?;
+            }
+            w.write_line("}")?.eob()?;
+
+            // as_*_mut
+            w.write_line(format!(
+                "pub fn as_{}_mut(&mut self) -> Option<&mut {}> {{",
+                name.as_fn_suffix(),
+                member.type_.as_lang()
+            ))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("if self.tag != {} {{", i))?;
+                w.new_block().write_line("return None;")?;
+                w.write_line("}")?;
+                w.write_line(format!(
+                    "Some(unsafe {{ &mut (*self.member.as_mut_ptr()).{} }})",
                     member.name.as_var()
                 ))?;
             }
@@ -65,7 +103,7 @@ impl RustGenerator {
             ))?;
             {
                 let mut w = w.new_block();
-                w.write_line(format!("assert_eq!(self.tag, {});", i))?;
+                w.write_line(format!("assert!(self.tag == {});", i))?;
                 w.write_line(format!(
                     "let uval = {} {{ {}: val }};",
                     inner_name.as_type(),
@@ -124,10 +162,12 @@ impl RustGenerator {
         w: &mut PrettyWriter<T>,
         name: &str,
         union_: &ASUnion,
+        options: &Options,
     ) -> Result<(), Error> {
         let tag_repr = union_.tag_repr.as_ref();
         let inner_name = format!("{}_member", name);
         w.write_line("#[repr(C)]")?
+            .write_line("#[derive(Copy, Clone)]")?
             .write_line(format!("pub union {} {{", inner_name.as_type()))?;
         {
             let mut w = w.new_block();
@@ -152,24 +192,16 @@ impl RustGenerator {
         w.write_line("}")?;
         w.eob()?;
 
-        w.write_line("#[repr(C, packed)]")?
+        // The padding after the tag already aligns the member, so that the union doesn't need
+        // to be packed, and references to its members can be safely borrowed. The layout is
+        // checked at compile time.
+        w.write_line("#[repr(C)]")?
+            .write_line("#[derive(Copy, Clone)]")?
             .write_line(format!("pub struct {} {{", name.as_type()))?;
         {
             let mut w = w.new_block();
             w.write_line(format!("pub tag: {},", tag_repr.as_lang()))?;
-            let pad_len = union_.padding_after_tag;
-            for i in 0..(pad_len & 1) {
-                w.write_line(format!("__pad8_{}: u8,", i))?;
-            }
-            for i in 0..(pad_len & 3) / 2 {
-                w.write_line(format!("__pad16_{}: u16,", i))?;
-            }
-            for i in 0..(pad_len & 7) / 4 {
-                w.write_line(format!("__pad32_{}: u32,", i))?;
-            }
-            for i in 0..pad_len / 8 {
-                w.write_line(format!("__pad64_{}: u64,", i))?;
-            }
+            Self::define_padding(&mut w, union_.padding_after_tag)?;
             w.write_line(format!(
                 "pub member: std::mem::MaybeUninit<{}>,",
                 inner_name.as_type()
@@ -177,6 +209,7 @@ impl RustGenerator {
         }
         w.write_line("}")?;
         w.eob()?;
+        Self::define_layout_assertions(w, name, &ASType::Union(union_.clone()))?;
 
         w.write_line(format!("impl {} {{", name.as_type()))?;
         {
@@ -190,12 +223,158 @@ impl RustGenerator {
             }
             w.write_line("}")?.eob()?;
 
+            let kind_name = format!("{}_kind", name).as_type();
+            w.write_line("/// The active member. Panics if the tag is not valid.")?;
+            w.write_line(format!("pub fn kind(&self) -> {} {{", kind_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("match self.tag {")?;
+                {
+                    let mut w = w.new_block();
+                    for (i, member) in union_.members.iter().enumerate() {
+                        w.write_line(format!(
+                            "{} => {}::{},",
+                            i,
+                            kind_name,
+                            member.name.as_type()
+                        ))?;
+                    }
+                    w.write_line("tag => panic!(\"Invalid tag: {}\", tag),")?;
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+
             for (i, member) in union_.members.iter().enumerate() {
                 w.eob()?;
                 Self::define_union_member(&mut w, name, i, member, &inner_name)?;
             }
         }
         w.write_line("}")?.eob()?;
+
+        Self::define_union_traits(w, name, union_, options)?;
+        Self::define_union_kind(w, name, union_)?;
+        Self::define_union_value(w, name, union_, options)?;
+        Ok(())
+    }
+
+    /// A plain enum of the members of a union, without their content
+    fn define_union_kind<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        w.write_line(format!("/// Discriminant of `{}`", name.as_type()))?
+            .write_line("#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]")?
+            .write_line(format!(
+                "pub enum {} {{",
+                format!("{}_kind", name).as_type()
+            ))?;
+        {
+            let mut w = w.new_block();
+            for member in &union_.members {
+                w.write_line(format!("{},", member.name.as_type()))?;
+            }
+        }
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+
+    /// A safe Rust enum carrying the content of the active member, convertible from and to
+    /// the tagged union
+    fn define_union_value<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        union_: &ASUnion,
+        options: &Options,
+    ) -> Result<(), Error> {
+        let union_name = name.as_type();
+        let kind_name = format!("{}_kind", name).as_type();
+        let value_name = format!("{}_value", name).as_type();
+
+        w.write_line(format!("/// Content of `{}`, as a Rust enum", union_name))?
+            .write_line(Self::union_value_derives(union_, options))?
+            .write_line(format!("pub enum {} {{", value_name))?;
+        {
+            let mut w = w.new_block();
+            for member in &union_.members {
+                match member.type_.as_ref() {
+                    ASType::Void => w.write_line(format!("{},", member.name.as_type()))?,
+                    member_type => w.write_line(format!(
+                        "{}({}),",
+                        member.name.as_type(),
+                        member_type.as_lang()
+                    ))?,
+                };
+            }
+        }
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!("impl From<{}> for {} {{", value_name, union_name))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("fn from(value: {}) -> Self {{", value_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("match value {")?;
+                {
+                    let mut w = w.new_block();
+                    for member in &union_.members {
+                        match member.type_.as_ref() {
+                            ASType::Void => w.write_line(format!(
+                                "{}::{} => Self::new_{}(),",
+                                value_name,
+                                member.name.as_type(),
+                                member.name.as_fn_suffix()
+                            ))?,
+                            _ => w.write_line(format!(
+                                "{}::{}(val) => Self::new_{}(val),",
+                                value_name,
+                                member.name.as_type(),
+                                member.name.as_fn_suffix()
+                            ))?,
+                        };
+                    }
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!("impl From<{}> for {} {{", union_name, value_name))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("/// Panics if the tag is not valid")?;
+            w.write_line(format!("fn from(tu: {}) -> Self {{", union_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("match tu.kind() {")?;
+                {
+                    let mut w = w.new_block();
+                    for member in &union_.members {
+                        match member.type_.as_ref() {
+                            ASType::Void => w.write_line(format!(
+                                "{}::{} => Self::{},",
+                                kind_name,
+                                member.name.as_type(),
+                                member.name.as_type()
+                            ))?,
+                            _ => w.write_line(format!(
+                                "{}::{} => Self::{}(unsafe {{ tu.member.assume_init().{} }}),",
+                                kind_name,
+                                member.name.as_type(),
+                                member.name.as_type(),
+                                member.name.as_var()
+                            ))?,
+                        };
+                    }
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
         Ok(())
     }
 }
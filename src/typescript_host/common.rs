@@ -0,0 +1,194 @@
+use convert_case::Case;
+
+use crate::astype::*;
+use crate::names::{apply_naming_policy, NameConversions, NameKind};
+
+pub trait Normalize {
+    fn as_str(&self) -> &str;
+
+    fn as_type(&self) -> String {
+        apply_naming_policy(NameKind::Type, self.as_str(), Case::Pascal)
+    }
+
+    fn as_fn(&self) -> String {
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Function,
+            self.as_str(),
+            Case::Camel,
+        ))
+    }
+
+    fn as_var(&self) -> String {
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Variable,
+            self.as_str(),
+            Case::Camel,
+        ))
+    }
+
+    fn as_const(&self) -> String {
+        apply_naming_policy(NameKind::Constant, self.as_str(), Case::UpperSnake)
+    }
+
+    fn as_namespace(&self) -> String {
+        apply_naming_policy(NameKind::Namespace, self.as_str(), Case::Pascal)
+    }
+}
+
+impl<T: AsRef<str>> Normalize for T {
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// The identifiers of this backend, checked for collisions
+pub const NAME_CONVERSIONS: NameConversions = NameConversions {
+    type_: |name| name.as_type(),
+    function: |name| name.as_fn(),
+    variable: |name| name.as_var(),
+    constant: |name| name.as_const(),
+    namespace: |name| name.as_namespace(),
+};
+
+/// Follows aliases up to the actual type
+pub fn resolve(type_: &ASType) -> &ASType {
+    match type_ {
+        ASType::Alias(alias) => resolve(alias.type_.as_ref()),
+        _ => type_,
+    }
+}
+
+/// Bytes are exchanged as `Uint8Array`s, other lists as pointers and lengths
+pub fn is_byte(type_: &ASType) -> bool {
+    matches!(resolve(type_), ASType::U8 | ASType::Char8)
+}
+
+/// 64-bit integers are represented as `bigint`s
+pub fn is_bigint(type_: &ASType) -> bool {
+    match resolve(type_) {
+        ASType::U64 | ASType::S64 => true,
+        ASType::Enum(enum_) => is_bigint(&enum_.repr),
+        ASType::Constants(constants) => is_bigint(&constants.repr),
+        _ => false,
+    }
+}
+
+pub trait ToLanguageRepresentation {
+    fn as_astype(&self) -> &ASType;
+
+    /// The TypeScript type of a value, once decoded from the guest memory
+    fn as_lang(&self) -> String {
+        match self.as_astype() {
+            ASType::Alias(alias) => alias.name.as_type(),
+            ASType::Bool => "boolean".to_string(),
+            ASType::Char32 => "Char32".to_string(),
+            ASType::Char8 => "Char8".to_string(),
+            ASType::F32 | ASType::F64 => "number".to_string(),
+            ASType::Handle(_resource_name) => "WasiHandle".to_string(),
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => "WasiPtr".to_string(),
+            ASType::Option(_) => todo!(),
+            ASType::Result(_) => todo!(),
+            ASType::S8 | ASType::S16 | ASType::S32 | ASType::U8 | ASType::U16 | ASType::U32 => {
+                "number".to_string()
+            }
+            ASType::S64 | ASType::U64 => "bigint".to_string(),
+            ASType::USize => "WasiSize".to_string(),
+            ASType::Void => "void".to_string(),
+            ASType::Constants(constants) => constants.repr.as_lang(),
+            ASType::Enum(enum_) => enum_.repr.as_lang(),
+            ASType::Struct(_) => unimplemented!(),
+            ASType::Tuple(_) => unimplemented!(),
+            ASType::Union(_) => unimplemented!(),
+            ASType::Slice(elements_type)
+            | ASType::ReadBuffer(elements_type)
+            | ASType::WriteBuffer(elements_type) => {
+                if is_byte(elements_type) {
+                    "Uint8Array".to_string()
+                } else {
+                    "WasiSlice".to_string()
+                }
+            }
+            ASType::String(_) => "string".to_string(),
+        }
+    }
+}
+
+impl ToLanguageRepresentation for ASType {
+    fn as_astype(&self) -> &ASType {
+        self
+    }
+}
+
+/// Checks whether the given word is a reserved keyword.
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED.contains(&word)
+}
+
+/// Checks the given word against a list of reserved keywords.
+/// If the given word conflicts with a keyword, a trailing underscore will be
+/// appended.
+pub fn escape_reserved_word(word: &str) -> String {
+    if is_reserved_word(word) {
+        format!("{}_", word)
+    } else {
+        word.to_string()
+    }
+}
+
+/// Reserved Keywords, and names used by the generated functions.
+///
+/// Source: [ECMAScript 2022 Language Specification](https://tc39.es/ecma262/#sec-keywords-and-reserved-words)
+const RESERVED: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+    "let",
+    "static",
+    "implements",
+    "interface",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "alloc",
+    "e",
+    "impl",
+    "mem",
+    "result",
+    "view",
+];
@@ -0,0 +1,328 @@
+use std::io::Write;
+use std::rc::Rc;
+
+use witx::{CallMode, WasmType};
+
+use super::*;
+
+/// A function, with its parameters and results as they are seen by the host implementation
+struct HostFunc {
+    name: String,
+    docs: String,
+    params: Vec<(String, ASType)>,
+    results: Vec<(String, Rc<ASType>)>,
+    result: ASResult,
+    result_is_tuple: bool,
+    signature: witx::WasmSignature,
+}
+
+impl HostFunc {
+    fn new(func_witx: &witx::Function) -> Self {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
+        let mut params = vec![];
+        for param_witx in &func_witx.params {
+            let param_name = param_witx.name.as_str();
+            let param_type = ASType::from(&param_witx.tref);
+            params.push((param_name.to_string(), param_type));
+        }
+
+        let results_witx = &func_witx.results;
+        assert_eq!(results_witx.len(), 1);
+        let result = match ASType::from(&results_witx[0].tref) {
+            ASType::Result(result) => result,
+            _ => unreachable!(),
+        };
+        let ok_type = result.ok_type.clone();
+        let mut results = vec![];
+        // A tuple in a result is expanded into additional parameters, transformed to
+        // pointers
+        let result_is_tuple = if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
+            for (i, tuple_member) in tuple_members.iter().enumerate() {
+                let name = format!("result{}_ptr", i);
+                results.push((name, tuple_member.type_.clone()));
+            }
+            true
+        } else {
+            let name = "result_ptr";
+            results.push((name.to_string(), ok_type));
+            false
+        };
+
+        HostFunc {
+            name: func_witx.name.as_str().to_string(),
+            docs: func_witx.docs.clone(),
+            params,
+            results,
+            result,
+            result_is_tuple,
+            signature: func_witx.wasm_signature(CallMode::DefinedImport),
+        }
+    }
+
+    /// Names of the core WebAssembly parameters
+    fn wasm_params_names(&self) -> Vec<String> {
+        let mut names = vec![];
+        for param in &self.params {
+            for decomposed in param.1.decompose(&param.0, false) {
+                names.push(decomposed.name.as_var());
+            }
+        }
+        for result in &self.results {
+            for decomposed in result.1.decompose(&result.0, true) {
+                names.push(decomposed.name.as_var());
+            }
+        }
+        assert_eq!(names.len(), self.signature.params.len());
+        names
+    }
+
+    /// The type returned by the host implementation
+    fn return_type(&self) -> String {
+        if self.result_is_tuple {
+            let types: Vec<_> = self
+                .results
+                .iter()
+                .map(|result| result.1.as_lang())
+                .collect();
+            return format!("[{}]", types.join(", "));
+        }
+        self.results[0].1.as_lang()
+    }
+
+    /// The error code returned on success
+    fn success(&self) -> String {
+        let error_type = self.result.error_type.as_ref();
+        if let (ASType::Alias(alias), ASType::Enum(enum_)) = (error_type, resolve(error_type)) {
+            if let Some(choice) = enum_.choices.iter().find(|choice| choice.value == 0) {
+                return format!("{}.{}", alias.name.as_namespace(), choice.name.as_const());
+            }
+        }
+        "0".to_string()
+    }
+
+    /// Whether returning the results requires allocating memory in the guest
+    fn allocates(&self) -> bool {
+        self.results.iter().any(|result| allocates(&result.1))
+    }
+}
+
+impl TypeScriptHostGenerator {
+    /// Defines the interface to implement by the host
+    pub fn define_impl_interface<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        funcs: &[Rc<witx::Function>],
+    ) -> Result<(), Error> {
+        Self::write_docs(
+            w,
+            &format!(
+                "Host implementation of the [{}] module\n\nThe functions receive the parameters decoded from the guest memory, and return\nthe results. Errors are returned to the guest by throwing a `WasiError`.",
+                module_name
+            ),
+        )?;
+        w.write_line(format!("export interface {} {{", module_name.as_type()))?;
+        {
+            let mut w = w.new_block();
+            for (i, func) in funcs.iter().enumerate() {
+                let func = HostFunc::new(func);
+                if i > 0 {
+                    w.eob()?;
+                }
+                Self::write_docs(&mut w, &func.docs)?;
+                let params: Vec<_> = func
+                    .params
+                    .iter()
+                    .filter(|param| !matches!(param.1.leaf(), ASType::Void))
+                    .map(|param| format!("{}: {}", param.0.as_var(), param.1.as_lang()))
+                    .collect();
+                w.write_line(format!(
+                    "{}({}): {};",
+                    func.name.as_fn(),
+                    params.join(", "),
+                    func.return_type()
+                ))?;
+            }
+        }
+        w.write_line("}")?;
+        w.eob()?;
+        Ok(())
+    }
+
+    /// Defines a function returning an import object, with functions calling the host
+    /// implementation
+    pub fn define_imports_factory<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        funcs: &[Rc<witx::Function>],
+    ) -> Result<(), Error> {
+        let alloc = funcs.iter().any(|func| HostFunc::new(func).allocates());
+        let mut docs = format!(
+            "Returns the imports of the [{}] module, calling the functions of `impl`\n\n`mem` must be bound to the instance once it has been created.",
+            module_name
+        );
+        if alloc {
+            docs.push_str(" `alloc` allocates the memory\nof the strings and byte arrays returned to the guest.");
+        }
+        Self::write_docs(w, &docs)?;
+        w.write_line(format!(
+            "export function {}(impl: {}, mem: GuestMemory{}): WebAssembly.Imports {{",
+            format!("{}_imports", module_name).as_fn(),
+            module_name.as_type(),
+            if alloc { ", alloc: GuestAllocator" } else { "" }
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("return {")?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("\"{}\": {{", module_name))?;
+                {
+                    let mut w = w.new_block();
+                    for func in funcs {
+                        Self::define_import(&mut w, &HostFunc::new(func))?;
+                    }
+                }
+                w.write_line("},")?;
+            }
+            w.write_line("};")?;
+        }
+        w.write_line("}")?;
+        w.eob()?;
+        Ok(())
+    }
+
+    fn define_import<T: Write>(w: &mut PrettyWriter<T>, func: &HostFunc) -> Result<(), Error> {
+        let params: Vec<_> = func
+            .wasm_params_names()
+            .into_iter()
+            .zip(func.signature.params.iter())
+            .map(|(name, type_)| format!("{}: {}", name, wasm_type(*type_)))
+            .collect();
+        let results: Vec<_> = func
+            .signature
+            .results
+            .iter()
+            .map(|type_| wasm_type(*type_))
+            .collect();
+        w.indent()?.write(format!("\"{}\": (", func.name))?;
+        if !params.is_empty() {
+            w.eol()?;
+        }
+        for (i, param) in params.iter().enumerate() {
+            let eol = if i + 1 == params.len() { "" } else { "," };
+            w.write_line_continued(format!("{}{}", param, eol))?;
+        }
+        w.write_line(format!("): {} => {{", results.join(", ")))?;
+        {
+            let mut w = w.new_block();
+            let results: Vec<_> = func
+                .results
+                .iter()
+                .enumerate()
+                .filter(|(_, result)| !matches!(result.1.leaf(), ASType::Void))
+                .collect();
+            let args: Vec<_> = func
+                .params
+                .iter()
+                .filter_map(|param| decode_param(&param.0, &param.1))
+                .collect();
+            w.write_line("try {")?;
+            {
+                let mut w = w.new_block();
+                let assignment = if results.is_empty() {
+                    ""
+                } else {
+                    "const result = "
+                };
+                if args.is_empty() {
+                    w.write_line(format!("{}impl.{}();", assignment, func.name.as_fn()))?;
+                } else {
+                    w.write_line(format!("{}impl.{}(", assignment, func.name.as_fn()))?;
+                    for arg in &args {
+                        w.write_line_continued(format!("{},", arg))?;
+                    }
+                    w.write_line(");")?;
+                }
+                let names: Vec<_> = results
+                    .iter()
+                    .map(|(_, result)| result.0.as_var())
+                    .collect();
+                let values: Vec<_> = results
+                    .iter()
+                    .zip(&names)
+                    .map(|((i, result), name)| {
+                        let value = if func.result_is_tuple {
+                            format!("result[{}]", i)
+                        } else {
+                            "result".to_string()
+                        };
+                        (result.1.as_ref(), name.as_str(), 0, value)
+                    })
+                    .collect();
+                Self::write_body(&mut w, &write_values(&values))?;
+            }
+            w.write_line("} catch (e) {")?;
+            {
+                let mut w = w.new_block();
+                w.write_line("if (e instanceof WasiError) {")?;
+                w.new_block().write_line("return e.errno;")?;
+                w.write_line("}")?;
+                w.write_line("throw e;")?;
+            }
+            w.write_line("}")?;
+            w.write_line(format!("return {};", func.success()))?;
+        }
+        w.write_line("},")?;
+        Ok(())
+    }
+}
+
+/// The TypeScript expression decoding a parameter from its core WebAssembly values
+fn decode_param(param_name: &str, type_: &ASType) -> Option<String> {
+    let name = param_name.as_var();
+    let ptr = format!("{}_ptr", param_name).as_var();
+    let len = format!("{}_len", param_name).as_var();
+    let expr = match type_.leaf() {
+        ASType::Void => return None,
+        ASType::String(_) => format!("mem.readString({}, {})", ptr, len),
+        ASType::Slice(elements_type) | ASType::ReadBuffer(elements_type)
+            if is_byte(elements_type) =>
+        {
+            format!("mem.bytes({}, {}).slice()", ptr, len)
+        }
+        // Output buffers are views of the guest memory, that the host writes to
+        ASType::WriteBuffer(elements_type) if is_byte(elements_type) => {
+            format!("mem.bytes({}, {})", ptr, len)
+        }
+        ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => {
+            format!("{{ ptr: {} >>> 0, len: {} >>> 0 }}", ptr, len)
+        }
+        _ => match resolve(type_) {
+            ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => read_value(type_, &name, 0),
+            ASType::Bool => format!("{} !== 0", name),
+            ASType::U64 => format!("BigInt.asUintN(64, {})", name),
+            ASType::Enum(enum_) if matches!(enum_.repr.as_ref(), ASType::U64) => {
+                format!("BigInt.asUintN(64, {})", name)
+            }
+            ASType::Constants(constants) if matches!(constants.repr.as_ref(), ASType::U64) => {
+                format!("BigInt.asUintN(64, {})", name)
+            }
+            ASType::U32
+            | ASType::Char32
+            | ASType::USize
+            | ASType::ConstPtr(_)
+            | ASType::MutPtr(_) => {
+                format!("{} >>> 0", name)
+            }
+            _ => name,
+        },
+    };
+    Some(expr)
+}
+
+fn wasm_type(type_: WasmType) -> &'static str {
+    match type_ {
+        WasmType::I32 | WasmType::F32 | WasmType::F64 => "number",
+        WasmType::I64 => "bigint",
+    }
+}
@@ -0,0 +1,115 @@
+use std::io::Write;
+
+use super::*;
+
+impl TypeScriptHostGenerator {
+    pub fn header<T: Write>(w: &mut PrettyWriter<T>) -> Result<(), Error> {
+        w.write_lines(
+            "
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */",
+        )?;
+        w.write_lines(
+            "
+export type WasiHandle = number;
+export type WasiPtr = number;
+export type WasiSize = number;
+export type Char8 = number;
+export type Char32 = number;",
+        )?;
+        w.write_lines(
+            "
+export interface WasiSlice {
+    ptr: WasiPtr;
+    len: WasiSize;
+}",
+        )?;
+        w.write_lines(
+            "
+/**
+ * Thrown by a host implementation to return an error code to the guest
+ */
+export class WasiError<E = number> extends Error {
+    constructor(readonly errno: E) {
+        super(`WASI error ${errno}`);
+    }
+}",
+        )?;
+        w.write_lines(
+            "
+/**
+ * Allocates `size` bytes aligned to `align` in the guest memory, and returns their address
+ */
+export type GuestAllocator = (size: number, align: number) => number;",
+        )?;
+        w.write_lines(
+            "
+const textDecoder = new TextDecoder(\"utf-8\", { fatal: true });
+const textEncoder = new TextEncoder();",
+        )?;
+        w.write_lines(
+            "
+/**
+ * The memory of a guest, that must be bound to the instance once it has been created
+ */
+export class GuestMemory {
+    private memory: WebAssembly.Memory | null = null;",
+        )?;
+        w.write_lines(
+            "
+    bind(instance: WebAssembly.Instance): void {
+        this.memory = instance.exports.memory as WebAssembly.Memory;
+    }",
+        )?;
+        w.write_lines(
+            "
+    view(): DataView {
+        return new DataView(this.buffer());
+    }",
+        )?;
+        w.write_lines(
+            "
+    bytes(ptr: number, len: number): Uint8Array {
+        return new Uint8Array(this.buffer(), ptr >>> 0, len >>> 0);
+    }",
+        )?;
+        w.write_lines(
+            "
+    readString(ptr: number, len: number): string {
+        return textDecoder.decode(this.bytes(ptr, len));
+    }",
+        )?;
+        w.write_lines(
+            "
+    /**
+     * Copies bytes to memory allocated in the guest, and stores their address and length at `ptr`
+     */
+    writeBytes(alloc: GuestAllocator, ptr: number, bytes: Uint8Array): void {
+        const dataPtr = alloc(bytes.length, 1) >>> 0;
+        this.bytes(dataPtr, bytes.length).set(bytes);
+        const view = this.view();
+        view.setUint32(ptr, dataPtr, true);
+        view.setUint32(ptr + 4, bytes.length, true);
+    }",
+        )?;
+        w.write_lines(
+            "
+    writeString(alloc: GuestAllocator, ptr: number, value: string): void {
+        this.writeBytes(alloc, ptr, textEncoder.encode(value));
+    }",
+        )?;
+        w.write_lines(
+            "
+    private buffer(): ArrayBuffer {
+        if (this.memory === null) {
+            throw new Error(\"The guest memory is not bound to an instance\");
+        }
+        return this.memory.buffer;
+    }
+}",
+        )?;
+        w.eob()?;
+        Ok(())
+    }
+}
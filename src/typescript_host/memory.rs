@@ -0,0 +1,163 @@
+//! Expressions reading and writing values in the guest memory, through a `DataView` named
+//! `view` and a `GuestMemory` named `mem`. Strings and byte arrays are written to memory
+//! allocated with a `GuestAllocator` named `alloc`.
+
+use super::*;
+
+/// An offset from a base pointer
+pub fn offset(base: &str, offset: usize) -> String {
+    if offset == 0 {
+        base.to_string()
+    } else {
+        format!("{} + {}", base, offset)
+    }
+}
+
+/// The `DataView` accessor suffix for a scalar, and its size
+fn accessor(type_: &ASType) -> (&'static str, usize) {
+    match resolve(type_) {
+        ASType::Bool | ASType::U8 | ASType::Char8 => ("Uint8", 1),
+        ASType::S8 => ("Int8", 1),
+        ASType::U16 => ("Uint16", 2),
+        ASType::S16 => ("Int16", 2),
+        ASType::U32 | ASType::Char32 | ASType::USize | ASType::ConstPtr(_) | ASType::MutPtr(_) => {
+            ("Uint32", 4)
+        }
+        ASType::S32 | ASType::Handle(_) => ("Int32", 4),
+        ASType::U64 => ("BigUint64", 8),
+        ASType::S64 => ("BigInt64", 8),
+        ASType::F32 => ("Float32", 4),
+        ASType::F64 => ("Float64", 8),
+        ASType::Enum(enum_) => accessor(&enum_.repr),
+        ASType::Constants(constants) => accessor(&constants.repr),
+        type_ => {
+            dbg!(type_);
+            unimplemented!()
+        }
+    }
+}
+
+fn get(type_: &ASType, at: &str) -> String {
+    match accessor(type_) {
+        (name, 1) => format!("view.get{}({})", name, at),
+        (name, _) => format!("view.get{}({}, true)", name, at),
+    }
+}
+
+fn set(type_: &ASType, at: &str, value: &str) -> String {
+    match accessor(type_) {
+        (name, 1) => format!("view.set{}({}, {});", name, at, value),
+        (name, _) => format!("view.set{}({}, {}, true);", name, at, value),
+    }
+}
+
+/// The name of a record or union, that has its own `read()` and `write()` functions
+fn record_name(type_: &ASType) -> String {
+    match type_ {
+        ASType::Alias(alias) => alias.name.as_namespace(),
+        _ => {
+            dbg!(type_);
+            unimplemented!()
+        }
+    }
+}
+
+/// An expression reading a value stored at `base + at`
+pub fn read_value(type_: &ASType, base: &str, at: usize) -> String {
+    let ptr_at = offset(base, at);
+    match resolve(type_) {
+        ASType::Void => "undefined".to_string(),
+        ASType::Bool => format!("{} !== 0", get(type_, &ptr_at)),
+        ASType::String(_) => format!(
+            "mem.readString(view.getUint32({}, true), view.getUint32({}, true))",
+            ptr_at,
+            offset(base, at + 4)
+        ),
+        ASType::Slice(elements_type)
+        | ASType::ReadBuffer(elements_type)
+        | ASType::WriteBuffer(elements_type) => {
+            let (ptr, len) = (
+                format!("view.getUint32({}, true)", ptr_at),
+                format!("view.getUint32({}, true)", offset(base, at + 4)),
+            );
+            if is_byte(elements_type) {
+                format!("mem.bytes({}, {}).slice()", ptr, len)
+            } else {
+                format!("{{ ptr: {}, len: {} }}", ptr, len)
+            }
+        }
+        ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => {
+            format!("{}.read(mem, {})", record_name(type_), ptr_at)
+        }
+        _ => get(type_, &ptr_at),
+    }
+}
+
+/// Whether writing a value requires allocating memory in the guest, for the content of
+/// strings and byte arrays
+pub fn allocates(type_: &ASType) -> bool {
+    match resolve(type_) {
+        ASType::String(_) => true,
+        ASType::Slice(elements_type)
+        | ASType::ReadBuffer(elements_type)
+        | ASType::WriteBuffer(elements_type) => is_byte(elements_type),
+        ASType::Struct(members) => members.iter().any(|member| allocates(&member.type_)),
+        ASType::Tuple(members) => members.iter().any(|member| allocates(&member.type_)),
+        ASType::Union(union_) => union_.members.iter().any(|member| allocates(&member.type_)),
+        _ => false,
+    }
+}
+
+/// Statements writing `value` at `base + at`
+pub fn write_value(type_: &ASType, base: &str, at: usize, value: &str) -> Vec<String> {
+    let ptr_at = offset(base, at);
+    match resolve(type_) {
+        ASType::Void => vec![],
+        ASType::Bool => vec![set(type_, &ptr_at, &format!("{} ? 1 : 0", value))],
+        ASType::String(_) => vec![format!("mem.writeString(alloc, {}, {});", ptr_at, value)],
+        ASType::Slice(elements_type)
+        | ASType::ReadBuffer(elements_type)
+        | ASType::WriteBuffer(elements_type)
+            if is_byte(elements_type) =>
+        {
+            vec![format!("mem.writeBytes(alloc, {}, {});", ptr_at, value)]
+        }
+        ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => vec![
+            format!("view.setUint32({}, {}.ptr, true);", ptr_at, value),
+            format!(
+                "view.setUint32({}, {}.len, true);",
+                offset(base, at + 4),
+                value
+            ),
+        ],
+        ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) if allocates(type_) => {
+            vec![format!(
+                "{}.write(mem, {}, {}, alloc);",
+                record_name(type_),
+                ptr_at,
+                value
+            )]
+        }
+        ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => vec![format!(
+            "{}.write(mem, {}, {});",
+            record_name(type_),
+            ptr_at,
+            value
+        )],
+        _ => vec![set(type_, &ptr_at, value)],
+    }
+}
+
+/// Statements writing values given as `(type, base, at, value)`.
+///
+/// Allocating may grow the guest memory, which detaches the `view` created before, so the
+/// values that require allocations are written last.
+pub fn write_values(values: &[(&ASType, &str, usize, String)]) -> Vec<String> {
+    let (allocating, others): (Vec<_>, Vec<_>) =
+        values.iter().partition(|(type_, ..)| allocates(type_));
+    others
+        .into_iter()
+        .chain(allocating)
+        .flat_map(|(type_, base, at, value)| write_value(type_, base, *at, value))
+        .collect()
+}
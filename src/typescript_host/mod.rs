@@ -0,0 +1,349 @@
+mod common;
+mod function;
+mod header;
+mod memory;
+mod r#struct;
+mod tuple;
+mod union;
+
+use std::io::Write;
+
+use common::*;
+use memory::*;
+
+use super::*;
+use crate::astype::*;
+use crate::error::*;
+use crate::pretty_writer::PrettyWriter;
+
+pub struct TypeScriptHostGenerator {
+    module_name: Option<String>,
+}
+
+impl TypeScriptHostGenerator {
+    pub fn new(module_name: Option<String>) -> Self {
+        TypeScriptHostGenerator { module_name }
+    }
+}
+
+impl<T: Write> Generator<T> for TypeScriptHostGenerator {
+    fn generate(
+        &self,
+        writer: &mut T,
+        module_witx: witx::Module,
+        options: &Options,
+    ) -> Result<(), Error> {
+        let mut w = PrettyWriter::new(writer, "    ");
+        let module_name = match &self.module_name {
+            None => module_witx.name().as_str().to_string(),
+            Some(module_name) => module_name.to_string(),
+        };
+        let module_id = module_witx.module_id();
+        let skip_imports = options.skip_imports;
+
+        names::check_name_collisions(&module_witx, skip_imports, &NAME_CONVERSIONS)?;
+
+        if !options.skip_header {
+            Self::header(&mut w)?;
+        }
+
+        let module_title_comments = format!(
+            "---------------------- Module: [{}] ----------------------",
+            module_name
+        );
+        Self::write_comments(&mut w, &module_title_comments)?;
+        w.eob()?;
+
+        for type_ in module_witx.typenames() {
+            if skip_imports && &type_.module != module_id {
+                continue;
+            }
+            let constants_for_type: Vec<_> = module_witx
+                .constants()
+                .filter_map(|x| {
+                    if x.ty == type_.name {
+                        Some(ASConstant {
+                            name: x.name.as_str().to_string(),
+                            value: x.value,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
+        }
+
+        let funcs: Vec<_> = module_witx.funcs().collect();
+        Self::define_impl_interface(&mut w, &module_name, &funcs)?;
+        Self::define_imports_factory(&mut w, &module_name, &funcs)?;
+
+        Ok(())
+    }
+}
+
+impl TypeScriptHostGenerator {
+    fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        if docs.is_empty() {
+            return Ok(());
+        }
+        w.write_line("/**")?;
+        for docs_line in docs.lines() {
+            if docs_line.is_empty() {
+                w.write_line(" *")?;
+            } else {
+                w.write_line(format!(" * {}", docs_line))?;
+            }
+        }
+        w.write_line(" */")?;
+        Ok(())
+    }
+
+    fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        if docs.is_empty() {
+            return Ok(());
+        }
+        w.write_line("/*")?;
+        for docs_line in docs.lines() {
+            if docs_line.is_empty() {
+                w.write_line(" *")?;
+            } else {
+                w.write_line(format!(" * {}", docs_line))?;
+            }
+        }
+        w.write_line(" */")?;
+        Ok(())
+    }
+
+    fn define_as_alias<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        other_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "export type {} = {};",
+            name.as_type(),
+            other_name.as_type()
+        ))?;
+        Ok(())
+    }
+
+    fn define_as_atom<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "export type {} = {};",
+            name.as_type(),
+            type_.as_lang()
+        ))?;
+        Ok(())
+    }
+
+    fn define_as_enum<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        enum_: &ASEnum,
+    ) -> Result<(), Error> {
+        let repr = enum_.repr.as_ref();
+        w.write_line(format!(
+            "export type {} = {};",
+            name.as_type(),
+            repr.as_lang()
+        ))?;
+        w.eob()?;
+        w.write_line(format!("export namespace {} {{", name.as_namespace()))?;
+        {
+            let mut w = w.new_block();
+            for choice in &enum_.choices {
+                w.write_line(format!(
+                    "export const {}: {} = {};",
+                    choice.name.as_const(),
+                    name.as_type(),
+                    literal(repr, choice.value as u64, false)
+                ))?;
+            }
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    fn define_as_constants<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        constants: &ASConstants,
+    ) -> Result<(), Error> {
+        let repr = constants.repr.as_ref();
+        w.write_line(format!(
+            "export type {} = {};",
+            name.as_type(),
+            repr.as_lang()
+        ))?;
+        w.eob()?;
+        Self::define_constants_for_type(w, name, repr, &constants.constants)?;
+        Ok(())
+    }
+
+    fn define_as_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        match type_ {
+            ASType::Alias(_)
+            | ASType::Bool
+            | ASType::Char8
+            | ASType::Char32
+            | ASType::F32
+            | ASType::F64
+            | ASType::U8
+            | ASType::U16
+            | ASType::U32
+            | ASType::U64
+            | ASType::S8
+            | ASType::S16
+            | ASType::S32
+            | ASType::S64
+            | ASType::USize
+            | ASType::Handle(_)
+            | ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
+            ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
+            ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
+            ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
+            ASType::Tuple(members) => Self::define_as_tuple(w, name, members)?,
+            ASType::Struct(members) => Self::define_as_struct(w, name, members)?,
+            _ => {
+                dbg!(type_);
+                unimplemented!();
+            }
+        }
+        Ok(())
+    }
+
+    fn define_constants_for_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        type_name: &str,
+        type_: &ASType,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        if constants.is_empty() {
+            return Ok(());
+        }
+        w.write_line(format!("export namespace {} {{", type_name.as_namespace()))?;
+        {
+            let mut w = w.new_block();
+            let mut hex = false;
+            let mut single_bits: usize = 0;
+            for constant in constants {
+                if constant.value > 0xffff {
+                    hex = true;
+                }
+                if constant.value.count_ones() == 1 {
+                    single_bits += 1;
+                }
+            }
+            if constants.len() > 2 && single_bits == constants.len() {
+                hex = true;
+            }
+            for constant in constants {
+                w.write_line(format!(
+                    "export const {}: {} = {};",
+                    constant.name.as_const(),
+                    type_name.as_type(),
+                    literal(type_, constant.value, hex)
+                ))?;
+            }
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Defines a `read()` function decoding a value from the guest memory, and a `write()`
+    /// function encoding it, that takes an allocator if the value contains strings or byte
+    /// arrays
+    fn define_read_write<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        read: &[String],
+        write: &[String],
+        alloc: bool,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "export function read(mem: GuestMemory, ptr: WasiPtr): {} {{",
+            name.as_type()
+        ))?;
+        Self::write_body(&mut w.new_block(), read)?;
+        w.write_line("}")?;
+        w.eob()?;
+        w.write_line(format!(
+            "export function write(mem: GuestMemory, ptr: WasiPtr, value: {}{}): void {{",
+            name.as_type(),
+            if alloc { ", alloc: GuestAllocator" } else { "" }
+        ))?;
+        Self::write_body(&mut w.new_block(), write)?;
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Writes statements, after a `view` declaration if they use it
+    fn write_body<T: Write>(w: &mut PrettyWriter<T>, statements: &[String]) -> Result<(), Error> {
+        if statements
+            .iter()
+            .any(|statement| statement.contains("view."))
+        {
+            w.write_line("const view = mem.view();")?;
+        }
+        for statement in statements {
+            w.write_lines(statement)?;
+        }
+        Ok(())
+    }
+
+    fn define_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        type_witx: &witx::NamedType,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        let docs = &type_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+        let type_name = type_witx.name.as_str();
+        let tref = &type_witx.tref;
+        let type_ = match tref {
+            witx::TypeRef::Name(other_type) => {
+                Self::define_as_alias(w, type_name, other_type.name.as_str())?;
+                ASType::from(tref)
+            }
+            witx::TypeRef::Value(type_witx) => {
+                let t = ASType::from(type_witx.as_ref());
+                Self::define_as_type(w, type_name, &t)?;
+                t
+            }
+        };
+        w.eob()?;
+        if !constants.is_empty() {
+            Self::define_constants_for_type(w, type_name, &type_, constants)?;
+            w.eob()?;
+        }
+        Ok(())
+    }
+}
+
+/// A numeric literal of the given type, with a `n` suffix for `bigint`s
+fn literal(type_: &ASType, value: u64, hex: bool) -> String {
+    let value_s = if hex {
+        format!("0x{:x}", value)
+    } else {
+        format!("{}", value)
+    };
+    if is_bigint(type_) {
+        format!("{}n", value_s)
+    } else {
+        value_s
+    }
+}
@@ -0,0 +1,46 @@
+use std::io::Write;
+
+use super::*;
+
+impl TypeScriptHostGenerator {
+    pub fn define_as_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[ASStructMember],
+    ) -> Result<(), Error> {
+        w.write_line(format!("export interface {} {{", name.as_type()))?;
+        {
+            let mut w = w.new_block();
+            for member in members {
+                w.write_line(format!(
+                    "{}: {};",
+                    member.name.as_var(),
+                    member.type_.as_lang()
+                ))?;
+            }
+        }
+        w.write_line("}")?;
+        w.eob()?;
+
+        let mut read = String::from("return {");
+        let mut values = vec![];
+        for member in members {
+            let member_type = member.type_.as_ref();
+            read.push_str(&format!(
+                "\n    {}: {},",
+                member.name.as_var(),
+                read_value(member_type, "ptr", member.offset)
+            ));
+            let value = format!("value.{}", member.name.as_var());
+            values.push((member_type, "ptr", member.offset, value));
+        }
+        read.push_str(if members.is_empty() { "};" } else { "\n};" });
+        let write = write_values(&values);
+        let alloc = members.iter().any(|member| allocates(&member.type_));
+
+        w.write_line(format!("export namespace {} {{", name.as_namespace()))?;
+        Self::define_read_write(&mut w.new_block(), name, &[read], &write, alloc)?;
+        w.write_line("}")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use std::io::Write;
+
+use super::*;
+
+impl TypeScriptHostGenerator {
+    pub fn define_as_tuple<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        members: &[ASTupleMember],
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "export type {} = [{}];",
+            name.as_type(),
+            members
+                .iter()
+                .map(|member| member.type_.as_lang())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))?;
+        w.eob()?;
+
+        let mut read = String::from("return [");
+        let mut values = vec![];
+        for (i, member) in members.iter().enumerate() {
+            let member_type = member.type_.as_ref();
+            read.push_str(&format!(
+                "\n    {},",
+                read_value(member_type, "ptr", member.offset)
+            ));
+            let value = format!("value[{}]", i);
+            values.push((member_type, "ptr", member.offset, value));
+        }
+        read.push_str(if members.is_empty() { "];" } else { "\n];" });
+        let write = write_values(&values);
+        let alloc = members.iter().any(|member| allocates(&member.type_));
+
+        w.write_line(format!("export namespace {} {{", name.as_namespace()))?;
+        Self::define_read_write(&mut w.new_block(), name, &[read], &write, alloc)?;
+        w.write_line("}")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use std::io::Write;
+
+use super::*;
+
+impl TypeScriptHostGenerator {
+    pub fn define_as_union<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        let tag_repr = union_.tag_repr.as_ref();
+        let member_offset = union_.member_offset;
+
+        // A discriminated union, with the content of the member named after it
+        w.write_line(format!("export type {} =", name.as_type()))?;
+        {
+            let mut w = w.new_block();
+            for (i, member) in union_.members.iter().enumerate() {
+                let eol = if i + 1 == union_.members.len() {
+                    ";"
+                } else {
+                    ""
+                };
+                match member.type_.as_ref() {
+                    ASType::Void => w.write_line(format!("| {{ tag: {} }}{}", i, eol))?,
+                    member_type => w.write_line(format!(
+                        "| {{ tag: {}; {}: {} }}{}",
+                        i,
+                        member.name.as_var(),
+                        member_type.as_lang(),
+                        eol
+                    ))?,
+                };
+            }
+        }
+        w.eob()?;
+
+        let mut read = vec![
+            format!("const tag = {};", tag_value(tag_repr)),
+            "switch (tag) {".to_string(),
+        ];
+        let mut write = write_value(tag_repr, "ptr", 0, &tag_literal(tag_repr, "value.tag"));
+        write.push("switch (value.tag) {".to_string());
+        for (i, member) in union_.members.iter().enumerate() {
+            let member_type = member.type_.as_ref();
+            read.push(format!("    case {}:", i));
+            if let ASType::Void = member_type {
+                read.push(format!("        return {{ tag: {} }};", i));
+                continue;
+            }
+            read.push(format!(
+                "        return {{ tag: {}, {}: {} }};",
+                i,
+                member.name.as_var(),
+                read_value(member_type, "ptr", member_offset)
+            ));
+            let value = format!("value.{}", member.name.as_var());
+            write.push(format!("    case {}:", i));
+            for statement in write_value(member_type, "ptr", member_offset, &value) {
+                write.push(format!("        {}", statement));
+            }
+            write.push("        break;".to_string());
+        }
+        read.push("    default:".to_string());
+        read.push(format!(
+            "        throw new Error(`Invalid tag for {}: ${{tag}}`);",
+            name.as_type()
+        ));
+        read.push("}".to_string());
+        write.push("}".to_string());
+
+        w.write_line(format!("export namespace {} {{", name.as_namespace()))?;
+        {
+            let mut w = w.new_block();
+            for (i, member) in union_.members.iter().enumerate() {
+                w.write_line(format!("export const {} = {};", member.name.as_const(), i))?;
+            }
+            w.eob()?;
+            let alloc = union_.members.iter().any(|member| allocates(&member.type_));
+            Self::define_read_write(&mut w, name, &read, &write, alloc)?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+}
+
+/// The tag, read as a `number`
+fn tag_value(tag_repr: &ASType) -> String {
+    let tag = read_value(tag_repr, "ptr", 0);
+    if is_bigint(tag_repr) {
+        format!("Number({})", tag)
+    } else {
+        tag
+    }
+}
+
+/// The tag, converted to its representation
+fn tag_literal(tag_repr: &ASType, tag: &str) -> String {
+    if is_bigint(tag_repr) {
+        format!("BigInt({})", tag)
+    } else {
+        tag.to_string()
+    }
+}
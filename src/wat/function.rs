@@ -0,0 +1,114 @@
+use std::io::Write;
+
+use witx::{CallMode, WasmType};
+
+use super::*;
+
+impl WatGenerator {
+    /// Declares an import with the core WebAssembly signature of a function
+    pub fn define_func_import<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let name = func_witx.name.as_str();
+        Self::write_comments(w, &func_witx.docs)?;
+        w.write_line(format!("(import \"{}\" \"{}\"", module_name, name))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("(func ${}.{}", module_name, name))?;
+            Self::define_func_signature(&mut w.new_block(), func_witx)?;
+            w.write_line(")")?;
+        }
+        w.write_line(")")?;
+        Ok(())
+    }
+
+    /// Defines an exported function that ignores its parameters, and returns `errno`
+    pub fn define_func_stub<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        func_witx: &witx::Function,
+        errno: u64,
+    ) -> Result<(), Error> {
+        let name = func_witx.name.as_str();
+        Self::write_comments(w, &func_witx.docs)?;
+        w.write_line(format!(
+            "(func ${}.{} (export \"{}\")",
+            module_name, name, name
+        ))?;
+        {
+            let mut w = w.new_block();
+            let signature = Self::define_func_signature(&mut w, func_witx)?;
+            for result in &signature.results {
+                w.write_line(format!("{}.const {}", wasm_type(*result), errno))?;
+            }
+        }
+        w.write_line(")")?;
+        Ok(())
+    }
+
+    fn define_func_signature<T: Write>(
+        w: &mut PrettyWriter<T>,
+        func_witx: &witx::Function,
+    ) -> Result<witx::WasmSignature, Error> {
+        let signature = func_witx.wasm_signature(CallMode::DefinedImport);
+        let params_names = Self::params_names(func_witx);
+        assert_eq!(signature.params.len(), params_names.len());
+        for (param, param_name) in signature.params.iter().zip(params_names.iter()) {
+            w.write_line(format!("(param ${} {})", param_name, wasm_type(*param)))?;
+        }
+        for result in &signature.results {
+            w.write_line(format!("(result {})", wasm_type(*result)))?;
+        }
+        Ok(signature)
+    }
+
+    /// Names of the core WebAssembly parameters: the decomposed parameters,
+    /// followed by the pointers to the results
+    fn params_names(func_witx: &witx::Function) -> Vec<String> {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
+        let mut params_names = vec![];
+        for param_witx in &func_witx.params {
+            let param_type = ASType::from(&param_witx.tref);
+            for decomposed in param_type.decompose(param_witx.name.as_str(), false) {
+                params_names.push(decomposed.name);
+            }
+        }
+
+        let results_witx = &func_witx.results;
+        assert_eq!(results_witx.len(), 1);
+        let result = match ASType::from(&results_witx[0].tref) {
+            ASType::Result(result) => result,
+            _ => unreachable!(),
+        };
+        let ok_type = result.ok_type.clone();
+        let mut results = vec![];
+        // A tuple in a result is expanded into additional parameters, transformed to
+        // pointers
+        if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
+            for (i, tuple_member) in tuple_members.iter().enumerate() {
+                let name = format!("result{}_ptr", i);
+                results.push((name, tuple_member.type_.clone()));
+            }
+        } else {
+            let name = "result_ptr";
+            results.push((name.to_string(), ok_type));
+        }
+        for result in &results {
+            for decomposed in result.1.decompose(&result.0, true) {
+                params_names.push(decomposed.name);
+            }
+        }
+        params_names
+    }
+}
+
+fn wasm_type(type_: WasmType) -> &'static str {
+    match type_ {
+        WasmType::I32 => "i32",
+        WasmType::I64 => "i64",
+        WasmType::F32 => "f32",
+        WasmType::F64 => "f64",
+    }
+}
@@ -0,0 +1,110 @@
+mod function;
+
+use std::cell::{Cell, RefCell};
+use std::io::Write;
+
+use super::*;
+use crate::astype::*;
+use crate::error::*;
+use crate::names::Scope;
+use crate::pretty_writer::PrettyWriter;
+
+pub struct WatGenerator {
+    module_name: Option<String>,
+    /// Whether the WebAssembly module shared by all the input files has been opened
+    module_opened: Cell<bool>,
+    /// Names of the stubs exported by the module, from all the input files
+    exports: RefCell<Scope>,
+}
+
+impl WatGenerator {
+    pub fn new(module_name: Option<String>) -> Self {
+        WatGenerator {
+            module_name,
+            module_opened: Cell::new(false),
+            exports: RefCell::new(Scope::new("exported stubs")),
+        }
+    }
+}
+
+impl<T: Write> Generator<T> for WatGenerator {
+    fn generate(
+        &self,
+        writer: &mut T,
+        module_witx: witx::Module,
+        options: &Options,
+    ) -> Result<(), Error> {
+        let mut w = PrettyWriter::new(writer, "  ");
+        let module_name = match &self.module_name {
+            None => module_witx.name().as_str().to_string(),
+            Some(module_name) => module_name.to_string(),
+        };
+
+        if !options.skip_header {
+            Self::header(&mut w)?;
+        }
+
+        // The functions of all the input files are in a single module
+        if self.module_opened.replace(true) {
+            w.eob()?;
+        } else {
+            w.write_line("(module")?;
+        }
+        let mut w = w.new_block();
+
+        let module_title_comments = format!(
+            "---------------------- Module: [{}] ----------------------",
+            module_name
+        );
+        Self::write_comments(&mut w, &module_title_comments)?;
+        w.eob()?;
+
+        for (i, func) in module_witx.funcs().enumerate() {
+            if i > 0 {
+                w.eob()?;
+            }
+            match options.stub_errno {
+                None => Self::define_func_import(&mut w, &module_name, func.as_ref())?,
+                Some(errno) => {
+                    let name = func.name.as_str();
+                    self.exports
+                        .borrow_mut()
+                        .insert(&format!("{}.{}", module_name, name), name.to_string())?;
+                    Self::define_func_stub(&mut w, &module_name, func.as_ref(), errno)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(&self, writer: &mut T) -> Result<(), Error> {
+        if self.module_opened.get() {
+            PrettyWriter::new(writer, "  ").write_line(")")?;
+        }
+        Ok(())
+    }
+}
+
+impl WatGenerator {
+    fn header<T: Write>(w: &mut PrettyWriter<T>) -> Result<(), Error> {
+        w.write_lines(
+            "
+;;
+;; This file was automatically generated by witx-codegen - Do not edit manually.
+;;",
+        )?;
+        w.eob()?;
+        Ok(())
+    }
+
+    fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        if docs.is_empty() {
+            return Ok(());
+        }
+        for docs_line in docs.lines() {
+            w.write_line(format!(";; {}", docs_line))?;
+        }
+        Ok(())
+    }
+}
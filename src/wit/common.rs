@@ -0,0 +1,173 @@
+use crate::astype::*;
+use crate::names::NameConversions;
+
+pub trait Normalize {
+    fn as_str(&self) -> &str;
+
+    fn as_type(&self) -> String {
+        escape_reserved_word(&as_kebab(self.as_str()))
+    }
+
+    fn as_fn(&self) -> String {
+        escape_reserved_word(&as_kebab(self.as_str()))
+    }
+
+    fn as_var(&self) -> String {
+        escape_reserved_word(&as_kebab(self.as_str()))
+    }
+
+    fn as_const(&self) -> String {
+        escape_reserved_word(&as_kebab(self.as_str()))
+    }
+
+    fn as_namespace(&self) -> String {
+        escape_reserved_word(&as_kebab(self.as_str()))
+    }
+}
+
+impl<T: AsRef<str>> Normalize for T {
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// The identifiers of this backend, checked for collisions
+pub const NAME_CONVERSIONS: NameConversions = NameConversions {
+    type_: |name| name.as_type(),
+    function: |name| name.as_fn(),
+    variable: |name| name.as_var(),
+    constant: |name| name.as_const(),
+    namespace: |name| name.as_namespace(),
+};
+
+pub trait ToLanguageRepresentation {
+    fn as_astype(&self) -> &ASType;
+
+    fn as_lang(&self) -> String {
+        match self.as_astype() {
+            ASType::Alias(alias) => alias.name.as_type(),
+            ASType::Bool => "bool".to_string(),
+            ASType::Char32 => "char".to_string(),
+            ASType::Char8 => "u8".to_string(),
+            ASType::F32 => "f32".to_string(),
+            ASType::F64 => "f64".to_string(),
+            ASType::Handle(resource_name) => resource_name.as_type(),
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => "u32".to_string(),
+            ASType::Option(option) => format!("option<{}>", option.type_.as_lang()),
+            ASType::Result(result) => match (result.ok_type.as_ref(), result.error_type.as_ref()) {
+                (ASType::Void, ASType::Void) => "result".to_string(),
+                (ok_type, ASType::Void) => format!("result<{}>", ok_type.as_lang()),
+                (ASType::Void, error_type) => format!("result<_, {}>", error_type.as_lang()),
+                (ok_type, error_type) => {
+                    format!("result<{}, {}>", ok_type.as_lang(), error_type.as_lang())
+                }
+            },
+            ASType::S8 => "s8".to_string(),
+            ASType::S16 => "s16".to_string(),
+            ASType::S32 => "s32".to_string(),
+            ASType::S64 => "s64".to_string(),
+            ASType::U8 => "u8".to_string(),
+            ASType::U16 => "u16".to_string(),
+            ASType::U32 => "u32".to_string(),
+            ASType::U64 => "u64".to_string(),
+            ASType::USize => "u32".to_string(),
+            ASType::Void => "_".to_string(),
+            ASType::Constants(_) => unimplemented!(),
+            ASType::Enum(enum_) => enum_.repr.as_ref().as_lang(),
+            ASType::Struct(_) => unimplemented!(),
+            ASType::Tuple(tuple_members) => format!(
+                "tuple<{}>",
+                tuple_members
+                    .iter()
+                    .map(|member| member.type_.as_lang())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            ASType::Union(_) => unimplemented!(),
+            ASType::Slice(element_type) => format!("list<{}>", element_type.as_lang()),
+            ASType::String(_) => "string".to_string(),
+            ASType::ReadBuffer(element_type) => format!("list<{}>", element_type.as_lang()),
+            ASType::WriteBuffer(element_type) => format!("list<{}>", element_type.as_lang()),
+        }
+    }
+}
+
+impl ToLanguageRepresentation for ASType {
+    fn as_astype(&self) -> &ASType {
+        self
+    }
+}
+
+/// Converts a WITX identifier to a WIT identifier.
+/// WIT identifier words cannot start with a digit, so such words are
+/// concatenated with the previous one (`x_25519` becomes `x25519`).
+pub fn as_kebab(name: &str) -> String {
+    let mut kebab = String::new();
+    for word in name.to_lowercase().split(['_', '-']) {
+        if word.is_empty() {
+            continue;
+        }
+        if !kebab.is_empty() && !word.starts_with(|c: char| c.is_ascii_digit()) {
+            kebab.push('-');
+        }
+        kebab.push_str(word);
+    }
+    kebab
+}
+
+/// Checks the given word against a list of reserved keywords.
+/// If the given word conflicts with a keyword, it is prefixed with `%`.
+pub fn escape_reserved_word(word: &str) -> String {
+    if RESERVED.contains(&word) {
+        format!("%{}", word)
+    } else {
+        word.to_string()
+    }
+}
+
+/// Reserved Keywords.
+///
+/// Source: [WIT format](https://github.com/WebAssembly/component-model/blob/main/design/mvp/WIT.md#keywords)
+const RESERVED: &[&str] = &[
+    "as",
+    "async",
+    "bool",
+    "borrow",
+    "char",
+    "constructor",
+    "enum",
+    "export",
+    "f32",
+    "f64",
+    "flags",
+    "from",
+    "func",
+    "future",
+    "import",
+    "include",
+    "interface",
+    "list",
+    "option",
+    "own",
+    "package",
+    "record",
+    "resource",
+    "result",
+    "s16",
+    "s32",
+    "s64",
+    "s8",
+    "static",
+    "stream",
+    "string",
+    "tuple",
+    "type",
+    "u16",
+    "u32",
+    "u64",
+    "u8",
+    "use",
+    "variant",
+    "with",
+    "world",
+];
@@ -0,0 +1,45 @@
+use std::io::Write;
+
+use super::*;
+
+impl WitGenerator {
+    pub fn define_func<T: Write>(
+        w: &mut PrettyWriter<T>,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
+        let name = func_witx.name.as_str().to_string();
+        let params_witx = &func_witx.params;
+        let mut params = vec![];
+        for param_witx in params_witx {
+            let param_name = param_witx.name.as_str();
+            let param_type = ASType::from(&param_witx.tref);
+            params.push((param_name.to_string(), param_type));
+        }
+
+        let results_witx = &func_witx.results;
+        assert_eq!(results_witx.len(), 1);
+        let result_witx = &results_witx[0];
+        let result = ASType::from(&result_witx.tref);
+        assert!(matches!(result, ASType::Result(_)));
+
+        let docs = &func_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+
+        w.write_line(format!(
+            "{}: func({}) -> {};",
+            name.as_fn(),
+            params
+                .iter()
+                .map(|param| format!("{}: {}", param.0.as_var(), param.1.as_lang()))
+                .collect::<Vec<_>>()
+                .join(", "),
+            result.as_lang()
+        ))?;
+        w.eob()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use std::io::Write;
+
+use super::*;
+
+impl WitGenerator {
+    pub fn header<T: Write>(w: &mut PrettyWriter<T>, module_name: &str) -> Result<(), Error> {
+        w.write_lines(
+            "
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//",
+        )?;
+        w.eob()?;
+        w.write_line(format!("package witx:{};", module_name.as_namespace()))?;
+        w.eob()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,543 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::anyhow;
+use convert_case::{Case, Casing};
+use wit_parser::{FunctionKind, Handle, Resolve, Type, TypeDef, TypeDefKind, TypeId, TypeOwner};
+
+use crate::error::*;
+use crate::pretty_writer::PrettyWriter;
+
+/// Load a WIT file or package directory, and lower it to a WITX module.
+///
+/// WIT types are translated into their WITX counterparts, so that the regular
+/// `ASType` lowering and all the backends can be used unchanged. Only the
+/// subset of the component model that maps onto the WITX memory layout is
+/// supported: functions must return a `result<T, E>` or a `result<_, E>`,
+/// tuples must be named unless they are returned by a function, and results
+/// in any other position, constructors, async functions, streams, futures,
+/// maps and fixed-size lists are rejected. Options are lowered to named
+/// variants.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<witx::Module, Error> {
+    let mut resolve = Resolve::new();
+    let (package_id, _) = resolve.push_path(path.as_ref()).map_err(Error::Wit)?;
+
+    let mut witx_source = vec![];
+    WitLowering::new(&resolve).lower_package(&mut witx_source, package_id)?;
+    let witx_source = String::from_utf8(witx_source).map_err(|e| Error::Wit(e.into()))?;
+
+    Ok(witx::parse(&witx_source)?)
+}
+
+struct WitLowering<'a> {
+    resolve: &'a Resolve,
+    /// Names of the WITX types, including the aliases given to anonymous types
+    type_names: RefCell<HashSet<String>>,
+    /// Names of the variants defined for anonymous options
+    option_names: RefCell<HashMap<TypeId, String>>,
+}
+
+impl<'a> WitLowering<'a> {
+    fn new(resolve: &'a Resolve) -> Self {
+        let type_names = resolve
+            .types
+            .iter()
+            .filter_map(|(_, type_def)| type_def.name.as_deref().map(as_witx_id))
+            .collect();
+        WitLowering {
+            resolve,
+            type_names: RefCell::new(type_names),
+            option_names: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn lower_package<T: Write>(
+        &self,
+        writer: &mut T,
+        package_id: wit_parser::PackageId,
+    ) -> Result<(), Error> {
+        let mut w = PrettyWriter::new(writer, "  ");
+        let package = &self.resolve.packages[package_id];
+
+        // A package with a single interface is named after that interface
+        let module_name = match package.interfaces.keys().collect::<Vec<_>>().as_slice() {
+            [interface_name] => interface_name.to_string(),
+            _ => format!("{}_{}", package.name.namespace, package.name.name),
+        };
+
+        w.write_line(format!("(module {}", as_witx_id(&module_name)))?;
+        {
+            let mut w = w.new_block();
+
+            // The type arena is topologically sorted, so definitions always precede their uses
+            for (type_id, type_def) in self.resolve.types.iter() {
+                if !matches!(type_def.owner, TypeOwner::Interface(_)) {
+                    continue;
+                }
+                if type_def.name.is_some() {
+                    self.lower_named_type(&mut w, type_id, type_def)?;
+                }
+            }
+
+            for interface_id in package.interfaces.values() {
+                let interface = &self.resolve.interfaces[*interface_id];
+                for function in interface.functions.values() {
+                    self.lower_function(&mut w, function)?;
+                }
+            }
+        }
+        w.write_line(")")?;
+        Ok(())
+    }
+
+    fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &wit_parser::Docs) -> Result<(), Error> {
+        if let Some(docs) = &docs.contents {
+            for docs_line in docs.lines() {
+                w.write_line(format!(";;; {}", docs_line))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn lower_named_type<T: Write>(
+        &self,
+        w: &mut PrettyWriter<T>,
+        type_id: TypeId,
+        type_def: &TypeDef,
+    ) -> Result<(), Error> {
+        let name = type_def.name.as_deref().unwrap();
+
+        // `use` statements create aliases with the same name as the original type
+        if let TypeDefKind::Type(Type::Id(target_id)) = &type_def.kind {
+            if self.resolve.types[*target_id].name.as_deref() == Some(name) {
+                return Ok(());
+            }
+        }
+
+        // Anonymous options are defined as named variants before the type using them
+        match &type_def.kind {
+            TypeDefKind::Record(record) => {
+                for field in &record.fields {
+                    self.define_options(w, &format!("{}_{}", name, field.name), &field.ty)?;
+                }
+            }
+            TypeDefKind::Variant(variant) => {
+                for case in &variant.cases {
+                    if let Some(ty) = &case.ty {
+                        self.define_options(w, &format!("{}_{}", name, case.name), ty)?;
+                    }
+                }
+            }
+            TypeDefKind::Tuple(tuple) => {
+                for (i, member) in tuple.types.iter().enumerate() {
+                    self.define_options(w, &format!("{}_{}", name, i), member)?;
+                }
+            }
+            TypeDefKind::Option(ty) => self.define_options(w, &format!("{}_some", name), ty)?,
+            TypeDefKind::List(ty) => self.define_options(w, &format!("{}_item", name), ty)?,
+            _ => {}
+        }
+
+        Self::write_docs(w, &type_def.docs)?;
+        match &type_def.kind {
+            TypeDefKind::Resource => {
+                // Resources are exposed as handles, under the name of the resource
+                w.write_line(format!("(resource {})", as_witx_id(name)))?;
+                w.write_line(format!(
+                    "(typename {} (handle {}))",
+                    as_witx_id(name),
+                    as_witx_id(name)
+                ))?;
+            }
+            TypeDefKind::Record(record) => {
+                w.write_line(format!("(typename {}", as_witx_id(name)))?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line("(record")?;
+                    {
+                        let mut w = w.new_block();
+                        for field in &record.fields {
+                            Self::write_docs(&mut w, &field.docs)?;
+                            w.write_line(format!(
+                                "(field {} {})",
+                                as_witx_id(&field.name),
+                                self.lower_type(&field.ty)?
+                            ))?;
+                        }
+                    }
+                    w.write_line(")")?;
+                }
+                w.write_line(")")?;
+            }
+            TypeDefKind::Flags(flags) => {
+                let repr = repr_for_flags(flags.flags.len())
+                    .ok_or_else(|| Error::Wit(anyhow!("Too many flags in [{}]", name)))?;
+                w.write_line(format!("(typename {}", as_witx_id(name)))?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line(format!("(flags (@witx repr {})", repr))?;
+                    {
+                        let mut w = w.new_block();
+                        for flag in &flags.flags {
+                            Self::write_docs(&mut w, &flag.docs)?;
+                            w.write_line(as_witx_id(&flag.name))?;
+                        }
+                    }
+                    w.write_line(")")?;
+                }
+                w.write_line(")")?;
+            }
+            TypeDefKind::Variant(variant) => {
+                w.write_line(format!("(typename {}", as_witx_id(name)))?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line(format!(
+                        "(variant (@witx tag {})",
+                        repr_for_cases(variant.cases.len())
+                    ))?;
+                    {
+                        let mut w = w.new_block();
+                        for case in &variant.cases {
+                            Self::write_docs(&mut w, &case.docs)?;
+                            match &case.ty {
+                                None => {
+                                    w.write_line(format!("(case {})", as_witx_id(&case.name)))?
+                                }
+                                Some(ty) => w.write_line(format!(
+                                    "(case {} {})",
+                                    as_witx_id(&case.name),
+                                    self.lower_type(ty)?
+                                ))?,
+                            };
+                        }
+                    }
+                    w.write_line(")")?;
+                }
+                w.write_line(")")?;
+            }
+            TypeDefKind::Enum(enum_) => {
+                w.write_line(format!("(typename {}", as_witx_id(name)))?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line(format!(
+                        "(enum (@witx tag {})",
+                        repr_for_cases(enum_.cases.len())
+                    ))?;
+                    {
+                        let mut w = w.new_block();
+                        for case in &enum_.cases {
+                            Self::write_docs(&mut w, &case.docs)?;
+                            w.write_line(as_witx_id(&case.name))?;
+                        }
+                    }
+                    w.write_line(")")?;
+                }
+                w.write_line(")")?;
+            }
+            _ => {
+                w.write_line(format!(
+                    "(typename {} {})",
+                    as_witx_id(name),
+                    self.lower_type_def(type_id, type_def)?
+                ))?;
+            }
+        }
+        w.eob()?;
+        Ok(())
+    }
+
+    fn lower_function<T: Write>(
+        &self,
+        w: &mut PrettyWriter<T>,
+        function: &wit_parser::Function,
+    ) -> Result<(), Error> {
+        let name = match &function.kind {
+            FunctionKind::Freestanding => function.name.to_string(),
+            FunctionKind::Method(resource_id) | FunctionKind::Static(resource_id) => {
+                let resource_name = self.resolve.types[*resource_id].name.as_deref().unwrap();
+                format!("{}_{}", resource_name, function.item_name())
+            }
+            FunctionKind::Constructor(resource_id) => {
+                let resource_name = self.resolve.types[*resource_id].name.as_deref().unwrap();
+                return Err(Error::Wit(anyhow!(
+                    "The constructor of [{}] is not supported, since it can't return a result",
+                    resource_name
+                )));
+            }
+            _ => {
+                return Err(Error::Wit(anyhow!(
+                    "Async function [{}] is not supported",
+                    function.name
+                )))
+            }
+        };
+        let result = match &function.result {
+            Some(Type::Id(result_id)) => match &self.resolve.types[*result_id].kind {
+                TypeDefKind::Result(result) => Some(result),
+                _ => None,
+            },
+            _ => None,
+        };
+        let result = result
+            .filter(|result| result.err.is_some())
+            .ok_or_else(|| {
+                Error::Wit(anyhow!(
+                    "Function [{}] must return a result<T, E> to be lowered to WITX",
+                    function.name
+                ))
+            })?;
+
+        for (param_name, param_type) in &function.params {
+            self.define_options(w, &format!("{}_{}", name, param_name), param_type)?;
+        }
+
+        // WITX only allows named types in results, so anonymous types get a name
+        let mut parts = vec!["expected".to_string()];
+        if let Some(ok) = &result.ok {
+            let ok_s = match self.anonymous_tuple(ok) {
+                Some(tuple) => {
+                    let members = tuple
+                        .types
+                        .iter()
+                        .enumerate()
+                        .map(|(i, member)| {
+                            let alias_name = format!("{}_result_{}", name, i);
+                            self.define_options(w, &alias_name, member)?;
+                            self.named_type(w, &alias_name, member)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    format!("(tuple {})", members.join(" "))
+                }
+                None => {
+                    let alias_name = format!("{}_result", name);
+                    self.define_options(w, &alias_name, ok)?;
+                    self.named_type(w, &alias_name, ok)?
+                }
+            };
+            parts.push(ok_s);
+        }
+        if let Some(err) = &result.err {
+            let alias_name = format!("{}_error", name);
+            self.define_options(w, &alias_name, err)?;
+            let err_s = self.named_type(w, &alias_name, err)?;
+            parts.push(format!("(error {})", err_s));
+        }
+        let result = format!("({})", parts.join(" "));
+
+        Self::write_docs(w, &function.docs)?;
+        w.write_line(format!(
+            "(@interface func (export \"{}\")",
+            name.to_case(Case::Snake)
+        ))?;
+        {
+            let mut w = w.new_block();
+            for (param_name, param_type) in &function.params {
+                w.write_line(format!(
+                    "(param {} {})",
+                    as_witx_id(param_name),
+                    self.lower_type(param_type)?
+                ))?;
+            }
+            w.write_line(format!("(result $error {})", result))?;
+        }
+        w.write_line(")")?;
+        w.eob()?;
+        Ok(())
+    }
+
+    fn anonymous_tuple(&self, type_: &Type) -> Option<&wit_parser::Tuple> {
+        match type_ {
+            Type::Id(type_id) => {
+                let type_def = &self.resolve.types[*type_id];
+                match &type_def.kind {
+                    TypeDefKind::Tuple(tuple) if type_def.name.is_none() => Some(tuple),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the name of a type, defining a type alias first if it is anonymous.
+    /// The alias gets a numeric suffix if its name is already taken.
+    fn named_type<T: Write>(
+        &self,
+        w: &mut PrettyWriter<T>,
+        alias_name: &str,
+        type_: &Type,
+    ) -> Result<String, Error> {
+        if let Type::Id(type_id) = type_ {
+            if let Some(name) = &self.resolve.types[*type_id].name {
+                return Ok(as_witx_id(name));
+            }
+            if let Some(alias) = self.option_names.borrow().get(type_id) {
+                return Ok(alias.clone());
+            }
+        }
+        let lowered_type = self.lower_type(type_)?;
+        let mut type_names = self.type_names.borrow_mut();
+        let mut alias = as_witx_id(alias_name);
+        for i in 2.. {
+            if !type_names.contains(&alias) {
+                break;
+            }
+            alias = as_witx_id(&format!("{}_{}", alias_name, i));
+        }
+        type_names.insert(alias.clone());
+        w.write_line(format!("(typename {} {})", alias, lowered_type))?;
+        w.eob()?;
+        Ok(alias)
+    }
+
+    /// Defines the anonymous options of a type as named variants, innermost first, so that
+    /// backends see them as regular unions
+    fn define_options<T: Write>(
+        &self,
+        w: &mut PrettyWriter<T>,
+        alias_name: &str,
+        type_: &Type,
+    ) -> Result<(), Error> {
+        let type_id = match type_ {
+            Type::Id(type_id) => *type_id,
+            _ => return Ok(()),
+        };
+        let type_def = &self.resolve.types[type_id];
+        if type_def.name.is_some() || self.option_names.borrow().contains_key(&type_id) {
+            return Ok(());
+        }
+        match &type_def.kind {
+            TypeDefKind::Type(ty) => self.define_options(w, alias_name, ty)?,
+            TypeDefKind::List(ty) => self.define_options(w, &format!("{}_item", alias_name), ty)?,
+            TypeDefKind::Tuple(tuple) => {
+                for (i, member) in tuple.types.iter().enumerate() {
+                    self.define_options(w, &format!("{}_{}", alias_name, i), member)?;
+                }
+            }
+            TypeDefKind::Option(ty) => {
+                self.define_options(w, &format!("{}_some", alias_name), ty)?;
+                let alias = self.named_type(w, alias_name, type_)?;
+                self.option_names.borrow_mut().insert(type_id, alias);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn lower_type(&self, type_: &Type) -> Result<String, Error> {
+        let type_s = match type_ {
+            Type::Bool => "bool".to_string(),
+            Type::U8 => "u8".to_string(),
+            Type::U16 => "u16".to_string(),
+            Type::U32 => "u32".to_string(),
+            Type::U64 => "u64".to_string(),
+            Type::S8 => "s8".to_string(),
+            Type::S16 => "s16".to_string(),
+            Type::S32 => "s32".to_string(),
+            Type::S64 => "s64".to_string(),
+            Type::F32 => "f32".to_string(),
+            Type::F64 => "f64".to_string(),
+            Type::Char => "char".to_string(),
+            Type::String => "string".to_string(),
+            Type::ErrorContext => {
+                return Err(Error::Wit(anyhow!("error-context is not supported")))
+            }
+            Type::Id(type_id) => {
+                let type_def = &self.resolve.types[*type_id];
+                match (&type_def.name, &type_def.kind) {
+                    (Some(name), _) => as_witx_id(name),
+                    // Backends only define tuples that have a name
+                    (None, TypeDefKind::Tuple(_)) => {
+                        return Err(Error::Wit(anyhow!(
+                            "Anonymous tuples are only supported in function results, use a \
+                             type alias instead"
+                        )))
+                    }
+                    (None, _) => self.lower_type_def(*type_id, type_def)?,
+                }
+            }
+        };
+        Ok(type_s)
+    }
+
+    fn lower_type_def(&self, type_id: TypeId, type_def: &TypeDef) -> Result<String, Error> {
+        let type_s = match &type_def.kind {
+            TypeDefKind::Type(type_) => self.lower_type(type_)?,
+            TypeDefKind::Handle(Handle::Own(resource_id))
+            | TypeDefKind::Handle(Handle::Borrow(resource_id)) => {
+                let resource_name = self.resolve.types[*resource_id].name.as_deref().unwrap();
+                as_witx_id(resource_name)
+            }
+            TypeDefKind::Tuple(tuple) => {
+                let members = tuple
+                    .types
+                    .iter()
+                    .map(|member| self.lower_type(member))
+                    .collect::<Result<Vec<_>, _>>()?;
+                format!("(tuple {})", members.join(" "))
+            }
+            TypeDefKind::Option(type_) => match self.option_names.borrow().get(&type_id) {
+                Some(alias) => alias.clone(),
+                // WITX reads `none` and `some` cases as an option, that backends can't define
+                None => format!(
+                    "(variant (@witx tag u8) (case $absent) (case $present {}))",
+                    self.lower_type(type_)?
+                ),
+            },
+            TypeDefKind::Result(_) => {
+                return Err(Error::Wit(anyhow!(
+                    "result<T, E> is only supported as the return type of a function"
+                )))
+            }
+            TypeDefKind::List(type_) => format!("(list {})", self.lower_type(type_)?),
+            TypeDefKind::Record(_)
+            | TypeDefKind::Resource
+            | TypeDefKind::Flags(_)
+            | TypeDefKind::Variant(_)
+            | TypeDefKind::Enum(_) => {
+                // WIT only allows these to be defined as named types
+                return Err(Error::Wit(anyhow!(
+                    "Anonymous type {:?} cannot be lowered to WITX",
+                    type_id
+                )));
+            }
+            TypeDefKind::Map(..)
+            | TypeDefKind::FixedSizeList(..)
+            | TypeDefKind::Future(_)
+            | TypeDefKind::Stream(_)
+            | TypeDefKind::Unknown => {
+                return Err(Error::Wit(anyhow!(
+                    "Type [{}] is not supported",
+                    type_def.name.as_deref().unwrap_or("<anonymous>")
+                )))
+            }
+        };
+        Ok(type_s)
+    }
+}
+
+/// Convert a WIT (kebab-case) identifier into a WITX identifier
+fn as_witx_id(name: &str) -> String {
+    format!("${}", name.to_case(Case::Snake))
+}
+
+/// Smallest tag representation for a variant or enum, as in the canonical ABI
+fn repr_for_cases(count: usize) -> &'static str {
+    match count {
+        0..=0xff => "u8",
+        0x100..=0xffff => "u16",
+        _ => "u32",
+    }
+}
+
+/// Smallest representation for a set of flags, or `None` if they don't fit in a `u64`
+fn repr_for_flags(count: usize) -> Option<&'static str> {
+    match count {
+        0..=8 => Some("u8"),
+        9..=16 => Some("u16"),
+        17..=32 => Some("u32"),
+        33..=64 => Some("u64"),
+        _ => None,
+    }
+}
@@ -0,0 +1,30 @@
+use std::io::Write;
+
+use super::*;
+
+impl WitGenerator {
+    pub fn define_as_union<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        w.write_line(format!("variant {} {{", name.as_type()))?;
+        {
+            let mut w = w.new_block();
+            for member in &union_.members {
+                let member_type = member.type_.as_ref();
+                Self::write_docs(&mut w, &member.docs)?;
+                match member_type {
+                    ASType::Void => w.write_line(format!("{},", member.name.as_var()))?,
+                    _ => w.write_line(format!(
+                        "{}({}),",
+                        member.name.as_var(),
+                        member_type.as_lang()
+                    ))?,
+                };
+            }
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+}
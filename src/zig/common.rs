@@ -2,7 +2,11 @@ use convert_case::{Case, Casing};
 
 use super::tuple::Tuple;
 use crate::astype::*;
+use crate::names::{
+    apply_naming_policy, apply_naming_policy_or_preserve, NameConversions, NameKind,
+};
 
+#[allow(dead_code)]
 pub trait IsNullable {
     fn is_nullable(&self) -> bool;
 }
@@ -27,11 +31,12 @@ pub trait Normalize {
     fn as_str(&self) -> &str;
 
     fn as_type(&self) -> String {
-        self.as_str().to_case(Case::Pascal)
+        apply_naming_policy(NameKind::Type, self.as_str(), Case::Pascal)
     }
 
+    /// Imports keep their WITX names, unless the naming policy changes them
     fn as_fn(&self) -> String {
-        self.as_str().to_case(Case::Camel)
+        apply_naming_policy_or_preserve(NameKind::Function, self.as_str())
     }
 
     fn as_fn_suffix(&self) -> String {
@@ -39,15 +44,19 @@ pub trait Normalize {
     }
 
     fn as_var(&self) -> String {
-        escape_reserved_word(&self.as_str().to_case(Case::Snake))
+        escape_reserved_word(&apply_naming_policy(
+            NameKind::Variable,
+            self.as_str(),
+            Case::Snake,
+        ))
     }
 
     fn as_const(&self) -> String {
-        self.as_str().to_case(Case::UpperSnake)
+        apply_naming_policy(NameKind::Constant, self.as_str(), Case::UpperSnake)
     }
 
     fn as_namespace(&self) -> String {
-        self.as_str().to_string().to_case(Case::Pascal)
+        apply_naming_policy(NameKind::Namespace, self.as_str(), Case::Pascal)
     }
 }
 
@@ -57,6 +66,15 @@ impl<T: AsRef<str>> Normalize for T {
     }
 }
 
+/// The identifiers of this backend, checked for collisions
+pub const NAME_CONVERSIONS: NameConversions = NameConversions {
+    type_: |name| name.as_type(),
+    function: |name| name.as_fn(),
+    variable: |name| name.as_var(),
+    constant: |name| name.as_const(),
+    namespace: |name| name.as_namespace(),
+};
+
 pub trait ToLanguageRepresentation {
     fn as_astype(&self) -> &ASType;
 
@@ -108,11 +126,16 @@ impl ToLanguageRepresentation for ASType {
     }
 }
 
+/// Checks whether the given word is a reserved keyword.
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED.contains(&word)
+}
+
 /// Checks the given word against a list of reserved keywords.
 /// If the given word conflicts with a keyword, a trailing underscore will be
 /// appended.
 pub fn escape_reserved_word(word: &str) -> String {
-    if RESERVED.iter().any(|k| *k == word) {
+    if is_reserved_word(word) {
         // If the camel-cased string matched any strict or reserved keywords, then
         // append a trailing underscore to the identifier we generate.
         format!("{}_", word)
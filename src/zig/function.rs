@@ -7,9 +7,34 @@ impl ZigGenerator {
         w: &mut PrettyWriter<T>,
         module_name: &str,
         func_witx: &witx::Function,
+        options: &Options,
     ) -> Result<(), Error> {
-        assert_eq!(func_witx.abi, witx::Abi::Preview1);
         let name = func_witx.name.as_str().to_string();
+        let (params_decomposed, results_decomposed, result) = Self::decompose_func(func_witx);
+
+        let docs = &func_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+
+        Self::define_func_raw(
+            w,
+            module_name,
+            &name,
+            &params_decomposed,
+            &results_decomposed,
+            &result,
+            options.trace,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the parameters of the import, followed by the pointers to the results
+    pub(super) fn decompose_func(
+        func_witx: &witx::Function,
+    ) -> (Vec<ASTypeDecomposed>, Vec<ASTypeDecomposed>, ASResult) {
+        assert_eq!(func_witx.abi, witx::Abi::Preview1);
         let params_witx = &func_witx.params;
         let mut params = vec![];
         for param_witx in params_witx {
@@ -29,11 +54,6 @@ impl ZigGenerator {
 
         let ok_type = result.ok_type.clone();
 
-        let docs = &func_witx.docs;
-        if !docs.is_empty() {
-            Self::write_docs(w, docs)?;
-        }
-
         let mut params_decomposed = vec![];
 
         for param in &params {
@@ -59,15 +79,6 @@ impl ZigGenerator {
             results_decomposed.append(&mut decomposed);
         }
 
-        Self::define_func_raw(
-            w,
-            module_name,
-            &name,
-            &params_decomposed,
-            &results_decomposed,
-            &result,
-        )?;
-
         let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
         let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
         assert_eq!(
@@ -75,7 +86,7 @@ impl ZigGenerator {
             params_decomposed.len() + results_decomposed.len() + 1
         );
 
-        Ok(())
+        (params_decomposed, results_decomposed, result)
     }
 
     fn define_func_raw<T: Write>(
@@ -85,9 +96,34 @@ impl ZigGenerator {
         params_decomposed: &[ASTypeDecomposed],
         results_decomposed: &[ASTypeDecomposed],
         result: &ASResult,
+        trace: bool,
     ) -> Result<(), Error> {
+        // The name of an extern function is the import name, so a function renamed by the
+        // naming policy has to call it from a wrapper
+        if trace || name.as_fn() != name {
+            return Self::define_func_wrapper(
+                w,
+                module_name,
+                name,
+                params_decomposed,
+                results_decomposed,
+                result,
+                trace,
+            );
+        }
         w.indent()?
             .write(format!("pub extern \"{}\" fn {}(", module_name, name))?;
+        Self::define_func_params(w, params_decomposed, results_decomposed)?;
+        w.write_line(format!(") callconv(.C) {};", result.error_type.as_lang()))?;
+        w.eob()?;
+        Ok(())
+    }
+
+    fn define_func_params<T: Write>(
+        w: &mut PrettyWriter<T>,
+        params_decomposed: &[ASTypeDecomposed],
+        results_decomposed: &[ASTypeDecomposed],
+    ) -> Result<(), Error> {
         if !params_decomposed.is_empty() || !results_decomposed.is_empty() {
             w.eol()?;
         }
@@ -98,7 +134,65 @@ impl ZigGenerator {
                 param.type_.as_lang(),
             ))?;
         }
-        w.write_line(format!(") callconv(.C) {};", result.error_type.as_lang()))?;
+        Ok(())
+    }
+
+    /// Defines a function around the import, that logs its arguments and the returned error
+    /// code if `trace` is set
+    fn define_func_wrapper<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        name: &str,
+        params_decomposed: &[ASTypeDecomposed],
+        results_decomposed: &[ASTypeDecomposed],
+        result: &ASResult,
+        trace: bool,
+    ) -> Result<(), Error> {
+        let all_params = params_decomposed
+            .iter()
+            .chain(results_decomposed.iter())
+            .map(|param| param.name.as_var())
+            .collect::<Vec<_>>();
+        w.indent()?.write(format!("pub fn {}(", name.as_fn()))?;
+        Self::define_func_params(w, params_decomposed, results_decomposed)?;
+        w.write_line(format!(") {} {{", result.error_type.as_lang()))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("const raw = struct {")?;
+            {
+                let mut w = w.new_block();
+                w.indent()?
+                    .write(format!("extern \"{}\" fn {}(", module_name, name))?;
+                Self::define_func_params(&mut w, params_decomposed, results_decomposed)?;
+                w.write_line(format!(") callconv(.C) {};", result.error_type.as_lang()))?;
+            }
+            w.write_line("};")?;
+            if trace {
+                w.write_line(format!(
+                    "const res = raw.{}({});",
+                    name,
+                    all_params.join(", ")
+                ))?;
+                let args_format = params_decomposed
+                    .iter()
+                    .map(|param| format!("{}={{any}}", param.name.as_var()))
+                    .collect::<Vec<_>>();
+                w.write_line(format!(
+                    "@import(\"std\").debug.print(\"[{}] {}({}) -> {{any}}\\n\", .{{ {}res }});",
+                    module_name,
+                    name,
+                    args_format.join(", "),
+                    params_decomposed
+                        .iter()
+                        .map(|param| format!("{}, ", param.name.as_var()))
+                        .collect::<String>()
+                ))?;
+                w.write_line("return res;")?;
+            } else {
+                w.write_line(format!("return raw.{}({});", name, all_params.join(", ")))?;
+            }
+        }
+        w.write_line("}")?;
         w.eob()?;
         Ok(())
     }
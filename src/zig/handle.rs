@@ -0,0 +1,118 @@
+use std::io::Write;
+
+use super::*;
+use crate::resources::{self, CloseFunction, HandleMethods};
+
+impl ZigGenerator {
+    pub fn define_as_handle<T: Write>(w: &mut PrettyWriter<T>, name: &str) -> Result<(), Error> {
+        w.write_line(format!(
+            "pub const {} = enum(WasiHandle) {{ _ }};",
+            name.as_type()
+        ))?;
+        Ok(())
+    }
+
+    /// A handle type, with the functions taking it as their first parameter as methods
+    pub fn define_as_handle_with_methods<T: Write>(
+        w: &mut PrettyWriter<T>,
+        name: &str,
+        module_name: &str,
+        methods: &HandleMethods,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "pub const {} = enum(WasiHandle) {{",
+            name.as_type()
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("_,")?;
+            for func in &methods.funcs {
+                w.eob()?;
+                Self::define_handle_method(&mut w, name, module_name, func)?;
+            }
+        }
+        w.write_line("};")?;
+        Ok(())
+    }
+
+    fn define_handle_method<T: Write>(
+        w: &mut PrettyWriter<T>,
+        handle_type: &str,
+        module_name: &str,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let (params_decomposed, results_decomposed, result) = Self::decompose_func(func_witx);
+        let docs = &func_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+        w.write_line(format!(
+            "pub fn {}(",
+            resources::method_name(handle_type, func_witx)
+        ))?;
+        w.write_line_continued(format!("self: {},", handle_type.as_type()))?;
+        // The first parameter is the handle itself
+        let params = params_decomposed[1..]
+            .iter()
+            .chain(results_decomposed.iter());
+        let mut args = vec!["self".to_string()];
+        for param in params {
+            w.write_line_continued(format!(
+                "{}: {},",
+                param.name.as_var(),
+                param.type_.as_lang(),
+            ))?;
+            args.push(param.name.as_var());
+        }
+        w.write_line(format!(") {} {{", result.error_type.as_lang()))?;
+        w.new_block().write_line(format!(
+            "return {}.{}({});",
+            module_name.as_namespace(),
+            func_witx.name.as_str().as_fn(),
+            args.join(", ")
+        ))?;
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// A wrapper owning a handle, and closing it in `deinit()`
+    pub fn define_owned_handle<T: Write>(
+        w: &mut PrettyWriter<T>,
+        module_name: &str,
+        close_function: &CloseFunction,
+    ) -> Result<(), Error> {
+        let handle_type = close_function.handle_type.as_type();
+        let owned_type = format!("owned_{}", close_function.handle_type).as_type();
+        let close_fn = close_function.func.name.as_str().as_fn();
+
+        w.write_line(format!(
+            "/// An owned `{}`, closed with `{}()` by `deinit()`",
+            handle_type, close_fn
+        ))?
+        .write_line(format!("pub const {} = struct {{", owned_type))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("handle: {},", handle_type))?.eob()?;
+            w.write_line("/// Take the ownership of a handle")?
+                .write_line(format!(
+                    "pub fn init(handle: {}) {} {{",
+                    handle_type, owned_type
+                ))?
+                .indent()?
+                .write_line("return .{ .handle = handle };")?
+                .write_line("}")?
+                .eob()?;
+            w.write_line("/// Close the handle")?
+                .write_line(format!("pub fn deinit(self: {}) void {{", owned_type))?
+                .indent()?
+                .write_line(format!(
+                    "_ = {}.{}(self.handle);",
+                    module_name.as_namespace(),
+                    close_fn
+                ))?
+                .write_line("}")?;
+        }
+        w.write_line("};")?.eob()?;
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
-mod common;
+pub(crate) mod common;
 mod function;
+mod handle;
 mod header;
 mod r#struct;
 mod tuple;
@@ -39,6 +40,8 @@ impl<T: Write> Generator<T> for ZigGenerator {
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
+        names::check_name_collisions(&module_witx, skip_imports, &NAME_CONVERSIONS)?;
+
         if !options.skip_header {
             Self::header(&mut w)?;
         }
@@ -50,6 +53,12 @@ impl<T: Write> Generator<T> for ZigGenerator {
         Self::write_comments(&mut w, &module_title_comments)?;
         w.eob()?;
 
+        let methods = if options.methods {
+            resources::handle_methods(&module_witx)
+        } else {
+            vec![]
+        };
+
         for type_ in module_witx.typenames() {
             if skip_imports && &type_.module != module_id {
                 continue;
@@ -67,7 +76,16 @@ impl<T: Write> Generator<T> for ZigGenerator {
                     }
                 })
                 .collect();
-            Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
+            let methods_for_type = methods
+                .iter()
+                .find(|methods| methods.handle_type == type_.name.as_str());
+            Self::define_type(
+                &mut w,
+                &module_name,
+                type_.as_ref(),
+                &constants_for_type,
+                methods_for_type,
+            )?;
         }
 
         w.write_line(format!(
@@ -77,12 +95,18 @@ impl<T: Write> Generator<T> for ZigGenerator {
         {
             let mut w = w.new_block();
             for func in module_witx.funcs() {
-                Self::define_func(&mut w, &module_name, func.as_ref())?;
+                Self::define_func(&mut w, &module_name, func.as_ref(), options)?;
             }
         }
         w.write_line("};")?;
         w.eob()?;
 
+        if options.raii {
+            for close_function in resources::close_functions(&module_witx) {
+                Self::define_owned_handle(&mut w, &module_name, &close_function)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -192,11 +216,11 @@ impl ZigGenerator {
             | ASType::S32
             | ASType::S64
             | ASType::USize
-            | ASType::Handle(_)
             | ASType::Slice(_)
             | ASType::String(_)
             | ASType::ReadBuffer(_)
             | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
+            ASType::Handle(_) => Self::define_as_handle(w, name)?,
             ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
             ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
             ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
@@ -251,8 +275,10 @@ impl ZigGenerator {
 
     fn define_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        module_name: &str,
         type_witx: &witx::NamedType,
         constants: &[ASConstant],
+        methods: Option<&resources::HandleMethods>,
     ) -> Result<(), Error> {
         let docs = &type_witx.docs;
         if !docs.is_empty() {
@@ -266,7 +292,12 @@ impl ZigGenerator {
             }
             witx::TypeRef::Value(type_witx) => {
                 let t = ASType::from(type_witx.as_ref());
-                Self::define_as_type(w, type_name, &t)?
+                match (&t, methods) {
+                    (ASType::Handle(_), Some(methods)) => {
+                        Self::define_as_handle_with_methods(w, type_name, module_name, methods)?
+                    }
+                    _ => Self::define_as_type(w, type_name, &t)?,
+                }
             }
         }
         w.eob()?;
@@ -0,0 +1,414 @@
+//! Checks that the generated code is accepted by the compilers of the target languages.
+//!
+//! Tests that require toolchains other than the native Rust one are opt-in, since they are
+//! not needed to build witx-codegen: `cargo test --test compile -- --ignored`. Each test is
+//! skipped when the corresponding toolchain is not installed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use structopt::StructOpt;
+use witx_codegen::{generate, Config, OutputType};
+
+const WITX_SOURCES: &[&str] = &[
+    "test_module.witx",
+    "wasi_ephemeral_crypto_common.witx",
+    "wasi_ephemeral_crypto_symmetric.witx",
+    "wasi_experimental_http.witx",
+];
+
+const WITX_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn generate_file(source: &str, output_type: OutputType, extension: &str) -> PathBuf {
+    let c = Config {
+        output_type,
+        ..Default::default()
+    };
+    generate_file_with(&output_type.to_string(), source, c, extension)
+}
+
+fn generate_file_with(test_name: &str, source: &str, mut c: Config, extension: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("compile")
+        .join(test_name)
+        .join(source);
+    fs::create_dir_all(&dir).unwrap();
+    let output_file = dir.join(format!("generated.{}", extension));
+    c.output_file = Some(output_file.to_str().unwrap().to_string());
+    c.witx_files = vec![format!("{}/tests/{}", WITX_DIR, source)];
+    generate(&c).unwrap();
+    output_file
+}
+
+/// Returns `true` if `command` can be run, so that missing toolchains can be skipped
+fn is_available(command: &str, version_arg: &str) -> bool {
+    match Command::new(command).arg(version_arg).output() {
+        Ok(output) => output.status.success(),
+        Err(_) => {
+            println!("[{}] not found, skipping", command);
+            false
+        }
+    }
+}
+
+fn run(command: &mut Command) -> Output {
+    println!("Running {:?}", command);
+    let output = command.output().unwrap();
+    if !output.status.success() {
+        panic!(
+            "{:?} failed:\n{}{}",
+            command,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    output
+}
+
+#[test]
+#[ignore]
+fn compile_rust() {
+    const TARGET: &str = "wasm32-wasip1";
+
+    if !is_available("rustc", "--version") {
+        return;
+    }
+    let sysroot = Command::new("rustc")
+        .args(["--print", "sysroot"])
+        .output()
+        .unwrap();
+    let sysroot = String::from_utf8(sysroot.stdout).unwrap();
+    // Without the WebAssembly target, the code is still type-checked for the native one
+    let target = if Path::new(sysroot.trim())
+        .join("lib/rustlib")
+        .join(TARGET)
+        .exists()
+    {
+        Some(TARGET)
+    } else {
+        println!("[{}] target not installed, checking natively", TARGET);
+        None
+    };
+
+    for (s, trace) in WITX_SOURCES.iter().flat_map(|s| [(s, false), (s, true)]) {
+        let generated = if trace {
+            let c = Config::from_iter(["witx-codegen", "-t", "rust", "--trace"]);
+            generate_file_with("rust_trace", s, c, "rs")
+        } else {
+            generate_file(s, OutputType::Rust, "rs")
+        };
+        let mut command = Command::new("rustc");
+        if let Some(target) = target {
+            command.args(["--target", target]);
+        }
+        run(command
+            .args([
+                "--crate-type",
+                "lib",
+                "--edition",
+                "2018",
+                "--emit",
+                "metadata",
+            ])
+            .arg("--out-dir")
+            .arg(generated.parent().unwrap())
+            .arg(&generated));
+    }
+}
+
+#[test]
+fn run_rust_mocks() {
+    let c = Config::from_iter(["witx-codegen", "-t", "rust", "--mocks", "--trace", "--raii"]);
+    let generated = generate_file_with("rust_mocks", "wasi_experimental_http.witx", c, "rs");
+    let dir = generated.parent().unwrap();
+    let main = dir.join("main.rs");
+    fs::write(
+        &main,
+        r#"include!("generated.rs");
+
+struct Http;
+
+impl wasi_experimental_http_mock::Imports for Http {
+    fn req(
+        &mut self,
+        url_ptr: WasiPtr<Char8>,
+        url_len: usize,
+        _method_ptr: WasiPtr<Char8>,
+        _method_len: usize,
+        _headers_ptr: WasiPtr<Char8>,
+        _headers_len: usize,
+        _body_ptr: WasiPtr<u8>,
+        _body_len: usize,
+        result_0_ptr: WasiMutPtr<StatusCode>,
+        result_1_ptr: WasiMutPtr<ResponseHandle>,
+    ) -> HttpError {
+        let url = unsafe { std::slice::from_raw_parts(url_ptr, url_len) };
+        if url != b"https://example.com" {
+            return HTTP_ERROR::INVALID_URL;
+        }
+        unsafe {
+            *result_0_ptr = 200;
+            *result_1_ptr = ResponseHandle(42);
+        }
+        HTTP_ERROR::SUCCESS
+    }
+
+    fn close(&mut self, response_handle: ResponseHandle) -> HttpError {
+        eprintln!("closed {:?}", response_handle);
+        HTTP_ERROR::SUCCESS
+    }
+}
+
+fn main() {
+    wasi_experimental_http_mock::set_imports(Http);
+    let req = |url: &str| req(url.as_ptr(), url.len(), "GET".as_ptr(), 3, [].as_ptr(), 0, [].as_ptr(), 0);
+    assert_eq!(req("https://example.com").unwrap(), (200, ResponseHandle(42)));
+    assert_eq!(req("nope"), Err(Error::WasiError(HTTP_ERROR::INVALID_URL as _)));
+    assert!(std::panic::catch_unwind(|| body_read(ResponseHandle(42), [].as_mut_ptr(), 0)).is_err());
+    drop(OwnedResponseHandle::new(ResponseHandle(43)));
+    assert_eq!(OwnedResponseHandle::new(ResponseHandle(44)).into_inner(), ResponseHandle(44));
+}
+"#,
+    )
+    .unwrap();
+
+    let executable = dir.join("main");
+    run(Command::new("rustc")
+        .args(["--edition", "2018", "-A", "warnings", "-o"])
+        .arg(&executable)
+        .arg(&main));
+    let output = run(&mut Command::new(&executable));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("[wasi_experimental_http] req(url_ptr="));
+    assert!(stderr.contains("body_len=0) -> 10\n"));
+    assert!(stderr.contains("closed ResponseHandle(43)\n"));
+    assert!(!stderr.contains("closed ResponseHandle(44)"));
+}
+
+#[test]
+fn run_rust_types() {
+    let c = Config::from_iter(["witx-codegen", "-t", "rust", "--mocks"]);
+    let generated = generate_file_with("rust_types", "test_module.witx", c, "rs");
+    let dir = generated.parent().unwrap();
+    let main = dir.join("main.rs");
+    fs::write(
+        &main,
+        r#"include!("generated.rs");
+
+fn main() {
+    let mut tu = TestTaggedUnion::new_third_choice(1.5);
+    assert_eq!(tu.kind(), TestTaggedUnionKind::ThirdChoice);
+    assert_eq!(tu.as_third_choice(), Some(&1.5));
+    assert_eq!(tu.as_first_choice(), None);
+    *tu.as_third_choice_mut().unwrap() = 2.5;
+    assert_eq!(tu.into_third_choice(), Some(2.5));
+    assert_eq!(TestTaggedUnion::new_empty_choice().into_first_choice(), None);
+
+    let tu = TestTaggedUnion::from(TestTaggedUnionValue::SecondChoice("hello".into()));
+    assert_eq!(tu.as_second_choice().unwrap().as_str(), Ok("hello"));
+    match TestTaggedUnionValue::from(tu) {
+        TestTaggedUnionValue::SecondChoice(s) => assert_eq!(s.as_str(), Ok("hello")),
+        _ => panic!("unexpected member"),
+    }
+    let tu = TestTaggedUnion::from(TestTaggedUnionValue::EmptyChoice);
+    assert!(matches!(TestTaggedUnionValue::from(tu), TestTaggedUnionValue::EmptyChoice));
+
+    let mut tu = TestTaggedUnion::new_empty_choice();
+    tu.tag = 42;
+    assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tu.kind())).is_err());
+    assert_eq!(format!("{:?}", tu), "TestTaggedUnion { tag: 42 }");
+
+    assert_eq!(format!("{:?}", TestTaggedUnion::new_first_choice(7)), "FirstChoice(7)");
+    assert_eq!(TestTaggedUnion::new_third_choice(1.5), TestTaggedUnion::new_third_choice(1.5));
+    assert_ne!(TestTaggedUnion::new_first_choice(1), TestTaggedUnion::new_first_choice(2));
+    assert_eq!(TestTaggedUnion::new_first_choice(0), TestTaggedUnion::default());
+    assert!(TestTaggedUnion::default().is_first_choice());
+
+    let tuple = TestTuple { v0: true, v1: 2, v2: 3, ..Default::default() };
+    assert_eq!(format!("{:?}", tuple), "TestTuple { v0: true, v1: 2, v2: 3 }");
+    let tuples: std::collections::HashSet<_> = vec![tuple, tuple, TestTuple::default()].into_iter().collect();
+    assert_eq!(tuples.len(), 2);
+}
+"#,
+    )
+    .unwrap();
+
+    let executable = dir.join("main");
+    run(Command::new("rustc")
+        .args(["--edition", "2018", "-A", "warnings", "-o"])
+        .arg(&executable)
+        .arg(&main));
+    run(&mut Command::new(&executable));
+}
+
+#[test]
+#[ignore]
+fn compile_zig() {
+    if !is_available("zig", "version") {
+        return;
+    }
+
+    for s in WITX_SOURCES {
+        let generated = generate_file(s, OutputType::Zig, "zig");
+
+        // Zig only analyzes declarations that are referenced
+        let dir = generated.parent().unwrap();
+        let root = dir.join("root.zig");
+        fs::write(
+            &root,
+            "const std = @import(\"std\");\n\
+             test {\n    std.testing.refAllDeclsRecursive(@import(\"generated.zig\"));\n}\n",
+        )
+        .unwrap();
+        run(Command::new("zig")
+            .args(["test", "-target", "wasm32-wasi", "--test-no-exec"])
+            .arg(format!("-femit-bin={}", dir.join("root.wasm").display()))
+            .arg(&root));
+    }
+}
+
+#[test]
+#[ignore]
+fn compile_assemblyscript() {
+    if !is_available("asc", "--version") {
+        return;
+    }
+
+    // The static methods of the exported wrapper classes are compiled as exports
+    for s in WITX_SOURCES {
+        let generated = generate_file(s, OutputType::AssemblyScript, "ts");
+        run(Command::new("asc").arg(&generated).arg("--noEmit"));
+    }
+}
+
+#[test]
+#[ignore]
+fn compile_cpp() {
+    if !is_available("clang++", "--version") {
+        return;
+    }
+
+    for s in WITX_SOURCES {
+        let generated = generate_file(s, OutputType::Cpp, "h");
+        let mut command = Command::new("clang++");
+        command.args([
+            "--target=wasm32",
+            "-std=c++17",
+            "-fsyntax-only",
+            "-x",
+            "c++",
+        ]);
+        // The standard headers for WebAssembly usually come from a WASI sysroot
+        if let Some(sysroot) = std::env::var_os("WASI_SYSROOT") {
+            command.arg("--sysroot").arg(sysroot);
+        }
+        run(command.arg(&generated));
+    }
+}
+
+#[test]
+#[ignore]
+fn compile_python_host() {
+    if !is_available("python3", "--version") {
+        return;
+    }
+    let has_wasmtime = Command::new("python3")
+        .args(["-c", "import wasmtime"])
+        .status()
+        .is_ok_and(|status| status.success());
+    if !has_wasmtime {
+        println!("[wasmtime] Python package not found, skipping");
+        return;
+    }
+
+    for s in WITX_SOURCES {
+        let generated = generate_file(s, OutputType::PythonHost, "py");
+        run(Command::new("python3")
+            .args(["-c", "import generated"])
+            .current_dir(generated.parent().unwrap()));
+    }
+}
+
+#[test]
+#[ignore]
+fn compile_typescript_host() {
+    if !is_available("tsc", "--version") {
+        return;
+    }
+
+    for s in WITX_SOURCES {
+        let generated = generate_file(s, OutputType::TypescriptHost, "ts");
+        run(Command::new("tsc")
+            .args([
+                "--noEmit",
+                "--strict",
+                "--target",
+                "es2020",
+                "--lib",
+                "es2020,dom",
+            ])
+            .arg(&generated));
+    }
+}
+
+#[test]
+#[ignore]
+fn compile_go_host() {
+    if !is_available("go", "version") {
+        return;
+    }
+
+    for s in WITX_SOURCES {
+        let generated = generate_file(s, OutputType::GoHost, "go");
+        let dir = generated.parent().unwrap();
+        let gofmt = run(Command::new("gofmt").arg("-l").arg(&generated));
+        assert!(gofmt.stdout.is_empty(), "{:?} is not formatted", generated);
+
+        // The generated package only depends on wazero
+        fs::write(dir.join("go.mod"), "module generated\n\ngo 1.21\n").unwrap();
+        run(Command::new("go")
+            .args(["get", "github.com/tetratelabs/wazero"])
+            .current_dir(dir));
+        run(Command::new("go").args(["vet", "."]).current_dir(dir));
+    }
+}
+
+#[test]
+#[ignore]
+fn compile_c_host() {
+    // The directory with the `wasm_export.h` header of WAMR
+    let wamr_include = match std::env::var_os("WAMR_INCLUDE") {
+        Some(wamr_include) => wamr_include,
+        None => {
+            println!("[WAMR_INCLUDE] not set, skipping");
+            return;
+        }
+    };
+    if !is_available("cc", "--version") {
+        return;
+    }
+
+    for s in WITX_SOURCES {
+        let header = generate_file(s, OutputType::CHostHeader, "h");
+        let source = generate_file(s, OutputType::CHostSource, "c");
+
+        // The source includes the header of the module by name
+        let module_name = fs::read_to_string(&source)
+            .unwrap()
+            .lines()
+            .find_map(|line| {
+                line.strip_prefix("#include \"")
+                    .and_then(|line| line.strip_suffix("_host.h\""))
+                    .map(str::to_string)
+            })
+            .unwrap();
+        let dir = source.parent().unwrap();
+        fs::copy(&header, dir.join(format!("{}_host.h", module_name))).unwrap();
+        run(Command::new("cc")
+            .args(["-std=c11", "-Wall", "-Wextra", "-Werror", "-fsyntax-only"])
+            .arg("-I")
+            .arg(&wamr_include)
+            .arg(&source));
+    }
+}
@@ -0,0 +1,456 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/**
+ * Pointer to the data of an `ArrayBuffer` or of a typed array
+ */
+export function wasiDataPtr<B>(buf: B): usize {
+    if (buf instanceof ArrayBuffer) {
+        return changetype<usize>(buf);
+    } else {
+        // @ts-ignore: ArrayBufferView
+        return buf.dataStart;
+    }
+}
+
+/**
+ * The value returned by a function, or the error code it returned instead
+ */
+export class WasiResult<T, E> {
+    error: E;
+    value: T;
+
+    constructor(error: E, value: T) {
+        this.error = error;
+        this.value = value;
+    }
+
+    static ok<T, E>(value: T): WasiResult<T, E> {
+        return new WasiResult<T, E>(<E>0, value);
+    }
+
+    static err<T, E>(error: E): WasiResult<T, E> {
+        if (isReference<T>()) {
+            return new WasiResult<T, E>(error, changetype<T>(0));
+        } else {
+            return new WasiResult<T, E>(error, <T>0);
+        }
+    }
+
+    isOk(): bool {
+        return this.error == <E>0;
+    }
+
+    isErr(): bool {
+        return this.error != <E>0;
+    }
+
+    /**
+     * Return the value, or abort if the function returned an error
+     */
+    unwrap(): T {
+        if (this.isErr()) {
+            throw new Error("WASI error " + this.error.toString());
+        }
+        return this.value;
+    }
+}
+
+/*
+ * ---------------------- Module: [test_module] ----------------------
+ */
+
+/**
+ * An enumeration
+ */
+export type TestErrno = u8;
+
+export namespace TestErrno {
+    export const SUCCESS: TestErrno = 0;
+    export const GUEST_ERROR: TestErrno = 1;
+    export const SOME_OTHER_ERROR: TestErrno = 2;
+}
+
+/**
+ * A u32 alias
+ */
+export type TestMediumInt = u32;
+
+/**
+ * Flags
+ */
+export type TestFlags = u8;
+
+export namespace TestFlags {
+    export const A: TestFlags = 0x1;
+    export const B: TestFlags = 0x2;
+    export const C: TestFlags = 0x4;
+}
+
+
+/**
+ * A structure
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class TestStruct {
+    private __pad64_0: u64;
+    private __pad32_0: u32;
+
+    constructor(a_boolean: bool, a_byte: u8, a_string: WasiString) {
+        this.a_boolean = a_boolean;
+        this.a_byte = a_byte;
+        this.a_string = a_string;
+    }
+
+    /**
+     * The record stored at `ptr` in the guest memory, such as one written by the host
+     */
+    static at(ptr: usize): TestStruct {
+        return changetype<TestStruct>(ptr);
+    }
+
+    get a_boolean(): bool {
+        return load<bool>(changetype<usize>(this), 0);
+    }
+
+    set a_boolean(val: bool) {
+        store<bool>(changetype<usize>(this), val, 0);
+    }
+
+    get a_byte(): u8 {
+        return load<u8>(changetype<usize>(this), 1);
+    }
+
+    set a_byte(val: u8) {
+        store<u8>(changetype<usize>(this), val, 1);
+    }
+
+    get a_string(): WasiString {
+        return changetype<WasiString>(changetype<usize>(this) + 4);
+    }
+
+    set a_string(val: WasiString) {
+        memory.copy(changetype<usize>(this) + 4, changetype<usize>(val), 8);
+    }
+}
+
+
+/**
+ * A tagged union
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class TestTaggedUnion {
+    tag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+    private __pad64_0: u64;
+
+    constructor(tag: u8) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 8);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u8, val: T = 0): TestTaggedUnion {
+        let tu = new TestTaggedUnion(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 8);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- first_choice: u8 if tag=0
+
+    static firstChoice(val: u8): TestTaggedUnion {
+        return TestTaggedUnion.new(0, val);
+    }
+
+    setFirstChoice(val: u8): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isFirstChoice(): bool {
+        return this.tag === 0;
+    }
+
+    getFirstChoice(): u8 {
+        return this.get<u8>();
+    }
+
+    // --- second_choice: WasiString if tag=1
+
+    static secondChoice(val: WasiString): TestTaggedUnion {
+        return TestTaggedUnion.new(1, val);
+    }
+
+    setSecondChoice(val: WasiString): void {
+        this.tag = 1;
+        this.set(val);
+    }
+
+    isSecondChoice(): bool {
+        return this.tag === 1;
+    }
+
+    getSecondChoice(): WasiString {
+        return this.get<WasiString>();
+    }
+
+    // --- third_choice: f32 if tag=2
+
+    static thirdChoice(val: f32): TestTaggedUnion {
+        return TestTaggedUnion.new(2, val);
+    }
+
+    setThirdChoice(val: f32): void {
+        this.tag = 2;
+        this.set(val);
+    }
+
+    isThirdChoice(): bool {
+        return this.tag === 2;
+    }
+
+    getThirdChoice(): f32 {
+        return this.get<f32>();
+    }
+
+    // --- empty_choice: (no associated content) if tag=3
+
+    static emptyChoice(): TestTaggedUnion {
+        return TestTaggedUnion.new(3);
+    }
+
+    setEmptyChoice(): void {
+        this.tag = 3;
+    }
+
+    isEmptyChoice(): bool {
+        return this.tag === 3;
+    }
+}
+
+
+export type TestResource = WasiHandle;
+
+export type TestResourceReadResult = WasiMutSlice<u8>;
+
+export type OpenResult = WasiHandle;
+
+/**
+ * Read from the resource
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "test_resource_read")
+export declare function testResourceRead(
+    self: TestResource,
+    buf_len: u32,
+    result_ptr: WasiMutPtr<TestResourceReadResult>
+): TestErrno;
+
+/**
+ * This function returns multiple values
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_multiple_values")
+export declare function aFunctionThatReturnsMultipleValues(
+    some_parameter: u64,
+    result_0_ptr: WasiMutPtr<TestMediumInt>,
+    result_1_ptr: WasiMutPtr<TestStruct>
+): TestErrno;
+
+/**
+ * This function takes a record
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_takes_a_record")
+export declare function aFunctionThatTakesARecord(
+    a_struct: TestStruct
+): TestErrno;
+
+/**
+ * Open a resource
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "open")
+export declare function open(
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    result_ptr: WasiMutPtr<OpenResult>
+): TestErrno;
+
+/**
+ * Values returned by `TestModule.aFunctionThatReturnsMultipleValues()`
+ */
+export class AFunctionThatReturnsMultipleValuesResults {
+    v0: TestMediumInt;
+    v1: TestStruct;
+
+    constructor(v0: TestMediumInt, v1: TestStruct) {
+        this.v0 = v0;
+        this.v1 = v1;
+    }
+}
+
+/**
+ * High-level functions of the `test_module` module
+ */
+export class TestModule {
+    /**
+     * Read from the resource
+     *
+     * The returned records are allocated with `heap.alloc()`, and have to be released with `heap.free()` by the caller.
+     */
+    static testResourceRead(
+        self: TestResource,
+        buf_len: u32
+    ): WasiResult<TestResourceReadResult, TestErrno> {
+        const result = changetype<TestResourceReadResult>(heap.alloc(8));
+        const res = testResourceRead(self, buf_len, changetype<usize>(result));
+        if (res != 0) {
+            heap.free(changetype<usize>(result));
+            return WasiResult.err<TestResourceReadResult, TestErrno>(res);
+        }
+        return WasiResult.ok<TestResourceReadResult, TestErrno>(result);
+    }
+
+    /**
+     * This function returns multiple values
+     *
+     * The returned records are allocated with `heap.alloc()`, and have to be released with `heap.free()` by the caller.
+     */
+    static aFunctionThatReturnsMultipleValues(
+        some_parameter: u64
+    ): WasiResult<AFunctionThatReturnsMultipleValuesResults, TestErrno> {
+        const result0_buf = new ArrayBuffer(4);
+        const result1 = changetype<TestStruct>(heap.alloc(12));
+        const res = aFunctionThatReturnsMultipleValues(
+            some_parameter,
+            changetype<usize>(result0_buf),
+            changetype<usize>(result1)
+        );
+        if (res != 0) {
+            heap.free(changetype<usize>(result1));
+            return WasiResult.err<AFunctionThatReturnsMultipleValuesResults, TestErrno>(res);
+        }
+        const values = new AFunctionThatReturnsMultipleValuesResults(
+            load<TestMediumInt>(changetype<usize>(result0_buf)),
+            result1
+        );
+        return WasiResult.ok<AFunctionThatReturnsMultipleValuesResults, TestErrno>(values);
+    }
+
+    /**
+     * This function takes a record
+     */
+    static aFunctionThatTakesARecord(a_struct: TestStruct): TestErrno {
+        const res = aFunctionThatTakesARecord(a_struct);
+        return res;
+    }
+
+    /**
+     * Open a resource
+     */
+    static open(name: string): WasiResult<OpenResult, TestErrno> {
+        const name_buf = String.UTF8.encode(name, false);
+        const result_buf = new ArrayBuffer(4);
+        const res = open(
+            changetype<usize>(name_buf),
+            name_buf.byteLength,
+            changetype<usize>(result_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<OpenResult, TestErrno>(res);
+        }
+        return WasiResult.ok<OpenResult, TestErrno>(
+            load<OpenResult>(changetype<usize>(result_buf))
+        );
+    }
+}
+
@@ -0,0 +1,152 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <stdbool.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+/*
+ * Checks that `len` elements of `elem_size` bytes starting at `ptr` are in the guest memory,
+ * and translates `ptr` to a native pointer. Raises an exception in the guest otherwise.
+ */
+static inline bool
+wasi_guest_range(wasm_exec_env_t exec_env, uint32_t ptr, uint32_t len, uint32_t elem_size,
+                 void **native)
+{
+    wasm_module_inst_t inst = wasm_runtime_get_module_inst(exec_env);
+    uint64_t size = (uint64_t) len * elem_size;
+
+    if (size > UINT32_MAX || !wasm_runtime_validate_app_addr(inst, ptr, (uint32_t) size)) {
+        wasm_runtime_set_exception(inst, "out of bounds memory access");
+        return false;
+    }
+    *native = wasm_runtime_addr_app_to_native(inst, ptr);
+    return true;
+}
+
+/* ---------------------- Module: [test_module] ---------------------- */
+
+#include "test_module_host.h"
+
+static uint32_t
+test_module_test_resource_read_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t self,
+    uint32_t buf_len,
+    uint32_t result_ptr)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->test_resource_read == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.test_resource_read is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 8, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->test_resource_read(
+        exec_env,
+        (test_resource_t) self,
+        (uint32_t) buf_len,
+        (test_resource_read_result_t *) result_ptr_native);
+}
+
+static uint32_t
+test_module_a_function_that_returns_multiple_values_wrapper(
+    wasm_exec_env_t exec_env,
+    uint64_t some_parameter,
+    uint32_t result_0_ptr,
+    uint32_t result_1_ptr)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_0_ptr_native = NULL;
+    void *result_1_ptr_native = NULL;
+
+    if (host->a_function_that_returns_multiple_values == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.a_function_that_returns_multiple_values is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_0_ptr, 1, 4, &result_0_ptr_native) ||
+        !wasi_guest_range(exec_env, result_1_ptr, 1, 12, &result_1_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->a_function_that_returns_multiple_values(
+        exec_env,
+        (uint64_t) some_parameter,
+        (test_medium_int_t *) result_0_ptr_native,
+        (test_struct_t *) result_1_ptr_native);
+}
+
+static uint32_t
+test_module_a_function_that_takes_a_record_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t a_struct)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *a_struct_native = NULL;
+
+    if (host->a_function_that_takes_a_record == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.a_function_that_takes_a_record is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, a_struct, 1, 12, &a_struct_native)) {
+        return 0;
+    }
+    return (uint32_t) host->a_function_that_takes_a_record(
+        exec_env,
+        (const test_struct_t *) a_struct_native);
+}
+
+static uint32_t
+test_module_open_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t name_ptr,
+    uint32_t name_len,
+    uint32_t result_ptr)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *name_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->open == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.open is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, name_ptr, name_len, 1, &name_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->open(
+        exec_env,
+        (const char *) name_ptr_native,
+        name_len,
+        (open_result_t *) result_ptr_native);
+}
+
+static NativeSymbol test_module_native_symbols[] = {
+    { "test_resource_read", (void *) test_module_test_resource_read_wrapper, "(iii)i", NULL },
+    { "a_function_that_returns_multiple_values", (void *) test_module_a_function_that_returns_multiple_values_wrapper, "(Iii)i", NULL },
+    { "a_function_that_takes_a_record", (void *) test_module_a_function_that_takes_a_record_wrapper, "(i)i", NULL },
+    { "open", (void *) test_module_open_wrapper, "(iii)i", NULL },
+};
+
+bool
+test_module_register_natives(const test_module_host_t *host)
+{
+    const size_t n = sizeof test_module_native_symbols / sizeof test_module_native_symbols[0];
+    size_t i;
+
+    /* The callbacks are found through the attachments of the symbols */
+    for (i = 0; i < n; i++) {
+        test_module_native_symbols[i].attachment = (void *) host;
+    }
+    return wasm_runtime_register_natives("test_module", test_module_native_symbols, (uint32_t) n);
+}
+
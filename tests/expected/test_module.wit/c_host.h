@@ -0,0 +1,128 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+#ifndef WITX_CODEGEN_HOST_TYPES
+#define WITX_CODEGEN_HOST_TYPES
+
+typedef int32_t wasi_handle_t;
+typedef uint32_t wasi_size_t;
+
+/* A pointer in the guest memory */
+typedef uint32_t wasi_guest_ptr_t;
+
+/* A list stored in the guest memory */
+typedef struct wasi_guest_slice {
+    wasi_guest_ptr_t ptr;
+    wasi_size_t len;
+} wasi_guest_slice_t;
+
+#endif
+
+/* ---------------------- Module: [test_module] ---------------------- */
+
+/** An enumeration */
+typedef uint8_t test_errno_t;
+
+#define TEST_ERRNO_SUCCESS ((test_errno_t) 0)
+#define TEST_ERRNO_GUEST_ERROR ((test_errno_t) 1)
+#define TEST_ERRNO_SOME_OTHER_ERROR ((test_errno_t) 2)
+
+/** A u32 alias */
+typedef uint32_t test_medium_int_t;
+
+/** Flags */
+typedef uint8_t test_flags_t;
+
+#define TEST_FLAGS_A ((test_flags_t) 0x1)
+#define TEST_FLAGS_B ((test_flags_t) 0x2)
+#define TEST_FLAGS_C ((test_flags_t) 0x4)
+
+/** A structure */
+typedef struct __attribute__((packed)) test_struct {
+    bool a_boolean;
+    uint8_t a_byte;
+    uint8_t __pad0[2];
+    wasi_guest_slice_t a_string;
+} test_struct_t;
+
+_Static_assert(offsetof(test_struct_t, a_boolean) == 0, "Error layout");
+_Static_assert(offsetof(test_struct_t, a_byte) == 1, "Error layout");
+_Static_assert(offsetof(test_struct_t, a_string) == 4, "Error layout");
+_Static_assert(sizeof(test_struct_t) == 12, "Error layout");
+
+/** A tagged union */
+typedef struct __attribute__((packed)) test_tagged_union {
+    uint8_t tag;
+    uint8_t __pad0[3];
+    union __attribute__((packed)) {
+        uint8_t first_choice;
+        wasi_guest_slice_t second_choice;
+        float third_choice;
+        uint8_t __size[8];
+    } member;
+} test_tagged_union_t;
+
+_Static_assert(offsetof(test_tagged_union_t, member) == 4, "Error layout");
+_Static_assert(sizeof(test_tagged_union_t) == 12, "Error layout");
+
+#define TEST_TAGGED_UNION_FIRST_CHOICE 0
+#define TEST_TAGGED_UNION_SECOND_CHOICE 1
+#define TEST_TAGGED_UNION_THIRD_CHOICE 2
+#define TEST_TAGGED_UNION_EMPTY_CHOICE 3
+
+typedef wasi_handle_t test_resource_t;
+
+typedef wasi_guest_slice_t test_resource_read_result_t;
+
+typedef test_resource_t open_result_t;
+
+/**
+ * Host implementation of the [test_module] module
+ *
+ * Guest pointers are checked and translated to native pointers before the callbacks are
+ * called. Callbacks left to `NULL` raise an exception in the guest.
+ */
+typedef struct test_module_host {
+    /** Read from the resource */
+    test_errno_t (*test_resource_read)(
+        wasm_exec_env_t exec_env,
+        test_resource_t self,
+        uint32_t buf_len,
+        test_resource_read_result_t *result_ptr);
+
+    /** This function returns multiple values */
+    test_errno_t (*a_function_that_returns_multiple_values)(
+        wasm_exec_env_t exec_env,
+        uint64_t some_parameter,
+        test_medium_int_t *result_0_ptr,
+        test_struct_t *result_1_ptr);
+
+    /** This function takes a record */
+    test_errno_t (*a_function_that_takes_a_record)(
+        wasm_exec_env_t exec_env,
+        const test_struct_t *a_struct);
+
+    /** Open a resource */
+    test_errno_t (*open)(
+        wasm_exec_env_t exec_env,
+        const char *name_ptr,
+        uint32_t name_len,
+        open_result_t *result_ptr);
+} test_module_host_t;
+
+/**
+ * Registers the native symbols of the [test_module] module, calling the callbacks of `host`
+ *
+ * `host` must remain valid as long as the runtime is used.
+ */
+bool test_module_register_natives(const test_module_host_t *host);
+
@@ -0,0 +1,134 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <cstdint>
+#include <cstring>
+#include <tuple>
+#include <cstddef>
+#include <variant>
+
+// namespace WitxCodegenHeader {
+using WasiHandle = int32_t;
+template <typename T> using WasiPtr = T *const;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<unsigned char>;
+
+template <typename R, typename E> using Expected = std::variant<R, E>;
+
+using WasiStringBytesPtr = WasiPtr<unsigned char>;
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t length;
+};
+
+template<typename T>
+struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t length;
+};
+
+template<typename T>
+struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t length;
+};
+
+// }
+
+// ---------------------- Module: [test_module] ----------------------
+
+/**
+* An enumeration
+**/
+enum class TestErrno : uint8_t {
+    SUCCESS = 0,
+    GUEST_ERROR = 1,
+    SOME_OTHER_ERROR = 2,
+};
+
+/**
+* A u32 alias
+**/
+using TestMediumInt = uint32_t;
+
+/**
+* Flags
+**/
+using TestFlags = uint8_t;
+
+namespace TEST_FLAGS {
+    const TestFlags A = 0x1;
+    const TestFlags B = 0x2;
+    const TestFlags C = 0x4;
+}
+
+
+/**
+* A structure
+**/
+struct __attribute__((packed)) TestStruct {
+    bool a_boolean;
+    uint8_t a_byte;
+    uint16_t __pad16_0;
+    WasiString a_string;
+};
+
+static_assert(offsetof(TestStruct, a_boolean) == 0, "Error layout");
+static_assert(offsetof(TestStruct, a_byte) == 1, "Error layout");
+static_assert(offsetof(TestStruct, a_string) == 4, "Error layout");
+
+/**
+* A tagged union
+**/
+union TestTaggedUnionMember {
+    uint8_t first_choice; // if tag=0
+    WasiString second_choice; // if tag=1
+    float third_choice; // if tag=2
+    // empty_choice: (no associated content) if tag=3
+};
+
+struct __attribute__((packed)) TestTaggedUnion {
+    uint8_t tag;
+    uint8_t __pad8_0;
+    uint16_t __pad16_0;
+    TestTaggedUnionMember member;
+};
+
+
+enum class TestResource : WasiHandle {};
+
+using TestResourceReadResult = WasiMutSlice<uint8_t>;
+
+using OpenResult = WasiHandle;
+
+/**
+* Read from the resource
+**/
+Expected<TestResourceReadResult, TestErrno> test_resource_read(
+    TestResource self,
+    uint32_t buf_len
+);
+
+/**
+* This function returns multiple values
+**/
+Expected<std::tuple<TestMediumInt, TestStruct>, TestErrno> a_function_that_returns_multiple_values(
+    uint64_t some_parameter
+);
+
+/**
+* This function takes a record
+**/
+Expected<std::monostate, TestErrno> a_function_that_takes_a_record(
+    TestStruct a_struct
+);
+
+/**
+* Open a resource
+**/
+Expected<OpenResult, TestErrno> open(
+    WasiPtr<unsigned char> name_ptr,
+    size_t name_len
+);
+
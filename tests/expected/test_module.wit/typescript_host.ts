@@ -0,0 +1,305 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = number;
+export type WasiPtr = number;
+export type WasiSize = number;
+export type Char8 = number;
+export type Char32 = number;
+
+export interface WasiSlice {
+    ptr: WasiPtr;
+    len: WasiSize;
+}
+
+/**
+ * Thrown by a host implementation to return an error code to the guest
+ */
+export class WasiError<E = number> extends Error {
+    constructor(readonly errno: E) {
+        super(`WASI error ${errno}`);
+    }
+}
+
+/**
+ * Allocates `size` bytes aligned to `align` in the guest memory, and returns their address
+ */
+export type GuestAllocator = (size: number, align: number) => number;
+
+const textDecoder = new TextDecoder("utf-8", { fatal: true });
+const textEncoder = new TextEncoder();
+
+/**
+ * The memory of a guest, that must be bound to the instance once it has been created
+ */
+export class GuestMemory {
+    private memory: WebAssembly.Memory | null = null;
+
+    bind(instance: WebAssembly.Instance): void {
+        this.memory = instance.exports.memory as WebAssembly.Memory;
+    }
+
+    view(): DataView {
+        return new DataView(this.buffer());
+    }
+
+    bytes(ptr: number, len: number): Uint8Array {
+        return new Uint8Array(this.buffer(), ptr >>> 0, len >>> 0);
+    }
+
+    readString(ptr: number, len: number): string {
+        return textDecoder.decode(this.bytes(ptr, len));
+    }
+
+    /**
+     * Copies bytes to memory allocated in the guest, and stores their address and length at `ptr`
+     */
+    writeBytes(alloc: GuestAllocator, ptr: number, bytes: Uint8Array): void {
+        const dataPtr = alloc(bytes.length, 1) >>> 0;
+        this.bytes(dataPtr, bytes.length).set(bytes);
+        const view = this.view();
+        view.setUint32(ptr, dataPtr, true);
+        view.setUint32(ptr + 4, bytes.length, true);
+    }
+
+    writeString(alloc: GuestAllocator, ptr: number, value: string): void {
+        this.writeBytes(alloc, ptr, textEncoder.encode(value));
+    }
+
+    private buffer(): ArrayBuffer {
+        if (this.memory === null) {
+            throw new Error("The guest memory is not bound to an instance");
+        }
+        return this.memory.buffer;
+    }
+}
+
+/*
+ * ---------------------- Module: [test_module] ----------------------
+ */
+
+/**
+ * An enumeration
+ */
+export type TestErrno = number;
+
+export namespace TestErrno {
+    export const SUCCESS: TestErrno = 0;
+    export const GUEST_ERROR: TestErrno = 1;
+    export const SOME_OTHER_ERROR: TestErrno = 2;
+}
+
+/**
+ * A u32 alias
+ */
+export type TestMediumInt = number;
+
+/**
+ * Flags
+ */
+export type TestFlags = number;
+
+export namespace TestFlags {
+    export const A: TestFlags = 0x1;
+    export const B: TestFlags = 0x2;
+    export const C: TestFlags = 0x4;
+}
+
+/**
+ * A structure
+ */
+export interface TestStruct {
+    aBoolean: boolean;
+    aByte: number;
+    aString: string;
+}
+
+export namespace TestStruct {
+    export function read(mem: GuestMemory, ptr: WasiPtr): TestStruct {
+        const view = mem.view();
+        return {
+            aBoolean: view.getUint8(ptr) !== 0,
+            aByte: view.getUint8(ptr + 1),
+            aString: mem.readString(view.getUint32(ptr + 4, true), view.getUint32(ptr + 8, true)),
+        };
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: TestStruct, alloc: GuestAllocator): void {
+        const view = mem.view();
+        view.setUint8(ptr, value.aBoolean ? 1 : 0);
+        view.setUint8(ptr + 1, value.aByte);
+        mem.writeString(alloc, ptr + 4, value.aString);
+    }
+}
+
+/**
+ * A tagged union
+ */
+export type TestTaggedUnion =
+    | { tag: 0; firstChoice: number }
+    | { tag: 1; secondChoice: string }
+    | { tag: 2; thirdChoice: number }
+    | { tag: 3 };
+
+export namespace TestTaggedUnion {
+    export const FIRST_CHOICE = 0;
+    export const SECOND_CHOICE = 1;
+    export const THIRD_CHOICE = 2;
+    export const EMPTY_CHOICE = 3;
+
+    export function read(mem: GuestMemory, ptr: WasiPtr): TestTaggedUnion {
+        const view = mem.view();
+        const tag = view.getUint8(ptr);
+        switch (tag) {
+            case 0:
+                return { tag: 0, firstChoice: view.getUint8(ptr + 4) };
+            case 1:
+                return { tag: 1, secondChoice: mem.readString(view.getUint32(ptr + 4, true), view.getUint32(ptr + 8, true)) };
+            case 2:
+                return { tag: 2, thirdChoice: view.getFloat32(ptr + 4, true) };
+            case 3:
+                return { tag: 3 };
+            default:
+                throw new Error(`Invalid tag for TestTaggedUnion: ${tag}`);
+        }
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: TestTaggedUnion, alloc: GuestAllocator): void {
+        const view = mem.view();
+        view.setUint8(ptr, value.tag);
+        switch (value.tag) {
+            case 0:
+                view.setUint8(ptr + 4, value.firstChoice);
+                break;
+            case 1:
+                mem.writeString(alloc, ptr + 4, value.secondChoice);
+                break;
+            case 2:
+                view.setFloat32(ptr + 4, value.thirdChoice, true);
+                break;
+        }
+    }
+}
+
+export type TestResource = WasiHandle;
+
+export type TestResourceReadResult = Uint8Array;
+
+export type OpenResult = TestResource;
+
+/**
+ * Host implementation of the [test_module] module
+ *
+ * The functions receive the parameters decoded from the guest memory, and return
+ * the results. Errors are returned to the guest by throwing a `WasiError`.
+ */
+export interface TestModule {
+    /**
+     * Read from the resource
+     */
+    testResourceRead(self: TestResource, bufLen: number): TestResourceReadResult;
+
+    /**
+     * This function returns multiple values
+     */
+    aFunctionThatReturnsMultipleValues(someParameter: bigint): [TestMediumInt, TestStruct];
+
+    /**
+     * This function takes a record
+     */
+    aFunctionThatTakesARecord(aStruct: TestStruct): void;
+
+    /**
+     * Open a resource
+     */
+    open(name: string): OpenResult;
+}
+
+/**
+ * Returns the imports of the [test_module] module, calling the functions of `impl`
+ *
+ * `mem` must be bound to the instance once it has been created. `alloc` allocates the memory
+ * of the strings and byte arrays returned to the guest.
+ */
+export function testModuleImports(impl: TestModule, mem: GuestMemory, alloc: GuestAllocator): WebAssembly.Imports {
+    return {
+        "test_module": {
+            "test_resource_read": (
+                self: number,
+                bufLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.testResourceRead(
+                        self,
+                        bufLen >>> 0,
+                    );
+                    mem.writeBytes(alloc, resultPtr, result);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+            "a_function_that_returns_multiple_values": (
+                someParameter: bigint,
+                result0Ptr: number,
+                result1Ptr: number
+            ): number => {
+                try {
+                    const result = impl.aFunctionThatReturnsMultipleValues(
+                        BigInt.asUintN(64, someParameter),
+                    );
+                    const view = mem.view();
+                    view.setUint32(result0Ptr, result[0], true);
+                    TestStruct.write(mem, result1Ptr, result[1], alloc);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+            "a_function_that_takes_a_record": (
+                aStruct: number
+            ): number => {
+                try {
+                    impl.aFunctionThatTakesARecord(
+                        TestStruct.read(mem, aStruct),
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+            "open": (
+                namePtr: number,
+                nameLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.open(
+                        mem.readString(namePtr, nameLen),
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+        },
+    };
+}
+
@@ -0,0 +1,46 @@
+
+;;
+;; This file was automatically generated by witx-codegen - Do not edit manually.
+;;
+
+(module
+  ;; ---------------------- Module: [test_module] ----------------------
+
+  ;; Read from the resource
+  (import "test_module" "test_resource_read"
+    (func $test_module.test_resource_read
+      (param $self i32)
+      (param $buf_len i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; This function returns multiple values
+  (import "test_module" "a_function_that_returns_multiple_values"
+    (func $test_module.a_function_that_returns_multiple_values
+      (param $some_parameter i64)
+      (param $result0_ptr i32)
+      (param $result1_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; This function takes a record
+  (import "test_module" "a_function_that_takes_a_record"
+    (func $test_module.a_function_that_takes_a_record
+      (param $a_struct i32)
+      (result i32)
+    )
+  )
+
+  ;; Open a resource
+  (import "test_module" "open"
+    (func $test_module.open
+      (param $name_ptr i32)
+      (param $name_len i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+)
@@ -0,0 +1,208 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [test_module] ----------------------
+
+/// An enumeration
+pub const TestErrno = enum(u8) {
+    SUCCESS = 0,
+    GUEST_ERROR = 1,
+    SOME_OTHER_ERROR = 2,
+};
+
+/// A u32 alias
+pub const TestMediumInt = u32;
+
+/// Flags
+pub const TestFlags = u8;
+pub const TEST_FLAGS_A: TestFlags = 0x1;
+pub const TEST_FLAGS_B: TestFlags = 0x2;
+pub const TEST_FLAGS_C: TestFlags = 0x4;
+
+
+
+/// A structure
+pub const TestStruct = extern struct {
+    a_boolean: bool,
+    a_byte: u8,
+    __pad16_0: u16 = undefined,
+    a_string: WasiString,
+};
+
+
+/// A tagged union
+pub const TestTaggedUnion = extern struct {
+    tag: enum(u8) {
+        first_choice = 0,
+        second_choice = 1,
+        third_choice = 2,
+        empty_choice = 3,
+    },
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    member = extern union {
+        first_choice: u8,
+        second_choice: WasiString,
+        third_choice: f32,
+    },
+
+
+fn newFirstChoice(val: u8) TestTaggedUnion {
+    return TestTaggedUnion{ .tag = .first_choice, .member = .{ .first_choice = val } };
+}
+
+pub fn FirstChoice(self: TestTaggedUnion) u8 {
+    std.debug.assert(self.tag == .first_choice);
+    return self.member.first_choice;
+}
+
+pub fn setFirstChoice(self: *TestTaggedUnion, val: u8) void {
+    std.debug.assert(self.tag == .first_choice);
+    self.member.first_choice = val;
+}
+
+fn isFirstChoice(self: TestTaggedUnion) bool {
+    return self.tag == .first_choice;
+}
+
+
+fn newSecondChoice(val: WasiString) TestTaggedUnion {
+    return TestTaggedUnion{ .tag = .second_choice, .member = .{ .second_choice = val } };
+}
+
+pub fn SecondChoice(self: TestTaggedUnion) WasiString {
+    std.debug.assert(self.tag == .second_choice);
+    return self.member.second_choice;
+}
+
+pub fn setSecondChoice(self: *TestTaggedUnion, val: WasiString) void {
+    std.debug.assert(self.tag == .second_choice);
+    self.member.second_choice = val;
+}
+
+fn isSecondChoice(self: TestTaggedUnion) bool {
+    return self.tag == .second_choice;
+}
+
+
+fn newThirdChoice(val: f32) TestTaggedUnion {
+    return TestTaggedUnion{ .tag = .third_choice, .member = .{ .third_choice = val } };
+}
+
+pub fn ThirdChoice(self: TestTaggedUnion) f32 {
+    std.debug.assert(self.tag == .third_choice);
+    return self.member.third_choice;
+}
+
+pub fn setThirdChoice(self: *TestTaggedUnion, val: f32) void {
+    std.debug.assert(self.tag == .third_choice);
+    self.member.third_choice = val;
+}
+
+fn isThirdChoice(self: TestTaggedUnion) bool {
+    return self.tag == .third_choice;
+}
+
+
+fn newEmptyChoice() TestTaggedUnion {
+    return TestTaggedUnion { .tag = .empty_choice };
+}
+
+fn isEmptyChoice(self: TestTaggedUnion) bool {
+    return self.tag == .empty_choice;
+}
+
+};
+
+
+pub const TestResource = enum(WasiHandle) { _ };
+
+pub const TestResourceReadResult = WasiMutSlice(u8);
+
+pub const OpenResult = WasiHandle;
+
+pub const TestModule = struct {
+    /// Read from the resource
+    pub extern "test_module" fn test_resource_read(
+        self: TestResource,
+        buf_len: u32,
+        result_ptr: WasiMutPtr(TestResourceReadResult),
+    ) callconv(.C) TestErrno;
+
+    /// This function returns multiple values
+    pub extern "test_module" fn a_function_that_returns_multiple_values(
+        some_parameter: u64,
+        result_0_ptr: WasiMutPtr(TestMediumInt),
+        result_1_ptr: WasiMutPtr(TestStruct),
+    ) callconv(.C) TestErrno;
+
+    /// This function takes a record
+    pub extern "test_module" fn a_function_that_takes_a_record(
+        a_struct: TestStruct,
+    ) callconv(.C) TestErrno;
+
+    /// Open a resource
+    pub extern "test_module" fn open(
+        name_ptr: WasiPtr(Char8),
+        name_len: usize,
+        result_ptr: WasiMutPtr(OpenResult),
+    ) callconv(.C) TestErrno;
+
+};
+
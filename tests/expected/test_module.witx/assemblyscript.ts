@@ -0,0 +1,635 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/**
+ * Pointer to the data of an `ArrayBuffer` or of a typed array
+ */
+export function wasiDataPtr<B>(buf: B): usize {
+    if (buf instanceof ArrayBuffer) {
+        return changetype<usize>(buf);
+    } else {
+        // @ts-ignore: ArrayBufferView
+        return buf.dataStart;
+    }
+}
+
+/**
+ * The value returned by a function, or the error code it returned instead
+ */
+export class WasiResult<T, E> {
+    error: E;
+    value: T;
+
+    constructor(error: E, value: T) {
+        this.error = error;
+        this.value = value;
+    }
+
+    static ok<T, E>(value: T): WasiResult<T, E> {
+        return new WasiResult<T, E>(<E>0, value);
+    }
+
+    static err<T, E>(error: E): WasiResult<T, E> {
+        if (isReference<T>()) {
+            return new WasiResult<T, E>(error, changetype<T>(0));
+        } else {
+            return new WasiResult<T, E>(error, <T>0);
+        }
+    }
+
+    isOk(): bool {
+        return this.error == <E>0;
+    }
+
+    isErr(): bool {
+        return this.error != <E>0;
+    }
+
+    /**
+     * Return the value, or abort if the function returned an error
+     */
+    unwrap(): T {
+        if (this.isErr()) {
+            throw new Error("WASI error " + this.error.toString());
+        }
+        return this.value;
+    }
+}
+
+/*
+ * ---------------------- Module: [test_module] ----------------------
+ */
+
+/**
+ * An enumeration
+ */
+export type TestErrno = u16;
+
+export namespace TestErrno {
+    export const SUCCESS: TestErrno = 0;
+    export const GUEST_ERROR: TestErrno = 1;
+    export const SOME_OTHER_ERROR: TestErrno = 2;
+}
+
+/**
+ * A boolean alias
+ */
+export type TestBool = bool;
+
+/**
+ * A u32 alias
+ */
+export type TestMediumInt = u32;
+
+export namespace TestMediumInt {
+    export const ZERO: TestMediumInt = 0;
+    export const ONE: TestMediumInt = 1;
+    export const TWO: TestMediumInt = 2;
+    export const THREE: TestMediumInt = 3;
+}
+
+/**
+ * A u64 alias
+ */
+export type TestBigInt = u64;
+
+export namespace TestBigInt {
+    export const ZERO: TestBigInt = 0x0;
+    export const A_HUNDRED: TestBigInt = 0x64;
+    export const A_BIG_VALUE: TestBigInt = 0xff00000000000000;
+    export const A_BIGGER_VALUE: TestBigInt = 0xffffffffffffffff;
+}
+
+/**
+ * Flags
+ */
+export type TestBigFlags = u8;
+
+export namespace TestBigFlags {
+    export const A: TestBigFlags = 0x1;
+    export const B: TestBigFlags = 0x2;
+    export const C: TestBigFlags = 0x4;
+    export const D: TestBigFlags = 0x8;
+}
+
+
+/**
+ * A structure
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class TestStruct {
+    private __pad64_0: u64;
+    private __pad32_0: u32;
+
+    constructor(a_boolean: bool, a_byte: u8, a_string: WasiString) {
+        this.a_boolean = a_boolean;
+        this.a_byte = a_byte;
+        this.a_string = a_string;
+    }
+
+    /**
+     * The record stored at `ptr` in the guest memory, such as one written by the host
+     */
+    static at(ptr: usize): TestStruct {
+        return changetype<TestStruct>(ptr);
+    }
+
+    get a_boolean(): bool {
+        return load<bool>(changetype<usize>(this), 0);
+    }
+
+    set a_boolean(val: bool) {
+        store<bool>(changetype<usize>(this), val, 0);
+    }
+
+    get a_byte(): u8 {
+        return load<u8>(changetype<usize>(this), 1);
+    }
+
+    set a_byte(val: u8) {
+        store<u8>(changetype<usize>(this), val, 1);
+    }
+
+    get a_string(): WasiString {
+        return changetype<WasiString>(changetype<usize>(this) + 4);
+    }
+
+    set a_string(val: WasiString) {
+        memory.copy(changetype<usize>(this) + 4, changetype<usize>(val), 8);
+    }
+}
+
+
+/**
+ * A tuple
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class TestTuple { // -- Tuple
+    private __pad64_0: u64;
+    private __pad64_1: u64;
+
+    constructor(v0: TestBool, v1: TestMediumInt, v2: TestBigInt) {
+        this.v0 = v0;
+        this.v1 = v1;
+        this.v2 = v2;
+    }
+
+    /**
+     * The record stored at `ptr` in the guest memory, such as one written by the host
+     */
+    static at(ptr: usize): TestTuple {
+        return changetype<TestTuple>(ptr);
+    }
+
+    get v0(): TestBool {
+        return load<TestBool>(changetype<usize>(this), 0);
+    }
+
+    set v0(val: TestBool) {
+        store<TestBool>(changetype<usize>(this), val, 0);
+    }
+
+    get v1(): TestMediumInt {
+        return load<TestMediumInt>(changetype<usize>(this), 4);
+    }
+
+    set v1(val: TestMediumInt) {
+        store<TestMediumInt>(changetype<usize>(this), val, 4);
+    }
+
+    get v2(): TestBigInt {
+        return load<TestBigInt>(changetype<usize>(this), 8);
+    }
+
+    set v2(val: TestBigInt) {
+        store<TestBigInt>(changetype<usize>(this), val, 8);
+    }
+}
+
+
+/**
+ * A string
+ */
+export type TestString = WasiString;
+
+/**
+ * An output buffer
+ */
+export type TestOutputBuffer = WasiMutSlice<u16>;
+
+/**
+ * An input buffer
+ */
+export type TestInputBuffer = WasiSlice<u16>;
+
+/**
+ * A tagged union
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class TestTaggedUnion {
+    tag: u16;
+    private __pad16_0: u16;
+    private __pad64_0: u64;
+
+    constructor(tag: u16) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 8);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u16, val: T = 0): TestTaggedUnion {
+        let tu = new TestTaggedUnion(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 8);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- first_choice: u8 if tag=0
+
+    static firstChoice(val: u8): TestTaggedUnion {
+        return TestTaggedUnion.new(0, val);
+    }
+
+    setFirstChoice(val: u8): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isFirstChoice(): bool {
+        return this.tag === 0;
+    }
+
+    getFirstChoice(): u8 {
+        return this.get<u8>();
+    }
+
+    // --- second_choice: WasiString if tag=1
+
+    static secondChoice(val: WasiString): TestTaggedUnion {
+        return TestTaggedUnion.new(1, val);
+    }
+
+    setSecondChoice(val: WasiString): void {
+        this.tag = 1;
+        this.set(val);
+    }
+
+    isSecondChoice(): bool {
+        return this.tag === 1;
+    }
+
+    getSecondChoice(): WasiString {
+        return this.get<WasiString>();
+    }
+
+    // --- third_choice: f32 if tag=2
+
+    static thirdChoice(val: f32): TestTaggedUnion {
+        return TestTaggedUnion.new(2, val);
+    }
+
+    setThirdChoice(val: f32): void {
+        this.tag = 2;
+        this.set(val);
+    }
+
+    isThirdChoice(): bool {
+        return this.tag === 2;
+    }
+
+    getThirdChoice(): f32 {
+        return this.get<f32>();
+    }
+
+    // --- empty_choice: (no associated content) if tag=3
+
+    static emptyChoice(): TestTaggedUnion {
+        return TestTaggedUnion.new(3);
+    }
+
+    setEmptyChoice(): void {
+        this.tag = 3;
+    }
+
+    isEmptyChoice(): bool {
+        return this.tag === 3;
+    }
+}
+
+
+/**
+ * This function returns multiple values
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_multiple_values")
+export declare function aFunctionThatReturnsMultipleValues(
+    some_parameter: u64,
+    some_other_parameter_ptr: WasiPtr<Char8>,
+    some_other_parameter_len: usize,
+    result_0_ptr: WasiMutPtr<TestMediumInt>,
+    result_1_ptr: WasiMutPtr<TestBigInt>
+): TestErrno;
+
+/**
+ * This function returns an actual tuple (expanded into multiple values)
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_an_actual_tuple")
+export declare function aFunctionThatReturnsAnActualTuple(
+    some_parameter: u64,
+    result_0_ptr: WasiMutPtr<TestBool>,
+    result_1_ptr: WasiMutPtr<TestMediumInt>,
+    result_2_ptr: WasiMutPtr<TestBigInt>
+): TestErrno;
+
+/**
+ * This function returns nothing
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_nothing")
+export declare function aFunctionThatReturnsNothing(
+    some_parameter: u64
+): TestErrno;
+
+/**
+ * This function gets a string
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_gets_a_string")
+export declare function aFunctionThatGetsAString(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+    result_ptr: WasiMutPtr<TestBool>
+): TestErrno;
+
+/**
+ * This function return a tagged union
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_a_tagged_union")
+export declare function aFunctionThatReturnsATaggedUnion(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+    result_ptr: WasiMutPtr<TestTaggedUnion>
+): TestErrno;
+
+/**
+ * This function gets and returns a string
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_gets_and_returns_a_string")
+export declare function aFunctionThatGetsAndReturnsAString(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+    result_ptr: WasiMutPtr<TestString>
+): TestErrno;
+
+/**
+ * Values returned by `TestModule.aFunctionThatReturnsMultipleValues()`
+ */
+export class AFunctionThatReturnsMultipleValuesResults {
+    v0: TestMediumInt;
+    v1: TestBigInt;
+
+    constructor(v0: TestMediumInt, v1: TestBigInt) {
+        this.v0 = v0;
+        this.v1 = v1;
+    }
+}
+
+/**
+ * Values returned by `TestModule.aFunctionThatReturnsAnActualTuple()`
+ */
+export class AFunctionThatReturnsAnActualTupleResults {
+    v0: TestBool;
+    v1: TestMediumInt;
+    v2: TestBigInt;
+
+    constructor(v0: TestBool, v1: TestMediumInt, v2: TestBigInt) {
+        this.v0 = v0;
+        this.v1 = v1;
+        this.v2 = v2;
+    }
+}
+
+/**
+ * High-level functions of the `test_module` module
+ */
+export class TestModule {
+    /**
+     * This function returns multiple values
+     */
+    static aFunctionThatReturnsMultipleValues(
+        some_parameter: u64,
+        some_other_parameter: string
+    ): WasiResult<AFunctionThatReturnsMultipleValuesResults, TestErrno> {
+        const some_other_parameter_buf = String.UTF8.encode(some_other_parameter, false);
+        const result0_buf = new ArrayBuffer(4);
+        const result1_buf = new ArrayBuffer(8);
+        const res = aFunctionThatReturnsMultipleValues(
+            some_parameter,
+            changetype<usize>(some_other_parameter_buf),
+            some_other_parameter_buf.byteLength,
+            changetype<usize>(result0_buf),
+            changetype<usize>(result1_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<AFunctionThatReturnsMultipleValuesResults, TestErrno>(res);
+        }
+        const values = new AFunctionThatReturnsMultipleValuesResults(
+            load<TestMediumInt>(changetype<usize>(result0_buf)),
+            load<TestBigInt>(changetype<usize>(result1_buf))
+        );
+        return WasiResult.ok<AFunctionThatReturnsMultipleValuesResults, TestErrno>(values);
+    }
+
+    /**
+     * This function returns an actual tuple (expanded into multiple values)
+     */
+    static aFunctionThatReturnsAnActualTuple(
+        some_parameter: u64
+    ): WasiResult<AFunctionThatReturnsAnActualTupleResults, TestErrno> {
+        const result0_buf = new ArrayBuffer(1);
+        const result1_buf = new ArrayBuffer(4);
+        const result2_buf = new ArrayBuffer(8);
+        const res = aFunctionThatReturnsAnActualTuple(
+            some_parameter,
+            changetype<usize>(result0_buf),
+            changetype<usize>(result1_buf),
+            changetype<usize>(result2_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<AFunctionThatReturnsAnActualTupleResults, TestErrno>(res);
+        }
+        const values = new AFunctionThatReturnsAnActualTupleResults(
+            load<TestBool>(changetype<usize>(result0_buf)),
+            load<TestMediumInt>(changetype<usize>(result1_buf)),
+            load<TestBigInt>(changetype<usize>(result2_buf))
+        );
+        return WasiResult.ok<AFunctionThatReturnsAnActualTupleResults, TestErrno>(values);
+    }
+
+    /**
+     * This function returns nothing
+     */
+    static aFunctionThatReturnsNothing(some_parameter: u64): TestErrno {
+        const res = aFunctionThatReturnsNothing(some_parameter);
+        return res;
+    }
+
+    /**
+     * This function gets a string
+     */
+    static aFunctionThatGetsAString(
+        str: string
+    ): WasiResult<TestBool, TestErrno> {
+        const str_buf = String.UTF8.encode(str, false);
+        const result_buf = new ArrayBuffer(1);
+        const res = aFunctionThatGetsAString(
+            changetype<usize>(str_buf),
+            str_buf.byteLength,
+            changetype<usize>(result_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<TestBool, TestErrno>(res);
+        }
+        return WasiResult.ok<TestBool, TestErrno>(
+            load<TestBool>(changetype<usize>(result_buf))
+        );
+    }
+
+    /**
+     * This function return a tagged union
+     *
+     * The returned records are allocated with `heap.alloc()`, and have to be released with `heap.free()` by the caller.
+     */
+    static aFunctionThatReturnsATaggedUnion(
+        str: string
+    ): WasiResult<TestTaggedUnion, TestErrno> {
+        const str_buf = String.UTF8.encode(str, false);
+        const result = changetype<TestTaggedUnion>(heap.alloc(12));
+        const res = aFunctionThatReturnsATaggedUnion(
+            changetype<usize>(str_buf),
+            str_buf.byteLength,
+            changetype<usize>(result)
+        );
+        if (res != 0) {
+            heap.free(changetype<usize>(result));
+            return WasiResult.err<TestTaggedUnion, TestErrno>(res);
+        }
+        return WasiResult.ok<TestTaggedUnion, TestErrno>(result);
+    }
+
+    /**
+     * This function gets and returns a string
+     */
+    static aFunctionThatGetsAndReturnsAString(
+        str: string
+    ): WasiResult<string, TestErrno> {
+        const str_buf = String.UTF8.encode(str, false);
+        const result_buf = new ArrayBuffer(8);
+        const res = aFunctionThatGetsAndReturnsAString(
+            changetype<usize>(str_buf),
+            str_buf.byteLength,
+            changetype<usize>(result_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<string, TestErrno>(res);
+        }
+        return WasiResult.ok<string, TestErrno>(
+            changetype<WasiString>(changetype<usize>(result_buf)).toString()
+        );
+    }
+}
+
@@ -0,0 +1,216 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <stdbool.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+/*
+ * Checks that `len` elements of `elem_size` bytes starting at `ptr` are in the guest memory,
+ * and translates `ptr` to a native pointer. Raises an exception in the guest otherwise.
+ */
+static inline bool
+wasi_guest_range(wasm_exec_env_t exec_env, uint32_t ptr, uint32_t len, uint32_t elem_size,
+                 void **native)
+{
+    wasm_module_inst_t inst = wasm_runtime_get_module_inst(exec_env);
+    uint64_t size = (uint64_t) len * elem_size;
+
+    if (size > UINT32_MAX || !wasm_runtime_validate_app_addr(inst, ptr, (uint32_t) size)) {
+        wasm_runtime_set_exception(inst, "out of bounds memory access");
+        return false;
+    }
+    *native = wasm_runtime_addr_app_to_native(inst, ptr);
+    return true;
+}
+
+/* ---------------------- Module: [test_module] ---------------------- */
+
+#include "test_module_host.h"
+
+static uint32_t
+test_module_a_function_that_returns_multiple_values_wrapper(
+    wasm_exec_env_t exec_env,
+    uint64_t some_parameter,
+    uint32_t some_other_parameter_ptr,
+    uint32_t some_other_parameter_len,
+    uint32_t result_0_ptr,
+    uint32_t result_1_ptr)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *some_other_parameter_ptr_native = NULL;
+    void *result_0_ptr_native = NULL;
+    void *result_1_ptr_native = NULL;
+
+    if (host->a_function_that_returns_multiple_values == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.a_function_that_returns_multiple_values is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, some_other_parameter_ptr, some_other_parameter_len, 1, &some_other_parameter_ptr_native) ||
+        !wasi_guest_range(exec_env, result_0_ptr, 1, 4, &result_0_ptr_native) ||
+        !wasi_guest_range(exec_env, result_1_ptr, 1, 8, &result_1_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->a_function_that_returns_multiple_values(
+        exec_env,
+        (uint64_t) some_parameter,
+        (const char *) some_other_parameter_ptr_native,
+        some_other_parameter_len,
+        (test_medium_int_t *) result_0_ptr_native,
+        (test_big_int_t *) result_1_ptr_native);
+}
+
+static uint32_t
+test_module_a_function_that_returns_an_actual_tuple_wrapper(
+    wasm_exec_env_t exec_env,
+    uint64_t some_parameter,
+    uint32_t result_0_ptr,
+    uint32_t result_1_ptr,
+    uint32_t result_2_ptr)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_0_ptr_native = NULL;
+    void *result_1_ptr_native = NULL;
+    void *result_2_ptr_native = NULL;
+
+    if (host->a_function_that_returns_an_actual_tuple == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.a_function_that_returns_an_actual_tuple is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_0_ptr, 1, 1, &result_0_ptr_native) ||
+        !wasi_guest_range(exec_env, result_1_ptr, 1, 4, &result_1_ptr_native) ||
+        !wasi_guest_range(exec_env, result_2_ptr, 1, 8, &result_2_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->a_function_that_returns_an_actual_tuple(
+        exec_env,
+        (uint64_t) some_parameter,
+        (test_bool_t *) result_0_ptr_native,
+        (test_medium_int_t *) result_1_ptr_native,
+        (test_big_int_t *) result_2_ptr_native);
+}
+
+static uint32_t
+test_module_a_function_that_returns_nothing_wrapper(
+    wasm_exec_env_t exec_env,
+    uint64_t some_parameter)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->a_function_that_returns_nothing == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.a_function_that_returns_nothing is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->a_function_that_returns_nothing(
+        exec_env,
+        (uint64_t) some_parameter);
+}
+
+static uint32_t
+test_module_a_function_that_gets_a_string_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t str_ptr,
+    uint32_t str_len,
+    uint32_t result_ptr)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *str_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->a_function_that_gets_a_string == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.a_function_that_gets_a_string is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, str_ptr, str_len, 1, &str_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 1, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->a_function_that_gets_a_string(
+        exec_env,
+        (const char *) str_ptr_native,
+        str_len,
+        (test_bool_t *) result_ptr_native);
+}
+
+static uint32_t
+test_module_a_function_that_returns_a_tagged_union_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t str_ptr,
+    uint32_t str_len,
+    uint32_t result_ptr)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *str_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->a_function_that_returns_a_tagged_union == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.a_function_that_returns_a_tagged_union is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, str_ptr, str_len, 1, &str_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 12, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->a_function_that_returns_a_tagged_union(
+        exec_env,
+        (const char *) str_ptr_native,
+        str_len,
+        (test_tagged_union_t *) result_ptr_native);
+}
+
+static uint32_t
+test_module_a_function_that_gets_and_returns_a_string_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t str_ptr,
+    uint32_t str_len,
+    uint32_t result_ptr)
+{
+    const test_module_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *str_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->a_function_that_gets_and_returns_a_string == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "test_module.a_function_that_gets_and_returns_a_string is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, str_ptr, str_len, 1, &str_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 8, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->a_function_that_gets_and_returns_a_string(
+        exec_env,
+        (const char *) str_ptr_native,
+        str_len,
+        (test_string_t *) result_ptr_native);
+}
+
+static NativeSymbol test_module_native_symbols[] = {
+    { "a_function_that_returns_multiple_values", (void *) test_module_a_function_that_returns_multiple_values_wrapper, "(Iiiii)i", NULL },
+    { "a_function_that_returns_an_actual_tuple", (void *) test_module_a_function_that_returns_an_actual_tuple_wrapper, "(Iiii)i", NULL },
+    { "a_function_that_returns_nothing", (void *) test_module_a_function_that_returns_nothing_wrapper, "(I)i", NULL },
+    { "a_function_that_gets_a_string", (void *) test_module_a_function_that_gets_a_string_wrapper, "(iii)i", NULL },
+    { "a_function_that_returns_a_tagged_union", (void *) test_module_a_function_that_returns_a_tagged_union_wrapper, "(iii)i", NULL },
+    { "a_function_that_gets_and_returns_a_string", (void *) test_module_a_function_that_gets_and_returns_a_string_wrapper, "(iii)i", NULL },
+};
+
+bool
+test_module_register_natives(const test_module_host_t *host)
+{
+    const size_t n = sizeof test_module_native_symbols / sizeof test_module_native_symbols[0];
+    size_t i;
+
+    /* The callbacks are found through the attachments of the symbols */
+    for (i = 0; i < n; i++) {
+        test_module_native_symbols[i].attachment = (void *) host;
+    }
+    return wasm_runtime_register_natives("test_module", test_module_native_symbols, (uint32_t) n);
+}
+
@@ -0,0 +1,178 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+#ifndef WITX_CODEGEN_HOST_TYPES
+#define WITX_CODEGEN_HOST_TYPES
+
+typedef int32_t wasi_handle_t;
+typedef uint32_t wasi_size_t;
+
+/* A pointer in the guest memory */
+typedef uint32_t wasi_guest_ptr_t;
+
+/* A list stored in the guest memory */
+typedef struct wasi_guest_slice {
+    wasi_guest_ptr_t ptr;
+    wasi_size_t len;
+} wasi_guest_slice_t;
+
+#endif
+
+/* ---------------------- Module: [test_module] ---------------------- */
+
+/** An enumeration */
+typedef uint16_t test_errno_t;
+
+#define TEST_ERRNO_SUCCESS ((test_errno_t) 0)
+#define TEST_ERRNO_GUEST_ERROR ((test_errno_t) 1)
+#define TEST_ERRNO_SOME_OTHER_ERROR ((test_errno_t) 2)
+
+/** A boolean alias */
+typedef bool test_bool_t;
+
+/** A u32 alias */
+typedef uint32_t test_medium_int_t;
+
+#define TEST_MEDIUM_INT_ZERO ((test_medium_int_t) 0)
+#define TEST_MEDIUM_INT_ONE ((test_medium_int_t) 1)
+#define TEST_MEDIUM_INT_TWO ((test_medium_int_t) 2)
+#define TEST_MEDIUM_INT_THREE ((test_medium_int_t) 3)
+
+/** A u64 alias */
+typedef uint64_t test_big_int_t;
+
+#define TEST_BIG_INT_ZERO ((test_big_int_t) 0x0)
+#define TEST_BIG_INT_A_HUNDRED ((test_big_int_t) 0x64)
+#define TEST_BIG_INT_A_BIG_VALUE ((test_big_int_t) 0xff00000000000000ULL)
+#define TEST_BIG_INT_A_BIGGER_VALUE ((test_big_int_t) 0xffffffffffffffffULL)
+
+/** Flags */
+typedef uint8_t test_big_flags_t;
+
+#define TEST_BIG_FLAGS_A ((test_big_flags_t) 0x1)
+#define TEST_BIG_FLAGS_B ((test_big_flags_t) 0x2)
+#define TEST_BIG_FLAGS_C ((test_big_flags_t) 0x4)
+#define TEST_BIG_FLAGS_D ((test_big_flags_t) 0x8)
+
+/** A structure */
+typedef struct __attribute__((packed)) test_struct {
+    bool a_boolean;
+    uint8_t a_byte;
+    uint8_t __pad0[2];
+    wasi_guest_slice_t a_string;
+} test_struct_t;
+
+_Static_assert(offsetof(test_struct_t, a_boolean) == 0, "Error layout");
+_Static_assert(offsetof(test_struct_t, a_byte) == 1, "Error layout");
+_Static_assert(offsetof(test_struct_t, a_string) == 4, "Error layout");
+_Static_assert(sizeof(test_struct_t) == 12, "Error layout");
+
+/** A tuple */
+typedef struct __attribute__((packed)) test_tuple {
+    test_bool_t v0;
+    uint8_t __pad0[3];
+    test_medium_int_t v1;
+    test_big_int_t v2;
+} test_tuple_t;
+
+_Static_assert(offsetof(test_tuple_t, v0) == 0, "Error layout");
+_Static_assert(offsetof(test_tuple_t, v1) == 4, "Error layout");
+_Static_assert(offsetof(test_tuple_t, v2) == 8, "Error layout");
+_Static_assert(sizeof(test_tuple_t) == 16, "Error layout");
+
+/** A string */
+typedef wasi_guest_slice_t test_string_t;
+
+/** An output buffer */
+typedef wasi_guest_slice_t test_output_buffer_t;
+
+/** An input buffer */
+typedef wasi_guest_slice_t test_input_buffer_t;
+
+/** A tagged union */
+typedef struct __attribute__((packed)) test_tagged_union {
+    uint16_t tag;
+    uint8_t __pad0[2];
+    union __attribute__((packed)) {
+        uint8_t first_choice;
+        wasi_guest_slice_t second_choice;
+        float third_choice;
+        uint8_t __size[8];
+    } member;
+} test_tagged_union_t;
+
+_Static_assert(offsetof(test_tagged_union_t, member) == 4, "Error layout");
+_Static_assert(sizeof(test_tagged_union_t) == 12, "Error layout");
+
+#define TEST_TAGGED_UNION_FIRST_CHOICE 0
+#define TEST_TAGGED_UNION_SECOND_CHOICE 1
+#define TEST_TAGGED_UNION_THIRD_CHOICE 2
+#define TEST_TAGGED_UNION_EMPTY_CHOICE 3
+
+/**
+ * Host implementation of the [test_module] module
+ *
+ * Guest pointers are checked and translated to native pointers before the callbacks are
+ * called. Callbacks left to `NULL` raise an exception in the guest.
+ */
+typedef struct test_module_host {
+    /** This function returns multiple values */
+    test_errno_t (*a_function_that_returns_multiple_values)(
+        wasm_exec_env_t exec_env,
+        uint64_t some_parameter,
+        const char *some_other_parameter_ptr,
+        uint32_t some_other_parameter_len,
+        test_medium_int_t *result_0_ptr,
+        test_big_int_t *result_1_ptr);
+
+    /** This function returns an actual tuple (expanded into multiple values) */
+    test_errno_t (*a_function_that_returns_an_actual_tuple)(
+        wasm_exec_env_t exec_env,
+        uint64_t some_parameter,
+        test_bool_t *result_0_ptr,
+        test_medium_int_t *result_1_ptr,
+        test_big_int_t *result_2_ptr);
+
+    /** This function returns nothing */
+    test_errno_t (*a_function_that_returns_nothing)(
+        wasm_exec_env_t exec_env,
+        uint64_t some_parameter);
+
+    /** This function gets a string */
+    test_errno_t (*a_function_that_gets_a_string)(
+        wasm_exec_env_t exec_env,
+        const char *str_ptr,
+        uint32_t str_len,
+        test_bool_t *result_ptr);
+
+    /** This function return a tagged union */
+    test_errno_t (*a_function_that_returns_a_tagged_union)(
+        wasm_exec_env_t exec_env,
+        const char *str_ptr,
+        uint32_t str_len,
+        test_tagged_union_t *result_ptr);
+
+    /** This function gets and returns a string */
+    test_errno_t (*a_function_that_gets_and_returns_a_string)(
+        wasm_exec_env_t exec_env,
+        const char *str_ptr,
+        uint32_t str_len,
+        test_string_t *result_ptr);
+} test_module_host_t;
+
+/**
+ * Registers the native symbols of the [test_module] module, calling the callbacks of `host`
+ *
+ * `host` must remain valid as long as the runtime is used.
+ */
+bool test_module_register_natives(const test_module_host_t *host);
+
@@ -0,0 +1,196 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <cstdint>
+#include <cstring>
+#include <tuple>
+#include <cstddef>
+#include <variant>
+
+// namespace WitxCodegenHeader {
+using WasiHandle = int32_t;
+template <typename T> using WasiPtr = T *const;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<unsigned char>;
+
+template <typename R, typename E> using Expected = std::variant<R, E>;
+
+using WasiStringBytesPtr = WasiPtr<unsigned char>;
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t length;
+};
+
+template<typename T>
+struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t length;
+};
+
+template<typename T>
+struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t length;
+};
+
+// }
+
+// ---------------------- Module: [test_module] ----------------------
+
+/**
+* An enumeration
+**/
+enum class TestErrno : uint16_t {
+    SUCCESS = 0,
+    GUEST_ERROR = 1,
+    SOME_OTHER_ERROR = 2,
+};
+
+/**
+* A boolean alias
+**/
+using TestBool = bool;
+
+/**
+* A u32 alias
+**/
+using TestMediumInt = uint32_t;
+
+namespace TEST_MEDIUM_INT {
+    const TestMediumInt ZERO = 0;
+    const TestMediumInt ONE = 1;
+    const TestMediumInt TWO = 2;
+    const TestMediumInt THREE = 3;
+}
+
+/**
+* A u64 alias
+**/
+using TestBigInt = uint64_t;
+
+namespace TEST_BIG_INT {
+    const TestBigInt ZERO = 0x0;
+    const TestBigInt A_HUNDRED = 0x64;
+    const TestBigInt A_BIG_VALUE = 0xff00000000000000;
+    const TestBigInt A_BIGGER_VALUE = 0xffffffffffffffff;
+}
+
+/**
+* Flags
+**/
+using TestBigFlags = uint8_t;
+
+namespace TEST_BIG_FLAGS {
+    const TestBigFlags A = 0x1;
+    const TestBigFlags B = 0x2;
+    const TestBigFlags C = 0x4;
+    const TestBigFlags D = 0x8;
+}
+
+
+/**
+* A structure
+**/
+struct __attribute__((packed)) TestStruct {
+    bool a_boolean;
+    uint8_t a_byte;
+    uint16_t __pad16_0;
+    WasiString a_string;
+};
+
+static_assert(offsetof(TestStruct, a_boolean) == 0, "Error layout");
+static_assert(offsetof(TestStruct, a_byte) == 1, "Error layout");
+static_assert(offsetof(TestStruct, a_string) == 4, "Error layout");
+
+/**
+* A tuple
+**/
+struct TestTuple { // -- Tuple
+    TestBool v0;
+    uint8_t __pad8_0;
+    uint16_t __pad16_0;
+    TestMediumInt v1;
+    TestBigInt v2;
+};
+
+
+/**
+* A string
+**/
+using TestString = WasiString;
+
+/**
+* An output buffer
+**/
+using TestOutputBuffer = WasiMutSlice<uint16_t>;
+
+/**
+* An input buffer
+**/
+using TestInputBuffer = WasiSlice<uint16_t>;
+
+/**
+* A tagged union
+**/
+union TestTaggedUnionMember {
+    uint8_t first_choice; // if tag=0
+    WasiString second_choice; // if tag=1
+    float third_choice; // if tag=2
+    // empty_choice: (no associated content) if tag=3
+};
+
+struct __attribute__((packed)) TestTaggedUnion {
+    uint16_t tag;
+    uint16_t __pad16_0;
+    TestTaggedUnionMember member;
+};
+
+
+/**
+* This function returns multiple values
+**/
+Expected<std::tuple<TestMediumInt, TestBigInt>, TestErrno> a_function_that_returns_multiple_values(
+    uint64_t some_parameter,
+    WasiPtr<unsigned char> some_other_parameter_ptr,
+    size_t some_other_parameter_len
+);
+
+/**
+* This function returns an actual tuple (expanded into multiple values)
+**/
+Expected<std::tuple<TestBool, TestMediumInt, TestBigInt>, TestErrno> a_function_that_returns_an_actual_tuple(
+    uint64_t some_parameter
+);
+
+/**
+* This function returns nothing
+**/
+Expected<std::monostate, TestErrno> a_function_that_returns_nothing(
+    uint64_t some_parameter
+);
+
+/**
+* This function gets a string
+**/
+Expected<TestBool, TestErrno> a_function_that_gets_a_string(
+    WasiPtr<unsigned char> str_ptr,
+    size_t str_len
+);
+
+/**
+* This function return a tagged union
+**/
+Expected<TestTaggedUnion, TestErrno> a_function_that_returns_a_tagged_union(
+    WasiPtr<unsigned char> str_ptr,
+    size_t str_len
+);
+
+/**
+* This function gets and returns a string
+**/
+Expected<TestString, TestErrno> a_function_that_gets_and_returns_a_string(
+    WasiPtr<unsigned char> str_ptr,
+    size_t str_len
+);
+
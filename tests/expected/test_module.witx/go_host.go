@@ -0,0 +1,428 @@
+// Code generated by witx-codegen. DO NOT EDIT.
+
+package testmodule
+
+import (
+	"context"
+	"errors"
+	"fmt"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiSlice is a list of values in the guest memory
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiSize
+}
+
+// WasiError is returned by a host implementation to return an error code to the guest
+type WasiError struct {
+	Errno uint32
+}
+
+func (e *WasiError) Error() string {
+	return fmt.Sprintf("WASI error %d", e.Errno)
+}
+
+// errno returns the error code of a WasiError, and panics on other errors, so that the
+// guest traps
+func errno(err error) uint64 {
+	var wasiErr *WasiError
+	if errors.As(err, &wasiErr) {
+		return api.EncodeU32(wasiErr.Errno)
+	}
+	panic(err)
+}
+
+func boolToU8(v bool) uint8 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// errOutOfBounds is raised when the guest passes a pointer outside of its memory
+var errOutOfBounds = errors.New("out of bounds memory access")
+
+// GuestAllocator allocates size bytes aligned to align in the memory of the guest module,
+// and returns their address
+type GuestAllocator func(ctx context.Context, mod api.Module, size, align uint32) (uint32, error)
+
+// guestMemory accesses the memory of the guest, and panics on invalid pointers
+type guestMemory struct {
+	api.Memory
+	// alloc is only set in functions returning strings or byte slices
+	alloc func(size, align uint32) uint32
+}
+
+// allocatingMemory returns a guestMemory that can allocate memory in the guest, and
+// panics if the allocation fails
+func allocatingMemory(ctx context.Context, mod api.Module, alloc GuestAllocator) guestMemory {
+	return guestMemory{mod.Memory(), func(size, align uint32) uint32 {
+		ptr, err := alloc(ctx, mod, size, align)
+		if err != nil {
+			panic(err)
+		}
+		return ptr
+	}}
+}
+
+func (m guestMemory) check(ok bool) {
+	if !ok {
+		panic(errOutOfBounds)
+	}
+}
+
+func (m guestMemory) u8(ptr uint32) uint8 {
+	v, ok := m.ReadByte(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU8(ptr uint32, v uint8) {
+	m.check(m.WriteByte(ptr, v))
+}
+
+func (m guestMemory) u16(ptr uint32) uint16 {
+	v, ok := m.ReadUint16Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU16(ptr uint32, v uint16) {
+	m.check(m.WriteUint16Le(ptr, v))
+}
+
+func (m guestMemory) u32(ptr uint32) uint32 {
+	v, ok := m.ReadUint32Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU32(ptr uint32, v uint32) {
+	m.check(m.WriteUint32Le(ptr, v))
+}
+
+func (m guestMemory) u64(ptr uint32) uint64 {
+	v, ok := m.ReadUint64Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU64(ptr uint32, v uint64) {
+	m.check(m.WriteUint64Le(ptr, v))
+}
+
+func (m guestMemory) f32(ptr uint32) float32 {
+	v, ok := m.ReadFloat32Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putF32(ptr uint32, v float32) {
+	m.check(m.WriteFloat32Le(ptr, v))
+}
+
+func (m guestMemory) f64(ptr uint32) float64 {
+	v, ok := m.ReadFloat64Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putF64(ptr uint32, v float64) {
+	m.check(m.WriteFloat64Le(ptr, v))
+}
+
+// bytes returns a view of the guest memory
+func (m guestMemory) bytes(ptr, len uint32) []byte {
+	b, ok := m.Read(ptr, len)
+	m.check(ok)
+	return b
+}
+
+func (m guestMemory) copyBytes(ptr, len uint32) []byte {
+	return append([]byte(nil), m.bytes(ptr, len)...)
+}
+
+func (m guestMemory) string(ptr, len uint32) string {
+	return string(m.bytes(ptr, len))
+}
+
+// putBytes copies data to memory allocated in the guest, and stores its address and
+// length at ptr
+func (m guestMemory) putBytes(ptr uint32, data []byte) {
+	dataPtr := m.alloc(uint32(len(data)), 1)
+	m.check(m.Write(dataPtr, data))
+	m.putU32(ptr, dataPtr)
+	m.putU32(ptr+4, uint32(len(data)))
+}
+
+// ---------------------- Module: [test_module] ----------------------
+
+// An enumeration
+type TestErrno uint16
+
+const (
+	TestErrnoSuccess        TestErrno = 0
+	TestErrnoGuestError     TestErrno = 1
+	TestErrnoSomeOtherError TestErrno = 2
+)
+
+// A boolean alias
+type TestBool = bool
+
+// A u32 alias
+type TestMediumInt = uint32
+
+const (
+	TestMediumIntZero  TestMediumInt = 0
+	TestMediumIntOne   TestMediumInt = 1
+	TestMediumIntTwo   TestMediumInt = 2
+	TestMediumIntThree TestMediumInt = 3
+)
+
+// A u64 alias
+type TestBigInt = uint64
+
+const (
+	TestBigIntZero         TestBigInt = 0x0
+	TestBigIntAHundred     TestBigInt = 0x64
+	TestBigIntABigValue    TestBigInt = 0xff00000000000000
+	TestBigIntABiggerValue TestBigInt = 0xffffffffffffffff
+)
+
+// Flags
+type TestBigFlags uint8
+
+const (
+	TestBigFlagsA TestBigFlags = 0x1
+	TestBigFlagsB TestBigFlags = 0x2
+	TestBigFlagsC TestBigFlags = 0x4
+	TestBigFlagsD TestBigFlags = 0x8
+)
+
+// A structure
+type TestStruct struct {
+	ABoolean bool
+	AByte    uint8
+	AString  string
+}
+
+func readTestStruct(m guestMemory, ptr uint32) TestStruct {
+	return TestStruct{
+		ABoolean: m.u8(ptr) != 0,
+		AByte:    uint8(m.u8(ptr+1)),
+		AString:  m.string(m.u32(ptr+4), m.u32(ptr+8)),
+	}
+}
+
+func writeTestStruct(m guestMemory, ptr uint32, v TestStruct) {
+	m.putU8(ptr, boolToU8(v.ABoolean))
+	m.putU8(ptr+1, uint8(v.AByte))
+	m.putBytes(ptr+4, []byte(v.AString))
+}
+
+// A tuple
+type TestTuple struct {
+	V0 TestBool
+	V1 TestMediumInt
+	V2 TestBigInt
+}
+
+func readTestTuple(m guestMemory, ptr uint32) TestTuple {
+	return TestTuple{
+		V0: m.u8(ptr) != 0,
+		V1: TestMediumInt(m.u32(ptr+4)),
+		V2: TestBigInt(m.u64(ptr+8)),
+	}
+}
+
+func writeTestTuple(m guestMemory, ptr uint32, v TestTuple) {
+	m.putU8(ptr, boolToU8(v.V0))
+	m.putU32(ptr+4, uint32(v.V1))
+	m.putU64(ptr+8, uint64(v.V2))
+}
+
+// A string
+type TestString = string
+
+// An output buffer
+type TestOutputBuffer = WasiSlice
+
+// An input buffer
+type TestInputBuffer = WasiSlice
+
+// A tagged union
+type TestTaggedUnion struct {
+	Tag          uint16
+	FirstChoice  uint8
+	SecondChoice string
+	ThirdChoice  float32
+}
+
+const (
+	TestTaggedUnionFirstChoice  = 0
+	TestTaggedUnionSecondChoice = 1
+	TestTaggedUnionThirdChoice  = 2
+	TestTaggedUnionEmptyChoice  = 3
+)
+
+func readTestTaggedUnion(m guestMemory, ptr uint32) TestTaggedUnion {
+	v := TestTaggedUnion{Tag: uint16(m.u16(ptr))}
+	switch v.Tag {
+	case TestTaggedUnionFirstChoice:
+		v.FirstChoice = uint8(m.u8(ptr+4))
+	case TestTaggedUnionSecondChoice:
+		v.SecondChoice = m.string(m.u32(ptr+4), m.u32(ptr+8))
+	case TestTaggedUnionThirdChoice:
+		v.ThirdChoice = float32(m.f32(ptr+4))
+	case TestTaggedUnionEmptyChoice:
+	default:
+		panic(fmt.Sprintf("invalid tag for TestTaggedUnion: %d", v.Tag))
+	}
+	return v
+}
+
+func writeTestTaggedUnion(m guestMemory, ptr uint32, v TestTaggedUnion) {
+	m.putU16(ptr, uint16(v.Tag))
+	switch v.Tag {
+	case TestTaggedUnionFirstChoice:
+		m.putU8(ptr+4, uint8(v.FirstChoice))
+	case TestTaggedUnionSecondChoice:
+		m.putBytes(ptr+4, []byte(v.SecondChoice))
+	case TestTaggedUnionThirdChoice:
+		m.putF32(ptr+4, float32(v.ThirdChoice))
+	}
+}
+
+// TestModule is the host implementation of the [test_module] module
+//
+// The methods receive the parameters decoded from the guest memory, and return the
+// results. Errors are returned to the guest by returning a *WasiError.
+type TestModule interface {
+	// This function returns multiple values
+	AFunctionThatReturnsMultipleValues(ctx context.Context, someParameter uint64, someOtherParameter string) (TestMediumInt, TestBigInt, error)
+
+	// This function returns an actual tuple (expanded into multiple values)
+	AFunctionThatReturnsAnActualTuple(ctx context.Context, someParameter uint64) (TestBool, TestMediumInt, TestBigInt, error)
+
+	// This function returns nothing
+	AFunctionThatReturnsNothing(ctx context.Context, someParameter uint64) error
+
+	// This function gets a string
+	AFunctionThatGetsAString(ctx context.Context, str string) (TestBool, error)
+
+	// This function return a tagged union
+	AFunctionThatReturnsATaggedUnion(ctx context.Context, str TestString) (TestTaggedUnion, error)
+
+	// This function gets and returns a string
+	AFunctionThatGetsAndReturnsAString(ctx context.Context, str TestString) (TestString, error)
+}
+
+// Instantiate adds the [test_module] module to the runtime, calling the methods of impl
+//
+// alloc allocates the memory of the strings and byte slices returned to the guest.
+func Instantiate(ctx context.Context, r wazero.Runtime, impl TestModule, alloc GuestAllocator) (api.Module, error) {
+	b := r.NewHostModuleBuilder("test_module")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v0, v1, err := impl.AFunctionThatReturnsMultipleValues(
+				ctx,
+				stack[0],
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[3]), uint32(v0))
+			m.putU64(api.DecodeU32(stack[4]), uint64(v1))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI64, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("some_parameter", "some_other_parameter_ptr", "some_other_parameter_len", "result0_ptr", "result1_ptr").
+		Export("a_function_that_returns_multiple_values")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v0, v1, v2, err := impl.AFunctionThatReturnsAnActualTuple(ctx, stack[0])
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU8(api.DecodeU32(stack[1]), boolToU8(v0))
+			m.putU32(api.DecodeU32(stack[2]), uint32(v1))
+			m.putU64(api.DecodeU32(stack[3]), uint64(v2))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI64, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("some_parameter", "result0_ptr", "result1_ptr", "result2_ptr").
+		Export("a_function_that_returns_an_actual_tuple")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.AFunctionThatReturnsNothing(ctx, stack[0])
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI64}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("some_parameter").
+		Export("a_function_that_returns_nothing")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.AFunctionThatGetsAString(
+				ctx,
+				m.string(api.DecodeU32(stack[0]), api.DecodeU32(stack[1])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU8(api.DecodeU32(stack[2]), boolToU8(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("str_ptr", "str_len", "result_ptr").
+		Export("a_function_that_gets_a_string")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := allocatingMemory(ctx, mod, alloc)
+			v, err := impl.AFunctionThatReturnsATaggedUnion(
+				ctx,
+				m.string(api.DecodeU32(stack[0]), api.DecodeU32(stack[1])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			writeTestTaggedUnion(m, api.DecodeU32(stack[2]), v)
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("str_ptr", "str_len", "result_ptr").
+		Export("a_function_that_returns_a_tagged_union")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := allocatingMemory(ctx, mod, alloc)
+			v, err := impl.AFunctionThatGetsAndReturnsAString(
+				ctx,
+				m.string(api.DecodeU32(stack[0]), api.DecodeU32(stack[1])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putBytes(api.DecodeU32(stack[2]), []byte(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("str_ptr", "str_len", "result_ptr").
+		Export("a_function_that_gets_and_returns_a_string")
+	return b.Instantiate(ctx)
+}
@@ -0,0 +1,404 @@
+
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+import ctypes
+import enum
+
+import wasmtime
+
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host implementation to return an error code to the guest"""
+
+    def __init__(self, errno):
+        super().__init__(errno)
+        self.errno = errno
+
+
+def read_bytes(caller, ptr, length):
+    """Copies length bytes from the guest memory"""
+    ptr &= 0xFFFFFFFF
+    return bytes(caller["memory"].read(caller, ptr, ptr + length))
+
+
+def write_bytes(caller, ptr, data):
+    """Copies data to the guest memory"""
+    caller["memory"].write(caller, data, ptr & 0xFFFFFFFF)
+
+
+def read_string(caller, ptr, length):
+    """Decodes an UTF-8 string from the guest memory"""
+    return read_bytes(caller, ptr, length).decode("utf-8")
+
+
+def read_value(caller, ptr, ctype):
+    """Reads a value of the given ctypes type from the guest memory"""
+    value = ctype.from_buffer_copy(read_bytes(caller, ptr, ctypes.sizeof(ctype)))
+    if isinstance(value, (ctypes.Structure, ctypes.Union)):
+        return value
+    return value.value
+
+
+def read_list(caller, ptr, length, ctype):
+    """Reads an array of values of the given ctypes type from the guest memory"""
+    array = (ctype * length).from_buffer_copy(
+        read_bytes(caller, ptr, ctypes.sizeof(ctype) * length)
+    )
+    return list(array)
+
+
+def write_value(caller, ptr, ctype, value):
+    """Writes a value of the given ctypes type to the guest memory"""
+    if not isinstance(value, ctype):
+        value = ctype(value)
+    write_bytes(caller, ptr, bytes(value))
+
+
+def write_allocated_bytes(caller, alloc, ptr, data):
+    """Copies data to memory allocated in the guest, and stores its address and
+    length at ptr"""
+    data_ptr = alloc(caller, len(data), 1)
+    write_bytes(caller, data_ptr, data)
+    write_value(caller, ptr, WasiSlice, WasiSlice(data_ptr, len(data)))
+
+
+def write_string(caller, alloc, ptr, value):
+    """Copies an UTF-8 string to memory allocated in the guest, and stores its
+    address and length at ptr"""
+    write_allocated_bytes(caller, alloc, ptr, value.encode("utf-8"))
+
+
+class GuestBuffer:
+    """A buffer in the guest memory, that the host can write to"""
+
+    def __init__(self, caller, ptr, length):
+        self.caller = caller
+        self.ptr = ptr
+        self.length = length
+
+    def __len__(self):
+        return self.length
+
+    def read(self):
+        return read_bytes(self.caller, self.ptr, self.length)
+
+    def write(self, data, offset=0):
+        if offset + len(data) > self.length:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        write_bytes(self.caller, self.ptr + offset, data)
+
+
+# ---------------------- Module: [test_module] ----------------------
+
+
+# An enumeration
+class TestErrno(enum.IntEnum):
+    SUCCESS = 0
+    GUEST_ERROR = 1
+    SOME_OTHER_ERROR = 2
+
+
+# A boolean alias
+TestBool = ctypes.c_bool
+
+
+# A u32 alias
+TestMediumInt = ctypes.c_uint32
+
+
+class TEST_MEDIUM_INT:
+    ZERO = 0
+    ONE = 1
+    TWO = 2
+    THREE = 3
+
+
+# A u64 alias
+TestBigInt = ctypes.c_uint64
+
+
+class TEST_BIG_INT:
+    ZERO = 0x0
+    A_HUNDRED = 0x64
+    A_BIG_VALUE = 0xff00000000000000
+    A_BIGGER_VALUE = 0xffffffffffffffff
+
+
+# Flags
+class TestBigFlags(enum.IntFlag):
+    A = 0x1
+    B = 0x2
+    C = 0x4
+    D = 0x8
+
+
+# A structure
+class TestStruct(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("a_boolean", ctypes.c_bool),
+        ("a_byte", ctypes.c_uint8),
+        ("__pad2", ctypes.c_uint8 * 2),
+        ("a_string", WasiString),
+    ]
+
+
+# A tuple
+class TestTuple(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("v0", TestBool),
+        ("__pad1", ctypes.c_uint8 * 3),
+        ("v1", TestMediumInt),
+        ("v2", TestBigInt),
+    ]
+
+
+# A string
+TestString = WasiString
+
+
+# An output buffer
+TestOutputBuffer = WasiSlice
+
+
+# An input buffer
+TestInputBuffer = WasiSlice
+
+
+# A tagged union
+class TestTaggedUnionMember(ctypes.Union):
+    _pack_ = 1
+    _fields_ = [
+        ("first_choice", ctypes.c_uint8),
+        ("second_choice", WasiString),
+        ("third_choice", ctypes.c_float),
+        ("__size", ctypes.c_uint8 * 8),
+    ]
+
+
+class TestTaggedUnion(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint16),
+        ("__pad1", ctypes.c_uint8 * 2),
+        ("member", TestTaggedUnionMember),
+    ]
+
+    def is_first_choice(self):
+        return self.tag == 0
+
+    def is_second_choice(self):
+        return self.tag == 1
+
+    def is_third_choice(self):
+        return self.tag == 2
+
+    def is_empty_choice(self):
+        return self.tag == 3
+
+
+class TestModuleHost:
+    """Host implementation of the [test_module] module
+
+    The methods receive the parameters decoded from the guest memory, and return
+    the results. Errors are returned to the guest by raising a WasiError.
+    """
+
+    def a_function_that_returns_multiple_values(
+        self,
+        some_parameter,
+        some_other_parameter,
+    ):
+        """This function returns multiple values"""
+        raise NotImplementedError("a_function_that_returns_multiple_values")
+
+    def a_function_that_returns_an_actual_tuple(self, some_parameter):
+        """This function returns an actual tuple (expanded into multiple values)"""
+        raise NotImplementedError("a_function_that_returns_an_actual_tuple")
+
+    def a_function_that_returns_nothing(self, some_parameter):
+        """This function returns nothing"""
+        raise NotImplementedError("a_function_that_returns_nothing")
+
+    def a_function_that_gets_a_string(self, str):
+        """This function gets a string"""
+        raise NotImplementedError("a_function_that_gets_a_string")
+
+    def a_function_that_returns_a_tagged_union(self, str):
+        """This function return a tagged union"""
+        raise NotImplementedError("a_function_that_returns_a_tagged_union")
+
+    def a_function_that_gets_and_returns_a_string(self, str):
+        """This function gets and returns a string"""
+        raise NotImplementedError("a_function_that_gets_and_returns_a_string")
+
+
+def add_test_module_to_linker(linker, host, alloc):
+    """Defines the functions of the [test_module] module in a wasmtime.Linker
+
+    The functions decode their parameters from the memory exported by the guest,
+    and call the methods of host.
+
+    Returned strings and bytes are copied to memory allocated in the guest by
+    alloc(caller, size, align), which returns the address of the allocation.
+    """
+
+    i32 = wasmtime.ValType.i32()
+    i64 = wasmtime.ValType.i64()
+    f32 = wasmtime.ValType.f32()
+    f64 = wasmtime.ValType.f64()
+
+    def a_function_that_returns_multiple_values(
+        caller,
+        some_parameter,
+        some_other_parameter_ptr,
+        some_other_parameter_len,
+        result_0_ptr,
+        result_1_ptr,
+    ):
+        try:
+            result = host.a_function_that_returns_multiple_values(
+                ctypes.c_uint64(some_parameter).value,
+                read_string(caller, some_other_parameter_ptr, some_other_parameter_len),
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_0_ptr, TestMediumInt, result[0])
+        write_value(caller, result_1_ptr, TestBigInt, result[1])
+        return 0
+
+    linker.define_func(
+        "test_module",
+        "a_function_that_returns_multiple_values",
+        wasmtime.FuncType([i64, i32, i32, i32, i32], [i32]),
+        a_function_that_returns_multiple_values,
+        access_caller=True,
+    )
+
+    def a_function_that_returns_an_actual_tuple(
+        caller,
+        some_parameter,
+        result_0_ptr,
+        result_1_ptr,
+        result_2_ptr,
+    ):
+        try:
+            result = host.a_function_that_returns_an_actual_tuple(
+                ctypes.c_uint64(some_parameter).value,
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_0_ptr, TestBool, result[0])
+        write_value(caller, result_1_ptr, TestMediumInt, result[1])
+        write_value(caller, result_2_ptr, TestBigInt, result[2])
+        return 0
+
+    linker.define_func(
+        "test_module",
+        "a_function_that_returns_an_actual_tuple",
+        wasmtime.FuncType([i64, i32, i32, i32], [i32]),
+        a_function_that_returns_an_actual_tuple,
+        access_caller=True,
+    )
+
+    def a_function_that_returns_nothing(caller, some_parameter):
+        try:
+            host.a_function_that_returns_nothing(
+                ctypes.c_uint64(some_parameter).value,
+            )
+        except WasiError as e:
+            return e.errno
+        return 0
+
+    linker.define_func(
+        "test_module",
+        "a_function_that_returns_nothing",
+        wasmtime.FuncType([i64], [i32]),
+        a_function_that_returns_nothing,
+        access_caller=True,
+    )
+
+    def a_function_that_gets_a_string(caller, str_ptr, str_len, result_ptr):
+        try:
+            result = host.a_function_that_gets_a_string(
+                read_string(caller, str_ptr, str_len),
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_ptr, TestBool, result)
+        return 0
+
+    linker.define_func(
+        "test_module",
+        "a_function_that_gets_a_string",
+        wasmtime.FuncType([i32, i32, i32], [i32]),
+        a_function_that_gets_a_string,
+        access_caller=True,
+    )
+
+    def a_function_that_returns_a_tagged_union(
+        caller,
+        str_ptr,
+        str_len,
+        result_ptr,
+    ):
+        try:
+            result = host.a_function_that_returns_a_tagged_union(
+                read_string(caller, str_ptr, str_len),
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_ptr, TestTaggedUnion, result)
+        return 0
+
+    linker.define_func(
+        "test_module",
+        "a_function_that_returns_a_tagged_union",
+        wasmtime.FuncType([i32, i32, i32], [i32]),
+        a_function_that_returns_a_tagged_union,
+        access_caller=True,
+    )
+
+    def a_function_that_gets_and_returns_a_string(
+        caller,
+        str_ptr,
+        str_len,
+        result_ptr,
+    ):
+        try:
+            result = host.a_function_that_gets_and_returns_a_string(
+                read_string(caller, str_ptr, str_len),
+            )
+        except WasiError as e:
+            return e.errno
+        write_string(caller, alloc, result_ptr, result)
+        return 0
+
+    linker.define_func(
+        "test_module",
+        "a_function_that_gets_and_returns_a_string",
+        wasmtime.FuncType([i32, i32, i32], [i32]),
+        a_function_that_gets_and_returns_a_string,
+        access_caller=True,
+    )
+
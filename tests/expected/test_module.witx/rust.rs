@@ -0,0 +1,674 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(&self, slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(&self, slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(&self, slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(unsafe { std::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(&self, slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [test_module] ----------------------
+
+/// An enumeration
+pub type TestErrno = u16;
+
+#[allow(non_snake_case)]
+pub mod TEST_ERRNO {
+    use super::TestErrno;
+    pub const SUCCESS: TestErrno = 0;
+    pub const GUEST_ERROR: TestErrno = 1;
+    pub const SOME_OTHER_ERROR: TestErrno = 2;
+}
+
+/// A boolean alias
+pub type TestBool = bool;
+
+/// A u32 alias
+pub type TestMediumInt = u32;
+
+#[allow(non_snake_case)]
+pub mod TEST_MEDIUM_INT {
+    use super::TestMediumInt;
+    pub const ZERO: TestMediumInt = 0;
+    pub const ONE: TestMediumInt = 1;
+    pub const TWO: TestMediumInt = 2;
+    pub const THREE: TestMediumInt = 3;
+}
+
+/// A u64 alias
+pub type TestBigInt = u64;
+
+#[allow(non_snake_case)]
+pub mod TEST_BIG_INT {
+    use super::TestBigInt;
+    pub const ZERO: TestBigInt = 0x0;
+    pub const A_HUNDRED: TestBigInt = 0x64;
+    pub const A_BIG_VALUE: TestBigInt = 0xff00000000000000;
+    pub const A_BIGGER_VALUE: TestBigInt = 0xffffffffffffffff;
+}
+
+/// Flags
+pub type TestBigFlags = u8;
+
+#[allow(non_snake_case)]
+pub mod TEST_BIG_FLAGS {
+    use super::TestBigFlags;
+    pub const A: TestBigFlags = 0x1;
+    pub const B: TestBigFlags = 0x2;
+    pub const C: TestBigFlags = 0x4;
+    pub const D: TestBigFlags = 0x8;
+}
+
+
+/// A structure
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct TestStruct {
+    pub a_boolean: bool,
+    pub a_byte: u8,
+    __pad16_0: u16,
+    pub a_string: WasiString,
+}
+
+#[cfg(target_arch = "wasm32")]
+const _: () = assert!(std::mem::size_of::<TestStruct>() == 12);
+#[cfg(target_arch = "wasm32")]
+const _: () = assert!(std::mem::align_of::<TestStruct>() == 4);
+
+impl std::fmt::Debug for TestStruct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestStruct")
+            .field("a_boolean", &{ self.a_boolean })
+            .field("a_byte", &{ self.a_byte })
+            .field("a_string", &{ self.a_string })
+            .finish()
+    }
+}
+
+impl PartialEq for TestStruct {
+    fn eq(&self, other: &Self) -> bool {
+        &{ self.a_boolean } == &{ other.a_boolean }
+            && &{ self.a_byte } == &{ other.a_byte }
+            && &{ self.a_string } == &{ other.a_string }
+    }
+}
+
+impl Eq for TestStruct {}
+
+impl std::hash::Hash for TestStruct {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&{ self.a_boolean }, state);
+        std::hash::Hash::hash(&{ self.a_byte }, state);
+        std::hash::Hash::hash(&{ self.a_string }, state);
+    }
+}
+
+impl Default for TestStruct {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+
+/// A tuple
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct TestTuple { // -- Tuple
+    pub v0: TestBool,
+    __pad8_0: u8,
+    __pad16_0: u16,
+    pub v1: TestMediumInt,
+    pub v2: TestBigInt,
+}
+
+#[cfg(any(target_arch = "wasm32", target_pointer_width = "64"))]
+const _: () = assert!(std::mem::size_of::<TestTuple>() == 16);
+#[cfg(any(target_arch = "wasm32", target_pointer_width = "64"))]
+const _: () = assert!(std::mem::align_of::<TestTuple>() == 8);
+
+impl std::fmt::Debug for TestTuple {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestTuple")
+            .field("v0", &{ self.v0 })
+            .field("v1", &{ self.v1 })
+            .field("v2", &{ self.v2 })
+            .finish()
+    }
+}
+
+impl PartialEq for TestTuple {
+    fn eq(&self, other: &Self) -> bool {
+        &{ self.v0 } == &{ other.v0 }
+            && &{ self.v1 } == &{ other.v1 }
+            && &{ self.v2 } == &{ other.v2 }
+    }
+}
+
+impl Eq for TestTuple {}
+
+impl std::hash::Hash for TestTuple {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&{ self.v0 }, state);
+        std::hash::Hash::hash(&{ self.v1 }, state);
+        std::hash::Hash::hash(&{ self.v2 }, state);
+    }
+}
+
+impl Default for TestTuple {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+
+/// A string
+pub type TestString = WasiString;
+
+/// An output buffer
+pub type TestOutputBuffer = WasiMutSlice<u16>;
+
+/// An input buffer
+pub type TestInputBuffer = WasiSlice<u16>;
+
+/// A tagged union
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union TestTaggedUnionMember {
+    first_choice: u8, // if tag=0
+    second_choice: WasiString, // if tag=1
+    third_choice: f32, // if tag=2
+    // empty_choice with no associated value if tag=3
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct TestTaggedUnion {
+    pub tag: u16,
+    __pad16_0: u16,
+    pub member: std::mem::MaybeUninit<TestTaggedUnionMember>,
+}
+
+#[cfg(target_arch = "wasm32")]
+const _: () = assert!(std::mem::size_of::<TestTaggedUnion>() == 12);
+#[cfg(target_arch = "wasm32")]
+const _: () = assert!(std::mem::align_of::<TestTaggedUnion>() == 4);
+
+impl TestTaggedUnion {
+    fn new(tag: u16) -> Self {
+        let mut tu = unsafe { std::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// The active member. Panics if the tag is not valid.
+    pub fn kind(&self) -> TestTaggedUnionKind {
+        match self.tag {
+            0 => TestTaggedUnionKind::FirstChoice,
+            1 => TestTaggedUnionKind::SecondChoice,
+            2 => TestTaggedUnionKind::ThirdChoice,
+            3 => TestTaggedUnionKind::EmptyChoice,
+            tag => panic!("Invalid tag: {}", tag),
+        }
+    }
+
+
+    // --- first_choice: u8 if tag=0
+
+    pub fn new_first_choice(val: u8) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = std::mem::MaybeUninit::new(TestTaggedUnionMember { first_choice: val });
+        tu
+    }
+
+    pub fn into_first_choice(self) -> Option<u8> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { self.member.assume_init().first_choice })
+    }
+
+    pub fn as_first_choice(&self) -> Option<&u8> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { &(*self.member.as_ptr()).first_choice })
+    }
+
+    pub fn as_first_choice_mut(&mut self) -> Option<&mut u8> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { &mut (*self.member.as_mut_ptr()).first_choice })
+    }
+
+    pub fn set_first_choice(&mut self, val: u8) {
+        assert!(self.tag == 0);
+        let uval = TestTaggedUnionMember { first_choice: val };
+        unsafe { *self.member.as_mut_ptr() = uval };
+    }
+
+    pub fn is_first_choice(&self) -> bool {
+        self.tag == 0
+    }
+
+
+    // --- second_choice: WasiString if tag=1
+
+    pub fn new_second_choice(val: WasiString) -> Self {
+        let mut tu = Self::new(1);
+        tu.member = std::mem::MaybeUninit::new(TestTaggedUnionMember { second_choice: val });
+        tu
+    }
+
+    pub fn into_second_choice(self) -> Option<WasiString> {
+        if self.tag != 1 {
+            return None;
+        }
+        Some(unsafe { self.member.assume_init().second_choice })
+    }
+
+    pub fn as_second_choice(&self) -> Option<&WasiString> {
+        if self.tag != 1 {
+            return None;
+        }
+        Some(unsafe { &(*self.member.as_ptr()).second_choice })
+    }
+
+    pub fn as_second_choice_mut(&mut self) -> Option<&mut WasiString> {
+        if self.tag != 1 {
+            return None;
+        }
+        Some(unsafe { &mut (*self.member.as_mut_ptr()).second_choice })
+    }
+
+    pub fn set_second_choice(&mut self, val: WasiString) {
+        assert!(self.tag == 1);
+        let uval = TestTaggedUnionMember { second_choice: val };
+        unsafe { *self.member.as_mut_ptr() = uval };
+    }
+
+    pub fn is_second_choice(&self) -> bool {
+        self.tag == 1
+    }
+
+
+    // --- third_choice: f32 if tag=2
+
+    pub fn new_third_choice(val: f32) -> Self {
+        let mut tu = Self::new(2);
+        tu.member = std::mem::MaybeUninit::new(TestTaggedUnionMember { third_choice: val });
+        tu
+    }
+
+    pub fn into_third_choice(self) -> Option<f32> {
+        if self.tag != 2 {
+            return None;
+        }
+        Some(unsafe { self.member.assume_init().third_choice })
+    }
+
+    pub fn as_third_choice(&self) -> Option<&f32> {
+        if self.tag != 2 {
+            return None;
+        }
+        Some(unsafe { &(*self.member.as_ptr()).third_choice })
+    }
+
+    pub fn as_third_choice_mut(&mut self) -> Option<&mut f32> {
+        if self.tag != 2 {
+            return None;
+        }
+        Some(unsafe { &mut (*self.member.as_mut_ptr()).third_choice })
+    }
+
+    pub fn set_third_choice(&mut self, val: f32) {
+        assert!(self.tag == 2);
+        let uval = TestTaggedUnionMember { third_choice: val };
+        unsafe { *self.member.as_mut_ptr() = uval };
+    }
+
+    pub fn is_third_choice(&self) -> bool {
+        self.tag == 2
+    }
+
+
+    // --- empty_choice: (no associated content) if tag=3
+
+    pub fn new_empty_choice() -> Self {
+        Self::new(3)
+    }
+
+    pub fn is_empty_choice(&self) -> bool {
+        self.tag == 3
+    }
+
+}
+
+impl std::fmt::Debug for TestTaggedUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.tag {
+            0 => f.debug_tuple("FirstChoice").field(unsafe { &(*self.member.as_ptr()).first_choice }).finish(),
+            1 => f.debug_tuple("SecondChoice").field(unsafe { &(*self.member.as_ptr()).second_choice }).finish(),
+            2 => f.debug_tuple("ThirdChoice").field(unsafe { &(*self.member.as_ptr()).third_choice }).finish(),
+            3 => f.write_str("EmptyChoice"),
+            tag => f.debug_struct("TestTaggedUnion").field("tag", &tag).finish(),
+        }
+    }
+}
+
+impl PartialEq for TestTaggedUnion {
+    fn eq(&self, other: &Self) -> bool {
+        if self.tag != other.tag {
+            return false;
+        }
+        match self.tag {
+            0 => self.as_first_choice() == other.as_first_choice(),
+            1 => self.as_second_choice() == other.as_second_choice(),
+            2 => self.as_third_choice() == other.as_third_choice(),
+            _ => true,
+        }
+    }
+}
+
+impl Default for TestTaggedUnion {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+/// Discriminant of `TestTaggedUnion`
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TestTaggedUnionKind {
+    FirstChoice,
+    SecondChoice,
+    ThirdChoice,
+    EmptyChoice,
+}
+
+/// Content of `TestTaggedUnion`, as a Rust enum
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TestTaggedUnionValue {
+    FirstChoice(u8),
+    SecondChoice(WasiString),
+    ThirdChoice(f32),
+    EmptyChoice,
+}
+
+impl From<TestTaggedUnionValue> for TestTaggedUnion {
+    fn from(value: TestTaggedUnionValue) -> Self {
+        match value {
+            TestTaggedUnionValue::FirstChoice(val) => Self::new_first_choice(val),
+            TestTaggedUnionValue::SecondChoice(val) => Self::new_second_choice(val),
+            TestTaggedUnionValue::ThirdChoice(val) => Self::new_third_choice(val),
+            TestTaggedUnionValue::EmptyChoice => Self::new_empty_choice(),
+        }
+    }
+}
+
+impl From<TestTaggedUnion> for TestTaggedUnionValue {
+    /// Panics if the tag is not valid
+    fn from(tu: TestTaggedUnion) -> Self {
+        match tu.kind() {
+            TestTaggedUnionKind::FirstChoice => Self::FirstChoice(unsafe { tu.member.assume_init().first_choice }),
+            TestTaggedUnionKind::SecondChoice => Self::SecondChoice(unsafe { tu.member.assume_init().second_choice }),
+            TestTaggedUnionKind::ThirdChoice => Self::ThirdChoice(unsafe { tu.member.assume_init().third_choice }),
+            TestTaggedUnionKind::EmptyChoice => Self::EmptyChoice,
+        }
+    }
+}
+
+
+/// This function returns multiple values
+pub fn a_function_that_returns_multiple_values(
+    some_parameter: u64,
+    some_other_parameter_ptr: WasiPtr<Char8>,
+    some_other_parameter_len: usize,
+) -> Result<(TestMediumInt, TestBigInt), Error> {
+    #[link(wasm_import_module = "test_module")]
+    extern "C" {
+        fn a_function_that_returns_multiple_values(
+            some_parameter: u64,
+            some_other_parameter_ptr: WasiPtr<Char8>,
+            some_other_parameter_len: usize,
+            result_0_ptr: WasiMutPtr<TestMediumInt>,
+            result_1_ptr: WasiMutPtr<TestBigInt>,
+        ) -> TestErrno;
+    }
+    let mut result_0_ptr = std::mem::MaybeUninit::uninit();
+    let mut result_1_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { a_function_that_returns_multiple_values(
+        some_parameter,
+        some_other_parameter_ptr,
+        some_other_parameter_len,
+        result_0_ptr.as_mut_ptr(),
+        result_1_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { (result_0_ptr.assume_init(), result_1_ptr.assume_init()) })
+}
+
+/// This function returns an actual tuple (expanded into multiple values)
+pub fn a_function_that_returns_an_actual_tuple(
+    some_parameter: u64,
+) -> Result<(TestBool, TestMediumInt, TestBigInt), Error> {
+    #[link(wasm_import_module = "test_module")]
+    extern "C" {
+        fn a_function_that_returns_an_actual_tuple(
+            some_parameter: u64,
+            result_0_ptr: WasiMutPtr<TestBool>,
+            result_1_ptr: WasiMutPtr<TestMediumInt>,
+            result_2_ptr: WasiMutPtr<TestBigInt>,
+        ) -> TestErrno;
+    }
+    let mut result_0_ptr = std::mem::MaybeUninit::uninit();
+    let mut result_1_ptr = std::mem::MaybeUninit::uninit();
+    let mut result_2_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { a_function_that_returns_an_actual_tuple(
+        some_parameter,
+        result_0_ptr.as_mut_ptr(),
+        result_1_ptr.as_mut_ptr(),
+        result_2_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { (result_0_ptr.assume_init(), result_1_ptr.assume_init(), result_2_ptr.assume_init()) })
+}
+
+/// This function returns nothing
+pub fn a_function_that_returns_nothing(
+    some_parameter: u64,
+) -> Result<(), Error> {
+    #[link(wasm_import_module = "test_module")]
+    extern "C" {
+        fn a_function_that_returns_nothing(
+            some_parameter: u64,
+        ) -> TestErrno;
+    }
+    let res = unsafe { a_function_that_returns_nothing(
+        some_parameter,
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(())
+}
+
+/// This function gets a string
+pub fn a_function_that_gets_a_string(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+) -> Result<TestBool, Error> {
+    #[link(wasm_import_module = "test_module")]
+    extern "C" {
+        fn a_function_that_gets_a_string(
+            str_ptr: WasiPtr<Char8>,
+            str_len: usize,
+            result_ptr: WasiMutPtr<TestBool>,
+        ) -> TestErrno;
+    }
+    let mut result_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { a_function_that_gets_a_string(
+        str_ptr,
+        str_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// This function return a tagged union
+pub fn a_function_that_returns_a_tagged_union(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+) -> Result<TestTaggedUnion, Error> {
+    #[link(wasm_import_module = "test_module")]
+    extern "C" {
+        fn a_function_that_returns_a_tagged_union(
+            str_ptr: WasiPtr<Char8>,
+            str_len: usize,
+            result_ptr: WasiMutPtr<TestTaggedUnion>,
+        ) -> TestErrno;
+    }
+    let mut result_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { a_function_that_returns_a_tagged_union(
+        str_ptr,
+        str_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// This function gets and returns a string
+pub fn a_function_that_gets_and_returns_a_string(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+) -> Result<TestString, Error> {
+    #[link(wasm_import_module = "test_module")]
+    extern "C" {
+        fn a_function_that_gets_and_returns_a_string(
+            str_ptr: WasiPtr<Char8>,
+            str_len: usize,
+            result_ptr: WasiMutPtr<TestString>,
+        ) -> TestErrno;
+    }
+    let mut result_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { a_function_that_gets_and_returns_a_string(
+        str_ptr,
+        str_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
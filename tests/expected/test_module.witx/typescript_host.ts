@@ -0,0 +1,414 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = number;
+export type WasiPtr = number;
+export type WasiSize = number;
+export type Char8 = number;
+export type Char32 = number;
+
+export interface WasiSlice {
+    ptr: WasiPtr;
+    len: WasiSize;
+}
+
+/**
+ * Thrown by a host implementation to return an error code to the guest
+ */
+export class WasiError<E = number> extends Error {
+    constructor(readonly errno: E) {
+        super(`WASI error ${errno}`);
+    }
+}
+
+/**
+ * Allocates `size` bytes aligned to `align` in the guest memory, and returns their address
+ */
+export type GuestAllocator = (size: number, align: number) => number;
+
+const textDecoder = new TextDecoder("utf-8", { fatal: true });
+const textEncoder = new TextEncoder();
+
+/**
+ * The memory of a guest, that must be bound to the instance once it has been created
+ */
+export class GuestMemory {
+    private memory: WebAssembly.Memory | null = null;
+
+    bind(instance: WebAssembly.Instance): void {
+        this.memory = instance.exports.memory as WebAssembly.Memory;
+    }
+
+    view(): DataView {
+        return new DataView(this.buffer());
+    }
+
+    bytes(ptr: number, len: number): Uint8Array {
+        return new Uint8Array(this.buffer(), ptr >>> 0, len >>> 0);
+    }
+
+    readString(ptr: number, len: number): string {
+        return textDecoder.decode(this.bytes(ptr, len));
+    }
+
+    /**
+     * Copies bytes to memory allocated in the guest, and stores their address and length at `ptr`
+     */
+    writeBytes(alloc: GuestAllocator, ptr: number, bytes: Uint8Array): void {
+        const dataPtr = alloc(bytes.length, 1) >>> 0;
+        this.bytes(dataPtr, bytes.length).set(bytes);
+        const view = this.view();
+        view.setUint32(ptr, dataPtr, true);
+        view.setUint32(ptr + 4, bytes.length, true);
+    }
+
+    writeString(alloc: GuestAllocator, ptr: number, value: string): void {
+        this.writeBytes(alloc, ptr, textEncoder.encode(value));
+    }
+
+    private buffer(): ArrayBuffer {
+        if (this.memory === null) {
+            throw new Error("The guest memory is not bound to an instance");
+        }
+        return this.memory.buffer;
+    }
+}
+
+/*
+ * ---------------------- Module: [test_module] ----------------------
+ */
+
+/**
+ * An enumeration
+ */
+export type TestErrno = number;
+
+export namespace TestErrno {
+    export const SUCCESS: TestErrno = 0;
+    export const GUEST_ERROR: TestErrno = 1;
+    export const SOME_OTHER_ERROR: TestErrno = 2;
+}
+
+/**
+ * A boolean alias
+ */
+export type TestBool = boolean;
+
+/**
+ * A u32 alias
+ */
+export type TestMediumInt = number;
+
+export namespace TestMediumInt {
+    export const ZERO: TestMediumInt = 0;
+    export const ONE: TestMediumInt = 1;
+    export const TWO: TestMediumInt = 2;
+    export const THREE: TestMediumInt = 3;
+}
+
+/**
+ * A u64 alias
+ */
+export type TestBigInt = bigint;
+
+export namespace TestBigInt {
+    export const ZERO: TestBigInt = 0x0n;
+    export const A_HUNDRED: TestBigInt = 0x64n;
+    export const A_BIG_VALUE: TestBigInt = 0xff00000000000000n;
+    export const A_BIGGER_VALUE: TestBigInt = 0xffffffffffffffffn;
+}
+
+/**
+ * Flags
+ */
+export type TestBigFlags = number;
+
+export namespace TestBigFlags {
+    export const A: TestBigFlags = 0x1;
+    export const B: TestBigFlags = 0x2;
+    export const C: TestBigFlags = 0x4;
+    export const D: TestBigFlags = 0x8;
+}
+
+/**
+ * A structure
+ */
+export interface TestStruct {
+    aBoolean: boolean;
+    aByte: number;
+    aString: string;
+}
+
+export namespace TestStruct {
+    export function read(mem: GuestMemory, ptr: WasiPtr): TestStruct {
+        const view = mem.view();
+        return {
+            aBoolean: view.getUint8(ptr) !== 0,
+            aByte: view.getUint8(ptr + 1),
+            aString: mem.readString(view.getUint32(ptr + 4, true), view.getUint32(ptr + 8, true)),
+        };
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: TestStruct, alloc: GuestAllocator): void {
+        const view = mem.view();
+        view.setUint8(ptr, value.aBoolean ? 1 : 0);
+        view.setUint8(ptr + 1, value.aByte);
+        mem.writeString(alloc, ptr + 4, value.aString);
+    }
+}
+
+/**
+ * A tuple
+ */
+export type TestTuple = [TestBool, TestMediumInt, TestBigInt];
+
+export namespace TestTuple {
+    export function read(mem: GuestMemory, ptr: WasiPtr): TestTuple {
+        const view = mem.view();
+        return [
+            view.getUint8(ptr) !== 0,
+            view.getUint32(ptr + 4, true),
+            view.getBigUint64(ptr + 8, true),
+        ];
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: TestTuple): void {
+        const view = mem.view();
+        view.setUint8(ptr, value[0] ? 1 : 0);
+        view.setUint32(ptr + 4, value[1], true);
+        view.setBigUint64(ptr + 8, value[2], true);
+    }
+}
+
+/**
+ * A string
+ */
+export type TestString = string;
+
+/**
+ * An output buffer
+ */
+export type TestOutputBuffer = WasiSlice;
+
+/**
+ * An input buffer
+ */
+export type TestInputBuffer = WasiSlice;
+
+/**
+ * A tagged union
+ */
+export type TestTaggedUnion =
+    | { tag: 0; firstChoice: number }
+    | { tag: 1; secondChoice: string }
+    | { tag: 2; thirdChoice: number }
+    | { tag: 3 };
+
+export namespace TestTaggedUnion {
+    export const FIRST_CHOICE = 0;
+    export const SECOND_CHOICE = 1;
+    export const THIRD_CHOICE = 2;
+    export const EMPTY_CHOICE = 3;
+
+    export function read(mem: GuestMemory, ptr: WasiPtr): TestTaggedUnion {
+        const view = mem.view();
+        const tag = view.getUint16(ptr, true);
+        switch (tag) {
+            case 0:
+                return { tag: 0, firstChoice: view.getUint8(ptr + 4) };
+            case 1:
+                return { tag: 1, secondChoice: mem.readString(view.getUint32(ptr + 4, true), view.getUint32(ptr + 8, true)) };
+            case 2:
+                return { tag: 2, thirdChoice: view.getFloat32(ptr + 4, true) };
+            case 3:
+                return { tag: 3 };
+            default:
+                throw new Error(`Invalid tag for TestTaggedUnion: ${tag}`);
+        }
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: TestTaggedUnion, alloc: GuestAllocator): void {
+        const view = mem.view();
+        view.setUint16(ptr, value.tag, true);
+        switch (value.tag) {
+            case 0:
+                view.setUint8(ptr + 4, value.firstChoice);
+                break;
+            case 1:
+                mem.writeString(alloc, ptr + 4, value.secondChoice);
+                break;
+            case 2:
+                view.setFloat32(ptr + 4, value.thirdChoice, true);
+                break;
+        }
+    }
+}
+
+/**
+ * Host implementation of the [test_module] module
+ *
+ * The functions receive the parameters decoded from the guest memory, and return
+ * the results. Errors are returned to the guest by throwing a `WasiError`.
+ */
+export interface TestModule {
+    /**
+     * This function returns multiple values
+     */
+    aFunctionThatReturnsMultipleValues(someParameter: bigint, someOtherParameter: string): [TestMediumInt, TestBigInt];
+
+    /**
+     * This function returns an actual tuple (expanded into multiple values)
+     */
+    aFunctionThatReturnsAnActualTuple(someParameter: bigint): [TestBool, TestMediumInt, TestBigInt];
+
+    /**
+     * This function returns nothing
+     */
+    aFunctionThatReturnsNothing(someParameter: bigint): void;
+
+    /**
+     * This function gets a string
+     */
+    aFunctionThatGetsAString(str: string): TestBool;
+
+    /**
+     * This function return a tagged union
+     */
+    aFunctionThatReturnsATaggedUnion(str: TestString): TestTaggedUnion;
+
+    /**
+     * This function gets and returns a string
+     */
+    aFunctionThatGetsAndReturnsAString(str: TestString): TestString;
+}
+
+/**
+ * Returns the imports of the [test_module] module, calling the functions of `impl`
+ *
+ * `mem` must be bound to the instance once it has been created. `alloc` allocates the memory
+ * of the strings and byte arrays returned to the guest.
+ */
+export function testModuleImports(impl: TestModule, mem: GuestMemory, alloc: GuestAllocator): WebAssembly.Imports {
+    return {
+        "test_module": {
+            "a_function_that_returns_multiple_values": (
+                someParameter: bigint,
+                someOtherParameterPtr: number,
+                someOtherParameterLen: number,
+                result0Ptr: number,
+                result1Ptr: number
+            ): number => {
+                try {
+                    const result = impl.aFunctionThatReturnsMultipleValues(
+                        BigInt.asUintN(64, someParameter),
+                        mem.readString(someOtherParameterPtr, someOtherParameterLen),
+                    );
+                    const view = mem.view();
+                    view.setUint32(result0Ptr, result[0], true);
+                    view.setBigUint64(result1Ptr, result[1], true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+            "a_function_that_returns_an_actual_tuple": (
+                someParameter: bigint,
+                result0Ptr: number,
+                result1Ptr: number,
+                result2Ptr: number
+            ): number => {
+                try {
+                    const result = impl.aFunctionThatReturnsAnActualTuple(
+                        BigInt.asUintN(64, someParameter),
+                    );
+                    const view = mem.view();
+                    view.setUint8(result0Ptr, result[0] ? 1 : 0);
+                    view.setUint32(result1Ptr, result[1], true);
+                    view.setBigUint64(result2Ptr, result[2], true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+            "a_function_that_returns_nothing": (
+                someParameter: bigint
+            ): number => {
+                try {
+                    impl.aFunctionThatReturnsNothing(
+                        BigInt.asUintN(64, someParameter),
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+            "a_function_that_gets_a_string": (
+                strPtr: number,
+                strLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.aFunctionThatGetsAString(
+                        mem.readString(strPtr, strLen),
+                    );
+                    const view = mem.view();
+                    view.setUint8(resultPtr, result ? 1 : 0);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+            "a_function_that_returns_a_tagged_union": (
+                strPtr: number,
+                strLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.aFunctionThatReturnsATaggedUnion(
+                        mem.readString(strPtr, strLen),
+                    );
+                    TestTaggedUnion.write(mem, resultPtr, result, alloc);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+            "a_function_that_gets_and_returns_a_string": (
+                strPtr: number,
+                strLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.aFunctionThatGetsAndReturnsAString(
+                        mem.readString(strPtr, strLen),
+                    );
+                    mem.writeString(alloc, resultPtr, result);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return TestErrno.SUCCESS;
+            },
+        },
+    };
+}
+
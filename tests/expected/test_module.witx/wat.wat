@@ -0,0 +1,69 @@
+
+;;
+;; This file was automatically generated by witx-codegen - Do not edit manually.
+;;
+
+(module
+  ;; ---------------------- Module: [test_module] ----------------------
+
+  ;; This function returns multiple values
+  (import "test_module" "a_function_that_returns_multiple_values"
+    (func $test_module.a_function_that_returns_multiple_values
+      (param $some_parameter i64)
+      (param $some_other_parameter_ptr i32)
+      (param $some_other_parameter_len i32)
+      (param $result0_ptr i32)
+      (param $result1_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; This function returns an actual tuple (expanded into multiple values)
+  (import "test_module" "a_function_that_returns_an_actual_tuple"
+    (func $test_module.a_function_that_returns_an_actual_tuple
+      (param $some_parameter i64)
+      (param $result0_ptr i32)
+      (param $result1_ptr i32)
+      (param $result2_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; This function returns nothing
+  (import "test_module" "a_function_that_returns_nothing"
+    (func $test_module.a_function_that_returns_nothing
+      (param $some_parameter i64)
+      (result i32)
+    )
+  )
+
+  ;; This function gets a string
+  (import "test_module" "a_function_that_gets_a_string"
+    (func $test_module.a_function_that_gets_a_string
+      (param $str_ptr i32)
+      (param $str_len i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; This function return a tagged union
+  (import "test_module" "a_function_that_returns_a_tagged_union"
+    (func $test_module.a_function_that_returns_a_tagged_union
+      (param $str_ptr i32)
+      (param $str_len i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; This function gets and returns a string
+  (import "test_module" "a_function_that_gets_and_returns_a_string"
+    (func $test_module.a_function_that_gets_and_returns_a_string
+      (param $str_ptr i32)
+      (param $str_len i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+)
@@ -0,0 +1,937 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/**
+ * Pointer to the data of an `ArrayBuffer` or of a typed array
+ */
+export function wasiDataPtr<B>(buf: B): usize {
+    if (buf instanceof ArrayBuffer) {
+        return changetype<usize>(buf);
+    } else {
+        // @ts-ignore: ArrayBufferView
+        return buf.dataStart;
+    }
+}
+
+/**
+ * The value returned by a function, or the error code it returned instead
+ */
+export class WasiResult<T, E> {
+    error: E;
+    value: T;
+
+    constructor(error: E, value: T) {
+        this.error = error;
+        this.value = value;
+    }
+
+    static ok<T, E>(value: T): WasiResult<T, E> {
+        return new WasiResult<T, E>(<E>0, value);
+    }
+
+    static err<T, E>(error: E): WasiResult<T, E> {
+        if (isReference<T>()) {
+            return new WasiResult<T, E>(error, changetype<T>(0));
+        } else {
+            return new WasiResult<T, E>(error, <T>0);
+        }
+    }
+
+    isOk(): bool {
+        return this.error == <E>0;
+    }
+
+    isErr(): bool {
+        return this.error != <E>0;
+    }
+
+    /**
+     * Return the value, or abort if the function returned an error
+     */
+    unwrap(): T {
+        if (this.isErr()) {
+            throw new Error("WASI error " + this.error.toString());
+        }
+        return this.value;
+    }
+}
+
+/*
+ * ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+ */
+
+/**
+ * Error codes.
+ */
+export type CryptoErrno = u16;
+
+export namespace CryptoErrno {
+    export const SUCCESS: CryptoErrno = 0;
+    export const GUEST_ERROR: CryptoErrno = 1;
+    export const NOT_IMPLEMENTED: CryptoErrno = 2;
+    export const UNSUPPORTED_FEATURE: CryptoErrno = 3;
+    export const PROHIBITED_OPERATION: CryptoErrno = 4;
+    export const UNSUPPORTED_ENCODING: CryptoErrno = 5;
+    export const UNSUPPORTED_ALGORITHM: CryptoErrno = 6;
+    export const UNSUPPORTED_OPTION: CryptoErrno = 7;
+    export const INVALID_KEY: CryptoErrno = 8;
+    export const INVALID_LENGTH: CryptoErrno = 9;
+    export const VERIFICATION_FAILED: CryptoErrno = 10;
+    export const RNG_ERROR: CryptoErrno = 11;
+    export const ALGORITHM_FAILURE: CryptoErrno = 12;
+    export const INVALID_SIGNATURE: CryptoErrno = 13;
+    export const CLOSED: CryptoErrno = 14;
+    export const INVALID_HANDLE: CryptoErrno = 15;
+    export const OVERFLOW: CryptoErrno = 16;
+    export const INTERNAL_ERROR: CryptoErrno = 17;
+    export const TOO_MANY_HANDLES: CryptoErrno = 18;
+    export const KEY_NOT_SUPPORTED: CryptoErrno = 19;
+    export const KEY_REQUIRED: CryptoErrno = 20;
+    export const INVALID_TAG: CryptoErrno = 21;
+    export const INVALID_OPERATION: CryptoErrno = 22;
+    export const NONCE_REQUIRED: CryptoErrno = 23;
+    export const INVALID_NONCE: CryptoErrno = 24;
+    export const OPTION_NOT_SET: CryptoErrno = 25;
+    export const NOT_FOUND: CryptoErrno = 26;
+    export const PARAMETERS_MISSING: CryptoErrno = 27;
+    export const IN_PROGRESS: CryptoErrno = 28;
+    export const INCOMPATIBLE_KEYS: CryptoErrno = 29;
+    export const EXPIRED: CryptoErrno = 30;
+}
+
+/**
+ * Encoding to use for importing or exporting a key pair.
+ */
+export type KeypairEncoding = u16;
+
+export namespace KeypairEncoding {
+    export const RAW: KeypairEncoding = 0;
+    export const PKCS_8: KeypairEncoding = 1;
+    export const PEM: KeypairEncoding = 2;
+    export const LOCAL: KeypairEncoding = 3;
+}
+
+/**
+ * Encoding to use for importing or exporting a public key.
+ */
+export type PublickeyEncoding = u16;
+
+export namespace PublickeyEncoding {
+    export const RAW: PublickeyEncoding = 0;
+    export const PKCS_8: PublickeyEncoding = 1;
+    export const PEM: PublickeyEncoding = 2;
+    export const SEC: PublickeyEncoding = 3;
+    export const COMPRESSED_SEC: PublickeyEncoding = 4;
+    export const LOCAL: PublickeyEncoding = 5;
+}
+
+/**
+ * Encoding to use for importing or exporting a secret key.
+ */
+export type SecretkeyEncoding = u16;
+
+export namespace SecretkeyEncoding {
+    export const RAW: SecretkeyEncoding = 0;
+    export const PKCS_8: SecretkeyEncoding = 1;
+    export const PEM: SecretkeyEncoding = 2;
+    export const SEC: SecretkeyEncoding = 3;
+    export const COMPRESSED_SEC: SecretkeyEncoding = 4;
+    export const LOCAL: SecretkeyEncoding = 5;
+}
+
+/**
+ * Encoding to use for importing or exporting a signature.
+ */
+export type SignatureEncoding = u16;
+
+export namespace SignatureEncoding {
+    export const RAW: SignatureEncoding = 0;
+    export const DER: SignatureEncoding = 1;
+}
+
+/**
+ * An algorithm category.
+ */
+export type AlgorithmType = u16;
+
+export namespace AlgorithmType {
+    export const SIGNATURES: AlgorithmType = 0;
+    export const SYMMETRIC: AlgorithmType = 1;
+    export const KEY_EXCHANGE: AlgorithmType = 2;
+}
+
+/**
+ * Version of a managed key.
+ *
+ * A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+ */
+export type Version = u64;
+
+export namespace Version {
+    export const UNSPECIFIED: Version = 0xff00000000000000;
+    export const LATEST: Version = 0xff00000000000001;
+    export const ALL: Version = 0xff00000000000002;
+}
+
+/**
+ * Size of a value.
+ */
+export type Size = usize;
+
+/**
+ * A UNIX timestamp, in seconds since 01/01/1970.
+ */
+export type Timestamp = u64;
+
+/**
+ * A 64-bit value
+ */
+export type U64 = u64;
+
+/**
+ * Handle for functions returning output whose size may be large or not known in advance.
+ *
+ * An `array_output` object contains a host-allocated byte array.
+ *
+ * A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+ * In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+ *
+ * An `array_output` handle is automatically closed after its full content has been consumed.
+ */
+export type ArrayOutput = WasiHandle;
+
+/**
+ * A set of options.
+ *
+ * This type is used to set non-default parameters.
+ *
+ * The exact set of allowed options depends on the algorithm being used.
+ */
+export type Options = WasiHandle;
+
+/**
+ * A handle to the optional secrets management facilities offered by a host.
+ *
+ * This is used to generate, retrieve and invalidate managed keys.
+ */
+export type SecretsManager = WasiHandle;
+
+/**
+ * A key pair.
+ */
+export type Keypair = WasiHandle;
+
+/**
+ * A state to absorb data to be signed.
+ *
+ * After a signature has been computed or verified, the state remains valid for further operations.
+ *
+ * A subsequent signature would sign all the data accumulated since the creation of the state object.
+ */
+export type SignatureState = WasiHandle;
+
+/**
+ * A signature.
+ */
+export type Signature = WasiHandle;
+
+/**
+ * A public key, for key exchange and signature verification.
+ */
+export type Publickey = WasiHandle;
+
+/**
+ * A secret key, for key exchange mechanisms.
+ */
+export type Secretkey = WasiHandle;
+
+/**
+ * A state to absorb signed data to be verified.
+ */
+export type SignatureVerificationState = WasiHandle;
+
+/**
+ * A state to perform symmetric operations.
+ *
+ * The state is not reset nor invalidated after an option has been performed.
+ * Incremental updates and sessions are thus supported.
+ */
+export type SymmetricState = WasiHandle;
+
+/**
+ * A symmetric key.
+ *
+ * The key can be imported from raw bytes, or can be a reference to a managed key.
+ *
+ * If it was imported, the host will wipe it from memory as soon as the handle is closed.
+ */
+export type SymmetricKey = WasiHandle;
+
+/**
+ * An authentication tag.
+ *
+ * This is an object returned by functions computing authentication tags.
+ *
+ * A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+ *
+ * This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+ *
+ * The host is reponsible for securely wiping them from memory on close.
+ */
+export type SymmetricTag = WasiHandle;
+
+/**
+ * Options index, only required by the Interface Types translation layer.
+ */
+export type OptOptionsU = u8;
+
+export namespace OptOptionsU {
+    export const SOME: OptOptionsU = 0;
+    export const NONE: OptOptionsU = 1;
+}
+
+/**
+ * An optional options set.
+ *
+ * This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class OptOptions {
+    tag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+    private __pad32_0: u32;
+
+    constructor(tag: u8) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 4);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u8, val: T = 0): OptOptions {
+        let tu = new OptOptions(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 4);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- some: Options if tag=0
+
+    static some(val: Options): OptOptions {
+        return OptOptions.new(0, val);
+    }
+
+    setSome(val: Options): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isSome(): bool {
+        return this.tag === 0;
+    }
+
+    getSome(): Options {
+        return this.get<Options>();
+    }
+
+    // --- none: (no associated content) if tag=1
+
+    static none(): OptOptions {
+        return OptOptions.new(1);
+    }
+
+    setNone(): void {
+        this.tag = 1;
+    }
+
+    isNone(): bool {
+        return this.tag === 1;
+    }
+}
+
+
+/**
+ * Symmetric key index, only required by the Interface Types translation layer.
+ */
+export type OptSymmetricKeyU = u8;
+
+export namespace OptSymmetricKeyU {
+    export const SOME: OptSymmetricKeyU = 0;
+    export const NONE: OptSymmetricKeyU = 1;
+}
+
+/**
+ * An optional symmetric key.
+ *
+ * This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class OptSymmetricKey {
+    tag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+    private __pad32_0: u32;
+
+    constructor(tag: u8) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 4);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u8, val: T = 0): OptSymmetricKey {
+        let tu = new OptSymmetricKey(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 4);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- some: SymmetricKey if tag=0
+
+    static some(val: SymmetricKey): OptSymmetricKey {
+        return OptSymmetricKey.new(0, val);
+    }
+
+    setSome(val: SymmetricKey): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isSome(): bool {
+        return this.tag === 0;
+    }
+
+    getSome(): SymmetricKey {
+        return this.get<SymmetricKey>();
+    }
+
+    // --- none: (no associated content) if tag=1
+
+    static none(): OptSymmetricKey {
+        return OptSymmetricKey.new(1);
+    }
+
+    setNone(): void {
+        this.tag = 1;
+    }
+
+    isNone(): bool {
+        return this.tag === 1;
+    }
+}
+
+
+/**
+ * Create a new object to set non-default options.
+ *
+ * Example usage:
+ *
+ * ```rust
+ * let options_handle = options_open(AlgorithmType::Symmetric)?;
+ * options_set(options_handle, "context", context)?;
+ * options_set_u64(options_handle, "threads", 4)?;
+ * let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+ * options_close(options_handle)?;
+ * ```
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_open")
+export declare function optionsOpen(
+    algorithm_type: AlgorithmType,
+    result_ptr: WasiMutPtr<Options>
+): CryptoErrno;
+
+/**
+ * Destroy an options object.
+ *
+ * Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_close")
+export declare function optionsClose(
+    handle: Options
+): CryptoErrno;
+
+/**
+ * Set or update an option.
+ *
+ * This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+ *
+ * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_set")
+export declare function optionsSet(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    value: WasiPtr<u8>,
+    value_len: Size
+): CryptoErrno;
+
+/**
+ * Set or update an integer option.
+ *
+ * This is used to set algorithm-specific parameters.
+ *
+ * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_set_u64")
+export declare function optionsSetU64(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    value: u64
+): CryptoErrno;
+
+/**
+ * Set or update a guest-allocated memory that the host can use or return data into.
+ *
+ * This is for example used to set the scratch buffer required by memory-hard functions.
+ *
+ * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_set_guest_buffer")
+export declare function optionsSetGuestBuffer(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    buffer: WasiMutPtr<u8>,
+    buffer_len: Size
+): CryptoErrno;
+
+/**
+ * Return the length of an `array_output` object.
+ *
+ * This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "array_output_len")
+export declare function arrayOutputLen(
+    array_output: ArrayOutput,
+    result_ptr: WasiMutPtr<Size>
+): CryptoErrno;
+
+/**
+ * Copy the content of an `array_output` object into an application-allocated buffer.
+ *
+ * Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+ *
+ * The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+ *
+ * The handle is automatically closed after all the data has been consumed.
+ *
+ * Example usage:
+ *
+ * ```rust
+ * let len = array_output_len(output_handle)?;
+ * let mut out = vec![0u8; len];
+ * array_output_pull(output_handle, &mut out)?;
+ * ```
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "array_output_pull")
+export declare function arrayOutputPull(
+    array_output: ArrayOutput,
+    buf: WasiMutPtr<u8>,
+    buf_len: Size,
+    result_ptr: WasiMutPtr<Size>
+): CryptoErrno;
+
+/**
+ * __(optional)__
+ * Create a context to use a secrets manager.
+ *
+ * The set of required and supported options is defined by the host.
+ *
+ * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+ * This is also an optional import, meaning that the function may not even exist.
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "secrets_manager_open")
+export declare function secretsManagerOpen(
+    options: OptOptions,
+    result_ptr: WasiMutPtr<SecretsManager>
+): CryptoErrno;
+
+/**
+ * __(optional)__
+ * Destroy a secrets manager context.
+ *
+ * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+ * This is also an optional import, meaning that the function may not even exist.
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "secrets_manager_close")
+export declare function secretsManagerClose(
+    secrets_manager: SecretsManager
+): CryptoErrno;
+
+/**
+ * __(optional)__
+ * Invalidate a managed key or key pair given an identifier and a version.
+ *
+ * This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+ *
+ * `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+ *
+ * The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+ *
+ * This is an optional import, meaning that the function may not even exist.
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "secrets_manager_invalidate")
+export declare function secretsManagerInvalidate(
+    secrets_manager: SecretsManager,
+    key_id: WasiPtr<u8>,
+    key_id_len: Size,
+    key_version: Version
+): CryptoErrno;
+
+/**
+ * High-level functions of the `wasi_ephemeral_crypto_common` module
+ */
+export class WasiEphemeralCryptoCommon {
+    /**
+     * Create a new object to set non-default options.
+     *
+     * Example usage:
+     *
+     * ```rust
+     * let options_handle = options_open(AlgorithmType::Symmetric)?;
+     * options_set(options_handle, "context", context)?;
+     * options_set_u64(options_handle, "threads", 4)?;
+     * let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+     * options_close(options_handle)?;
+     * ```
+     */
+    static optionsOpen(
+        algorithm_type: AlgorithmType
+    ): WasiResult<Options, CryptoErrno> {
+        const result_buf = new ArrayBuffer(4);
+        const res = optionsOpen(algorithm_type, changetype<usize>(result_buf));
+        if (res != 0) {
+            return WasiResult.err<Options, CryptoErrno>(res);
+        }
+        return WasiResult.ok<Options, CryptoErrno>(
+            load<Options>(changetype<usize>(result_buf))
+        );
+    }
+
+    /**
+     * Destroy an options object.
+     *
+     * Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+     */
+    static optionsClose(handle: Options): CryptoErrno {
+        const res = optionsClose(handle);
+        return res;
+    }
+
+    /**
+     * Set or update an option.
+     *
+     * This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    static optionsSet(
+        handle: Options,
+        name: string,
+        value: WasiPtr<u8>,
+        value_len: Size
+    ): CryptoErrno {
+        const name_buf = String.UTF8.encode(name, false);
+        const res = optionsSet(
+            handle,
+            changetype<usize>(name_buf),
+            name_buf.byteLength,
+            value,
+            value_len
+        );
+        return res;
+    }
+
+    /**
+     * Set or update an integer option.
+     *
+     * This is used to set algorithm-specific parameters.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    static optionsSetU64(
+        handle: Options,
+        name: string,
+        value: u64
+    ): CryptoErrno {
+        const name_buf = String.UTF8.encode(name, false);
+        const res = optionsSetU64(
+            handle,
+            changetype<usize>(name_buf),
+            name_buf.byteLength,
+            value
+        );
+        return res;
+    }
+
+    /**
+     * Set or update a guest-allocated memory that the host can use or return data into.
+     *
+     * This is for example used to set the scratch buffer required by memory-hard functions.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    static optionsSetGuestBuffer(
+        handle: Options,
+        name: string,
+        buffer: WasiMutPtr<u8>,
+        buffer_len: Size
+    ): CryptoErrno {
+        const name_buf = String.UTF8.encode(name, false);
+        const res = optionsSetGuestBuffer(
+            handle,
+            changetype<usize>(name_buf),
+            name_buf.byteLength,
+            buffer,
+            buffer_len
+        );
+        return res;
+    }
+
+    /**
+     * Return the length of an `array_output` object.
+     *
+     * This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+     */
+    static arrayOutputLen(
+        array_output: ArrayOutput
+    ): WasiResult<Size, CryptoErrno> {
+        const result_buf = new ArrayBuffer(4);
+        const res = arrayOutputLen(array_output, changetype<usize>(result_buf));
+        if (res != 0) {
+            return WasiResult.err<Size, CryptoErrno>(res);
+        }
+        return WasiResult.ok<Size, CryptoErrno>(
+            load<Size>(changetype<usize>(result_buf))
+        );
+    }
+
+    /**
+     * Copy the content of an `array_output` object into an application-allocated buffer.
+     *
+     * Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+     *
+     * The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+     *
+     * The handle is automatically closed after all the data has been consumed.
+     *
+     * Example usage:
+     *
+     * ```rust
+     * let len = array_output_len(output_handle)?;
+     * let mut out = vec![0u8; len];
+     * array_output_pull(output_handle, &mut out)?;
+     * ```
+     */
+    static arrayOutputPull(
+        array_output: ArrayOutput,
+        buf: WasiMutPtr<u8>,
+        buf_len: Size
+    ): WasiResult<Size, CryptoErrno> {
+        const result_buf = new ArrayBuffer(4);
+        const res = arrayOutputPull(
+            array_output,
+            buf,
+            buf_len,
+            changetype<usize>(result_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<Size, CryptoErrno>(res);
+        }
+        return WasiResult.ok<Size, CryptoErrno>(
+            load<Size>(changetype<usize>(result_buf))
+        );
+    }
+
+    /**
+     * __(optional)__
+     * Create a context to use a secrets manager.
+     *
+     * The set of required and supported options is defined by the host.
+     *
+     * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+     * This is also an optional import, meaning that the function may not even exist.
+     */
+    static secretsManagerOpen(
+        options: OptOptions
+    ): WasiResult<SecretsManager, CryptoErrno> {
+        const result_buf = new ArrayBuffer(4);
+        const res = secretsManagerOpen(options, changetype<usize>(result_buf));
+        if (res != 0) {
+            return WasiResult.err<SecretsManager, CryptoErrno>(res);
+        }
+        return WasiResult.ok<SecretsManager, CryptoErrno>(
+            load<SecretsManager>(changetype<usize>(result_buf))
+        );
+    }
+
+    /**
+     * __(optional)__
+     * Destroy a secrets manager context.
+     *
+     * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+     * This is also an optional import, meaning that the function may not even exist.
+     */
+    static secretsManagerClose(secrets_manager: SecretsManager): CryptoErrno {
+        const res = secretsManagerClose(secrets_manager);
+        return res;
+    }
+
+    /**
+     * __(optional)__
+     * Invalidate a managed key or key pair given an identifier and a version.
+     *
+     * This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+     *
+     * `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+     *
+     * The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+     *
+     * This is an optional import, meaning that the function may not even exist.
+     */
+    static secretsManagerInvalidate(
+        secrets_manager: SecretsManager,
+        key_id: WasiPtr<u8>,
+        key_id_len: Size,
+        key_version: Version
+    ): CryptoErrno {
+        const res = secretsManagerInvalidate(
+            secrets_manager,
+            key_id,
+            key_id_len,
+            key_version
+        );
+        return res;
+    }
+}
+
@@ -0,0 +1,298 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <stdbool.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+/*
+ * Checks that `len` elements of `elem_size` bytes starting at `ptr` are in the guest memory,
+ * and translates `ptr` to a native pointer. Raises an exception in the guest otherwise.
+ */
+static inline bool
+wasi_guest_range(wasm_exec_env_t exec_env, uint32_t ptr, uint32_t len, uint32_t elem_size,
+                 void **native)
+{
+    wasm_module_inst_t inst = wasm_runtime_get_module_inst(exec_env);
+    uint64_t size = (uint64_t) len * elem_size;
+
+    if (size > UINT32_MAX || !wasm_runtime_validate_app_addr(inst, ptr, (uint32_t) size)) {
+        wasm_runtime_set_exception(inst, "out of bounds memory access");
+        return false;
+    }
+    *native = wasm_runtime_addr_app_to_native(inst, ptr);
+    return true;
+}
+
+/* ---------------------- Module: [wasi_ephemeral_crypto_common] ---------------------- */
+
+#include "wasi_ephemeral_crypto_common_host.h"
+
+static uint32_t
+wasi_ephemeral_crypto_common_options_open_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t algorithm_type,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->options_open == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.options_open is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->options_open(
+        exec_env,
+        (algorithm_type_t) algorithm_type,
+        (options_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_options_close_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->options_close == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.options_close is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->options_close(
+        exec_env,
+        (options_t) handle);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_options_set_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t name_ptr,
+    uint32_t name_len,
+    uint32_t value,
+    uint32_t value_len)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *name_ptr_native = NULL;
+
+    if (host->options_set == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.options_set is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, name_ptr, name_len, 1, &name_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->options_set(
+        exec_env,
+        (options_t) handle,
+        (const char *) name_ptr_native,
+        name_len,
+        (wasi_guest_ptr_t) value,
+        (size_t_) value_len);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_options_set_u_64_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t name_ptr,
+    uint32_t name_len,
+    uint64_t value)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *name_ptr_native = NULL;
+
+    if (host->options_set_u_64 == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.options_set_u64 is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, name_ptr, name_len, 1, &name_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->options_set_u_64(
+        exec_env,
+        (options_t) handle,
+        (const char *) name_ptr_native,
+        name_len,
+        (uint64_t) value);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_options_set_guest_buffer_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t name_ptr,
+    uint32_t name_len,
+    uint32_t buffer,
+    uint32_t buffer_len)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *name_ptr_native = NULL;
+
+    if (host->options_set_guest_buffer == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.options_set_guest_buffer is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, name_ptr, name_len, 1, &name_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->options_set_guest_buffer(
+        exec_env,
+        (options_t) handle,
+        (const char *) name_ptr_native,
+        name_len,
+        (wasi_guest_ptr_t) buffer,
+        (size_t_) buffer_len);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_array_output_len_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t array_output,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->array_output_len == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.array_output_len is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->array_output_len(
+        exec_env,
+        (array_output_t) array_output,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_array_output_pull_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t array_output,
+    uint32_t buf,
+    uint32_t buf_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->array_output_pull == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.array_output_pull is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->array_output_pull(
+        exec_env,
+        (array_output_t) array_output,
+        (wasi_guest_ptr_t) buf,
+        (size_t_) buf_len,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_secrets_manager_open_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t options,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *options_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->secrets_manager_open == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.secrets_manager_open is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, options, 1, 8, &options_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->secrets_manager_open(
+        exec_env,
+        (const opt_options_t *) options_native,
+        (secrets_manager_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_secrets_manager_close_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t secrets_manager)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->secrets_manager_close == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.secrets_manager_close is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->secrets_manager_close(
+        exec_env,
+        (secrets_manager_t) secrets_manager);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_common_secrets_manager_invalidate_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t secrets_manager,
+    uint32_t key_id,
+    uint32_t key_id_len,
+    uint64_t key_version)
+{
+    const wasi_ephemeral_crypto_common_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->secrets_manager_invalidate == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_common.secrets_manager_invalidate is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->secrets_manager_invalidate(
+        exec_env,
+        (secrets_manager_t) secrets_manager,
+        (wasi_guest_ptr_t) key_id,
+        (size_t_) key_id_len,
+        (version_t) key_version);
+}
+
+static NativeSymbol wasi_ephemeral_crypto_common_native_symbols[] = {
+    { "options_open", (void *) wasi_ephemeral_crypto_common_options_open_wrapper, "(ii)i", NULL },
+    { "options_close", (void *) wasi_ephemeral_crypto_common_options_close_wrapper, "(i)i", NULL },
+    { "options_set", (void *) wasi_ephemeral_crypto_common_options_set_wrapper, "(iiiii)i", NULL },
+    { "options_set_u64", (void *) wasi_ephemeral_crypto_common_options_set_u_64_wrapper, "(iiiI)i", NULL },
+    { "options_set_guest_buffer", (void *) wasi_ephemeral_crypto_common_options_set_guest_buffer_wrapper, "(iiiii)i", NULL },
+    { "array_output_len", (void *) wasi_ephemeral_crypto_common_array_output_len_wrapper, "(ii)i", NULL },
+    { "array_output_pull", (void *) wasi_ephemeral_crypto_common_array_output_pull_wrapper, "(iiii)i", NULL },
+    { "secrets_manager_open", (void *) wasi_ephemeral_crypto_common_secrets_manager_open_wrapper, "(ii)i", NULL },
+    { "secrets_manager_close", (void *) wasi_ephemeral_crypto_common_secrets_manager_close_wrapper, "(i)i", NULL },
+    { "secrets_manager_invalidate", (void *) wasi_ephemeral_crypto_common_secrets_manager_invalidate_wrapper, "(iiiI)i", NULL },
+};
+
+bool
+wasi_ephemeral_crypto_common_register_natives(const wasi_ephemeral_crypto_common_host_t *host)
+{
+    const size_t n = sizeof wasi_ephemeral_crypto_common_native_symbols / sizeof wasi_ephemeral_crypto_common_native_symbols[0];
+    size_t i;
+
+    /* The callbacks are found through the attachments of the symbols */
+    for (i = 0; i < n; i++) {
+        wasi_ephemeral_crypto_common_native_symbols[i].attachment = (void *) host;
+    }
+    return wasm_runtime_register_natives("wasi_ephemeral_crypto_common", wasi_ephemeral_crypto_common_native_symbols, (uint32_t) n);
+}
+
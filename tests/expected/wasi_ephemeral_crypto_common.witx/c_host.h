@@ -0,0 +1,425 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+#ifndef WITX_CODEGEN_HOST_TYPES
+#define WITX_CODEGEN_HOST_TYPES
+
+typedef int32_t wasi_handle_t;
+typedef uint32_t wasi_size_t;
+
+/* A pointer in the guest memory */
+typedef uint32_t wasi_guest_ptr_t;
+
+/* A list stored in the guest memory */
+typedef struct wasi_guest_slice {
+    wasi_guest_ptr_t ptr;
+    wasi_size_t len;
+} wasi_guest_slice_t;
+
+#endif
+
+/* ---------------------- Module: [wasi_ephemeral_crypto_common] ---------------------- */
+
+/** Error codes. */
+typedef uint16_t crypto_errno_t;
+
+#define CRYPTO_ERRNO_SUCCESS ((crypto_errno_t) 0)
+#define CRYPTO_ERRNO_GUEST_ERROR ((crypto_errno_t) 1)
+#define CRYPTO_ERRNO_NOT_IMPLEMENTED ((crypto_errno_t) 2)
+#define CRYPTO_ERRNO_UNSUPPORTED_FEATURE ((crypto_errno_t) 3)
+#define CRYPTO_ERRNO_PROHIBITED_OPERATION ((crypto_errno_t) 4)
+#define CRYPTO_ERRNO_UNSUPPORTED_ENCODING ((crypto_errno_t) 5)
+#define CRYPTO_ERRNO_UNSUPPORTED_ALGORITHM ((crypto_errno_t) 6)
+#define CRYPTO_ERRNO_UNSUPPORTED_OPTION ((crypto_errno_t) 7)
+#define CRYPTO_ERRNO_INVALID_KEY ((crypto_errno_t) 8)
+#define CRYPTO_ERRNO_INVALID_LENGTH ((crypto_errno_t) 9)
+#define CRYPTO_ERRNO_VERIFICATION_FAILED ((crypto_errno_t) 10)
+#define CRYPTO_ERRNO_RNG_ERROR ((crypto_errno_t) 11)
+#define CRYPTO_ERRNO_ALGORITHM_FAILURE ((crypto_errno_t) 12)
+#define CRYPTO_ERRNO_INVALID_SIGNATURE ((crypto_errno_t) 13)
+#define CRYPTO_ERRNO_CLOSED ((crypto_errno_t) 14)
+#define CRYPTO_ERRNO_INVALID_HANDLE ((crypto_errno_t) 15)
+#define CRYPTO_ERRNO_OVERFLOW ((crypto_errno_t) 16)
+#define CRYPTO_ERRNO_INTERNAL_ERROR ((crypto_errno_t) 17)
+#define CRYPTO_ERRNO_TOO_MANY_HANDLES ((crypto_errno_t) 18)
+#define CRYPTO_ERRNO_KEY_NOT_SUPPORTED ((crypto_errno_t) 19)
+#define CRYPTO_ERRNO_KEY_REQUIRED ((crypto_errno_t) 20)
+#define CRYPTO_ERRNO_INVALID_TAG ((crypto_errno_t) 21)
+#define CRYPTO_ERRNO_INVALID_OPERATION ((crypto_errno_t) 22)
+#define CRYPTO_ERRNO_NONCE_REQUIRED ((crypto_errno_t) 23)
+#define CRYPTO_ERRNO_INVALID_NONCE ((crypto_errno_t) 24)
+#define CRYPTO_ERRNO_OPTION_NOT_SET ((crypto_errno_t) 25)
+#define CRYPTO_ERRNO_NOT_FOUND ((crypto_errno_t) 26)
+#define CRYPTO_ERRNO_PARAMETERS_MISSING ((crypto_errno_t) 27)
+#define CRYPTO_ERRNO_IN_PROGRESS ((crypto_errno_t) 28)
+#define CRYPTO_ERRNO_INCOMPATIBLE_KEYS ((crypto_errno_t) 29)
+#define CRYPTO_ERRNO_EXPIRED ((crypto_errno_t) 30)
+
+/** Encoding to use for importing or exporting a key pair. */
+typedef uint16_t keypair_encoding_t;
+
+#define KEYPAIR_ENCODING_RAW ((keypair_encoding_t) 0)
+#define KEYPAIR_ENCODING_PKCS_8 ((keypair_encoding_t) 1)
+#define KEYPAIR_ENCODING_PEM ((keypair_encoding_t) 2)
+#define KEYPAIR_ENCODING_LOCAL ((keypair_encoding_t) 3)
+
+/** Encoding to use for importing or exporting a public key. */
+typedef uint16_t publickey_encoding_t;
+
+#define PUBLICKEY_ENCODING_RAW ((publickey_encoding_t) 0)
+#define PUBLICKEY_ENCODING_PKCS_8 ((publickey_encoding_t) 1)
+#define PUBLICKEY_ENCODING_PEM ((publickey_encoding_t) 2)
+#define PUBLICKEY_ENCODING_SEC ((publickey_encoding_t) 3)
+#define PUBLICKEY_ENCODING_COMPRESSED_SEC ((publickey_encoding_t) 4)
+#define PUBLICKEY_ENCODING_LOCAL ((publickey_encoding_t) 5)
+
+/** Encoding to use for importing or exporting a secret key. */
+typedef uint16_t secretkey_encoding_t;
+
+#define SECRETKEY_ENCODING_RAW ((secretkey_encoding_t) 0)
+#define SECRETKEY_ENCODING_PKCS_8 ((secretkey_encoding_t) 1)
+#define SECRETKEY_ENCODING_PEM ((secretkey_encoding_t) 2)
+#define SECRETKEY_ENCODING_SEC ((secretkey_encoding_t) 3)
+#define SECRETKEY_ENCODING_COMPRESSED_SEC ((secretkey_encoding_t) 4)
+#define SECRETKEY_ENCODING_LOCAL ((secretkey_encoding_t) 5)
+
+/** Encoding to use for importing or exporting a signature. */
+typedef uint16_t signature_encoding_t;
+
+#define SIGNATURE_ENCODING_RAW ((signature_encoding_t) 0)
+#define SIGNATURE_ENCODING_DER ((signature_encoding_t) 1)
+
+/** An algorithm category. */
+typedef uint16_t algorithm_type_t;
+
+#define ALGORITHM_TYPE_SIGNATURES ((algorithm_type_t) 0)
+#define ALGORITHM_TYPE_SYMMETRIC ((algorithm_type_t) 1)
+#define ALGORITHM_TYPE_KEY_EXCHANGE ((algorithm_type_t) 2)
+
+/**
+ * Version of a managed key.
+ *
+ * A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+ */
+typedef uint64_t version_t;
+
+#define VERSION_UNSPECIFIED ((version_t) 0xff00000000000000ULL)
+#define VERSION_LATEST ((version_t) 0xff00000000000001ULL)
+#define VERSION_ALL ((version_t) 0xff00000000000002ULL)
+
+/** Size of a value. */
+typedef wasi_size_t size_t_;
+
+/** A UNIX timestamp, in seconds since 01/01/1970. */
+typedef uint64_t timestamp_t;
+
+/** A 64-bit value */
+typedef uint64_t u_64_t;
+
+/**
+ * Handle for functions returning output whose size may be large or not known in advance.
+ *
+ * An `array_output` object contains a host-allocated byte array.
+ *
+ * A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+ * In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+ *
+ * An `array_output` handle is automatically closed after its full content has been consumed.
+ */
+typedef wasi_handle_t array_output_t;
+
+/**
+ * A set of options.
+ *
+ * This type is used to set non-default parameters.
+ *
+ * The exact set of allowed options depends on the algorithm being used.
+ */
+typedef wasi_handle_t options_t;
+
+/**
+ * A handle to the optional secrets management facilities offered by a host.
+ *
+ * This is used to generate, retrieve and invalidate managed keys.
+ */
+typedef wasi_handle_t secrets_manager_t;
+
+/** A key pair. */
+typedef wasi_handle_t keypair_t;
+
+/**
+ * A state to absorb data to be signed.
+ *
+ * After a signature has been computed or verified, the state remains valid for further operations.
+ *
+ * A subsequent signature would sign all the data accumulated since the creation of the state object.
+ */
+typedef wasi_handle_t signature_state_t;
+
+/** A signature. */
+typedef wasi_handle_t signature_t;
+
+/** A public key, for key exchange and signature verification. */
+typedef wasi_handle_t publickey_t;
+
+/** A secret key, for key exchange mechanisms. */
+typedef wasi_handle_t secretkey_t;
+
+/** A state to absorb signed data to be verified. */
+typedef wasi_handle_t signature_verification_state_t;
+
+/**
+ * A state to perform symmetric operations.
+ *
+ * The state is not reset nor invalidated after an option has been performed.
+ * Incremental updates and sessions are thus supported.
+ */
+typedef wasi_handle_t symmetric_state_t;
+
+/**
+ * A symmetric key.
+ *
+ * The key can be imported from raw bytes, or can be a reference to a managed key.
+ *
+ * If it was imported, the host will wipe it from memory as soon as the handle is closed.
+ */
+typedef wasi_handle_t symmetric_key_t;
+
+/**
+ * An authentication tag.
+ *
+ * This is an object returned by functions computing authentication tags.
+ *
+ * A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+ *
+ * This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+ *
+ * The host is reponsible for securely wiping them from memory on close.
+ */
+typedef wasi_handle_t symmetric_tag_t;
+
+/** Options index, only required by the Interface Types translation layer. */
+typedef uint8_t opt_options_u_t;
+
+#define OPT_OPTIONS_U_SOME ((opt_options_u_t) 0)
+#define OPT_OPTIONS_U_NONE ((opt_options_u_t) 1)
+
+/**
+ * An optional options set.
+ *
+ * This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+ */
+typedef struct __attribute__((packed)) opt_options {
+    uint8_t tag;
+    uint8_t __pad0[3];
+    union __attribute__((packed)) {
+        options_t some;
+        uint8_t __size[4];
+    } member;
+} opt_options_t;
+
+_Static_assert(offsetof(opt_options_t, member) == 4, "Error layout");
+_Static_assert(sizeof(opt_options_t) == 8, "Error layout");
+
+#define OPT_OPTIONS_SOME 0
+#define OPT_OPTIONS_NONE 1
+
+/** Symmetric key index, only required by the Interface Types translation layer. */
+typedef uint8_t opt_symmetric_key_u_t;
+
+#define OPT_SYMMETRIC_KEY_U_SOME ((opt_symmetric_key_u_t) 0)
+#define OPT_SYMMETRIC_KEY_U_NONE ((opt_symmetric_key_u_t) 1)
+
+/**
+ * An optional symmetric key.
+ *
+ * This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+ */
+typedef struct __attribute__((packed)) opt_symmetric_key {
+    uint8_t tag;
+    uint8_t __pad0[3];
+    union __attribute__((packed)) {
+        symmetric_key_t some;
+        uint8_t __size[4];
+    } member;
+} opt_symmetric_key_t;
+
+_Static_assert(offsetof(opt_symmetric_key_t, member) == 4, "Error layout");
+_Static_assert(sizeof(opt_symmetric_key_t) == 8, "Error layout");
+
+#define OPT_SYMMETRIC_KEY_SOME 0
+#define OPT_SYMMETRIC_KEY_NONE 1
+
+/**
+ * Host implementation of the [wasi_ephemeral_crypto_common] module
+ *
+ * Guest pointers are checked and translated to native pointers before the callbacks are
+ * called. Callbacks left to `NULL` raise an exception in the guest.
+ */
+typedef struct wasi_ephemeral_crypto_common_host {
+    /**
+     * Create a new object to set non-default options.
+     *
+     * Example usage:
+     *
+     * ```rust
+     * let options_handle = options_open(AlgorithmType::Symmetric)?;
+     * options_set(options_handle, "context", context)?;
+     * options_set_u64(options_handle, "threads", 4)?;
+     * let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+     * options_close(options_handle)?;
+     * ```
+     */
+    crypto_errno_t (*options_open)(
+        wasm_exec_env_t exec_env,
+        algorithm_type_t algorithm_type,
+        options_t *result_ptr);
+
+    /**
+     * Destroy an options object.
+     *
+     * Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+     */
+    crypto_errno_t (*options_close)(
+        wasm_exec_env_t exec_env,
+        options_t handle);
+
+    /**
+     * Set or update an option.
+     *
+     * This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    crypto_errno_t (*options_set)(
+        wasm_exec_env_t exec_env,
+        options_t handle,
+        const char *name_ptr,
+        uint32_t name_len,
+        wasi_guest_ptr_t value,
+        size_t_ value_len);
+
+    /**
+     * Set or update an integer option.
+     *
+     * This is used to set algorithm-specific parameters.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    crypto_errno_t (*options_set_u_64)(
+        wasm_exec_env_t exec_env,
+        options_t handle,
+        const char *name_ptr,
+        uint32_t name_len,
+        uint64_t value);
+
+    /**
+     * Set or update a guest-allocated memory that the host can use or return data into.
+     *
+     * This is for example used to set the scratch buffer required by memory-hard functions.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    crypto_errno_t (*options_set_guest_buffer)(
+        wasm_exec_env_t exec_env,
+        options_t handle,
+        const char *name_ptr,
+        uint32_t name_len,
+        wasi_guest_ptr_t buffer,
+        size_t_ buffer_len);
+
+    /**
+     * Return the length of an `array_output` object.
+     *
+     * This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+     */
+    crypto_errno_t (*array_output_len)(
+        wasm_exec_env_t exec_env,
+        array_output_t array_output,
+        size_t_ *result_ptr);
+
+    /**
+     * Copy the content of an `array_output` object into an application-allocated buffer.
+     *
+     * Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+     *
+     * The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+     *
+     * The handle is automatically closed after all the data has been consumed.
+     *
+     * Example usage:
+     *
+     * ```rust
+     * let len = array_output_len(output_handle)?;
+     * let mut out = vec![0u8; len];
+     * array_output_pull(output_handle, &mut out)?;
+     * ```
+     */
+    crypto_errno_t (*array_output_pull)(
+        wasm_exec_env_t exec_env,
+        array_output_t array_output,
+        wasi_guest_ptr_t buf,
+        size_t_ buf_len,
+        size_t_ *result_ptr);
+
+    /**
+     * __(optional)__
+     * Create a context to use a secrets manager.
+     *
+     * The set of required and supported options is defined by the host.
+     *
+     * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+     * This is also an optional import, meaning that the function may not even exist.
+     */
+    crypto_errno_t (*secrets_manager_open)(
+        wasm_exec_env_t exec_env,
+        const opt_options_t *options,
+        secrets_manager_t *result_ptr);
+
+    /**
+     * __(optional)__
+     * Destroy a secrets manager context.
+     *
+     * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+     * This is also an optional import, meaning that the function may not even exist.
+     */
+    crypto_errno_t (*secrets_manager_close)(
+        wasm_exec_env_t exec_env,
+        secrets_manager_t secrets_manager);
+
+    /**
+     * __(optional)__
+     * Invalidate a managed key or key pair given an identifier and a version.
+     *
+     * This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+     *
+     * `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+     *
+     * The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+     *
+     * This is an optional import, meaning that the function may not even exist.
+     */
+    crypto_errno_t (*secrets_manager_invalidate)(
+        wasm_exec_env_t exec_env,
+        secrets_manager_t secrets_manager,
+        wasi_guest_ptr_t key_id,
+        size_t_ key_id_len,
+        version_t key_version);
+} wasi_ephemeral_crypto_common_host_t;
+
+/**
+ * Registers the native symbols of the [wasi_ephemeral_crypto_common] module, calling the callbacks of `host`
+ *
+ * `host` must remain valid as long as the runtime is used.
+ */
+bool wasi_ephemeral_crypto_common_register_natives(const wasi_ephemeral_crypto_common_host_t *host);
+
@@ -0,0 +1,445 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <cstdint>
+#include <cstring>
+#include <tuple>
+#include <cstddef>
+#include <variant>
+
+// namespace WitxCodegenHeader {
+using WasiHandle = int32_t;
+template <typename T> using WasiPtr = T *const;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<unsigned char>;
+
+template <typename R, typename E> using Expected = std::variant<R, E>;
+
+using WasiStringBytesPtr = WasiPtr<unsigned char>;
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t length;
+};
+
+template<typename T>
+struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t length;
+};
+
+template<typename T>
+struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t length;
+};
+
+// }
+
+// ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+/**
+* Error codes.
+**/
+enum class CryptoErrno : uint16_t {
+    SUCCESS = 0,
+    GUEST_ERROR = 1,
+    NOT_IMPLEMENTED = 2,
+    UNSUPPORTED_FEATURE = 3,
+    PROHIBITED_OPERATION = 4,
+    UNSUPPORTED_ENCODING = 5,
+    UNSUPPORTED_ALGORITHM = 6,
+    UNSUPPORTED_OPTION = 7,
+    INVALID_KEY = 8,
+    INVALID_LENGTH = 9,
+    VERIFICATION_FAILED = 10,
+    RNG_ERROR = 11,
+    ALGORITHM_FAILURE = 12,
+    INVALID_SIGNATURE = 13,
+    CLOSED = 14,
+    INVALID_HANDLE = 15,
+    OVERFLOW = 16,
+    INTERNAL_ERROR = 17,
+    TOO_MANY_HANDLES = 18,
+    KEY_NOT_SUPPORTED = 19,
+    KEY_REQUIRED = 20,
+    INVALID_TAG = 21,
+    INVALID_OPERATION = 22,
+    NONCE_REQUIRED = 23,
+    INVALID_NONCE = 24,
+    OPTION_NOT_SET = 25,
+    NOT_FOUND = 26,
+    PARAMETERS_MISSING = 27,
+    IN_PROGRESS = 28,
+    INCOMPATIBLE_KEYS = 29,
+    EXPIRED = 30,
+};
+
+/**
+* Encoding to use for importing or exporting a key pair.
+**/
+enum class KeypairEncoding : uint16_t {
+    RAW = 0,
+    PKCS_8 = 1,
+    PEM = 2,
+    LOCAL = 3,
+};
+
+/**
+* Encoding to use for importing or exporting a public key.
+**/
+enum class PublickeyEncoding : uint16_t {
+    RAW = 0,
+    PKCS_8 = 1,
+    PEM = 2,
+    SEC = 3,
+    COMPRESSED_SEC = 4,
+    LOCAL = 5,
+};
+
+/**
+* Encoding to use for importing or exporting a secret key.
+**/
+enum class SecretkeyEncoding : uint16_t {
+    RAW = 0,
+    PKCS_8 = 1,
+    PEM = 2,
+    SEC = 3,
+    COMPRESSED_SEC = 4,
+    LOCAL = 5,
+};
+
+/**
+* Encoding to use for importing or exporting a signature.
+**/
+enum class SignatureEncoding : uint16_t {
+    RAW = 0,
+    DER = 1,
+};
+
+/**
+* An algorithm category.
+**/
+enum class AlgorithmType : uint16_t {
+    SIGNATURES = 0,
+    SYMMETRIC = 1,
+    KEY_EXCHANGE = 2,
+};
+
+/**
+* Version of a managed key.
+* 
+* A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+**/
+using Version = uint64_t;
+
+namespace VERSION {
+    const Version UNSPECIFIED = 0xff00000000000000;
+    const Version LATEST = 0xff00000000000001;
+    const Version ALL = 0xff00000000000002;
+}
+
+/**
+* Size of a value.
+**/
+using Size = size_t;
+
+/**
+* A UNIX timestamp, in seconds since 01/01/1970.
+**/
+using Timestamp = uint64_t;
+
+/**
+* A 64-bit value
+**/
+using U64 = uint64_t;
+
+/**
+* Handle for functions returning output whose size may be large or not known in advance.
+* 
+* An `array_output` object contains a host-allocated byte array.
+* 
+* A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+* In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+* 
+* An `array_output` handle is automatically closed after its full content has been consumed.
+**/
+enum class ArrayOutput : WasiHandle {};
+
+/**
+* A set of options.
+* 
+* This type is used to set non-default parameters.
+* 
+* The exact set of allowed options depends on the algorithm being used.
+**/
+enum class Options : WasiHandle {};
+
+/**
+* A handle to the optional secrets management facilities offered by a host.
+* 
+* This is used to generate, retrieve and invalidate managed keys.
+**/
+enum class SecretsManager : WasiHandle {};
+
+/**
+* A key pair.
+**/
+enum class Keypair : WasiHandle {};
+
+/**
+* A state to absorb data to be signed.
+* 
+* After a signature has been computed or verified, the state remains valid for further operations.
+* 
+* A subsequent signature would sign all the data accumulated since the creation of the state object.
+**/
+enum class SignatureState : WasiHandle {};
+
+/**
+* A signature.
+**/
+enum class Signature : WasiHandle {};
+
+/**
+* A public key, for key exchange and signature verification.
+**/
+enum class Publickey : WasiHandle {};
+
+/**
+* A secret key, for key exchange mechanisms.
+**/
+enum class Secretkey : WasiHandle {};
+
+/**
+* A state to absorb signed data to be verified.
+**/
+enum class SignatureVerificationState : WasiHandle {};
+
+/**
+* A state to perform symmetric operations.
+* 
+* The state is not reset nor invalidated after an option has been performed.
+* Incremental updates and sessions are thus supported.
+**/
+enum class SymmetricState : WasiHandle {};
+
+/**
+* A symmetric key.
+* 
+* The key can be imported from raw bytes, or can be a reference to a managed key.
+* 
+* If it was imported, the host will wipe it from memory as soon as the handle is closed.
+**/
+enum class SymmetricKey : WasiHandle {};
+
+/**
+* An authentication tag.
+* 
+* This is an object returned by functions computing authentication tags.
+* 
+* A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+* 
+* This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+* 
+* The host is reponsible for securely wiping them from memory on close.
+**/
+enum class SymmetricTag : WasiHandle {};
+
+/**
+* Options index, only required by the Interface Types translation layer.
+**/
+enum class OptOptionsU : uint8_t {
+    SOME = 0,
+    NONE = 1,
+};
+
+/**
+* An optional options set.
+* 
+* This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+**/
+union OptOptionsMember {
+    Options some; // if tag=0
+    // none: (no associated content) if tag=1
+};
+
+struct __attribute__((packed)) OptOptions {
+    uint8_t tag;
+    uint8_t __pad8_0;
+    uint16_t __pad16_0;
+    OptOptionsMember member;
+};
+
+
+/**
+* Symmetric key index, only required by the Interface Types translation layer.
+**/
+enum class OptSymmetricKeyU : uint8_t {
+    SOME = 0,
+    NONE = 1,
+};
+
+/**
+* An optional symmetric key.
+* 
+* This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+**/
+union OptSymmetricKeyMember {
+    SymmetricKey some; // if tag=0
+    // none: (no associated content) if tag=1
+};
+
+struct __attribute__((packed)) OptSymmetricKey {
+    uint8_t tag;
+    uint8_t __pad8_0;
+    uint16_t __pad16_0;
+    OptSymmetricKeyMember member;
+};
+
+
+/**
+* Create a new object to set non-default options.
+* 
+* Example usage:
+* 
+* ```rust
+* let options_handle = options_open(AlgorithmType::Symmetric)?;
+* options_set(options_handle, "context", context)?;
+* options_set_u64(options_handle, "threads", 4)?;
+* let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+* options_close(options_handle)?;
+* ```
+**/
+Expected<Options, CryptoErrno> options_open(
+    AlgorithmType algorithm_type
+);
+
+/**
+* Destroy an options object.
+* 
+* Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+**/
+Expected<std::monostate, CryptoErrno> options_close(
+    Options handle
+);
+
+/**
+* Set or update an option.
+* 
+* This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+* 
+* This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+**/
+Expected<std::monostate, CryptoErrno> options_set(
+    Options handle,
+    WasiPtr<unsigned char> name_ptr,
+    size_t name_len,
+    WasiPtr<uint8_t> value,
+    Size value_len
+);
+
+/**
+* Set or update an integer option.
+* 
+* This is used to set algorithm-specific parameters.
+* 
+* This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+**/
+Expected<std::monostate, CryptoErrno> options_set_u_64(
+    Options handle,
+    WasiPtr<unsigned char> name_ptr,
+    size_t name_len,
+    uint64_t value
+);
+
+/**
+* Set or update a guest-allocated memory that the host can use or return data into.
+* 
+* This is for example used to set the scratch buffer required by memory-hard functions.
+* 
+* This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+**/
+Expected<std::monostate, CryptoErrno> options_set_guest_buffer(
+    Options handle,
+    WasiPtr<unsigned char> name_ptr,
+    size_t name_len,
+    WasiMutPtr<uint8_t> buffer,
+    Size buffer_len
+);
+
+/**
+* Return the length of an `array_output` object.
+* 
+* This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+**/
+Expected<Size, CryptoErrno> array_output_len(
+    ArrayOutput array_output
+);
+
+/**
+* Copy the content of an `array_output` object into an application-allocated buffer.
+* 
+* Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+* 
+* The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+* 
+* The handle is automatically closed after all the data has been consumed.
+* 
+* Example usage:
+* 
+* ```rust
+* let len = array_output_len(output_handle)?;
+* let mut out = vec![0u8; len];
+* array_output_pull(output_handle, &mut out)?;
+* ```
+**/
+Expected<Size, CryptoErrno> array_output_pull(
+    ArrayOutput array_output,
+    WasiMutPtr<uint8_t> buf,
+    Size buf_len
+);
+
+/**
+* __(optional)__
+* Create a context to use a secrets manager.
+* 
+* The set of required and supported options is defined by the host.
+* 
+* The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+* This is also an optional import, meaning that the function may not even exist.
+**/
+Expected<SecretsManager, CryptoErrno> secrets_manager_open(
+    OptOptions options
+);
+
+/**
+* __(optional)__
+* Destroy a secrets manager context.
+* 
+* The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+* This is also an optional import, meaning that the function may not even exist.
+**/
+Expected<std::monostate, CryptoErrno> secrets_manager_close(
+    SecretsManager secrets_manager
+);
+
+/**
+* __(optional)__
+* Invalidate a managed key or key pair given an identifier and a version.
+* 
+* This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+* 
+* `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+* 
+* The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+* 
+* This is an optional import, meaning that the function may not even exist.
+**/
+Expected<std::monostate, CryptoErrno> secrets_manager_invalidate(
+    SecretsManager secrets_manager,
+    WasiPtr<uint8_t> key_id,
+    Size key_id_len,
+    Version key_version
+);
+
@@ -0,0 +1,679 @@
+// Code generated by witx-codegen. DO NOT EDIT.
+
+package wasiephemeralcryptocommon
+
+import (
+	"context"
+	"errors"
+	"fmt"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiSlice is a list of values in the guest memory
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiSize
+}
+
+// WasiError is returned by a host implementation to return an error code to the guest
+type WasiError struct {
+	Errno uint32
+}
+
+func (e *WasiError) Error() string {
+	return fmt.Sprintf("WASI error %d", e.Errno)
+}
+
+// errno returns the error code of a WasiError, and panics on other errors, so that the
+// guest traps
+func errno(err error) uint64 {
+	var wasiErr *WasiError
+	if errors.As(err, &wasiErr) {
+		return api.EncodeU32(wasiErr.Errno)
+	}
+	panic(err)
+}
+
+func boolToU8(v bool) uint8 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// errOutOfBounds is raised when the guest passes a pointer outside of its memory
+var errOutOfBounds = errors.New("out of bounds memory access")
+
+// GuestAllocator allocates size bytes aligned to align in the memory of the guest module,
+// and returns their address
+type GuestAllocator func(ctx context.Context, mod api.Module, size, align uint32) (uint32, error)
+
+// guestMemory accesses the memory of the guest, and panics on invalid pointers
+type guestMemory struct {
+	api.Memory
+	// alloc is only set in functions returning strings or byte slices
+	alloc func(size, align uint32) uint32
+}
+
+// allocatingMemory returns a guestMemory that can allocate memory in the guest, and
+// panics if the allocation fails
+func allocatingMemory(ctx context.Context, mod api.Module, alloc GuestAllocator) guestMemory {
+	return guestMemory{mod.Memory(), func(size, align uint32) uint32 {
+		ptr, err := alloc(ctx, mod, size, align)
+		if err != nil {
+			panic(err)
+		}
+		return ptr
+	}}
+}
+
+func (m guestMemory) check(ok bool) {
+	if !ok {
+		panic(errOutOfBounds)
+	}
+}
+
+func (m guestMemory) u8(ptr uint32) uint8 {
+	v, ok := m.ReadByte(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU8(ptr uint32, v uint8) {
+	m.check(m.WriteByte(ptr, v))
+}
+
+func (m guestMemory) u16(ptr uint32) uint16 {
+	v, ok := m.ReadUint16Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU16(ptr uint32, v uint16) {
+	m.check(m.WriteUint16Le(ptr, v))
+}
+
+func (m guestMemory) u32(ptr uint32) uint32 {
+	v, ok := m.ReadUint32Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU32(ptr uint32, v uint32) {
+	m.check(m.WriteUint32Le(ptr, v))
+}
+
+func (m guestMemory) u64(ptr uint32) uint64 {
+	v, ok := m.ReadUint64Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU64(ptr uint32, v uint64) {
+	m.check(m.WriteUint64Le(ptr, v))
+}
+
+func (m guestMemory) f32(ptr uint32) float32 {
+	v, ok := m.ReadFloat32Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putF32(ptr uint32, v float32) {
+	m.check(m.WriteFloat32Le(ptr, v))
+}
+
+func (m guestMemory) f64(ptr uint32) float64 {
+	v, ok := m.ReadFloat64Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putF64(ptr uint32, v float64) {
+	m.check(m.WriteFloat64Le(ptr, v))
+}
+
+// bytes returns a view of the guest memory
+func (m guestMemory) bytes(ptr, len uint32) []byte {
+	b, ok := m.Read(ptr, len)
+	m.check(ok)
+	return b
+}
+
+func (m guestMemory) copyBytes(ptr, len uint32) []byte {
+	return append([]byte(nil), m.bytes(ptr, len)...)
+}
+
+func (m guestMemory) string(ptr, len uint32) string {
+	return string(m.bytes(ptr, len))
+}
+
+// putBytes copies data to memory allocated in the guest, and stores its address and
+// length at ptr
+func (m guestMemory) putBytes(ptr uint32, data []byte) {
+	dataPtr := m.alloc(uint32(len(data)), 1)
+	m.check(m.Write(dataPtr, data))
+	m.putU32(ptr, dataPtr)
+	m.putU32(ptr+4, uint32(len(data)))
+}
+
+// ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+// Error codes.
+type CryptoErrno uint16
+
+const (
+	CryptoErrnoSuccess              CryptoErrno = 0
+	CryptoErrnoGuestError           CryptoErrno = 1
+	CryptoErrnoNotImplemented       CryptoErrno = 2
+	CryptoErrnoUnsupportedFeature   CryptoErrno = 3
+	CryptoErrnoProhibitedOperation  CryptoErrno = 4
+	CryptoErrnoUnsupportedEncoding  CryptoErrno = 5
+	CryptoErrnoUnsupportedAlgorithm CryptoErrno = 6
+	CryptoErrnoUnsupportedOption    CryptoErrno = 7
+	CryptoErrnoInvalidKey           CryptoErrno = 8
+	CryptoErrnoInvalidLength        CryptoErrno = 9
+	CryptoErrnoVerificationFailed   CryptoErrno = 10
+	CryptoErrnoRngError             CryptoErrno = 11
+	CryptoErrnoAlgorithmFailure     CryptoErrno = 12
+	CryptoErrnoInvalidSignature     CryptoErrno = 13
+	CryptoErrnoClosed               CryptoErrno = 14
+	CryptoErrnoInvalidHandle        CryptoErrno = 15
+	CryptoErrnoOverflow             CryptoErrno = 16
+	CryptoErrnoInternalError        CryptoErrno = 17
+	CryptoErrnoTooManyHandles       CryptoErrno = 18
+	CryptoErrnoKeyNotSupported      CryptoErrno = 19
+	CryptoErrnoKeyRequired          CryptoErrno = 20
+	CryptoErrnoInvalidTag           CryptoErrno = 21
+	CryptoErrnoInvalidOperation     CryptoErrno = 22
+	CryptoErrnoNonceRequired        CryptoErrno = 23
+	CryptoErrnoInvalidNonce         CryptoErrno = 24
+	CryptoErrnoOptionNotSet         CryptoErrno = 25
+	CryptoErrnoNotFound             CryptoErrno = 26
+	CryptoErrnoParametersMissing    CryptoErrno = 27
+	CryptoErrnoInProgress           CryptoErrno = 28
+	CryptoErrnoIncompatibleKeys     CryptoErrno = 29
+	CryptoErrnoExpired              CryptoErrno = 30
+)
+
+// Encoding to use for importing or exporting a key pair.
+type KeypairEncoding uint16
+
+const (
+	KeypairEncodingRaw   KeypairEncoding = 0
+	KeypairEncodingPkcs8 KeypairEncoding = 1
+	KeypairEncodingPem   KeypairEncoding = 2
+	KeypairEncodingLocal KeypairEncoding = 3
+)
+
+// Encoding to use for importing or exporting a public key.
+type PublickeyEncoding uint16
+
+const (
+	PublickeyEncodingRaw           PublickeyEncoding = 0
+	PublickeyEncodingPkcs8         PublickeyEncoding = 1
+	PublickeyEncodingPem           PublickeyEncoding = 2
+	PublickeyEncodingSec           PublickeyEncoding = 3
+	PublickeyEncodingCompressedSec PublickeyEncoding = 4
+	PublickeyEncodingLocal         PublickeyEncoding = 5
+)
+
+// Encoding to use for importing or exporting a secret key.
+type SecretkeyEncoding uint16
+
+const (
+	SecretkeyEncodingRaw           SecretkeyEncoding = 0
+	SecretkeyEncodingPkcs8         SecretkeyEncoding = 1
+	SecretkeyEncodingPem           SecretkeyEncoding = 2
+	SecretkeyEncodingSec           SecretkeyEncoding = 3
+	SecretkeyEncodingCompressedSec SecretkeyEncoding = 4
+	SecretkeyEncodingLocal         SecretkeyEncoding = 5
+)
+
+// Encoding to use for importing or exporting a signature.
+type SignatureEncoding uint16
+
+const (
+	SignatureEncodingRaw SignatureEncoding = 0
+	SignatureEncodingDer SignatureEncoding = 1
+)
+
+// An algorithm category.
+type AlgorithmType uint16
+
+const (
+	AlgorithmTypeSignatures  AlgorithmType = 0
+	AlgorithmTypeSymmetric   AlgorithmType = 1
+	AlgorithmTypeKeyExchange AlgorithmType = 2
+)
+
+// Version of a managed key.
+//
+// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+type Version = uint64
+
+const (
+	VersionUnspecified Version = 0xff00000000000000
+	VersionLatest      Version = 0xff00000000000001
+	VersionAll         Version = 0xff00000000000002
+)
+
+// Size of a value.
+type Size = WasiSize
+
+// A UNIX timestamp, in seconds since 01/01/1970.
+type Timestamp = uint64
+
+// A 64-bit value
+type U64 = uint64
+
+// Handle for functions returning output whose size may be large or not known in advance.
+//
+// An `array_output` object contains a host-allocated byte array.
+//
+// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+//
+// An `array_output` handle is automatically closed after its full content has been consumed.
+type ArrayOutput = WasiHandle
+
+// A set of options.
+//
+// This type is used to set non-default parameters.
+//
+// The exact set of allowed options depends on the algorithm being used.
+type Options = WasiHandle
+
+// A handle to the optional secrets management facilities offered by a host.
+//
+// This is used to generate, retrieve and invalidate managed keys.
+type SecretsManager = WasiHandle
+
+// A key pair.
+type Keypair = WasiHandle
+
+// A state to absorb data to be signed.
+//
+// After a signature has been computed or verified, the state remains valid for further operations.
+//
+// A subsequent signature would sign all the data accumulated since the creation of the state object.
+type SignatureState = WasiHandle
+
+// A signature.
+type Signature = WasiHandle
+
+// A public key, for key exchange and signature verification.
+type Publickey = WasiHandle
+
+// A secret key, for key exchange mechanisms.
+type Secretkey = WasiHandle
+
+// A state to absorb signed data to be verified.
+type SignatureVerificationState = WasiHandle
+
+// A state to perform symmetric operations.
+//
+// The state is not reset nor invalidated after an option has been performed.
+// Incremental updates and sessions are thus supported.
+type SymmetricState = WasiHandle
+
+// A symmetric key.
+//
+// The key can be imported from raw bytes, or can be a reference to a managed key.
+//
+// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+type SymmetricKey = WasiHandle
+
+// An authentication tag.
+//
+// This is an object returned by functions computing authentication tags.
+//
+// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+//
+// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+//
+// The host is reponsible for securely wiping them from memory on close.
+type SymmetricTag = WasiHandle
+
+// Options index, only required by the Interface Types translation layer.
+type OptOptionsU uint8
+
+const (
+	OptOptionsUSome OptOptionsU = 0
+	OptOptionsUNone OptOptionsU = 1
+)
+
+// An optional options set.
+//
+// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+type OptOptions struct {
+	Tag  uint8
+	Some Options
+}
+
+const (
+	OptOptionsSome = 0
+	OptOptionsNone = 1
+)
+
+func readOptOptions(m guestMemory, ptr uint32) OptOptions {
+	v := OptOptions{Tag: uint8(m.u8(ptr))}
+	switch v.Tag {
+	case OptOptionsSome:
+		v.Some = Options(m.u32(ptr+4))
+	case OptOptionsNone:
+	default:
+		panic(fmt.Sprintf("invalid tag for OptOptions: %d", v.Tag))
+	}
+	return v
+}
+
+func writeOptOptions(m guestMemory, ptr uint32, v OptOptions) {
+	m.putU8(ptr, uint8(v.Tag))
+	switch v.Tag {
+	case OptOptionsSome:
+		m.putU32(ptr+4, uint32(v.Some))
+	}
+}
+
+// Symmetric key index, only required by the Interface Types translation layer.
+type OptSymmetricKeyU uint8
+
+const (
+	OptSymmetricKeyUSome OptSymmetricKeyU = 0
+	OptSymmetricKeyUNone OptSymmetricKeyU = 1
+)
+
+// An optional symmetric key.
+//
+// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+type OptSymmetricKey struct {
+	Tag  uint8
+	Some SymmetricKey
+}
+
+const (
+	OptSymmetricKeySome = 0
+	OptSymmetricKeyNone = 1
+)
+
+func readOptSymmetricKey(m guestMemory, ptr uint32) OptSymmetricKey {
+	v := OptSymmetricKey{Tag: uint8(m.u8(ptr))}
+	switch v.Tag {
+	case OptSymmetricKeySome:
+		v.Some = SymmetricKey(m.u32(ptr+4))
+	case OptSymmetricKeyNone:
+	default:
+		panic(fmt.Sprintf("invalid tag for OptSymmetricKey: %d", v.Tag))
+	}
+	return v
+}
+
+func writeOptSymmetricKey(m guestMemory, ptr uint32, v OptSymmetricKey) {
+	m.putU8(ptr, uint8(v.Tag))
+	switch v.Tag {
+	case OptSymmetricKeySome:
+		m.putU32(ptr+4, uint32(v.Some))
+	}
+}
+
+// WasiEphemeralCryptoCommon is the host implementation of the [wasi_ephemeral_crypto_common] module
+//
+// The methods receive the parameters decoded from the guest memory, and return the
+// results. Errors are returned to the guest by returning a *WasiError.
+type WasiEphemeralCryptoCommon interface {
+	// Create a new object to set non-default options.
+	//
+	// Example usage:
+	//
+	// ```rust
+	// let options_handle = options_open(AlgorithmType::Symmetric)?;
+	// options_set(options_handle, "context", context)?;
+	// options_set_u64(options_handle, "threads", 4)?;
+	// let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+	// options_close(options_handle)?;
+	// ```
+	OptionsOpen(ctx context.Context, algorithmType AlgorithmType) (Options, error)
+
+	// Destroy an options object.
+	//
+	// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+	OptionsClose(ctx context.Context, handle Options) error
+
+	// Set or update an option.
+	//
+	// This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+	//
+	// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+	OptionsSet(ctx context.Context, handle Options, name string, value WasiPtr, valueLen Size) error
+
+	// Set or update an integer option.
+	//
+	// This is used to set algorithm-specific parameters.
+	//
+	// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+	OptionsSetU64(ctx context.Context, handle Options, name string, value uint64) error
+
+	// Set or update a guest-allocated memory that the host can use or return data into.
+	//
+	// This is for example used to set the scratch buffer required by memory-hard functions.
+	//
+	// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+	OptionsSetGuestBuffer(ctx context.Context, handle Options, name string, buffer WasiPtr, bufferLen Size) error
+
+	// Return the length of an `array_output` object.
+	//
+	// This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+	ArrayOutputLen(ctx context.Context, arrayOutput ArrayOutput) (Size, error)
+
+	// Copy the content of an `array_output` object into an application-allocated buffer.
+	//
+	// Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+	//
+	// The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+	//
+	// The handle is automatically closed after all the data has been consumed.
+	//
+	// Example usage:
+	//
+	// ```rust
+	// let len = array_output_len(output_handle)?;
+	// let mut out = vec![0u8; len];
+	// array_output_pull(output_handle, &mut out)?;
+	// ```
+	ArrayOutputPull(ctx context.Context, arrayOutput ArrayOutput, buf WasiPtr, bufLen Size) (Size, error)
+
+	// __(optional)__
+	// Create a context to use a secrets manager.
+	//
+	// The set of required and supported options is defined by the host.
+	//
+	// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+	// This is also an optional import, meaning that the function may not even exist.
+	SecretsManagerOpen(ctx context.Context, options OptOptions) (SecretsManager, error)
+
+	// __(optional)__
+	// Destroy a secrets manager context.
+	//
+	// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+	// This is also an optional import, meaning that the function may not even exist.
+	SecretsManagerClose(ctx context.Context, secretsManager SecretsManager) error
+
+	// __(optional)__
+	// Invalidate a managed key or key pair given an identifier and a version.
+	//
+	// This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+	//
+	// `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+	//
+	// The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+	//
+	// This is an optional import, meaning that the function may not even exist.
+	SecretsManagerInvalidate(ctx context.Context, secretsManager SecretsManager, keyId WasiPtr, keyIdLen Size, keyVersion Version) error
+}
+
+// Instantiate adds the [wasi_ephemeral_crypto_common] module to the runtime, calling the methods of impl
+func Instantiate(ctx context.Context, r wazero.Runtime, impl WasiEphemeralCryptoCommon) (api.Module, error) {
+	b := r.NewHostModuleBuilder("wasi_ephemeral_crypto_common")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.OptionsOpen(ctx, AlgorithmType(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[1]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("algorithm_type", "result_ptr").
+		Export("options_open")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.OptionsClose(ctx, Options(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle").
+		Export("options_close")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			err := impl.OptionsSet(
+				ctx,
+				Options(api.DecodeU32(stack[0])),
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+				WasiPtr(api.DecodeU32(stack[3])),
+				Size(api.DecodeU32(stack[4])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "value", "value_len").
+		Export("options_set")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			err := impl.OptionsSetU64(
+				ctx,
+				Options(api.DecodeU32(stack[0])),
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+				stack[3],
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI64}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "value").
+		Export("options_set_u64")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			err := impl.OptionsSetGuestBuffer(
+				ctx,
+				Options(api.DecodeU32(stack[0])),
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+				WasiPtr(api.DecodeU32(stack[3])),
+				Size(api.DecodeU32(stack[4])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "buffer", "buffer_len").
+		Export("options_set_guest_buffer")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.ArrayOutputLen(ctx, ArrayOutput(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[1]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("array_output", "result_ptr").
+		Export("array_output_len")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.ArrayOutputPull(
+				ctx,
+				ArrayOutput(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[3]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("array_output", "buf", "buf_len", "result_ptr").
+		Export("array_output_pull")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SecretsManagerOpen(
+				ctx,
+				readOptOptions(m, api.DecodeU32(stack[0])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[1]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("options", "result_ptr").
+		Export("secrets_manager_open")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SecretsManagerClose(ctx, SecretsManager(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager").
+		Export("secrets_manager_close")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SecretsManagerInvalidate(
+				ctx,
+				SecretsManager(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+				stack[3],
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI64}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "key_id", "key_id_len", "key_version").
+		Export("secrets_manager_invalidate")
+	return b.Instantiate(ctx)
+}
@@ -0,0 +1,691 @@
+
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+import ctypes
+import enum
+
+import wasmtime
+
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host implementation to return an error code to the guest"""
+
+    def __init__(self, errno):
+        super().__init__(errno)
+        self.errno = errno
+
+
+def read_bytes(caller, ptr, length):
+    """Copies length bytes from the guest memory"""
+    ptr &= 0xFFFFFFFF
+    return bytes(caller["memory"].read(caller, ptr, ptr + length))
+
+
+def write_bytes(caller, ptr, data):
+    """Copies data to the guest memory"""
+    caller["memory"].write(caller, data, ptr & 0xFFFFFFFF)
+
+
+def read_string(caller, ptr, length):
+    """Decodes an UTF-8 string from the guest memory"""
+    return read_bytes(caller, ptr, length).decode("utf-8")
+
+
+def read_value(caller, ptr, ctype):
+    """Reads a value of the given ctypes type from the guest memory"""
+    value = ctype.from_buffer_copy(read_bytes(caller, ptr, ctypes.sizeof(ctype)))
+    if isinstance(value, (ctypes.Structure, ctypes.Union)):
+        return value
+    return value.value
+
+
+def read_list(caller, ptr, length, ctype):
+    """Reads an array of values of the given ctypes type from the guest memory"""
+    array = (ctype * length).from_buffer_copy(
+        read_bytes(caller, ptr, ctypes.sizeof(ctype) * length)
+    )
+    return list(array)
+
+
+def write_value(caller, ptr, ctype, value):
+    """Writes a value of the given ctypes type to the guest memory"""
+    if not isinstance(value, ctype):
+        value = ctype(value)
+    write_bytes(caller, ptr, bytes(value))
+
+
+def write_allocated_bytes(caller, alloc, ptr, data):
+    """Copies data to memory allocated in the guest, and stores its address and
+    length at ptr"""
+    data_ptr = alloc(caller, len(data), 1)
+    write_bytes(caller, data_ptr, data)
+    write_value(caller, ptr, WasiSlice, WasiSlice(data_ptr, len(data)))
+
+
+def write_string(caller, alloc, ptr, value):
+    """Copies an UTF-8 string to memory allocated in the guest, and stores its
+    address and length at ptr"""
+    write_allocated_bytes(caller, alloc, ptr, value.encode("utf-8"))
+
+
+class GuestBuffer:
+    """A buffer in the guest memory, that the host can write to"""
+
+    def __init__(self, caller, ptr, length):
+        self.caller = caller
+        self.ptr = ptr
+        self.length = length
+
+    def __len__(self):
+        return self.length
+
+    def read(self):
+        return read_bytes(self.caller, self.ptr, self.length)
+
+    def write(self, data, offset=0):
+        if offset + len(data) > self.length:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        write_bytes(self.caller, self.ptr + offset, data)
+
+
+# ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+
+# Error codes.
+class CryptoErrno(enum.IntEnum):
+    SUCCESS = 0
+    GUEST_ERROR = 1
+    NOT_IMPLEMENTED = 2
+    UNSUPPORTED_FEATURE = 3
+    PROHIBITED_OPERATION = 4
+    UNSUPPORTED_ENCODING = 5
+    UNSUPPORTED_ALGORITHM = 6
+    UNSUPPORTED_OPTION = 7
+    INVALID_KEY = 8
+    INVALID_LENGTH = 9
+    VERIFICATION_FAILED = 10
+    RNG_ERROR = 11
+    ALGORITHM_FAILURE = 12
+    INVALID_SIGNATURE = 13
+    CLOSED = 14
+    INVALID_HANDLE = 15
+    OVERFLOW = 16
+    INTERNAL_ERROR = 17
+    TOO_MANY_HANDLES = 18
+    KEY_NOT_SUPPORTED = 19
+    KEY_REQUIRED = 20
+    INVALID_TAG = 21
+    INVALID_OPERATION = 22
+    NONCE_REQUIRED = 23
+    INVALID_NONCE = 24
+    OPTION_NOT_SET = 25
+    NOT_FOUND = 26
+    PARAMETERS_MISSING = 27
+    IN_PROGRESS = 28
+    INCOMPATIBLE_KEYS = 29
+    EXPIRED = 30
+
+
+# Encoding to use for importing or exporting a key pair.
+class KeypairEncoding(enum.IntEnum):
+    RAW = 0
+    PKCS_8 = 1
+    PEM = 2
+    LOCAL = 3
+
+
+# Encoding to use for importing or exporting a public key.
+class PublickeyEncoding(enum.IntEnum):
+    RAW = 0
+    PKCS_8 = 1
+    PEM = 2
+    SEC = 3
+    COMPRESSED_SEC = 4
+    LOCAL = 5
+
+
+# Encoding to use for importing or exporting a secret key.
+class SecretkeyEncoding(enum.IntEnum):
+    RAW = 0
+    PKCS_8 = 1
+    PEM = 2
+    SEC = 3
+    COMPRESSED_SEC = 4
+    LOCAL = 5
+
+
+# Encoding to use for importing or exporting a signature.
+class SignatureEncoding(enum.IntEnum):
+    RAW = 0
+    DER = 1
+
+
+# An algorithm category.
+class AlgorithmType(enum.IntEnum):
+    SIGNATURES = 0
+    SYMMETRIC = 1
+    KEY_EXCHANGE = 2
+
+
+# Version of a managed key.
+#
+# A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+Version = ctypes.c_uint64
+
+
+class VERSION:
+    UNSPECIFIED = 0xff00000000000000
+    LATEST = 0xff00000000000001
+    ALL = 0xff00000000000002
+
+
+# Size of a value.
+Size = WasiSize
+
+
+# A UNIX timestamp, in seconds since 01/01/1970.
+Timestamp = ctypes.c_uint64
+
+
+# A 64-bit value
+U64 = ctypes.c_uint64
+
+
+# Handle for functions returning output whose size may be large or not known in advance.
+#
+# An `array_output` object contains a host-allocated byte array.
+#
+# A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+# In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+#
+# An `array_output` handle is automatically closed after its full content has been consumed.
+ArrayOutput = WasiHandle
+
+
+# A set of options.
+#
+# This type is used to set non-default parameters.
+#
+# The exact set of allowed options depends on the algorithm being used.
+Options = WasiHandle
+
+
+# A handle to the optional secrets management facilities offered by a host.
+#
+# This is used to generate, retrieve and invalidate managed keys.
+SecretsManager = WasiHandle
+
+
+# A key pair.
+Keypair = WasiHandle
+
+
+# A state to absorb data to be signed.
+#
+# After a signature has been computed or verified, the state remains valid for further operations.
+#
+# A subsequent signature would sign all the data accumulated since the creation of the state object.
+SignatureState = WasiHandle
+
+
+# A signature.
+Signature = WasiHandle
+
+
+# A public key, for key exchange and signature verification.
+Publickey = WasiHandle
+
+
+# A secret key, for key exchange mechanisms.
+Secretkey = WasiHandle
+
+
+# A state to absorb signed data to be verified.
+SignatureVerificationState = WasiHandle
+
+
+# A state to perform symmetric operations.
+#
+# The state is not reset nor invalidated after an option has been performed.
+# Incremental updates and sessions are thus supported.
+SymmetricState = WasiHandle
+
+
+# A symmetric key.
+#
+# The key can be imported from raw bytes, or can be a reference to a managed key.
+#
+# If it was imported, the host will wipe it from memory as soon as the handle is closed.
+SymmetricKey = WasiHandle
+
+
+# An authentication tag.
+#
+# This is an object returned by functions computing authentication tags.
+#
+# A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+#
+# This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+#
+# The host is reponsible for securely wiping them from memory on close.
+SymmetricTag = WasiHandle
+
+
+# Options index, only required by the Interface Types translation layer.
+class OptOptionsU(enum.IntEnum):
+    SOME = 0
+    NONE = 1
+
+
+# An optional options set.
+#
+# This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+class OptOptionsMember(ctypes.Union):
+    _pack_ = 1
+    _fields_ = [
+        ("some", Options),
+        ("__size", ctypes.c_uint8 * 4),
+    ]
+
+
+class OptOptions(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint8),
+        ("__pad1", ctypes.c_uint8 * 3),
+        ("member", OptOptionsMember),
+    ]
+
+    def is_some(self):
+        return self.tag == 0
+
+    def is_none(self):
+        return self.tag == 1
+
+
+# Symmetric key index, only required by the Interface Types translation layer.
+class OptSymmetricKeyU(enum.IntEnum):
+    SOME = 0
+    NONE = 1
+
+
+# An optional symmetric key.
+#
+# This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+class OptSymmetricKeyMember(ctypes.Union):
+    _pack_ = 1
+    _fields_ = [
+        ("some", SymmetricKey),
+        ("__size", ctypes.c_uint8 * 4),
+    ]
+
+
+class OptSymmetricKey(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint8),
+        ("__pad1", ctypes.c_uint8 * 3),
+        ("member", OptSymmetricKeyMember),
+    ]
+
+    def is_some(self):
+        return self.tag == 0
+
+    def is_none(self):
+        return self.tag == 1
+
+
+class WasiEphemeralCryptoCommonHost:
+    """Host implementation of the [wasi_ephemeral_crypto_common] module
+
+    The methods receive the parameters decoded from the guest memory, and return
+    the results. Errors are returned to the guest by raising a WasiError.
+    """
+
+    def options_open(self, algorithm_type):
+        """Create a new object to set non-default options.
+
+        Example usage:
+
+        ```rust
+        let options_handle = options_open(AlgorithmType::Symmetric)?;
+        options_set(options_handle, "context", context)?;
+        options_set_u64(options_handle, "threads", 4)?;
+        let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+        options_close(options_handle)?;
+        ```
+        """
+        raise NotImplementedError("options_open")
+
+    def options_close(self, handle):
+        """Destroy an options object.
+
+        Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+        """
+        raise NotImplementedError("options_close")
+
+    def options_set(self, handle, name, value, value_len):
+        """Set or update an option.
+
+        This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+
+        This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+        """
+        raise NotImplementedError("options_set")
+
+    def options_set_u_64(self, handle, name, value):
+        """Set or update an integer option.
+
+        This is used to set algorithm-specific parameters.
+
+        This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+        """
+        raise NotImplementedError("options_set_u64")
+
+    def options_set_guest_buffer(self, handle, name, buffer, buffer_len):
+        """Set or update a guest-allocated memory that the host can use or return data into.
+
+        This is for example used to set the scratch buffer required by memory-hard functions.
+
+        This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+        """
+        raise NotImplementedError("options_set_guest_buffer")
+
+    def array_output_len(self, array_output):
+        """Return the length of an `array_output` object.
+
+        This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+        """
+        raise NotImplementedError("array_output_len")
+
+    def array_output_pull(self, array_output, buf, buf_len):
+        """Copy the content of an `array_output` object into an application-allocated buffer.
+
+        Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+
+        The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+
+        The handle is automatically closed after all the data has been consumed.
+
+        Example usage:
+
+        ```rust
+        let len = array_output_len(output_handle)?;
+        let mut out = vec![0u8; len];
+        array_output_pull(output_handle, &mut out)?;
+        ```
+        """
+        raise NotImplementedError("array_output_pull")
+
+    def secrets_manager_open(self, options):
+        """__(optional)__
+        Create a context to use a secrets manager.
+
+        The set of required and supported options is defined by the host.
+
+        The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+        This is also an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("secrets_manager_open")
+
+    def secrets_manager_close(self, secrets_manager):
+        """__(optional)__
+        Destroy a secrets manager context.
+
+        The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+        This is also an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("secrets_manager_close")
+
+    def secrets_manager_invalidate(
+        self,
+        secrets_manager,
+        key_id,
+        key_id_len,
+        key_version,
+    ):
+        """__(optional)__
+        Invalidate a managed key or key pair given an identifier and a version.
+
+        This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+
+        `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+
+        The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+
+        This is an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("secrets_manager_invalidate")
+
+
+def add_wasi_ephemeral_crypto_common_to_linker(linker, host):
+    """Defines the functions of the [wasi_ephemeral_crypto_common] module in a wasmtime.Linker
+
+    The functions decode their parameters from the memory exported by the guest,
+    and call the methods of host.
+    """
+
+    i32 = wasmtime.ValType.i32()
+    i64 = wasmtime.ValType.i64()
+    f32 = wasmtime.ValType.f32()
+    f64 = wasmtime.ValType.f64()
+
+    def options_open(caller, algorithm_type, result_ptr):
+        try:
+            result = host.options_open(
+                AlgorithmType(ctypes.c_uint16(algorithm_type).value),
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_ptr, Options, result)
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "options_open",
+        wasmtime.FuncType([i32, i32], [i32]),
+        options_open,
+        access_caller=True,
+    )
+
+    def options_close(caller, handle):
+        try:
+            host.options_close(
+                Options(handle).value,
+            )
+        except WasiError as e:
+            return e.errno
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "options_close",
+        wasmtime.FuncType([i32], [i32]),
+        options_close,
+        access_caller=True,
+    )
+
+    def options_set(caller, handle, name_ptr, name_len, value, value_len):
+        try:
+            host.options_set(
+                Options(handle).value,
+                read_string(caller, name_ptr, name_len),
+                value & 0xFFFFFFFF,
+                Size(value_len).value,
+            )
+        except WasiError as e:
+            return e.errno
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "options_set",
+        wasmtime.FuncType([i32, i32, i32, i32, i32], [i32]),
+        options_set,
+        access_caller=True,
+    )
+
+    def options_set_u_64(caller, handle, name_ptr, name_len, value):
+        try:
+            host.options_set_u_64(
+                Options(handle).value,
+                read_string(caller, name_ptr, name_len),
+                ctypes.c_uint64(value).value,
+            )
+        except WasiError as e:
+            return e.errno
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "options_set_u64",
+        wasmtime.FuncType([i32, i32, i32, i64], [i32]),
+        options_set_u_64,
+        access_caller=True,
+    )
+
+    def options_set_guest_buffer(
+        caller,
+        handle,
+        name_ptr,
+        name_len,
+        buffer,
+        buffer_len,
+    ):
+        try:
+            host.options_set_guest_buffer(
+                Options(handle).value,
+                read_string(caller, name_ptr, name_len),
+                buffer & 0xFFFFFFFF,
+                Size(buffer_len).value,
+            )
+        except WasiError as e:
+            return e.errno
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "options_set_guest_buffer",
+        wasmtime.FuncType([i32, i32, i32, i32, i32], [i32]),
+        options_set_guest_buffer,
+        access_caller=True,
+    )
+
+    def array_output_len(caller, array_output, result_ptr):
+        try:
+            result = host.array_output_len(
+                ArrayOutput(array_output).value,
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_ptr, Size, result)
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "array_output_len",
+        wasmtime.FuncType([i32, i32], [i32]),
+        array_output_len,
+        access_caller=True,
+    )
+
+    def array_output_pull(caller, array_output, buf, buf_len, result_ptr):
+        try:
+            result = host.array_output_pull(
+                ArrayOutput(array_output).value,
+                buf & 0xFFFFFFFF,
+                Size(buf_len).value,
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_ptr, Size, result)
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "array_output_pull",
+        wasmtime.FuncType([i32, i32, i32, i32], [i32]),
+        array_output_pull,
+        access_caller=True,
+    )
+
+    def secrets_manager_open(caller, options, result_ptr):
+        try:
+            result = host.secrets_manager_open(
+                read_value(caller, options, OptOptions),
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_ptr, SecretsManager, result)
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "secrets_manager_open",
+        wasmtime.FuncType([i32, i32], [i32]),
+        secrets_manager_open,
+        access_caller=True,
+    )
+
+    def secrets_manager_close(caller, secrets_manager):
+        try:
+            host.secrets_manager_close(
+                SecretsManager(secrets_manager).value,
+            )
+        except WasiError as e:
+            return e.errno
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "secrets_manager_close",
+        wasmtime.FuncType([i32], [i32]),
+        secrets_manager_close,
+        access_caller=True,
+    )
+
+    def secrets_manager_invalidate(
+        caller,
+        secrets_manager,
+        key_id,
+        key_id_len,
+        key_version,
+    ):
+        try:
+            host.secrets_manager_invalidate(
+                SecretsManager(secrets_manager).value,
+                key_id & 0xFFFFFFFF,
+                Size(key_id_len).value,
+                Version(key_version).value,
+            )
+        except WasiError as e:
+            return e.errno
+        return 0
+
+    linker.define_func(
+        "wasi_ephemeral_crypto_common",
+        "secrets_manager_invalidate",
+        wasmtime.FuncType([i32, i32, i32, i64], [i32]),
+        secrets_manager_invalidate,
+        access_caller=True,
+    )
+
@@ -0,0 +1,990 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(&self, slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(&self, slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(&self, slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(unsafe { std::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(&self, slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+/// Error codes.
+pub type CryptoErrno = u16;
+
+#[allow(non_snake_case)]
+pub mod CRYPTO_ERRNO {
+    use super::CryptoErrno;
+    pub const SUCCESS: CryptoErrno = 0;
+    pub const GUEST_ERROR: CryptoErrno = 1;
+    pub const NOT_IMPLEMENTED: CryptoErrno = 2;
+    pub const UNSUPPORTED_FEATURE: CryptoErrno = 3;
+    pub const PROHIBITED_OPERATION: CryptoErrno = 4;
+    pub const UNSUPPORTED_ENCODING: CryptoErrno = 5;
+    pub const UNSUPPORTED_ALGORITHM: CryptoErrno = 6;
+    pub const UNSUPPORTED_OPTION: CryptoErrno = 7;
+    pub const INVALID_KEY: CryptoErrno = 8;
+    pub const INVALID_LENGTH: CryptoErrno = 9;
+    pub const VERIFICATION_FAILED: CryptoErrno = 10;
+    pub const RNG_ERROR: CryptoErrno = 11;
+    pub const ALGORITHM_FAILURE: CryptoErrno = 12;
+    pub const INVALID_SIGNATURE: CryptoErrno = 13;
+    pub const CLOSED: CryptoErrno = 14;
+    pub const INVALID_HANDLE: CryptoErrno = 15;
+    pub const OVERFLOW: CryptoErrno = 16;
+    pub const INTERNAL_ERROR: CryptoErrno = 17;
+    pub const TOO_MANY_HANDLES: CryptoErrno = 18;
+    pub const KEY_NOT_SUPPORTED: CryptoErrno = 19;
+    pub const KEY_REQUIRED: CryptoErrno = 20;
+    pub const INVALID_TAG: CryptoErrno = 21;
+    pub const INVALID_OPERATION: CryptoErrno = 22;
+    pub const NONCE_REQUIRED: CryptoErrno = 23;
+    pub const INVALID_NONCE: CryptoErrno = 24;
+    pub const OPTION_NOT_SET: CryptoErrno = 25;
+    pub const NOT_FOUND: CryptoErrno = 26;
+    pub const PARAMETERS_MISSING: CryptoErrno = 27;
+    pub const IN_PROGRESS: CryptoErrno = 28;
+    pub const INCOMPATIBLE_KEYS: CryptoErrno = 29;
+    pub const EXPIRED: CryptoErrno = 30;
+}
+
+/// Encoding to use for importing or exporting a key pair.
+pub type KeypairEncoding = u16;
+
+#[allow(non_snake_case)]
+pub mod KEYPAIR_ENCODING {
+    use super::KeypairEncoding;
+    pub const RAW: KeypairEncoding = 0;
+    pub const PKCS_8: KeypairEncoding = 1;
+    pub const PEM: KeypairEncoding = 2;
+    pub const LOCAL: KeypairEncoding = 3;
+}
+
+/// Encoding to use for importing or exporting a public key.
+pub type PublickeyEncoding = u16;
+
+#[allow(non_snake_case)]
+pub mod PUBLICKEY_ENCODING {
+    use super::PublickeyEncoding;
+    pub const RAW: PublickeyEncoding = 0;
+    pub const PKCS_8: PublickeyEncoding = 1;
+    pub const PEM: PublickeyEncoding = 2;
+    pub const SEC: PublickeyEncoding = 3;
+    pub const COMPRESSED_SEC: PublickeyEncoding = 4;
+    pub const LOCAL: PublickeyEncoding = 5;
+}
+
+/// Encoding to use for importing or exporting a secret key.
+pub type SecretkeyEncoding = u16;
+
+#[allow(non_snake_case)]
+pub mod SECRETKEY_ENCODING {
+    use super::SecretkeyEncoding;
+    pub const RAW: SecretkeyEncoding = 0;
+    pub const PKCS_8: SecretkeyEncoding = 1;
+    pub const PEM: SecretkeyEncoding = 2;
+    pub const SEC: SecretkeyEncoding = 3;
+    pub const COMPRESSED_SEC: SecretkeyEncoding = 4;
+    pub const LOCAL: SecretkeyEncoding = 5;
+}
+
+/// Encoding to use for importing or exporting a signature.
+pub type SignatureEncoding = u16;
+
+#[allow(non_snake_case)]
+pub mod SIGNATURE_ENCODING {
+    use super::SignatureEncoding;
+    pub const RAW: SignatureEncoding = 0;
+    pub const DER: SignatureEncoding = 1;
+}
+
+/// An algorithm category.
+pub type AlgorithmType = u16;
+
+#[allow(non_snake_case)]
+pub mod ALGORITHM_TYPE {
+    use super::AlgorithmType;
+    pub const SIGNATURES: AlgorithmType = 0;
+    pub const SYMMETRIC: AlgorithmType = 1;
+    pub const KEY_EXCHANGE: AlgorithmType = 2;
+}
+
+/// Version of a managed key.
+/// 
+/// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+pub type Version = u64;
+
+#[allow(non_snake_case)]
+pub mod VERSION {
+    use super::Version;
+    pub const UNSPECIFIED: Version = 0xff00000000000000;
+    pub const LATEST: Version = 0xff00000000000001;
+    pub const ALL: Version = 0xff00000000000002;
+}
+
+/// Size of a value.
+pub type Size = usize;
+
+/// A UNIX timestamp, in seconds since 01/01/1970.
+pub type Timestamp = u64;
+
+/// A 64-bit value
+pub type U64 = u64;
+
+/// Handle for functions returning output whose size may be large or not known in advance.
+/// 
+/// An `array_output` object contains a host-allocated byte array.
+/// 
+/// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+/// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+/// 
+/// An `array_output` handle is automatically closed after its full content has been consumed.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ArrayOutput(pub WasiHandle);
+
+/// A set of options.
+/// 
+/// This type is used to set non-default parameters.
+/// 
+/// The exact set of allowed options depends on the algorithm being used.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Options(pub WasiHandle);
+
+/// A handle to the optional secrets management facilities offered by a host.
+/// 
+/// This is used to generate, retrieve and invalidate managed keys.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SecretsManager(pub WasiHandle);
+
+/// A key pair.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Keypair(pub WasiHandle);
+
+/// A state to absorb data to be signed.
+/// 
+/// After a signature has been computed or verified, the state remains valid for further operations.
+/// 
+/// A subsequent signature would sign all the data accumulated since the creation of the state object.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SignatureState(pub WasiHandle);
+
+/// A signature.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Signature(pub WasiHandle);
+
+/// A public key, for key exchange and signature verification.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Publickey(pub WasiHandle);
+
+/// A secret key, for key exchange mechanisms.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Secretkey(pub WasiHandle);
+
+/// A state to absorb signed data to be verified.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SignatureVerificationState(pub WasiHandle);
+
+/// A state to perform symmetric operations.
+/// 
+/// The state is not reset nor invalidated after an option has been performed.
+/// Incremental updates and sessions are thus supported.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SymmetricState(pub WasiHandle);
+
+/// A symmetric key.
+/// 
+/// The key can be imported from raw bytes, or can be a reference to a managed key.
+/// 
+/// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SymmetricKey(pub WasiHandle);
+
+/// An authentication tag.
+/// 
+/// This is an object returned by functions computing authentication tags.
+/// 
+/// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+/// 
+/// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+/// 
+/// The host is reponsible for securely wiping them from memory on close.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SymmetricTag(pub WasiHandle);
+
+/// Options index, only required by the Interface Types translation layer.
+pub type OptOptionsU = u8;
+
+#[allow(non_snake_case)]
+pub mod OPT_OPTIONS_U {
+    use super::OptOptionsU;
+    pub const SOME: OptOptionsU = 0;
+    pub const NONE: OptOptionsU = 1;
+}
+
+/// An optional options set.
+/// 
+/// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union OptOptionsMember {
+    some: Options, // if tag=0
+    // none with no associated value if tag=1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct OptOptions {
+    pub tag: u8,
+    __pad8_0: u8,
+    __pad16_0: u16,
+    pub member: std::mem::MaybeUninit<OptOptionsMember>,
+}
+
+#[cfg(any(target_arch = "wasm32", target_pointer_width = "64"))]
+const _: () = assert!(std::mem::size_of::<OptOptions>() == 8);
+#[cfg(any(target_arch = "wasm32", target_pointer_width = "64"))]
+const _: () = assert!(std::mem::align_of::<OptOptions>() == 4);
+
+impl OptOptions {
+    fn new(tag: u8) -> Self {
+        let mut tu = unsafe { std::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// The active member. Panics if the tag is not valid.
+    pub fn kind(&self) -> OptOptionsKind {
+        match self.tag {
+            0 => OptOptionsKind::Some,
+            1 => OptOptionsKind::None,
+            tag => panic!("Invalid tag: {}", tag),
+        }
+    }
+
+
+    // --- some: Options if tag=0
+
+    pub fn new_some(val: Options) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = std::mem::MaybeUninit::new(OptOptionsMember { some: val });
+        tu
+    }
+
+    pub fn into_some(self) -> Option<Options> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { self.member.assume_init().some })
+    }
+
+    pub fn as_some(&self) -> Option<&Options> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { &(*self.member.as_ptr()).some })
+    }
+
+    pub fn as_some_mut(&mut self) -> Option<&mut Options> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { &mut (*self.member.as_mut_ptr()).some })
+    }
+
+    pub fn set_some(&mut self, val: Options) {
+        assert!(self.tag == 0);
+        let uval = OptOptionsMember { some: val };
+        unsafe { *self.member.as_mut_ptr() = uval };
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.tag == 0
+    }
+
+
+    // --- none: (no associated content) if tag=1
+
+    pub fn new_none() -> Self {
+        Self::new(1)
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.tag == 1
+    }
+
+}
+
+impl std::fmt::Debug for OptOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.tag {
+            0 => f.debug_tuple("Some").field(unsafe { &(*self.member.as_ptr()).some }).finish(),
+            1 => f.write_str("None"),
+            tag => f.debug_struct("OptOptions").field("tag", &tag).finish(),
+        }
+    }
+}
+
+impl PartialEq for OptOptions {
+    fn eq(&self, other: &Self) -> bool {
+        if self.tag != other.tag {
+            return false;
+        }
+        match self.tag {
+            0 => self.as_some() == other.as_some(),
+            _ => true,
+        }
+    }
+}
+
+impl Eq for OptOptions {}
+
+impl std::hash::Hash for OptOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.tag, state);
+        match self.tag {
+            0 => std::hash::Hash::hash(unsafe { &(*self.member.as_ptr()).some }, state),
+            _ => {}
+        }
+    }
+}
+
+impl Default for OptOptions {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+/// Discriminant of `OptOptions`
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OptOptionsKind {
+    Some,
+    None,
+}
+
+/// Content of `OptOptions`, as a Rust enum
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OptOptionsValue {
+    Some(Options),
+    None,
+}
+
+impl From<OptOptionsValue> for OptOptions {
+    fn from(value: OptOptionsValue) -> Self {
+        match value {
+            OptOptionsValue::Some(val) => Self::new_some(val),
+            OptOptionsValue::None => Self::new_none(),
+        }
+    }
+}
+
+impl From<OptOptions> for OptOptionsValue {
+    /// Panics if the tag is not valid
+    fn from(tu: OptOptions) -> Self {
+        match tu.kind() {
+            OptOptionsKind::Some => Self::Some(unsafe { tu.member.assume_init().some }),
+            OptOptionsKind::None => Self::None,
+        }
+    }
+}
+
+
+/// Symmetric key index, only required by the Interface Types translation layer.
+pub type OptSymmetricKeyU = u8;
+
+#[allow(non_snake_case)]
+pub mod OPT_SYMMETRIC_KEY_U {
+    use super::OptSymmetricKeyU;
+    pub const SOME: OptSymmetricKeyU = 0;
+    pub const NONE: OptSymmetricKeyU = 1;
+}
+
+/// An optional symmetric key.
+/// 
+/// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union OptSymmetricKeyMember {
+    some: SymmetricKey, // if tag=0
+    // none with no associated value if tag=1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct OptSymmetricKey {
+    pub tag: u8,
+    __pad8_0: u8,
+    __pad16_0: u16,
+    pub member: std::mem::MaybeUninit<OptSymmetricKeyMember>,
+}
+
+#[cfg(any(target_arch = "wasm32", target_pointer_width = "64"))]
+const _: () = assert!(std::mem::size_of::<OptSymmetricKey>() == 8);
+#[cfg(any(target_arch = "wasm32", target_pointer_width = "64"))]
+const _: () = assert!(std::mem::align_of::<OptSymmetricKey>() == 4);
+
+impl OptSymmetricKey {
+    fn new(tag: u8) -> Self {
+        let mut tu = unsafe { std::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// The active member. Panics if the tag is not valid.
+    pub fn kind(&self) -> OptSymmetricKeyKind {
+        match self.tag {
+            0 => OptSymmetricKeyKind::Some,
+            1 => OptSymmetricKeyKind::None,
+            tag => panic!("Invalid tag: {}", tag),
+        }
+    }
+
+
+    // --- some: SymmetricKey if tag=0
+
+    pub fn new_some(val: SymmetricKey) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = std::mem::MaybeUninit::new(OptSymmetricKeyMember { some: val });
+        tu
+    }
+
+    pub fn into_some(self) -> Option<SymmetricKey> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { self.member.assume_init().some })
+    }
+
+    pub fn as_some(&self) -> Option<&SymmetricKey> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { &(*self.member.as_ptr()).some })
+    }
+
+    pub fn as_some_mut(&mut self) -> Option<&mut SymmetricKey> {
+        if self.tag != 0 {
+            return None;
+        }
+        Some(unsafe { &mut (*self.member.as_mut_ptr()).some })
+    }
+
+    pub fn set_some(&mut self, val: SymmetricKey) {
+        assert!(self.tag == 0);
+        let uval = OptSymmetricKeyMember { some: val };
+        unsafe { *self.member.as_mut_ptr() = uval };
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.tag == 0
+    }
+
+
+    // --- none: (no associated content) if tag=1
+
+    pub fn new_none() -> Self {
+        Self::new(1)
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.tag == 1
+    }
+
+}
+
+impl std::fmt::Debug for OptSymmetricKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.tag {
+            0 => f.debug_tuple("Some").field(unsafe { &(*self.member.as_ptr()).some }).finish(),
+            1 => f.write_str("None"),
+            tag => f.debug_struct("OptSymmetricKey").field("tag", &tag).finish(),
+        }
+    }
+}
+
+impl PartialEq for OptSymmetricKey {
+    fn eq(&self, other: &Self) -> bool {
+        if self.tag != other.tag {
+            return false;
+        }
+        match self.tag {
+            0 => self.as_some() == other.as_some(),
+            _ => true,
+        }
+    }
+}
+
+impl Eq for OptSymmetricKey {}
+
+impl std::hash::Hash for OptSymmetricKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.tag, state);
+        match self.tag {
+            0 => std::hash::Hash::hash(unsafe { &(*self.member.as_ptr()).some }, state),
+            _ => {}
+        }
+    }
+}
+
+impl Default for OptSymmetricKey {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+/// Discriminant of `OptSymmetricKey`
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OptSymmetricKeyKind {
+    Some,
+    None,
+}
+
+/// Content of `OptSymmetricKey`, as a Rust enum
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OptSymmetricKeyValue {
+    Some(SymmetricKey),
+    None,
+}
+
+impl From<OptSymmetricKeyValue> for OptSymmetricKey {
+    fn from(value: OptSymmetricKeyValue) -> Self {
+        match value {
+            OptSymmetricKeyValue::Some(val) => Self::new_some(val),
+            OptSymmetricKeyValue::None => Self::new_none(),
+        }
+    }
+}
+
+impl From<OptSymmetricKey> for OptSymmetricKeyValue {
+    /// Panics if the tag is not valid
+    fn from(tu: OptSymmetricKey) -> Self {
+        match tu.kind() {
+            OptSymmetricKeyKind::Some => Self::Some(unsafe { tu.member.assume_init().some }),
+            OptSymmetricKeyKind::None => Self::None,
+        }
+    }
+}
+
+
+/// Create a new object to set non-default options.
+/// 
+/// Example usage:
+/// 
+/// ```rust
+/// let options_handle = options_open(AlgorithmType::Symmetric)?;
+/// options_set(options_handle, "context", context)?;
+/// options_set_u64(options_handle, "threads", 4)?;
+/// let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+/// options_close(options_handle)?;
+/// ```
+pub fn options_open(
+    algorithm_type: AlgorithmType,
+) -> Result<Options, Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn options_open(
+            algorithm_type: AlgorithmType,
+            result_ptr: WasiMutPtr<Options>,
+        ) -> CryptoErrno;
+    }
+    let mut result_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { options_open(
+        algorithm_type,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// Destroy an options object.
+/// 
+/// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+pub fn options_close(
+    handle: Options,
+) -> Result<(), Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn options_close(
+            handle: Options,
+        ) -> CryptoErrno;
+    }
+    let res = unsafe { options_close(
+        handle,
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(())
+}
+
+/// Set or update an option.
+/// 
+/// This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+/// 
+/// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+pub fn options_set(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    value: WasiPtr<u8>,
+    value_len: Size,
+) -> Result<(), Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn options_set(
+            handle: Options,
+            name_ptr: WasiPtr<Char8>,
+            name_len: usize,
+            value: WasiPtr<u8>,
+            value_len: Size,
+        ) -> CryptoErrno;
+    }
+    let res = unsafe { options_set(
+        handle,
+        name_ptr,
+        name_len,
+        value,
+        value_len,
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(())
+}
+
+/// Set or update an integer option.
+/// 
+/// This is used to set algorithm-specific parameters.
+/// 
+/// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+pub fn options_set_u_64(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    value: u64,
+) -> Result<(), Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        #[link_name = "options_set_u64"]
+        fn options_set_u_64(
+            handle: Options,
+            name_ptr: WasiPtr<Char8>,
+            name_len: usize,
+            value: u64,
+        ) -> CryptoErrno;
+    }
+    let res = unsafe { options_set_u_64(
+        handle,
+        name_ptr,
+        name_len,
+        value,
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(())
+}
+
+/// Set or update a guest-allocated memory that the host can use or return data into.
+/// 
+/// This is for example used to set the scratch buffer required by memory-hard functions.
+/// 
+/// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+pub fn options_set_guest_buffer(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    buffer: WasiMutPtr<u8>,
+    buffer_len: Size,
+) -> Result<(), Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn options_set_guest_buffer(
+            handle: Options,
+            name_ptr: WasiPtr<Char8>,
+            name_len: usize,
+            buffer: WasiMutPtr<u8>,
+            buffer_len: Size,
+        ) -> CryptoErrno;
+    }
+    let res = unsafe { options_set_guest_buffer(
+        handle,
+        name_ptr,
+        name_len,
+        buffer,
+        buffer_len,
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(())
+}
+
+/// Return the length of an `array_output` object.
+/// 
+/// This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+pub fn array_output_len(
+    array_output: ArrayOutput,
+) -> Result<Size, Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn array_output_len(
+            array_output: ArrayOutput,
+            result_ptr: WasiMutPtr<Size>,
+        ) -> CryptoErrno;
+    }
+    let mut result_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { array_output_len(
+        array_output,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// Copy the content of an `array_output` object into an application-allocated buffer.
+/// 
+/// Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+/// 
+/// The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+/// 
+/// The handle is automatically closed after all the data has been consumed.
+/// 
+/// Example usage:
+/// 
+/// ```rust
+/// let len = array_output_len(output_handle)?;
+/// let mut out = vec![0u8; len];
+/// array_output_pull(output_handle, &mut out)?;
+/// ```
+pub fn array_output_pull(
+    array_output: ArrayOutput,
+    buf: WasiMutPtr<u8>,
+    buf_len: Size,
+) -> Result<Size, Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn array_output_pull(
+            array_output: ArrayOutput,
+            buf: WasiMutPtr<u8>,
+            buf_len: Size,
+            result_ptr: WasiMutPtr<Size>,
+        ) -> CryptoErrno;
+    }
+    let mut result_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { array_output_pull(
+        array_output,
+        buf,
+        buf_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// __(optional)__
+/// Create a context to use a secrets manager.
+/// 
+/// The set of required and supported options is defined by the host.
+/// 
+/// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+/// This is also an optional import, meaning that the function may not even exist.
+pub fn secrets_manager_open(
+    options: OptOptions,
+) -> Result<SecretsManager, Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn secrets_manager_open(
+            options: OptOptions,
+            result_ptr: WasiMutPtr<SecretsManager>,
+        ) -> CryptoErrno;
+    }
+    let mut result_ptr = std::mem::MaybeUninit::uninit();
+    let res = unsafe { secrets_manager_open(
+        options,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// __(optional)__
+/// Destroy a secrets manager context.
+/// 
+/// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+/// This is also an optional import, meaning that the function may not even exist.
+pub fn secrets_manager_close(
+    secrets_manager: SecretsManager,
+) -> Result<(), Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn secrets_manager_close(
+            secrets_manager: SecretsManager,
+        ) -> CryptoErrno;
+    }
+    let res = unsafe { secrets_manager_close(
+        secrets_manager,
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(())
+}
+
+/// __(optional)__
+/// Invalidate a managed key or key pair given an identifier and a version.
+/// 
+/// This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+/// 
+/// `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+/// 
+/// The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+/// 
+/// This is an optional import, meaning that the function may not even exist.
+pub fn secrets_manager_invalidate(
+    secrets_manager: SecretsManager,
+    key_id: WasiPtr<u8>,
+    key_id_len: Size,
+    key_version: Version,
+) -> Result<(), Error> {
+    #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+    extern "C" {
+        fn secrets_manager_invalidate(
+            secrets_manager: SecretsManager,
+            key_id: WasiPtr<u8>,
+            key_id_len: Size,
+            key_version: Version,
+        ) -> CryptoErrno;
+    }
+    let res = unsafe { secrets_manager_invalidate(
+        secrets_manager,
+        key_id,
+        key_id_len,
+        key_version,
+    )};
+    if res != 0 {
+        return Err(Error::WasiError(res as _));
+    }
+    Ok(())
+}
+
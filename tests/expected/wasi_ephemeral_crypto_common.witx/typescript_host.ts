@@ -0,0 +1,716 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = number;
+export type WasiPtr = number;
+export type WasiSize = number;
+export type Char8 = number;
+export type Char32 = number;
+
+export interface WasiSlice {
+    ptr: WasiPtr;
+    len: WasiSize;
+}
+
+/**
+ * Thrown by a host implementation to return an error code to the guest
+ */
+export class WasiError<E = number> extends Error {
+    constructor(readonly errno: E) {
+        super(`WASI error ${errno}`);
+    }
+}
+
+/**
+ * Allocates `size` bytes aligned to `align` in the guest memory, and returns their address
+ */
+export type GuestAllocator = (size: number, align: number) => number;
+
+const textDecoder = new TextDecoder("utf-8", { fatal: true });
+const textEncoder = new TextEncoder();
+
+/**
+ * The memory of a guest, that must be bound to the instance once it has been created
+ */
+export class GuestMemory {
+    private memory: WebAssembly.Memory | null = null;
+
+    bind(instance: WebAssembly.Instance): void {
+        this.memory = instance.exports.memory as WebAssembly.Memory;
+    }
+
+    view(): DataView {
+        return new DataView(this.buffer());
+    }
+
+    bytes(ptr: number, len: number): Uint8Array {
+        return new Uint8Array(this.buffer(), ptr >>> 0, len >>> 0);
+    }
+
+    readString(ptr: number, len: number): string {
+        return textDecoder.decode(this.bytes(ptr, len));
+    }
+
+    /**
+     * Copies bytes to memory allocated in the guest, and stores their address and length at `ptr`
+     */
+    writeBytes(alloc: GuestAllocator, ptr: number, bytes: Uint8Array): void {
+        const dataPtr = alloc(bytes.length, 1) >>> 0;
+        this.bytes(dataPtr, bytes.length).set(bytes);
+        const view = this.view();
+        view.setUint32(ptr, dataPtr, true);
+        view.setUint32(ptr + 4, bytes.length, true);
+    }
+
+    writeString(alloc: GuestAllocator, ptr: number, value: string): void {
+        this.writeBytes(alloc, ptr, textEncoder.encode(value));
+    }
+
+    private buffer(): ArrayBuffer {
+        if (this.memory === null) {
+            throw new Error("The guest memory is not bound to an instance");
+        }
+        return this.memory.buffer;
+    }
+}
+
+/*
+ * ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+ */
+
+/**
+ * Error codes.
+ */
+export type CryptoErrno = number;
+
+export namespace CryptoErrno {
+    export const SUCCESS: CryptoErrno = 0;
+    export const GUEST_ERROR: CryptoErrno = 1;
+    export const NOT_IMPLEMENTED: CryptoErrno = 2;
+    export const UNSUPPORTED_FEATURE: CryptoErrno = 3;
+    export const PROHIBITED_OPERATION: CryptoErrno = 4;
+    export const UNSUPPORTED_ENCODING: CryptoErrno = 5;
+    export const UNSUPPORTED_ALGORITHM: CryptoErrno = 6;
+    export const UNSUPPORTED_OPTION: CryptoErrno = 7;
+    export const INVALID_KEY: CryptoErrno = 8;
+    export const INVALID_LENGTH: CryptoErrno = 9;
+    export const VERIFICATION_FAILED: CryptoErrno = 10;
+    export const RNG_ERROR: CryptoErrno = 11;
+    export const ALGORITHM_FAILURE: CryptoErrno = 12;
+    export const INVALID_SIGNATURE: CryptoErrno = 13;
+    export const CLOSED: CryptoErrno = 14;
+    export const INVALID_HANDLE: CryptoErrno = 15;
+    export const OVERFLOW: CryptoErrno = 16;
+    export const INTERNAL_ERROR: CryptoErrno = 17;
+    export const TOO_MANY_HANDLES: CryptoErrno = 18;
+    export const KEY_NOT_SUPPORTED: CryptoErrno = 19;
+    export const KEY_REQUIRED: CryptoErrno = 20;
+    export const INVALID_TAG: CryptoErrno = 21;
+    export const INVALID_OPERATION: CryptoErrno = 22;
+    export const NONCE_REQUIRED: CryptoErrno = 23;
+    export const INVALID_NONCE: CryptoErrno = 24;
+    export const OPTION_NOT_SET: CryptoErrno = 25;
+    export const NOT_FOUND: CryptoErrno = 26;
+    export const PARAMETERS_MISSING: CryptoErrno = 27;
+    export const IN_PROGRESS: CryptoErrno = 28;
+    export const INCOMPATIBLE_KEYS: CryptoErrno = 29;
+    export const EXPIRED: CryptoErrno = 30;
+}
+
+/**
+ * Encoding to use for importing or exporting a key pair.
+ */
+export type KeypairEncoding = number;
+
+export namespace KeypairEncoding {
+    export const RAW: KeypairEncoding = 0;
+    export const PKCS_8: KeypairEncoding = 1;
+    export const PEM: KeypairEncoding = 2;
+    export const LOCAL: KeypairEncoding = 3;
+}
+
+/**
+ * Encoding to use for importing or exporting a public key.
+ */
+export type PublickeyEncoding = number;
+
+export namespace PublickeyEncoding {
+    export const RAW: PublickeyEncoding = 0;
+    export const PKCS_8: PublickeyEncoding = 1;
+    export const PEM: PublickeyEncoding = 2;
+    export const SEC: PublickeyEncoding = 3;
+    export const COMPRESSED_SEC: PublickeyEncoding = 4;
+    export const LOCAL: PublickeyEncoding = 5;
+}
+
+/**
+ * Encoding to use for importing or exporting a secret key.
+ */
+export type SecretkeyEncoding = number;
+
+export namespace SecretkeyEncoding {
+    export const RAW: SecretkeyEncoding = 0;
+    export const PKCS_8: SecretkeyEncoding = 1;
+    export const PEM: SecretkeyEncoding = 2;
+    export const SEC: SecretkeyEncoding = 3;
+    export const COMPRESSED_SEC: SecretkeyEncoding = 4;
+    export const LOCAL: SecretkeyEncoding = 5;
+}
+
+/**
+ * Encoding to use for importing or exporting a signature.
+ */
+export type SignatureEncoding = number;
+
+export namespace SignatureEncoding {
+    export const RAW: SignatureEncoding = 0;
+    export const DER: SignatureEncoding = 1;
+}
+
+/**
+ * An algorithm category.
+ */
+export type AlgorithmType = number;
+
+export namespace AlgorithmType {
+    export const SIGNATURES: AlgorithmType = 0;
+    export const SYMMETRIC: AlgorithmType = 1;
+    export const KEY_EXCHANGE: AlgorithmType = 2;
+}
+
+/**
+ * Version of a managed key.
+ *
+ * A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+ */
+export type Version = bigint;
+
+export namespace Version {
+    export const UNSPECIFIED: Version = 0xff00000000000000n;
+    export const LATEST: Version = 0xff00000000000001n;
+    export const ALL: Version = 0xff00000000000002n;
+}
+
+/**
+ * Size of a value.
+ */
+export type Size = WasiSize;
+
+/**
+ * A UNIX timestamp, in seconds since 01/01/1970.
+ */
+export type Timestamp = bigint;
+
+/**
+ * A 64-bit value
+ */
+export type U64 = bigint;
+
+/**
+ * Handle for functions returning output whose size may be large or not known in advance.
+ *
+ * An `array_output` object contains a host-allocated byte array.
+ *
+ * A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+ * In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+ *
+ * An `array_output` handle is automatically closed after its full content has been consumed.
+ */
+export type ArrayOutput = WasiHandle;
+
+/**
+ * A set of options.
+ *
+ * This type is used to set non-default parameters.
+ *
+ * The exact set of allowed options depends on the algorithm being used.
+ */
+export type Options = WasiHandle;
+
+/**
+ * A handle to the optional secrets management facilities offered by a host.
+ *
+ * This is used to generate, retrieve and invalidate managed keys.
+ */
+export type SecretsManager = WasiHandle;
+
+/**
+ * A key pair.
+ */
+export type Keypair = WasiHandle;
+
+/**
+ * A state to absorb data to be signed.
+ *
+ * After a signature has been computed or verified, the state remains valid for further operations.
+ *
+ * A subsequent signature would sign all the data accumulated since the creation of the state object.
+ */
+export type SignatureState = WasiHandle;
+
+/**
+ * A signature.
+ */
+export type Signature = WasiHandle;
+
+/**
+ * A public key, for key exchange and signature verification.
+ */
+export type Publickey = WasiHandle;
+
+/**
+ * A secret key, for key exchange mechanisms.
+ */
+export type Secretkey = WasiHandle;
+
+/**
+ * A state to absorb signed data to be verified.
+ */
+export type SignatureVerificationState = WasiHandle;
+
+/**
+ * A state to perform symmetric operations.
+ *
+ * The state is not reset nor invalidated after an option has been performed.
+ * Incremental updates and sessions are thus supported.
+ */
+export type SymmetricState = WasiHandle;
+
+/**
+ * A symmetric key.
+ *
+ * The key can be imported from raw bytes, or can be a reference to a managed key.
+ *
+ * If it was imported, the host will wipe it from memory as soon as the handle is closed.
+ */
+export type SymmetricKey = WasiHandle;
+
+/**
+ * An authentication tag.
+ *
+ * This is an object returned by functions computing authentication tags.
+ *
+ * A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+ *
+ * This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+ *
+ * The host is reponsible for securely wiping them from memory on close.
+ */
+export type SymmetricTag = WasiHandle;
+
+/**
+ * Options index, only required by the Interface Types translation layer.
+ */
+export type OptOptionsU = number;
+
+export namespace OptOptionsU {
+    export const SOME: OptOptionsU = 0;
+    export const NONE: OptOptionsU = 1;
+}
+
+/**
+ * An optional options set.
+ *
+ * This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+ */
+export type OptOptions =
+    | { tag: 0; some: Options }
+    | { tag: 1 };
+
+export namespace OptOptions {
+    export const SOME = 0;
+    export const NONE = 1;
+
+    export function read(mem: GuestMemory, ptr: WasiPtr): OptOptions {
+        const view = mem.view();
+        const tag = view.getUint8(ptr);
+        switch (tag) {
+            case 0:
+                return { tag: 0, some: view.getInt32(ptr + 4, true) };
+            case 1:
+                return { tag: 1 };
+            default:
+                throw new Error(`Invalid tag for OptOptions: ${tag}`);
+        }
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: OptOptions): void {
+        const view = mem.view();
+        view.setUint8(ptr, value.tag);
+        switch (value.tag) {
+            case 0:
+                view.setInt32(ptr + 4, value.some, true);
+                break;
+        }
+    }
+}
+
+/**
+ * Symmetric key index, only required by the Interface Types translation layer.
+ */
+export type OptSymmetricKeyU = number;
+
+export namespace OptSymmetricKeyU {
+    export const SOME: OptSymmetricKeyU = 0;
+    export const NONE: OptSymmetricKeyU = 1;
+}
+
+/**
+ * An optional symmetric key.
+ *
+ * This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+ */
+export type OptSymmetricKey =
+    | { tag: 0; some: SymmetricKey }
+    | { tag: 1 };
+
+export namespace OptSymmetricKey {
+    export const SOME = 0;
+    export const NONE = 1;
+
+    export function read(mem: GuestMemory, ptr: WasiPtr): OptSymmetricKey {
+        const view = mem.view();
+        const tag = view.getUint8(ptr);
+        switch (tag) {
+            case 0:
+                return { tag: 0, some: view.getInt32(ptr + 4, true) };
+            case 1:
+                return { tag: 1 };
+            default:
+                throw new Error(`Invalid tag for OptSymmetricKey: ${tag}`);
+        }
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: OptSymmetricKey): void {
+        const view = mem.view();
+        view.setUint8(ptr, value.tag);
+        switch (value.tag) {
+            case 0:
+                view.setInt32(ptr + 4, value.some, true);
+                break;
+        }
+    }
+}
+
+/**
+ * Host implementation of the [wasi_ephemeral_crypto_common] module
+ *
+ * The functions receive the parameters decoded from the guest memory, and return
+ * the results. Errors are returned to the guest by throwing a `WasiError`.
+ */
+export interface WasiEphemeralCryptoCommon {
+    /**
+     * Create a new object to set non-default options.
+     *
+     * Example usage:
+     *
+     * ```rust
+     * let options_handle = options_open(AlgorithmType::Symmetric)?;
+     * options_set(options_handle, "context", context)?;
+     * options_set_u64(options_handle, "threads", 4)?;
+     * let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+     * options_close(options_handle)?;
+     * ```
+     */
+    optionsOpen(algorithmType: AlgorithmType): Options;
+
+    /**
+     * Destroy an options object.
+     *
+     * Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+     */
+    optionsClose(handle: Options): void;
+
+    /**
+     * Set or update an option.
+     *
+     * This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    optionsSet(handle: Options, name: string, value: WasiPtr, valueLen: Size): void;
+
+    /**
+     * Set or update an integer option.
+     *
+     * This is used to set algorithm-specific parameters.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    optionsSetU64(handle: Options, name: string, value: bigint): void;
+
+    /**
+     * Set or update a guest-allocated memory that the host can use or return data into.
+     *
+     * This is for example used to set the scratch buffer required by memory-hard functions.
+     *
+     * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+     */
+    optionsSetGuestBuffer(handle: Options, name: string, buffer: WasiPtr, bufferLen: Size): void;
+
+    /**
+     * Return the length of an `array_output` object.
+     *
+     * This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+     */
+    arrayOutputLen(arrayOutput: ArrayOutput): Size;
+
+    /**
+     * Copy the content of an `array_output` object into an application-allocated buffer.
+     *
+     * Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+     *
+     * The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+     *
+     * The handle is automatically closed after all the data has been consumed.
+     *
+     * Example usage:
+     *
+     * ```rust
+     * let len = array_output_len(output_handle)?;
+     * let mut out = vec![0u8; len];
+     * array_output_pull(output_handle, &mut out)?;
+     * ```
+     */
+    arrayOutputPull(arrayOutput: ArrayOutput, buf: WasiPtr, bufLen: Size): Size;
+
+    /**
+     * __(optional)__
+     * Create a context to use a secrets manager.
+     *
+     * The set of required and supported options is defined by the host.
+     *
+     * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+     * This is also an optional import, meaning that the function may not even exist.
+     */
+    secretsManagerOpen(options: OptOptions): SecretsManager;
+
+    /**
+     * __(optional)__
+     * Destroy a secrets manager context.
+     *
+     * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+     * This is also an optional import, meaning that the function may not even exist.
+     */
+    secretsManagerClose(secretsManager: SecretsManager): void;
+
+    /**
+     * __(optional)__
+     * Invalidate a managed key or key pair given an identifier and a version.
+     *
+     * This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+     *
+     * `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+     *
+     * The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+     *
+     * This is an optional import, meaning that the function may not even exist.
+     */
+    secretsManagerInvalidate(secretsManager: SecretsManager, keyId: WasiPtr, keyIdLen: Size, keyVersion: Version): void;
+}
+
+/**
+ * Returns the imports of the [wasi_ephemeral_crypto_common] module, calling the functions of `impl`
+ *
+ * `mem` must be bound to the instance once it has been created.
+ */
+export function wasiEphemeralCryptoCommonImports(impl: WasiEphemeralCryptoCommon, mem: GuestMemory): WebAssembly.Imports {
+    return {
+        "wasi_ephemeral_crypto_common": {
+            "options_open": (
+                algorithmType: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.optionsOpen(
+                        algorithmType,
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "options_close": (
+                handle: number
+            ): number => {
+                try {
+                    impl.optionsClose(
+                        handle,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "options_set": (
+                handle: number,
+                namePtr: number,
+                nameLen: number,
+                value: number,
+                valueLen: number
+            ): number => {
+                try {
+                    impl.optionsSet(
+                        handle,
+                        mem.readString(namePtr, nameLen),
+                        value >>> 0,
+                        valueLen >>> 0,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "options_set_u64": (
+                handle: number,
+                namePtr: number,
+                nameLen: number,
+                value: bigint
+            ): number => {
+                try {
+                    impl.optionsSetU64(
+                        handle,
+                        mem.readString(namePtr, nameLen),
+                        BigInt.asUintN(64, value),
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "options_set_guest_buffer": (
+                handle: number,
+                namePtr: number,
+                nameLen: number,
+                buffer: number,
+                bufferLen: number
+            ): number => {
+                try {
+                    impl.optionsSetGuestBuffer(
+                        handle,
+                        mem.readString(namePtr, nameLen),
+                        buffer >>> 0,
+                        bufferLen >>> 0,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "array_output_len": (
+                arrayOutput: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.arrayOutputLen(
+                        arrayOutput,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "array_output_pull": (
+                arrayOutput: number,
+                buf: number,
+                bufLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.arrayOutputPull(
+                        arrayOutput,
+                        buf >>> 0,
+                        bufLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "secrets_manager_open": (
+                options: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.secretsManagerOpen(
+                        OptOptions.read(mem, options),
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "secrets_manager_close": (
+                secretsManager: number
+            ): number => {
+                try {
+                    impl.secretsManagerClose(
+                        secretsManager,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "secrets_manager_invalidate": (
+                secretsManager: number,
+                keyId: number,
+                keyIdLen: number,
+                keyVersion: bigint
+            ): number => {
+                try {
+                    impl.secretsManagerInvalidate(
+                        secretsManager,
+                        keyId >>> 0,
+                        keyIdLen >>> 0,
+                        BigInt.asUintN(64, keyVersion),
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+        },
+    };
+}
+
@@ -0,0 +1,167 @@
+
+;;
+;; This file was automatically generated by witx-codegen - Do not edit manually.
+;;
+
+(module
+  ;; ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+  ;; Create a new object to set non-default options.
+  ;; 
+  ;; Example usage:
+  ;; 
+  ;; ```rust
+  ;; let options_handle = options_open(AlgorithmType::Symmetric)?;
+  ;; options_set(options_handle, "context", context)?;
+  ;; options_set_u64(options_handle, "threads", 4)?;
+  ;; let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+  ;; options_close(options_handle)?;
+  ;; ```
+  (import "wasi_ephemeral_crypto_common" "options_open"
+    (func $wasi_ephemeral_crypto_common.options_open
+      (param $algorithm_type i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; Destroy an options object.
+  ;; 
+  ;; Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+  (import "wasi_ephemeral_crypto_common" "options_close"
+    (func $wasi_ephemeral_crypto_common.options_close
+      (param $handle i32)
+      (result i32)
+    )
+  )
+
+  ;; Set or update an option.
+  ;; 
+  ;; This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+  ;; 
+  ;; This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+  (import "wasi_ephemeral_crypto_common" "options_set"
+    (func $wasi_ephemeral_crypto_common.options_set
+      (param $handle i32)
+      (param $name_ptr i32)
+      (param $name_len i32)
+      (param $value i32)
+      (param $value_len i32)
+      (result i32)
+    )
+  )
+
+  ;; Set or update an integer option.
+  ;; 
+  ;; This is used to set algorithm-specific parameters.
+  ;; 
+  ;; This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+  (import "wasi_ephemeral_crypto_common" "options_set_u64"
+    (func $wasi_ephemeral_crypto_common.options_set_u64
+      (param $handle i32)
+      (param $name_ptr i32)
+      (param $name_len i32)
+      (param $value i64)
+      (result i32)
+    )
+  )
+
+  ;; Set or update a guest-allocated memory that the host can use or return data into.
+  ;; 
+  ;; This is for example used to set the scratch buffer required by memory-hard functions.
+  ;; 
+  ;; This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+  (import "wasi_ephemeral_crypto_common" "options_set_guest_buffer"
+    (func $wasi_ephemeral_crypto_common.options_set_guest_buffer
+      (param $handle i32)
+      (param $name_ptr i32)
+      (param $name_len i32)
+      (param $buffer i32)
+      (param $buffer_len i32)
+      (result i32)
+    )
+  )
+
+  ;; Return the length of an `array_output` object.
+  ;; 
+  ;; This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+  (import "wasi_ephemeral_crypto_common" "array_output_len"
+    (func $wasi_ephemeral_crypto_common.array_output_len
+      (param $array_output i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; Copy the content of an `array_output` object into an application-allocated buffer.
+  ;; 
+  ;; Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+  ;; 
+  ;; The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+  ;; 
+  ;; The handle is automatically closed after all the data has been consumed.
+  ;; 
+  ;; Example usage:
+  ;; 
+  ;; ```rust
+  ;; let len = array_output_len(output_handle)?;
+  ;; let mut out = vec![0u8; len];
+  ;; array_output_pull(output_handle, &mut out)?;
+  ;; ```
+  (import "wasi_ephemeral_crypto_common" "array_output_pull"
+    (func $wasi_ephemeral_crypto_common.array_output_pull
+      (param $array_output i32)
+      (param $buf i32)
+      (param $buf_len i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; __(optional)__
+  ;; Create a context to use a secrets manager.
+  ;; 
+  ;; The set of required and supported options is defined by the host.
+  ;; 
+  ;; The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+  ;; This is also an optional import, meaning that the function may not even exist.
+  (import "wasi_ephemeral_crypto_common" "secrets_manager_open"
+    (func $wasi_ephemeral_crypto_common.secrets_manager_open
+      (param $options i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; __(optional)__
+  ;; Destroy a secrets manager context.
+  ;; 
+  ;; The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+  ;; This is also an optional import, meaning that the function may not even exist.
+  (import "wasi_ephemeral_crypto_common" "secrets_manager_close"
+    (func $wasi_ephemeral_crypto_common.secrets_manager_close
+      (param $secrets_manager i32)
+      (result i32)
+    )
+  )
+
+  ;; __(optional)__
+  ;; Invalidate a managed key or key pair given an identifier and a version.
+  ;; 
+  ;; This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+  ;; 
+  ;; `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+  ;; 
+  ;; The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+  ;; 
+  ;; This is an optional import, meaning that the function may not even exist.
+  (import "wasi_ephemeral_crypto_common" "secrets_manager_invalidate"
+    (func $wasi_ephemeral_crypto_common.secrets_manager_invalidate
+      (param $secrets_manager i32)
+      (param $key_id i32)
+      (param $key_id_len i32)
+      (param $key_version i64)
+      (result i32)
+    )
+  )
+)
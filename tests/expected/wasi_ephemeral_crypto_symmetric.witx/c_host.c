@@ -0,0 +1,784 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <stdbool.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+/*
+ * Checks that `len` elements of `elem_size` bytes starting at `ptr` are in the guest memory,
+ * and translates `ptr` to a native pointer. Raises an exception in the guest otherwise.
+ */
+static inline bool
+wasi_guest_range(wasm_exec_env_t exec_env, uint32_t ptr, uint32_t len, uint32_t elem_size,
+                 void **native)
+{
+    wasm_module_inst_t inst = wasm_runtime_get_module_inst(exec_env);
+    uint64_t size = (uint64_t) len * elem_size;
+
+    if (size > UINT32_MAX || !wasm_runtime_validate_app_addr(inst, ptr, (uint32_t) size)) {
+        wasm_runtime_set_exception(inst, "out of bounds memory access");
+        return false;
+    }
+    *native = wasm_runtime_addr_app_to_native(inst, ptr);
+    return true;
+}
+
+/* ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ---------------------- */
+
+#include "wasi_ephemeral_crypto_symmetric_host.h"
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_generate_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t algorithm_ptr,
+    uint32_t algorithm_len,
+    uint32_t options,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *algorithm_ptr_native = NULL;
+    void *options_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_key_generate == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_generate is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, algorithm_ptr, algorithm_len, 1, &algorithm_ptr_native) ||
+        !wasi_guest_range(exec_env, options, 1, 8, &options_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_generate(
+        exec_env,
+        (const char *) algorithm_ptr_native,
+        algorithm_len,
+        (const opt_options_t *) options_native,
+        (symmetric_key_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_import_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t algorithm_ptr,
+    uint32_t algorithm_len,
+    uint32_t raw,
+    uint32_t raw_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *algorithm_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_key_import == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_import is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, algorithm_ptr, algorithm_len, 1, &algorithm_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_import(
+        exec_env,
+        (const char *) algorithm_ptr_native,
+        algorithm_len,
+        (wasi_guest_ptr_t) raw,
+        (size_t_) raw_len,
+        (symmetric_key_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_export_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t symmetric_key,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_key_export == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_export is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_export(
+        exec_env,
+        (symmetric_key_t) symmetric_key,
+        (array_output_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_close_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t symmetric_key)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->symmetric_key_close == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_close is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_close(
+        exec_env,
+        (symmetric_key_t) symmetric_key);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_generate_managed_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t secrets_manager,
+    uint32_t algorithm_ptr,
+    uint32_t algorithm_len,
+    uint32_t options,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *algorithm_ptr_native = NULL;
+    void *options_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_key_generate_managed == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_generate_managed is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, algorithm_ptr, algorithm_len, 1, &algorithm_ptr_native) ||
+        !wasi_guest_range(exec_env, options, 1, 8, &options_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_generate_managed(
+        exec_env,
+        (secrets_manager_t) secrets_manager,
+        (const char *) algorithm_ptr_native,
+        algorithm_len,
+        (const opt_options_t *) options_native,
+        (symmetric_key_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_store_managed_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t secrets_manager,
+    uint32_t symmetric_key,
+    uint32_t symmetric_key_id,
+    uint32_t symmetric_key_id_max_len)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->symmetric_key_store_managed == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_store_managed is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_store_managed(
+        exec_env,
+        (secrets_manager_t) secrets_manager,
+        (symmetric_key_t) symmetric_key,
+        (wasi_guest_ptr_t) symmetric_key_id,
+        (size_t_) symmetric_key_id_max_len);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_replace_managed_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t secrets_manager,
+    uint32_t symmetric_key_old,
+    uint32_t symmetric_key_new,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_key_replace_managed == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_replace_managed is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 8, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_replace_managed(
+        exec_env,
+        (secrets_manager_t) secrets_manager,
+        (symmetric_key_t) symmetric_key_old,
+        (symmetric_key_t) symmetric_key_new,
+        (version_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_id_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t symmetric_key,
+    uint32_t symmetric_key_id,
+    uint32_t symmetric_key_id_max_len,
+    uint32_t result_0_ptr,
+    uint32_t result_1_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_0_ptr_native = NULL;
+    void *result_1_ptr_native = NULL;
+
+    if (host->symmetric_key_id == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_id is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_0_ptr, 1, 4, &result_0_ptr_native) ||
+        !wasi_guest_range(exec_env, result_1_ptr, 1, 8, &result_1_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_id(
+        exec_env,
+        (symmetric_key_t) symmetric_key,
+        (wasi_guest_ptr_t) symmetric_key_id,
+        (size_t_) symmetric_key_id_max_len,
+        (size_t_ *) result_0_ptr_native,
+        (version_t *) result_1_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_key_from_id_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t secrets_manager,
+    uint32_t symmetric_key_id,
+    uint32_t symmetric_key_id_len,
+    uint64_t symmetric_key_version,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_key_from_id == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_key_from_id is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_key_from_id(
+        exec_env,
+        (secrets_manager_t) secrets_manager,
+        (wasi_guest_ptr_t) symmetric_key_id,
+        (size_t_) symmetric_key_id_len,
+        (version_t) symmetric_key_version,
+        (symmetric_key_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_open_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t algorithm_ptr,
+    uint32_t algorithm_len,
+    uint32_t key,
+    uint32_t options,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *algorithm_ptr_native = NULL;
+    void *key_native = NULL;
+    void *options_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_open == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_open is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, algorithm_ptr, algorithm_len, 1, &algorithm_ptr_native) ||
+        !wasi_guest_range(exec_env, key, 1, 8, &key_native) ||
+        !wasi_guest_range(exec_env, options, 1, 8, &options_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_open(
+        exec_env,
+        (const char *) algorithm_ptr_native,
+        algorithm_len,
+        (const opt_symmetric_key_t *) key_native,
+        (const opt_options_t *) options_native,
+        (symmetric_state_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_options_get_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t name_ptr,
+    uint32_t name_len,
+    uint32_t value,
+    uint32_t value_max_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *name_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_options_get == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_options_get is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, name_ptr, name_len, 1, &name_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_options_get(
+        exec_env,
+        (symmetric_state_t) handle,
+        (const char *) name_ptr_native,
+        name_len,
+        (wasi_guest_ptr_t) value,
+        (size_t_) value_max_len,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_options_get_u_64_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t name_ptr,
+    uint32_t name_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *name_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_options_get_u_64 == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_options_get_u64 is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, name_ptr, name_len, 1, &name_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 8, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_options_get_u_64(
+        exec_env,
+        (symmetric_state_t) handle,
+        (const char *) name_ptr_native,
+        name_len,
+        (u_64_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_close_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->symmetric_state_close == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_close is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_close(
+        exec_env,
+        (symmetric_state_t) handle);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_absorb_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t data,
+    uint32_t data_len)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->symmetric_state_absorb == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_absorb is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_absorb(
+        exec_env,
+        (symmetric_state_t) handle,
+        (wasi_guest_ptr_t) data,
+        (size_t_) data_len);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_squeeze_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t out,
+    uint32_t out_len)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->symmetric_state_squeeze == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_squeeze is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_squeeze(
+        exec_env,
+        (symmetric_state_t) handle,
+        (wasi_guest_ptr_t) out,
+        (size_t_) out_len);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_squeeze_tag_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_squeeze_tag == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_squeeze_tag is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_squeeze_tag(
+        exec_env,
+        (symmetric_state_t) handle,
+        (symmetric_tag_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_squeeze_key_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t alg_str_ptr,
+    uint32_t alg_str_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *alg_str_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_squeeze_key == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_squeeze_key is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, alg_str_ptr, alg_str_len, 1, &alg_str_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_squeeze_key(
+        exec_env,
+        (symmetric_state_t) handle,
+        (const char *) alg_str_ptr_native,
+        alg_str_len,
+        (symmetric_key_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_max_tag_len_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_max_tag_len == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_max_tag_len is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_max_tag_len(
+        exec_env,
+        (symmetric_state_t) handle,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_encrypt_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t out,
+    uint32_t out_len,
+    uint32_t data,
+    uint32_t data_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_encrypt == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_encrypt is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_encrypt(
+        exec_env,
+        (symmetric_state_t) handle,
+        (wasi_guest_ptr_t) out,
+        (size_t_) out_len,
+        (wasi_guest_ptr_t) data,
+        (size_t_) data_len,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_encrypt_detached_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t out,
+    uint32_t out_len,
+    uint32_t data,
+    uint32_t data_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_encrypt_detached == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_encrypt_detached is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_encrypt_detached(
+        exec_env,
+        (symmetric_state_t) handle,
+        (wasi_guest_ptr_t) out,
+        (size_t_) out_len,
+        (wasi_guest_ptr_t) data,
+        (size_t_) data_len,
+        (symmetric_tag_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_decrypt_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t out,
+    uint32_t out_len,
+    uint32_t data,
+    uint32_t data_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_decrypt == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_decrypt is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_decrypt(
+        exec_env,
+        (symmetric_state_t) handle,
+        (wasi_guest_ptr_t) out,
+        (size_t_) out_len,
+        (wasi_guest_ptr_t) data,
+        (size_t_) data_len,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_decrypt_detached_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle,
+    uint32_t out,
+    uint32_t out_len,
+    uint32_t data,
+    uint32_t data_len,
+    uint32_t raw_tag,
+    uint32_t raw_tag_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_state_decrypt_detached == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_decrypt_detached is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_decrypt_detached(
+        exec_env,
+        (symmetric_state_t) handle,
+        (wasi_guest_ptr_t) out,
+        (size_t_) out_len,
+        (wasi_guest_ptr_t) data,
+        (size_t_) data_len,
+        (wasi_guest_ptr_t) raw_tag,
+        (size_t_) raw_tag_len,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_state_ratchet_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t handle)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->symmetric_state_ratchet == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_state_ratchet is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->symmetric_state_ratchet(
+        exec_env,
+        (symmetric_state_t) handle);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_tag_len_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t symmetric_tag,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_tag_len == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_tag_len is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_tag_len(
+        exec_env,
+        (symmetric_tag_t) symmetric_tag,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_tag_pull_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t symmetric_tag,
+    uint32_t buf,
+    uint32_t buf_len,
+    uint32_t result_ptr)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *result_ptr_native = NULL;
+
+    if (host->symmetric_tag_pull == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_tag_pull is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->symmetric_tag_pull(
+        exec_env,
+        (symmetric_tag_t) symmetric_tag,
+        (wasi_guest_ptr_t) buf,
+        (size_t_) buf_len,
+        (size_t_ *) result_ptr_native);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_tag_verify_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t symmetric_tag,
+    uint32_t expected_raw_tag_ptr,
+    uint32_t expected_raw_tag_len)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->symmetric_tag_verify == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_tag_verify is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->symmetric_tag_verify(
+        exec_env,
+        (symmetric_tag_t) symmetric_tag,
+        (wasi_guest_ptr_t) expected_raw_tag_ptr,
+        (size_t_) expected_raw_tag_len);
+}
+
+static uint32_t
+wasi_ephemeral_crypto_symmetric_symmetric_tag_close_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t symmetric_tag)
+{
+    const wasi_ephemeral_crypto_symmetric_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->symmetric_tag_close == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_ephemeral_crypto_symmetric.symmetric_tag_close is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->symmetric_tag_close(
+        exec_env,
+        (symmetric_tag_t) symmetric_tag);
+}
+
+static NativeSymbol wasi_ephemeral_crypto_symmetric_native_symbols[] = {
+    { "symmetric_key_generate", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_generate_wrapper, "(iiii)i", NULL },
+    { "symmetric_key_import", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_import_wrapper, "(iiiii)i", NULL },
+    { "symmetric_key_export", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_export_wrapper, "(ii)i", NULL },
+    { "symmetric_key_close", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_close_wrapper, "(i)i", NULL },
+    { "symmetric_key_generate_managed", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_generate_managed_wrapper, "(iiiii)i", NULL },
+    { "symmetric_key_store_managed", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_store_managed_wrapper, "(iiii)i", NULL },
+    { "symmetric_key_replace_managed", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_replace_managed_wrapper, "(iiii)i", NULL },
+    { "symmetric_key_id", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_id_wrapper, "(iiiii)i", NULL },
+    { "symmetric_key_from_id", (void *) wasi_ephemeral_crypto_symmetric_symmetric_key_from_id_wrapper, "(iiiIi)i", NULL },
+    { "symmetric_state_open", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_open_wrapper, "(iiiii)i", NULL },
+    { "symmetric_state_options_get", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_options_get_wrapper, "(iiiiii)i", NULL },
+    { "symmetric_state_options_get_u64", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_options_get_u_64_wrapper, "(iiii)i", NULL },
+    { "symmetric_state_close", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_close_wrapper, "(i)i", NULL },
+    { "symmetric_state_absorb", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_absorb_wrapper, "(iii)i", NULL },
+    { "symmetric_state_squeeze", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_squeeze_wrapper, "(iii)i", NULL },
+    { "symmetric_state_squeeze_tag", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_squeeze_tag_wrapper, "(ii)i", NULL },
+    { "symmetric_state_squeeze_key", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_squeeze_key_wrapper, "(iiii)i", NULL },
+    { "symmetric_state_max_tag_len", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_max_tag_len_wrapper, "(ii)i", NULL },
+    { "symmetric_state_encrypt", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_encrypt_wrapper, "(iiiiii)i", NULL },
+    { "symmetric_state_encrypt_detached", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_encrypt_detached_wrapper, "(iiiiii)i", NULL },
+    { "symmetric_state_decrypt", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_decrypt_wrapper, "(iiiiii)i", NULL },
+    { "symmetric_state_decrypt_detached", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_decrypt_detached_wrapper, "(iiiiiiii)i", NULL },
+    { "symmetric_state_ratchet", (void *) wasi_ephemeral_crypto_symmetric_symmetric_state_ratchet_wrapper, "(i)i", NULL },
+    { "symmetric_tag_len", (void *) wasi_ephemeral_crypto_symmetric_symmetric_tag_len_wrapper, "(ii)i", NULL },
+    { "symmetric_tag_pull", (void *) wasi_ephemeral_crypto_symmetric_symmetric_tag_pull_wrapper, "(iiii)i", NULL },
+    { "symmetric_tag_verify", (void *) wasi_ephemeral_crypto_symmetric_symmetric_tag_verify_wrapper, "(iii)i", NULL },
+    { "symmetric_tag_close", (void *) wasi_ephemeral_crypto_symmetric_symmetric_tag_close_wrapper, "(i)i", NULL },
+};
+
+bool
+wasi_ephemeral_crypto_symmetric_register_natives(const wasi_ephemeral_crypto_symmetric_host_t *host)
+{
+    const size_t n = sizeof wasi_ephemeral_crypto_symmetric_native_symbols / sizeof wasi_ephemeral_crypto_symmetric_native_symbols[0];
+    size_t i;
+
+    /* The callbacks are found through the attachments of the symbols */
+    for (i = 0; i < n; i++) {
+        wasi_ephemeral_crypto_symmetric_native_symbols[i].attachment = (void *) host;
+    }
+    return wasm_runtime_register_natives("wasi_ephemeral_crypto_symmetric", wasi_ephemeral_crypto_symmetric_native_symbols, (uint32_t) n);
+}
+
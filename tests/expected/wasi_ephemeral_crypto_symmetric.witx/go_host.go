@@ -0,0 +1,1338 @@
+// Code generated by witx-codegen. DO NOT EDIT.
+
+package wasiephemeralcryptosymmetric
+
+import (
+	"context"
+	"errors"
+	"fmt"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiSlice is a list of values in the guest memory
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiSize
+}
+
+// WasiError is returned by a host implementation to return an error code to the guest
+type WasiError struct {
+	Errno uint32
+}
+
+func (e *WasiError) Error() string {
+	return fmt.Sprintf("WASI error %d", e.Errno)
+}
+
+// errno returns the error code of a WasiError, and panics on other errors, so that the
+// guest traps
+func errno(err error) uint64 {
+	var wasiErr *WasiError
+	if errors.As(err, &wasiErr) {
+		return api.EncodeU32(wasiErr.Errno)
+	}
+	panic(err)
+}
+
+func boolToU8(v bool) uint8 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// errOutOfBounds is raised when the guest passes a pointer outside of its memory
+var errOutOfBounds = errors.New("out of bounds memory access")
+
+// GuestAllocator allocates size bytes aligned to align in the memory of the guest module,
+// and returns their address
+type GuestAllocator func(ctx context.Context, mod api.Module, size, align uint32) (uint32, error)
+
+// guestMemory accesses the memory of the guest, and panics on invalid pointers
+type guestMemory struct {
+	api.Memory
+	// alloc is only set in functions returning strings or byte slices
+	alloc func(size, align uint32) uint32
+}
+
+// allocatingMemory returns a guestMemory that can allocate memory in the guest, and
+// panics if the allocation fails
+func allocatingMemory(ctx context.Context, mod api.Module, alloc GuestAllocator) guestMemory {
+	return guestMemory{mod.Memory(), func(size, align uint32) uint32 {
+		ptr, err := alloc(ctx, mod, size, align)
+		if err != nil {
+			panic(err)
+		}
+		return ptr
+	}}
+}
+
+func (m guestMemory) check(ok bool) {
+	if !ok {
+		panic(errOutOfBounds)
+	}
+}
+
+func (m guestMemory) u8(ptr uint32) uint8 {
+	v, ok := m.ReadByte(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU8(ptr uint32, v uint8) {
+	m.check(m.WriteByte(ptr, v))
+}
+
+func (m guestMemory) u16(ptr uint32) uint16 {
+	v, ok := m.ReadUint16Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU16(ptr uint32, v uint16) {
+	m.check(m.WriteUint16Le(ptr, v))
+}
+
+func (m guestMemory) u32(ptr uint32) uint32 {
+	v, ok := m.ReadUint32Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU32(ptr uint32, v uint32) {
+	m.check(m.WriteUint32Le(ptr, v))
+}
+
+func (m guestMemory) u64(ptr uint32) uint64 {
+	v, ok := m.ReadUint64Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU64(ptr uint32, v uint64) {
+	m.check(m.WriteUint64Le(ptr, v))
+}
+
+func (m guestMemory) f32(ptr uint32) float32 {
+	v, ok := m.ReadFloat32Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putF32(ptr uint32, v float32) {
+	m.check(m.WriteFloat32Le(ptr, v))
+}
+
+func (m guestMemory) f64(ptr uint32) float64 {
+	v, ok := m.ReadFloat64Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putF64(ptr uint32, v float64) {
+	m.check(m.WriteFloat64Le(ptr, v))
+}
+
+// bytes returns a view of the guest memory
+func (m guestMemory) bytes(ptr, len uint32) []byte {
+	b, ok := m.Read(ptr, len)
+	m.check(ok)
+	return b
+}
+
+func (m guestMemory) copyBytes(ptr, len uint32) []byte {
+	return append([]byte(nil), m.bytes(ptr, len)...)
+}
+
+func (m guestMemory) string(ptr, len uint32) string {
+	return string(m.bytes(ptr, len))
+}
+
+// putBytes copies data to memory allocated in the guest, and stores its address and
+// length at ptr
+func (m guestMemory) putBytes(ptr uint32, data []byte) {
+	dataPtr := m.alloc(uint32(len(data)), 1)
+	m.check(m.Write(dataPtr, data))
+	m.putU32(ptr, dataPtr)
+	m.putU32(ptr+4, uint32(len(data)))
+}
+
+// ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
+
+// Error codes.
+type CryptoErrno uint16
+
+const (
+	CryptoErrnoSuccess              CryptoErrno = 0
+	CryptoErrnoGuestError           CryptoErrno = 1
+	CryptoErrnoNotImplemented       CryptoErrno = 2
+	CryptoErrnoUnsupportedFeature   CryptoErrno = 3
+	CryptoErrnoProhibitedOperation  CryptoErrno = 4
+	CryptoErrnoUnsupportedEncoding  CryptoErrno = 5
+	CryptoErrnoUnsupportedAlgorithm CryptoErrno = 6
+	CryptoErrnoUnsupportedOption    CryptoErrno = 7
+	CryptoErrnoInvalidKey           CryptoErrno = 8
+	CryptoErrnoInvalidLength        CryptoErrno = 9
+	CryptoErrnoVerificationFailed   CryptoErrno = 10
+	CryptoErrnoRngError             CryptoErrno = 11
+	CryptoErrnoAlgorithmFailure     CryptoErrno = 12
+	CryptoErrnoInvalidSignature     CryptoErrno = 13
+	CryptoErrnoClosed               CryptoErrno = 14
+	CryptoErrnoInvalidHandle        CryptoErrno = 15
+	CryptoErrnoOverflow             CryptoErrno = 16
+	CryptoErrnoInternalError        CryptoErrno = 17
+	CryptoErrnoTooManyHandles       CryptoErrno = 18
+	CryptoErrnoKeyNotSupported      CryptoErrno = 19
+	CryptoErrnoKeyRequired          CryptoErrno = 20
+	CryptoErrnoInvalidTag           CryptoErrno = 21
+	CryptoErrnoInvalidOperation     CryptoErrno = 22
+	CryptoErrnoNonceRequired        CryptoErrno = 23
+	CryptoErrnoInvalidNonce         CryptoErrno = 24
+	CryptoErrnoOptionNotSet         CryptoErrno = 25
+	CryptoErrnoNotFound             CryptoErrno = 26
+	CryptoErrnoParametersMissing    CryptoErrno = 27
+	CryptoErrnoInProgress           CryptoErrno = 28
+	CryptoErrnoIncompatibleKeys     CryptoErrno = 29
+	CryptoErrnoExpired              CryptoErrno = 30
+)
+
+// Encoding to use for importing or exporting a key pair.
+type KeypairEncoding uint16
+
+const (
+	KeypairEncodingRaw   KeypairEncoding = 0
+	KeypairEncodingPkcs8 KeypairEncoding = 1
+	KeypairEncodingPem   KeypairEncoding = 2
+	KeypairEncodingLocal KeypairEncoding = 3
+)
+
+// Encoding to use for importing or exporting a public key.
+type PublickeyEncoding uint16
+
+const (
+	PublickeyEncodingRaw           PublickeyEncoding = 0
+	PublickeyEncodingPkcs8         PublickeyEncoding = 1
+	PublickeyEncodingPem           PublickeyEncoding = 2
+	PublickeyEncodingSec           PublickeyEncoding = 3
+	PublickeyEncodingCompressedSec PublickeyEncoding = 4
+	PublickeyEncodingLocal         PublickeyEncoding = 5
+)
+
+// Encoding to use for importing or exporting a secret key.
+type SecretkeyEncoding uint16
+
+const (
+	SecretkeyEncodingRaw           SecretkeyEncoding = 0
+	SecretkeyEncodingPkcs8         SecretkeyEncoding = 1
+	SecretkeyEncodingPem           SecretkeyEncoding = 2
+	SecretkeyEncodingSec           SecretkeyEncoding = 3
+	SecretkeyEncodingCompressedSec SecretkeyEncoding = 4
+	SecretkeyEncodingLocal         SecretkeyEncoding = 5
+)
+
+// Encoding to use for importing or exporting a signature.
+type SignatureEncoding uint16
+
+const (
+	SignatureEncodingRaw SignatureEncoding = 0
+	SignatureEncodingDer SignatureEncoding = 1
+)
+
+// An algorithm category.
+type AlgorithmType uint16
+
+const (
+	AlgorithmTypeSignatures  AlgorithmType = 0
+	AlgorithmTypeSymmetric   AlgorithmType = 1
+	AlgorithmTypeKeyExchange AlgorithmType = 2
+)
+
+// Version of a managed key.
+//
+// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+type Version = uint64
+
+// Size of a value.
+type Size = WasiSize
+
+// A UNIX timestamp, in seconds since 01/01/1970.
+type Timestamp = uint64
+
+// A 64-bit value
+type U64 = uint64
+
+// Handle for functions returning output whose size may be large or not known in advance.
+//
+// An `array_output` object contains a host-allocated byte array.
+//
+// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+//
+// An `array_output` handle is automatically closed after its full content has been consumed.
+type ArrayOutput = WasiHandle
+
+// A set of options.
+//
+// This type is used to set non-default parameters.
+//
+// The exact set of allowed options depends on the algorithm being used.
+type Options = WasiHandle
+
+// A handle to the optional secrets management facilities offered by a host.
+//
+// This is used to generate, retrieve and invalidate managed keys.
+type SecretsManager = WasiHandle
+
+// A key pair.
+type Keypair = WasiHandle
+
+// A state to absorb data to be signed.
+//
+// After a signature has been computed or verified, the state remains valid for further operations.
+//
+// A subsequent signature would sign all the data accumulated since the creation of the state object.
+type SignatureState = WasiHandle
+
+// A signature.
+type Signature = WasiHandle
+
+// A public key, for key exchange and signature verification.
+type Publickey = WasiHandle
+
+// A secret key, for key exchange mechanisms.
+type Secretkey = WasiHandle
+
+// A state to absorb signed data to be verified.
+type SignatureVerificationState = WasiHandle
+
+// A state to perform symmetric operations.
+//
+// The state is not reset nor invalidated after an option has been performed.
+// Incremental updates and sessions are thus supported.
+type SymmetricState = WasiHandle
+
+// A symmetric key.
+//
+// The key can be imported from raw bytes, or can be a reference to a managed key.
+//
+// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+type SymmetricKey = WasiHandle
+
+// An authentication tag.
+//
+// This is an object returned by functions computing authentication tags.
+//
+// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+//
+// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+//
+// The host is reponsible for securely wiping them from memory on close.
+type SymmetricTag = WasiHandle
+
+// Options index, only required by the Interface Types translation layer.
+type OptOptionsU uint8
+
+const (
+	OptOptionsUSome OptOptionsU = 0
+	OptOptionsUNone OptOptionsU = 1
+)
+
+// An optional options set.
+//
+// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+type OptOptions struct {
+	Tag  uint8
+	Some Options
+}
+
+const (
+	OptOptionsSome = 0
+	OptOptionsNone = 1
+)
+
+func readOptOptions(m guestMemory, ptr uint32) OptOptions {
+	v := OptOptions{Tag: uint8(m.u8(ptr))}
+	switch v.Tag {
+	case OptOptionsSome:
+		v.Some = Options(m.u32(ptr+4))
+	case OptOptionsNone:
+	default:
+		panic(fmt.Sprintf("invalid tag for OptOptions: %d", v.Tag))
+	}
+	return v
+}
+
+func writeOptOptions(m guestMemory, ptr uint32, v OptOptions) {
+	m.putU8(ptr, uint8(v.Tag))
+	switch v.Tag {
+	case OptOptionsSome:
+		m.putU32(ptr+4, uint32(v.Some))
+	}
+}
+
+// Symmetric key index, only required by the Interface Types translation layer.
+type OptSymmetricKeyU uint8
+
+const (
+	OptSymmetricKeyUSome OptSymmetricKeyU = 0
+	OptSymmetricKeyUNone OptSymmetricKeyU = 1
+)
+
+// An optional symmetric key.
+//
+// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+type OptSymmetricKey struct {
+	Tag  uint8
+	Some SymmetricKey
+}
+
+const (
+	OptSymmetricKeySome = 0
+	OptSymmetricKeyNone = 1
+)
+
+func readOptSymmetricKey(m guestMemory, ptr uint32) OptSymmetricKey {
+	v := OptSymmetricKey{Tag: uint8(m.u8(ptr))}
+	switch v.Tag {
+	case OptSymmetricKeySome:
+		v.Some = SymmetricKey(m.u32(ptr+4))
+	case OptSymmetricKeyNone:
+	default:
+		panic(fmt.Sprintf("invalid tag for OptSymmetricKey: %d", v.Tag))
+	}
+	return v
+}
+
+func writeOptSymmetricKey(m guestMemory, ptr uint32, v OptSymmetricKey) {
+	m.putU8(ptr, uint8(v.Tag))
+	switch v.Tag {
+	case OptSymmetricKeySome:
+		m.putU32(ptr+4, uint32(v.Some))
+	}
+}
+
+// WasiEphemeralCryptoSymmetric is the host implementation of the [wasi_ephemeral_crypto_symmetric] module
+//
+// The methods receive the parameters decoded from the guest memory, and return the
+// results. Errors are returned to the guest by returning a *WasiError.
+type WasiEphemeralCryptoSymmetric interface {
+	// Generate a new symmetric key for a given algorithm.
+	//
+	// `options` can be `None` to use the default parameters, or an algoritm-specific set of parameters to override.
+	//
+	// This function may return `unsupported_feature` if key generation is not supported by the host for the chosen algorithm, or `unsupported_algorithm` if the algorithm is not supported by the host.
+	SymmetricKeyGenerate(ctx context.Context, algorithm string, options OptOptions) (SymmetricKey, error)
+
+	// Create a symmetric key from raw material.
+	//
+	// The algorithm is internally stored along with the key, and trying to use the key with an operation expecting a different algorithm will return `invalid_key`.
+	//
+	// The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+	SymmetricKeyImport(ctx context.Context, algorithm string, raw WasiPtr, rawLen Size) (SymmetricKey, error)
+
+	// Export a symmetric key as raw material.
+	//
+	// This is mainly useful to export a managed key.
+	//
+	// May return `prohibited_operation` if this operation is denied.
+	SymmetricKeyExport(ctx context.Context, symmetricKey SymmetricKey) (ArrayOutput, error)
+
+	// Destroy a symmetric key.
+	//
+	// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+	SymmetricKeyClose(ctx context.Context, symmetricKey SymmetricKey) error
+
+	// __(optional)__
+	// Generate a new managed symmetric key.
+	//
+	// The key is generated and stored by the secrets management facilities.
+	//
+	// It may be used through its identifier, but the host may not allow it to be exported.
+	//
+	// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+	// or `unsupported_algorithm` if a key cannot be created for the chosen algorithm.
+	//
+	// The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+	//
+	// This is also an optional import, meaning that the function may not even exist.
+	SymmetricKeyGenerateManaged(ctx context.Context, secretsManager SecretsManager, algorithm string, options OptOptions) (SymmetricKey, error)
+
+	// __(optional)__
+	// Store a symmetric key into the secrets manager.
+	//
+	// On success, the function stores the key identifier into `$symmetric_key_id`,
+	// into which up to `$symmetric_key_id_max_len` can be written.
+	//
+	// The function returns `overflow` if the supplied buffer is too small.
+	SymmetricKeyStoreManaged(ctx context.Context, secretsManager SecretsManager, symmetricKey SymmetricKey, symmetricKeyId WasiPtr, symmetricKeyIdMaxLen Size) error
+
+	// __(optional)__
+	// Replace a managed symmetric key.
+	//
+	// This function crates a new version of a managed symmetric key, by replacing `$kp_old` with `$kp_new`.
+	//
+	// It does several things:
+	//
+	// - The key identifier for `$symmetric_key_new` is set to the one of `$symmetric_key_old`.
+	// - A new, unique version identifier is assigned to `$kp_new`. This version will be equivalent to using `$version_latest` until the key is replaced.
+	// - The `$symmetric_key_old` handle is closed.
+	//
+	// Both keys must share the same algorithm and have compatible parameters. If this is not the case, `incompatible_keys` is returned.
+	//
+	// The function may also return the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+	// or if keys cannot be rotated.
+	//
+	// Finally, `prohibited_operation` can be returned if `$symmetric_key_new` wasn't created by the secrets manager, and the secrets manager prohibits imported keys.
+	//
+	// If the operation succeeded, the new version is returned.
+	//
+	// This is an optional import, meaning that the function may not even exist.
+	SymmetricKeyReplaceManaged(ctx context.Context, secretsManager SecretsManager, symmetricKeyOld SymmetricKey, symmetricKeyNew SymmetricKey) (Version, error)
+
+	// __(optional)__
+	// Return the key identifier and version of a managed symmetric key.
+	//
+	// If the key is not managed, `unsupported_feature` is returned instead.
+	//
+	// This is an optional import, meaning that the function may not even exist.
+	SymmetricKeyId(ctx context.Context, symmetricKey SymmetricKey, symmetricKeyId WasiPtr, symmetricKeyIdMaxLen Size) (Size, Version, error)
+
+	// __(optional)__
+	// Return a managed symmetric key from a key identifier.
+	//
+	// `kp_version` can be set to `version_latest` to retrieve the most recent version of a symmetric key.
+	//
+	// If no key matching the provided information is found, `not_found` is returned instead.
+	//
+	// This is an optional import, meaning that the function may not even exist.
+	SymmetricKeyFromId(ctx context.Context, secretsManager SecretsManager, symmetricKeyId WasiPtr, symmetricKeyIdLen Size, symmetricKeyVersion Version) (SymmetricKey, error)
+
+	// Create a new state to aborb and produce data using symmetric operations.
+	//
+	// The state remains valid after every operation in order to support incremental updates.
+	//
+	// The function has two optional parameters: a key and an options set.
+	//
+	// It will fail with a `key_not_supported` error code if a key was provided but the chosen algorithm doesn't natively support keying.
+	//
+	// On the other hand, if a key is required, but was not provided, a `key_required` error will be thrown.
+	//
+	// Some algorithms may require additional parameters. They have to be supplied as an options set:
+	//
+	// ```rust
+	// let options_handle = ctx.options_open()?;
+	// ctx.options_set("context", b"My application")?;
+	// ctx.options_set_u64("fanout", 16)?;
+	// let state_handle = ctx.symmetric_state_open("BLAKE2b-512", None, Some(options_handle))?;
+	// ```
+	//
+	// If some parameters are mandatory but were not set, the `parameters_missing` error code will be returned.
+	//
+	// A notable exception is the `nonce` parameter, that is common to most AEAD constructions.
+	//
+	// If a nonce is required but was not supplied:
+	//
+	// - If it is safe to do so, the host will automatically generate a nonce. This is true for nonces that are large enough to be randomly generated, or if the host is able to maintain a global counter.
+	// - If not, the function will fail and return the dedicated `nonce_required` error code.
+	//
+	// A nonce that was automatically generated can be retrieved after the function returns with `symmetric_state_get(state_handle, "nonce")`.
+	//
+	// **Sample usage patterns:**
+	//
+	// - **Hashing**
+	//
+	// ```rust
+	// let mut out = [0u8; 64];
+	// let state_handle = ctx.symmetric_state_open("SHAKE-128", None, None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+	// ```
+	//
+	// - **MAC**
+	//
+	// ```rust
+	// let mut raw_tag = [0u8; 64];
+	// let key_handle = ctx.symmetric_key_import("HMAC/SHA-512", b"key")?;
+	// let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+	// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+	// ctx.symmetric_tag_pull(computed_tag_handle, &mut raw_tag)?;
+	// ```
+	//
+	// Verification:
+	//
+	// ```rust
+	// let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+	// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+	// ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+	// ```
+	//
+	// - **Tuple hashing**
+	//
+	// ```rust
+	// let mut out = [0u8; 64];
+	// let state_handle = ctx.symmetric_state_open("TupleHashXOF256", None, None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"value 1")?;
+	// ctx.symmetric_state_absorb(state_handle, b"value 2")?;
+	// ctx.symmetric_state_absorb(state_handle, b"value 3")?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+	// ```
+	// Unlike MACs and regular hash functions, inputs are domain separated instead of being concatenated.
+	//
+	// - **Key derivation using extract-and-expand**
+	//
+	// Extract:
+	//
+	// ```rust
+	// let mut prk = vec![0u8; 64];
+	// let key_handle = ctx.symmetric_key_import("HKDF-EXTRACT/SHA-512", b"key")?;
+	// let state_handle = ctx.symmetric_state_open("HKDF-EXTRACT/SHA-512", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"salt")?;
+	// let prk_handle = ctx.symmetric_state_squeeze_key(state_handle, "HKDF-EXPAND/SHA-512")?;
+	// ```
+	//
+	// Expand:
+	//
+	// ```rust
+	// let mut subkey = vec![0u8; 32];
+	// let state_handle = ctx.symmetric_state_open("HKDF-EXPAND/SHA-512", Some(prk_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"info")?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut subkey)?;
+	// ```
+	//
+	// - **Key derivation using a XOF**
+	//
+	// ```rust
+	// let mut subkey1 = vec![0u8; 32];
+	// let mut subkey2 = vec![0u8; 32];
+	// let key_handle = ctx.symmetric_key_import("BLAKE3", b"key")?;
+	// let state_handle = ctx.symmetric_state_open("BLAKE3", Some(key_handle), None)?;
+	// ctx.symmetric_absorb(state_handle, b"context")?;
+	// ctx.squeeze(state_handle, &mut subkey1)?;
+	// ctx.squeeze(state_handle, &mut subkey2)?;
+	// ```
+	//
+	// - **Password hashing**
+	//
+	// ```rust
+	// let mut memory = vec![0u8; 1_000_000_000];
+	// let options_handle = ctx.symmetric_options_open()?;
+	// ctx.symmetric_options_set_guest_buffer(options_handle, "memory", &mut memory)?;
+	// ctx.symmetric_options_set_u64(options_handle, "opslimit", 5)?;
+	// ctx.symmetric_options_set_u64(options_handle, "parallelism", 8)?;
+	//
+	// let state_handle = ctx.symmetric_state_open("ARGON2-ID-13", None, Some(options))?;
+	// ctx.symmtric_state_absorb(state_handle, b"password")?;
+	//
+	// let pw_str_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+	// let mut pw_str = vec![0u8; ctx.symmetric_tag_len(pw_str_handle)?];
+	// ctx.symmetric_tag_pull(pw_str_handle, &mut pw_str)?;
+	// ```
+	//
+	// - **AEAD encryption with an explicit nonce**
+	//
+	// ```rust
+	// let key_handle = ctx.symmetric_key_generate("AES-256-GCM", None)?;
+	// let message = b"test";
+	//
+	// let options_handle = ctx.symmetric_options_open()?;
+	// ctx.symmetric_options_set(options_handle, "nonce", nonce)?;
+	//
+	// let state_handle = ctx.symmetric_state_open("AES-256-GCM", Some(key_handle), Some(options_handle))?;
+	// let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+	// ctx.symmetric_state_absorb(state_handle, "additional data")?;
+	// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+	// ```
+	//
+	// - **AEAD encryption with automatic nonce generation**
+	//
+	// ```rust
+	// let key_handle = ctx.symmetric_key_generate("AES-256-GCM-SIV", None)?;
+	// let message = b"test";
+	// let mut nonce = [0u8; 24];
+	//
+	// let state_handle = ctx.symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), None)?;
+	//
+	// let nonce_handle = ctx.symmetric_state_options_get(state_handle, "nonce")?;
+	// ctx.array_output_pull(nonce_handle, &mut nonce)?;
+	//
+	// let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+	// ctx.symmetric_state_absorb(state_handle, "additional data")?;
+	// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+	// ```
+	//
+	// - **Session authenticated modes**
+	//
+	// ```rust
+	// let mut out = [0u8; 16];
+	// let mut out2 = [0u8; 16];
+	// let mut ciphertext = [0u8; 20];
+	// let key_handle = ctx.symmetric_key_generate("Xoodyak-128", None)?;
+	// let state_handle = ctx.symmetric_state_open("Xoodyak-128", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, b"abcd")?;
+	// ctx.symmetric_state_absorb(state_handle, b"more data")?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut out2)?;
+	// ctx.symmetric_state_ratchet(state_handle)?;
+	// ctx.symmetric_state_absorb(state_handle, b"more data")?;
+	// let next_key_handle = ctx.symmetric_state_squeeze_key(state_handle, "Xoodyak-128")?;
+	// // ...
+	// ```
+	SymmetricStateOpen(ctx context.Context, algorithm string, key OptSymmetricKey, options OptOptions) (SymmetricState, error)
+
+	// Retrieve a parameter from the current state.
+	//
+	// In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+	//
+	// The function may return `options_not_set` if an option was not set, which is different from an empty value.
+	//
+	// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+	SymmetricStateOptionsGet(ctx context.Context, handle SymmetricState, name string, value WasiPtr, valueMaxLen Size) (Size, error)
+
+	// Retrieve an integer parameter from the current state.
+	//
+	// In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+	//
+	// The function may return `options_not_set` if an option was not set.
+	//
+	// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+	SymmetricStateOptionsGetU64(ctx context.Context, handle SymmetricState, name string) (U64, error)
+
+	// Destroy a symmetric state.
+	//
+	// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+	SymmetricStateClose(ctx context.Context, handle SymmetricState) error
+
+	// Absorb data into the state.
+	//
+	// - **Hash functions:** adds data to be hashed.
+	// - **MAC functions:** adds data to be authenticated.
+	// - **Tuplehash-like constructions:** adds a new tuple to the state.
+	// - **Key derivation functions:** adds to the IKM or to the subkey information.
+	// - **AEAD constructions:** adds additional data to be authenticated.
+	// - **Stateful hash objects, permutation-based constructions:** absorbs.
+	//
+	// If the chosen algorithm doesn't accept input data, the `invalid_operation` error code is returned.
+	//
+	// If too much data has been fed for the algorithm, `overflow` may be thrown.
+	SymmetricStateAbsorb(ctx context.Context, handle SymmetricState, data WasiPtr, dataLen Size) error
+
+	// Squeeze bytes from the state.
+	//
+	// - **Hash functions:** this tries to output an `out_len` bytes digest from the absorbed data. The hash function output will be truncated if necessary. If the requested size is too large, the `invalid_len` error code is returned.
+	// - **Key derivation functions:** : outputs an arbitrary-long derived key.
+	// - **RNGs, DRBGs, stream ciphers:**: outputs arbitrary-long data.
+	// - **Stateful hash objects, permutation-based constructions:** squeeze.
+	//
+	// Other kinds of algorithms may return `invalid_operation` instead.
+	//
+	// For password-stretching functions, the function may return `in_progress`.
+	// In that case, the guest should retry with the same parameters until the function completes.
+	SymmetricStateSqueeze(ctx context.Context, handle SymmetricState, out WasiPtr, outLen Size) error
+
+	// Compute and return a tag for all the data injected into the state so far.
+	//
+	// - **MAC functions**: returns a tag authenticating the absorbed data.
+	// - **Tuplehash-like constructions:** returns a tag authenticating all the absorbed tuples.
+	// - **Password-hashing functions:** returns a standard string containing all the required parameters for password verification.
+	//
+	// Other kinds of algorithms may return `invalid_operation` instead.
+	//
+	// For password-stretching functions, the function may return `in_progress`.
+	// In that case, the guest should retry with the same parameters until the function completes.
+	SymmetricStateSqueezeTag(ctx context.Context, handle SymmetricState) (SymmetricTag, error)
+
+	// Use the current state to produce a key for a target algorithm.
+	//
+	// For extract-then-expand constructions, this returns the PRK.
+	// For session-base authentication encryption, this returns a key that can be used to resume a session without storing a nonce.
+	//
+	// `invalid_operation` is returned for algorithms not supporting this operation.
+	SymmetricStateSqueezeKey(ctx context.Context, handle SymmetricState, algStr string) (SymmetricKey, error)
+
+	// Return the maximum length of an authentication tag for the current algorithm.
+	//
+	// This allows guests to compute the size required to store a ciphertext along with its authentication tag.
+	//
+	// The returned length may include the encryption mode's padding requirements in addition to the actual tag.
+	//
+	// For an encryption operation, the size of the output buffer should be `input_len + symmetric_state_max_tag_len()`.
+	//
+	// For a decryption operation, the size of the buffer that will store the decrypted data must be `ciphertext_len - symmetric_state_max_tag_len()`.
+	SymmetricStateMaxTagLen(ctx context.Context, handle SymmetricState) (Size, error)
+
+	// Encrypt data with an attached tag.
+	//
+	// - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+	// - **AEAD:** encrypts `data` into `out`, including the authentication tag to the output. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The `symmetric_state_max_tag_len()` function can be used to retrieve the overhead of adding the tag, as well as padding if necessary.
+	// - **SHOE, Xoodyak, Strobe:** encrypts data, squeezes a tag and appends it to the output.
+	//
+	// If `out` and `data` are the same address, encryption may happen in-place.
+	//
+	// The function returns the actual size of the ciphertext along with the tag.
+	//
+	// `invalid_operation` is returned for algorithms not supporting encryption.
+	SymmetricStateEncrypt(ctx context.Context, handle SymmetricState, out WasiPtr, outLen Size, data WasiPtr, dataLen Size) (Size, error)
+
+	// Encrypt data, with a detached tag.
+	//
+	// - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+	// - **AEAD:** encrypts `data` into `out` and returns the tag separately. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The output and input buffers must be of the same length.
+	// - **SHOE, Xoodyak, Strobe:** encrypts data and squeezes a tag.
+	//
+	// If `out` and `data` are the same address, encryption may happen in-place.
+	//
+	// The function returns the tag.
+	//
+	// `invalid_operation` is returned for algorithms not supporting encryption.
+	SymmetricStateEncryptDetached(ctx context.Context, handle SymmetricState, out WasiPtr, outLen Size, data WasiPtr, dataLen Size) (SymmetricTag, error)
+
+	// - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+	// - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+	// - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the one that was appended to the ciphertext.
+	//
+	// If `out` and `data` are the same address, decryption may happen in-place.
+	//
+	// `out_len` must be exactly `data_len` + `max_tag_len` bytes.
+	//
+	// The function returns the actual size of the decrypted message, which can be smaller than `out_len` for modes that requires padding.
+	//
+	// `invalid_tag` is returned if the tag didn't verify.
+	//
+	// `invalid_operation` is returned for algorithms not supporting encryption.
+	SymmetricStateDecrypt(ctx context.Context, handle SymmetricState, out WasiPtr, outLen Size, data WasiPtr, dataLen Size) (Size, error)
+
+	// - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+	// - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+	// - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the expected one.
+	//
+	// `raw_tag` is the expected tag, as raw bytes.
+	//
+	// `out` and `data` be must have the same length.
+	// If they also share the same address, decryption may happen in-place.
+	//
+	// The function returns the actual size of the decrypted message.
+	//
+	// `invalid_tag` is returned if the tag verification failed.
+	//
+	// `invalid_operation` is returned for algorithms not supporting encryption.
+	SymmetricStateDecryptDetached(ctx context.Context, handle SymmetricState, out WasiPtr, outLen Size, data WasiPtr, dataLen Size, rawTag WasiPtr, rawTagLen Size) (Size, error)
+
+	// Make it impossible to recover the previous state.
+	//
+	// This operation is supported by some systems keeping a rolling state over an entire session, for forward security.
+	//
+	// `invalid_operation` is returned for algorithms not supporting ratcheting.
+	SymmetricStateRatchet(ctx context.Context, handle SymmetricState) error
+
+	// Return the length of an authentication tag.
+	//
+	// This function can be used by a guest to allocate the correct buffer size to copy a computed authentication tag.
+	SymmetricTagLen(ctx context.Context, symmetricTag SymmetricTag) (Size, error)
+
+	// Copy an authentication tag into a guest-allocated buffer.
+	//
+	// The handle automatically becomes invalid after this operation. Manually closing it is not required.
+	//
+	// Example usage:
+	//
+	// ```rust
+	// let mut raw_tag = [0u8; 16];
+	// ctx.symmetric_tag_pull(raw_tag_handle, &mut raw_tag)?;
+	// ```
+	//
+	// The function returns `overflow` if the supplied buffer is too small to copy the tag.
+	//
+	// Otherwise, it returns the number of bytes that have been copied.
+	SymmetricTagPull(ctx context.Context, symmetricTag SymmetricTag, buf WasiPtr, bufLen Size) (Size, error)
+
+	// Verify that a computed authentication tag matches the expected value, in constant-time.
+	//
+	// The expected tag must be provided as a raw byte string.
+	//
+	// The function returns `invalid_tag` if the tags don't match.
+	//
+	// Example usage:
+	//
+	// ```rust
+	// let key_handle = ctx.symmetric_key_import("HMAC/SHA-256", b"key")?;
+	// let state_handle = ctx.symmetric_state_open("HMAC/SHA-256", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+	// ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+	// ```
+	SymmetricTagVerify(ctx context.Context, symmetricTag SymmetricTag, expectedRawTagPtr WasiPtr, expectedRawTagLen Size) error
+
+	// Explicitly destroy an unused authentication tag.
+	//
+	// This is usually not necessary, as `symmetric_tag_pull()` automatically closes a tag after it has been copied.
+	//
+	// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+	SymmetricTagClose(ctx context.Context, symmetricTag SymmetricTag) error
+}
+
+// Instantiate adds the [wasi_ephemeral_crypto_symmetric] module to the runtime, calling the methods of impl
+func Instantiate(ctx context.Context, r wazero.Runtime, impl WasiEphemeralCryptoSymmetric) (api.Module, error) {
+	b := r.NewHostModuleBuilder("wasi_ephemeral_crypto_symmetric")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricKeyGenerate(
+				ctx,
+				m.string(api.DecodeU32(stack[0]), api.DecodeU32(stack[1])),
+				readOptOptions(m, api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[3]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("algorithm_ptr", "algorithm_len", "options", "result_ptr").
+		Export("symmetric_key_generate")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricKeyImport(
+				ctx,
+				m.string(api.DecodeU32(stack[0]), api.DecodeU32(stack[1])),
+				WasiPtr(api.DecodeU32(stack[2])),
+				Size(api.DecodeU32(stack[3])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[4]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("algorithm_ptr", "algorithm_len", "raw", "raw_len", "result_ptr").
+		Export("symmetric_key_import")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricKeyExport(ctx, SymmetricKey(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[1]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_key", "result_ptr").
+		Export("symmetric_key_export")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SymmetricKeyClose(ctx, SymmetricKey(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_key").
+		Export("symmetric_key_close")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricKeyGenerateManaged(
+				ctx,
+				SecretsManager(api.DecodeU32(stack[0])),
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+				readOptOptions(m, api.DecodeU32(stack[3])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[4]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "algorithm_ptr", "algorithm_len", "options", "result_ptr").
+		Export("symmetric_key_generate_managed")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SymmetricKeyStoreManaged(
+				ctx,
+				SecretsManager(api.DecodeU32(stack[0])),
+				SymmetricKey(api.DecodeU32(stack[1])),
+				WasiPtr(api.DecodeU32(stack[2])),
+				Size(api.DecodeU32(stack[3])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "symmetric_key", "symmetric_key_id", "symmetric_key_id_max_len").
+		Export("symmetric_key_store_managed")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricKeyReplaceManaged(
+				ctx,
+				SecretsManager(api.DecodeU32(stack[0])),
+				SymmetricKey(api.DecodeU32(stack[1])),
+				SymmetricKey(api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU64(api.DecodeU32(stack[3]), uint64(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "symmetric_key_old", "symmetric_key_new", "result_ptr").
+		Export("symmetric_key_replace_managed")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v0, v1, err := impl.SymmetricKeyId(
+				ctx,
+				SymmetricKey(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[3]), uint32(v0))
+			m.putU64(api.DecodeU32(stack[4]), uint64(v1))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_key", "symmetric_key_id", "symmetric_key_id_max_len", "result0_ptr", "result1_ptr").
+		Export("symmetric_key_id")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricKeyFromId(
+				ctx,
+				SecretsManager(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+				stack[3],
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[4]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI64, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "symmetric_key_id", "symmetric_key_id_len", "symmetric_key_version", "result_ptr").
+		Export("symmetric_key_from_id")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateOpen(
+				ctx,
+				m.string(api.DecodeU32(stack[0]), api.DecodeU32(stack[1])),
+				readOptSymmetricKey(m, api.DecodeU32(stack[2])),
+				readOptOptions(m, api.DecodeU32(stack[3])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[4]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("algorithm_ptr", "algorithm_len", "key", "options", "result_ptr").
+		Export("symmetric_state_open")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateOptionsGet(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+				WasiPtr(api.DecodeU32(stack[3])),
+				Size(api.DecodeU32(stack[4])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[5]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "value", "value_max_len", "result_ptr").
+		Export("symmetric_state_options_get")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateOptionsGetU64(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU64(api.DecodeU32(stack[3]), uint64(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "result_ptr").
+		Export("symmetric_state_options_get_u64")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SymmetricStateClose(ctx, SymmetricState(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle").
+		Export("symmetric_state_close")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SymmetricStateAbsorb(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "data", "data_len").
+		Export("symmetric_state_absorb")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SymmetricStateSqueeze(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len").
+		Export("symmetric_state_squeeze")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateSqueezeTag(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[1]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "result_ptr").
+		Export("symmetric_state_squeeze_tag")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateSqueezeKey(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[3]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "alg_str_ptr", "alg_str_len", "result_ptr").
+		Export("symmetric_state_squeeze_key")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateMaxTagLen(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[1]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "result_ptr").
+		Export("symmetric_state_max_tag_len")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateEncrypt(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+				WasiPtr(api.DecodeU32(stack[3])),
+				Size(api.DecodeU32(stack[4])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[5]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len", "data", "data_len", "result_ptr").
+		Export("symmetric_state_encrypt")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateEncryptDetached(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+				WasiPtr(api.DecodeU32(stack[3])),
+				Size(api.DecodeU32(stack[4])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[5]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len", "data", "data_len", "result_ptr").
+		Export("symmetric_state_encrypt_detached")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateDecrypt(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+				WasiPtr(api.DecodeU32(stack[3])),
+				Size(api.DecodeU32(stack[4])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[5]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len", "data", "data_len", "result_ptr").
+		Export("symmetric_state_decrypt")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricStateDecryptDetached(
+				ctx,
+				SymmetricState(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+				WasiPtr(api.DecodeU32(stack[3])),
+				Size(api.DecodeU32(stack[4])),
+				WasiPtr(api.DecodeU32(stack[5])),
+				Size(api.DecodeU32(stack[6])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[7]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len", "data", "data_len", "raw_tag", "raw_tag_len", "result_ptr").
+		Export("symmetric_state_decrypt_detached")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SymmetricStateRatchet(ctx, SymmetricState(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle").
+		Export("symmetric_state_ratchet")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricTagLen(ctx, SymmetricTag(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[1]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_tag", "result_ptr").
+		Export("symmetric_tag_len")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.SymmetricTagPull(
+				ctx,
+				SymmetricTag(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[3]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_tag", "buf", "buf_len", "result_ptr").
+		Export("symmetric_tag_pull")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SymmetricTagVerify(
+				ctx,
+				SymmetricTag(api.DecodeU32(stack[0])),
+				WasiPtr(api.DecodeU32(stack[1])),
+				Size(api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_tag", "expected_raw_tag_ptr", "expected_raw_tag_len").
+		Export("symmetric_tag_verify")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.SymmetricTagClose(ctx, SymmetricTag(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_tag").
+		Export("symmetric_tag_close")
+	return b.Instantiate(ctx)
+}
@@ -0,0 +1,1490 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = number;
+export type WasiPtr = number;
+export type WasiSize = number;
+export type Char8 = number;
+export type Char32 = number;
+
+export interface WasiSlice {
+    ptr: WasiPtr;
+    len: WasiSize;
+}
+
+/**
+ * Thrown by a host implementation to return an error code to the guest
+ */
+export class WasiError<E = number> extends Error {
+    constructor(readonly errno: E) {
+        super(`WASI error ${errno}`);
+    }
+}
+
+/**
+ * Allocates `size` bytes aligned to `align` in the guest memory, and returns their address
+ */
+export type GuestAllocator = (size: number, align: number) => number;
+
+const textDecoder = new TextDecoder("utf-8", { fatal: true });
+const textEncoder = new TextEncoder();
+
+/**
+ * The memory of a guest, that must be bound to the instance once it has been created
+ */
+export class GuestMemory {
+    private memory: WebAssembly.Memory | null = null;
+
+    bind(instance: WebAssembly.Instance): void {
+        this.memory = instance.exports.memory as WebAssembly.Memory;
+    }
+
+    view(): DataView {
+        return new DataView(this.buffer());
+    }
+
+    bytes(ptr: number, len: number): Uint8Array {
+        return new Uint8Array(this.buffer(), ptr >>> 0, len >>> 0);
+    }
+
+    readString(ptr: number, len: number): string {
+        return textDecoder.decode(this.bytes(ptr, len));
+    }
+
+    /**
+     * Copies bytes to memory allocated in the guest, and stores their address and length at `ptr`
+     */
+    writeBytes(alloc: GuestAllocator, ptr: number, bytes: Uint8Array): void {
+        const dataPtr = alloc(bytes.length, 1) >>> 0;
+        this.bytes(dataPtr, bytes.length).set(bytes);
+        const view = this.view();
+        view.setUint32(ptr, dataPtr, true);
+        view.setUint32(ptr + 4, bytes.length, true);
+    }
+
+    writeString(alloc: GuestAllocator, ptr: number, value: string): void {
+        this.writeBytes(alloc, ptr, textEncoder.encode(value));
+    }
+
+    private buffer(): ArrayBuffer {
+        if (this.memory === null) {
+            throw new Error("The guest memory is not bound to an instance");
+        }
+        return this.memory.buffer;
+    }
+}
+
+/*
+ * ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
+ */
+
+/**
+ * Error codes.
+ */
+export type CryptoErrno = number;
+
+export namespace CryptoErrno {
+    export const SUCCESS: CryptoErrno = 0;
+    export const GUEST_ERROR: CryptoErrno = 1;
+    export const NOT_IMPLEMENTED: CryptoErrno = 2;
+    export const UNSUPPORTED_FEATURE: CryptoErrno = 3;
+    export const PROHIBITED_OPERATION: CryptoErrno = 4;
+    export const UNSUPPORTED_ENCODING: CryptoErrno = 5;
+    export const UNSUPPORTED_ALGORITHM: CryptoErrno = 6;
+    export const UNSUPPORTED_OPTION: CryptoErrno = 7;
+    export const INVALID_KEY: CryptoErrno = 8;
+    export const INVALID_LENGTH: CryptoErrno = 9;
+    export const VERIFICATION_FAILED: CryptoErrno = 10;
+    export const RNG_ERROR: CryptoErrno = 11;
+    export const ALGORITHM_FAILURE: CryptoErrno = 12;
+    export const INVALID_SIGNATURE: CryptoErrno = 13;
+    export const CLOSED: CryptoErrno = 14;
+    export const INVALID_HANDLE: CryptoErrno = 15;
+    export const OVERFLOW: CryptoErrno = 16;
+    export const INTERNAL_ERROR: CryptoErrno = 17;
+    export const TOO_MANY_HANDLES: CryptoErrno = 18;
+    export const KEY_NOT_SUPPORTED: CryptoErrno = 19;
+    export const KEY_REQUIRED: CryptoErrno = 20;
+    export const INVALID_TAG: CryptoErrno = 21;
+    export const INVALID_OPERATION: CryptoErrno = 22;
+    export const NONCE_REQUIRED: CryptoErrno = 23;
+    export const INVALID_NONCE: CryptoErrno = 24;
+    export const OPTION_NOT_SET: CryptoErrno = 25;
+    export const NOT_FOUND: CryptoErrno = 26;
+    export const PARAMETERS_MISSING: CryptoErrno = 27;
+    export const IN_PROGRESS: CryptoErrno = 28;
+    export const INCOMPATIBLE_KEYS: CryptoErrno = 29;
+    export const EXPIRED: CryptoErrno = 30;
+}
+
+/**
+ * Encoding to use for importing or exporting a key pair.
+ */
+export type KeypairEncoding = number;
+
+export namespace KeypairEncoding {
+    export const RAW: KeypairEncoding = 0;
+    export const PKCS_8: KeypairEncoding = 1;
+    export const PEM: KeypairEncoding = 2;
+    export const LOCAL: KeypairEncoding = 3;
+}
+
+/**
+ * Encoding to use for importing or exporting a public key.
+ */
+export type PublickeyEncoding = number;
+
+export namespace PublickeyEncoding {
+    export const RAW: PublickeyEncoding = 0;
+    export const PKCS_8: PublickeyEncoding = 1;
+    export const PEM: PublickeyEncoding = 2;
+    export const SEC: PublickeyEncoding = 3;
+    export const COMPRESSED_SEC: PublickeyEncoding = 4;
+    export const LOCAL: PublickeyEncoding = 5;
+}
+
+/**
+ * Encoding to use for importing or exporting a secret key.
+ */
+export type SecretkeyEncoding = number;
+
+export namespace SecretkeyEncoding {
+    export const RAW: SecretkeyEncoding = 0;
+    export const PKCS_8: SecretkeyEncoding = 1;
+    export const PEM: SecretkeyEncoding = 2;
+    export const SEC: SecretkeyEncoding = 3;
+    export const COMPRESSED_SEC: SecretkeyEncoding = 4;
+    export const LOCAL: SecretkeyEncoding = 5;
+}
+
+/**
+ * Encoding to use for importing or exporting a signature.
+ */
+export type SignatureEncoding = number;
+
+export namespace SignatureEncoding {
+    export const RAW: SignatureEncoding = 0;
+    export const DER: SignatureEncoding = 1;
+}
+
+/**
+ * An algorithm category.
+ */
+export type AlgorithmType = number;
+
+export namespace AlgorithmType {
+    export const SIGNATURES: AlgorithmType = 0;
+    export const SYMMETRIC: AlgorithmType = 1;
+    export const KEY_EXCHANGE: AlgorithmType = 2;
+}
+
+/**
+ * Version of a managed key.
+ *
+ * A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+ */
+export type Version = bigint;
+
+/**
+ * Size of a value.
+ */
+export type Size = WasiSize;
+
+/**
+ * A UNIX timestamp, in seconds since 01/01/1970.
+ */
+export type Timestamp = bigint;
+
+/**
+ * A 64-bit value
+ */
+export type U64 = bigint;
+
+/**
+ * Handle for functions returning output whose size may be large or not known in advance.
+ *
+ * An `array_output` object contains a host-allocated byte array.
+ *
+ * A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+ * In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+ *
+ * An `array_output` handle is automatically closed after its full content has been consumed.
+ */
+export type ArrayOutput = WasiHandle;
+
+/**
+ * A set of options.
+ *
+ * This type is used to set non-default parameters.
+ *
+ * The exact set of allowed options depends on the algorithm being used.
+ */
+export type Options = WasiHandle;
+
+/**
+ * A handle to the optional secrets management facilities offered by a host.
+ *
+ * This is used to generate, retrieve and invalidate managed keys.
+ */
+export type SecretsManager = WasiHandle;
+
+/**
+ * A key pair.
+ */
+export type Keypair = WasiHandle;
+
+/**
+ * A state to absorb data to be signed.
+ *
+ * After a signature has been computed or verified, the state remains valid for further operations.
+ *
+ * A subsequent signature would sign all the data accumulated since the creation of the state object.
+ */
+export type SignatureState = WasiHandle;
+
+/**
+ * A signature.
+ */
+export type Signature = WasiHandle;
+
+/**
+ * A public key, for key exchange and signature verification.
+ */
+export type Publickey = WasiHandle;
+
+/**
+ * A secret key, for key exchange mechanisms.
+ */
+export type Secretkey = WasiHandle;
+
+/**
+ * A state to absorb signed data to be verified.
+ */
+export type SignatureVerificationState = WasiHandle;
+
+/**
+ * A state to perform symmetric operations.
+ *
+ * The state is not reset nor invalidated after an option has been performed.
+ * Incremental updates and sessions are thus supported.
+ */
+export type SymmetricState = WasiHandle;
+
+/**
+ * A symmetric key.
+ *
+ * The key can be imported from raw bytes, or can be a reference to a managed key.
+ *
+ * If it was imported, the host will wipe it from memory as soon as the handle is closed.
+ */
+export type SymmetricKey = WasiHandle;
+
+/**
+ * An authentication tag.
+ *
+ * This is an object returned by functions computing authentication tags.
+ *
+ * A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+ *
+ * This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+ *
+ * The host is reponsible for securely wiping them from memory on close.
+ */
+export type SymmetricTag = WasiHandle;
+
+/**
+ * Options index, only required by the Interface Types translation layer.
+ */
+export type OptOptionsU = number;
+
+export namespace OptOptionsU {
+    export const SOME: OptOptionsU = 0;
+    export const NONE: OptOptionsU = 1;
+}
+
+/**
+ * An optional options set.
+ *
+ * This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+ */
+export type OptOptions =
+    | { tag: 0; some: Options }
+    | { tag: 1 };
+
+export namespace OptOptions {
+    export const SOME = 0;
+    export const NONE = 1;
+
+    export function read(mem: GuestMemory, ptr: WasiPtr): OptOptions {
+        const view = mem.view();
+        const tag = view.getUint8(ptr);
+        switch (tag) {
+            case 0:
+                return { tag: 0, some: view.getInt32(ptr + 4, true) };
+            case 1:
+                return { tag: 1 };
+            default:
+                throw new Error(`Invalid tag for OptOptions: ${tag}`);
+        }
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: OptOptions): void {
+        const view = mem.view();
+        view.setUint8(ptr, value.tag);
+        switch (value.tag) {
+            case 0:
+                view.setInt32(ptr + 4, value.some, true);
+                break;
+        }
+    }
+}
+
+/**
+ * Symmetric key index, only required by the Interface Types translation layer.
+ */
+export type OptSymmetricKeyU = number;
+
+export namespace OptSymmetricKeyU {
+    export const SOME: OptSymmetricKeyU = 0;
+    export const NONE: OptSymmetricKeyU = 1;
+}
+
+/**
+ * An optional symmetric key.
+ *
+ * This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+ */
+export type OptSymmetricKey =
+    | { tag: 0; some: SymmetricKey }
+    | { tag: 1 };
+
+export namespace OptSymmetricKey {
+    export const SOME = 0;
+    export const NONE = 1;
+
+    export function read(mem: GuestMemory, ptr: WasiPtr): OptSymmetricKey {
+        const view = mem.view();
+        const tag = view.getUint8(ptr);
+        switch (tag) {
+            case 0:
+                return { tag: 0, some: view.getInt32(ptr + 4, true) };
+            case 1:
+                return { tag: 1 };
+            default:
+                throw new Error(`Invalid tag for OptSymmetricKey: ${tag}`);
+        }
+    }
+
+    export function write(mem: GuestMemory, ptr: WasiPtr, value: OptSymmetricKey): void {
+        const view = mem.view();
+        view.setUint8(ptr, value.tag);
+        switch (value.tag) {
+            case 0:
+                view.setInt32(ptr + 4, value.some, true);
+                break;
+        }
+    }
+}
+
+/**
+ * Host implementation of the [wasi_ephemeral_crypto_symmetric] module
+ *
+ * The functions receive the parameters decoded from the guest memory, and return
+ * the results. Errors are returned to the guest by throwing a `WasiError`.
+ */
+export interface WasiEphemeralCryptoSymmetric {
+    /**
+     * Generate a new symmetric key for a given algorithm.
+     *
+     * `options` can be `None` to use the default parameters, or an algoritm-specific set of parameters to override.
+     *
+     * This function may return `unsupported_feature` if key generation is not supported by the host for the chosen algorithm, or `unsupported_algorithm` if the algorithm is not supported by the host.
+     */
+    symmetricKeyGenerate(algorithm: string, options: OptOptions): SymmetricKey;
+
+    /**
+     * Create a symmetric key from raw material.
+     *
+     * The algorithm is internally stored along with the key, and trying to use the key with an operation expecting a different algorithm will return `invalid_key`.
+     *
+     * The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+     */
+    symmetricKeyImport(algorithm: string, raw: WasiPtr, rawLen: Size): SymmetricKey;
+
+    /**
+     * Export a symmetric key as raw material.
+     *
+     * This is mainly useful to export a managed key.
+     *
+     * May return `prohibited_operation` if this operation is denied.
+     */
+    symmetricKeyExport(symmetricKey: SymmetricKey): ArrayOutput;
+
+    /**
+     * Destroy a symmetric key.
+     *
+     * Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+     */
+    symmetricKeyClose(symmetricKey: SymmetricKey): void;
+
+    /**
+     * __(optional)__
+     * Generate a new managed symmetric key.
+     *
+     * The key is generated and stored by the secrets management facilities.
+     *
+     * It may be used through its identifier, but the host may not allow it to be exported.
+     *
+     * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+     * or `unsupported_algorithm` if a key cannot be created for the chosen algorithm.
+     *
+     * The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+     *
+     * This is also an optional import, meaning that the function may not even exist.
+     */
+    symmetricKeyGenerateManaged(secretsManager: SecretsManager, algorithm: string, options: OptOptions): SymmetricKey;
+
+    /**
+     * __(optional)__
+     * Store a symmetric key into the secrets manager.
+     *
+     * On success, the function stores the key identifier into `$symmetric_key_id`,
+     * into which up to `$symmetric_key_id_max_len` can be written.
+     *
+     * The function returns `overflow` if the supplied buffer is too small.
+     */
+    symmetricKeyStoreManaged(secretsManager: SecretsManager, symmetricKey: SymmetricKey, symmetricKeyId: WasiPtr, symmetricKeyIdMaxLen: Size): void;
+
+    /**
+     * __(optional)__
+     * Replace a managed symmetric key.
+     *
+     * This function crates a new version of a managed symmetric key, by replacing `$kp_old` with `$kp_new`.
+     *
+     * It does several things:
+     *
+     * - The key identifier for `$symmetric_key_new` is set to the one of `$symmetric_key_old`.
+     * - A new, unique version identifier is assigned to `$kp_new`. This version will be equivalent to using `$version_latest` until the key is replaced.
+     * - The `$symmetric_key_old` handle is closed.
+     *
+     * Both keys must share the same algorithm and have compatible parameters. If this is not the case, `incompatible_keys` is returned.
+     *
+     * The function may also return the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+     * or if keys cannot be rotated.
+     *
+     * Finally, `prohibited_operation` can be returned if `$symmetric_key_new` wasn't created by the secrets manager, and the secrets manager prohibits imported keys.
+     *
+     * If the operation succeeded, the new version is returned.
+     *
+     * This is an optional import, meaning that the function may not even exist.
+     */
+    symmetricKeyReplaceManaged(secretsManager: SecretsManager, symmetricKeyOld: SymmetricKey, symmetricKeyNew: SymmetricKey): Version;
+
+    /**
+     * __(optional)__
+     * Return the key identifier and version of a managed symmetric key.
+     *
+     * If the key is not managed, `unsupported_feature` is returned instead.
+     *
+     * This is an optional import, meaning that the function may not even exist.
+     */
+    symmetricKeyId(symmetricKey: SymmetricKey, symmetricKeyId: WasiPtr, symmetricKeyIdMaxLen: Size): [Size, Version];
+
+    /**
+     * __(optional)__
+     * Return a managed symmetric key from a key identifier.
+     *
+     * `kp_version` can be set to `version_latest` to retrieve the most recent version of a symmetric key.
+     *
+     * If no key matching the provided information is found, `not_found` is returned instead.
+     *
+     * This is an optional import, meaning that the function may not even exist.
+     */
+    symmetricKeyFromId(secretsManager: SecretsManager, symmetricKeyId: WasiPtr, symmetricKeyIdLen: Size, symmetricKeyVersion: Version): SymmetricKey;
+
+    /**
+     * Create a new state to aborb and produce data using symmetric operations.
+     *
+     * The state remains valid after every operation in order to support incremental updates.
+     *
+     * The function has two optional parameters: a key and an options set.
+     *
+     * It will fail with a `key_not_supported` error code if a key was provided but the chosen algorithm doesn't natively support keying.
+     *
+     * On the other hand, if a key is required, but was not provided, a `key_required` error will be thrown.
+     *
+     * Some algorithms may require additional parameters. They have to be supplied as an options set:
+     *
+     * ```rust
+     * let options_handle = ctx.options_open()?;
+     * ctx.options_set("context", b"My application")?;
+     * ctx.options_set_u64("fanout", 16)?;
+     * let state_handle = ctx.symmetric_state_open("BLAKE2b-512", None, Some(options_handle))?;
+     * ```
+     *
+     * If some parameters are mandatory but were not set, the `parameters_missing` error code will be returned.
+     *
+     * A notable exception is the `nonce` parameter, that is common to most AEAD constructions.
+     *
+     * If a nonce is required but was not supplied:
+     *
+     * - If it is safe to do so, the host will automatically generate a nonce. This is true for nonces that are large enough to be randomly generated, or if the host is able to maintain a global counter.
+     * - If not, the function will fail and return the dedicated `nonce_required` error code.
+     *
+     * A nonce that was automatically generated can be retrieved after the function returns with `symmetric_state_get(state_handle, "nonce")`.
+     *
+     * **Sample usage patterns:**
+     *
+     * - **Hashing**
+     *
+     * ```rust
+     * let mut out = [0u8; 64];
+     * let state_handle = ctx.symmetric_state_open("SHAKE-128", None, None)?;
+     * ctx.symmetric_state_absorb(state_handle, b"data")?;
+     * ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+     * ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+     * ```
+     *
+     * - **MAC**
+     *
+     * ```rust
+     * let mut raw_tag = [0u8; 64];
+     * let key_handle = ctx.symmetric_key_import("HMAC/SHA-512", b"key")?;
+     * let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+     * ctx.symmetric_state_absorb(state_handle, b"data")?;
+     * ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+     * let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+     * ctx.symmetric_tag_pull(computed_tag_handle, &mut raw_tag)?;
+     * ```
+     *
+     * Verification:
+     *
+     * ```rust
+     * let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+     * ctx.symmetric_state_absorb(state_handle, b"data")?;
+     * ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+     * let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+     * ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+     * ```
+     *
+     * - **Tuple hashing**
+     *
+     * ```rust
+     * let mut out = [0u8; 64];
+     * let state_handle = ctx.symmetric_state_open("TupleHashXOF256", None, None)?;
+     * ctx.symmetric_state_absorb(state_handle, b"value 1")?;
+     * ctx.symmetric_state_absorb(state_handle, b"value 2")?;
+     * ctx.symmetric_state_absorb(state_handle, b"value 3")?;
+     * ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+     * ```
+     * Unlike MACs and regular hash functions, inputs are domain separated instead of being concatenated.
+     *
+     * - **Key derivation using extract-and-expand**
+     *
+     * Extract:
+     *
+     * ```rust
+     * let mut prk = vec![0u8; 64];
+     * let key_handle = ctx.symmetric_key_import("HKDF-EXTRACT/SHA-512", b"key")?;
+     * let state_handle = ctx.symmetric_state_open("HKDF-EXTRACT/SHA-512", Some(key_handle), None)?;
+     * ctx.symmetric_state_absorb(state_handle, b"salt")?;
+     * let prk_handle = ctx.symmetric_state_squeeze_key(state_handle, "HKDF-EXPAND/SHA-512")?;
+     * ```
+     *
+     * Expand:
+     *
+     * ```rust
+     * let mut subkey = vec![0u8; 32];
+     * let state_handle = ctx.symmetric_state_open("HKDF-EXPAND/SHA-512", Some(prk_handle), None)?;
+     * ctx.symmetric_state_absorb(state_handle, b"info")?;
+     * ctx.symmetric_state_squeeze(state_handle, &mut subkey)?;
+     * ```
+     *
+     * - **Key derivation using a XOF**
+     *
+     * ```rust
+     * let mut subkey1 = vec![0u8; 32];
+     * let mut subkey2 = vec![0u8; 32];
+     * let key_handle = ctx.symmetric_key_import("BLAKE3", b"key")?;
+     * let state_handle = ctx.symmetric_state_open("BLAKE3", Some(key_handle), None)?;
+     * ctx.symmetric_absorb(state_handle, b"context")?;
+     * ctx.squeeze(state_handle, &mut subkey1)?;
+     * ctx.squeeze(state_handle, &mut subkey2)?;
+     * ```
+     *
+     * - **Password hashing**
+     *
+     * ```rust
+     * let mut memory = vec![0u8; 1_000_000_000];
+     * let options_handle = ctx.symmetric_options_open()?;
+     * ctx.symmetric_options_set_guest_buffer(options_handle, "memory", &mut memory)?;
+     * ctx.symmetric_options_set_u64(options_handle, "opslimit", 5)?;
+     * ctx.symmetric_options_set_u64(options_handle, "parallelism", 8)?;
+     *
+     * let state_handle = ctx.symmetric_state_open("ARGON2-ID-13", None, Some(options))?;
+     * ctx.symmtric_state_absorb(state_handle, b"password")?;
+     *
+     * let pw_str_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+     * let mut pw_str = vec![0u8; ctx.symmetric_tag_len(pw_str_handle)?];
+     * ctx.symmetric_tag_pull(pw_str_handle, &mut pw_str)?;
+     * ```
+     *
+     * - **AEAD encryption with an explicit nonce**
+     *
+     * ```rust
+     * let key_handle = ctx.symmetric_key_generate("AES-256-GCM", None)?;
+     * let message = b"test";
+     *
+     * let options_handle = ctx.symmetric_options_open()?;
+     * ctx.symmetric_options_set(options_handle, "nonce", nonce)?;
+     *
+     * let state_handle = ctx.symmetric_state_open("AES-256-GCM", Some(key_handle), Some(options_handle))?;
+     * let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+     * ctx.symmetric_state_absorb(state_handle, "additional data")?;
+     * ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+     * ```
+     *
+     * - **AEAD encryption with automatic nonce generation**
+     *
+     * ```rust
+     * let key_handle = ctx.symmetric_key_generate("AES-256-GCM-SIV", None)?;
+     * let message = b"test";
+     * let mut nonce = [0u8; 24];
+     *
+     * let state_handle = ctx.symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), None)?;
+     *
+     * let nonce_handle = ctx.symmetric_state_options_get(state_handle, "nonce")?;
+     * ctx.array_output_pull(nonce_handle, &mut nonce)?;
+     *
+     * let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+     * ctx.symmetric_state_absorb(state_handle, "additional data")?;
+     * ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+     * ```
+     *
+     * - **Session authenticated modes**
+     *
+     * ```rust
+     * let mut out = [0u8; 16];
+     * let mut out2 = [0u8; 16];
+     * let mut ciphertext = [0u8; 20];
+     * let key_handle = ctx.symmetric_key_generate("Xoodyak-128", None)?;
+     * let state_handle = ctx.symmetric_state_open("Xoodyak-128", Some(key_handle), None)?;
+     * ctx.symmetric_state_absorb(state_handle, b"data")?;
+     * ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, b"abcd")?;
+     * ctx.symmetric_state_absorb(state_handle, b"more data")?;
+     * ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+     * ctx.symmetric_state_squeeze(state_handle, &mut out2)?;
+     * ctx.symmetric_state_ratchet(state_handle)?;
+     * ctx.symmetric_state_absorb(state_handle, b"more data")?;
+     * let next_key_handle = ctx.symmetric_state_squeeze_key(state_handle, "Xoodyak-128")?;
+     * // ...
+     * ```
+     */
+    symmetricStateOpen(algorithm: string, key: OptSymmetricKey, options: OptOptions): SymmetricState;
+
+    /**
+     * Retrieve a parameter from the current state.
+     *
+     * In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+     *
+     * The function may return `options_not_set` if an option was not set, which is different from an empty value.
+     *
+     * It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+     */
+    symmetricStateOptionsGet(handle: SymmetricState, name: string, value: WasiPtr, valueMaxLen: Size): Size;
+
+    /**
+     * Retrieve an integer parameter from the current state.
+     *
+     * In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+     *
+     * The function may return `options_not_set` if an option was not set.
+     *
+     * It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+     */
+    symmetricStateOptionsGetU64(handle: SymmetricState, name: string): U64;
+
+    /**
+     * Destroy a symmetric state.
+     *
+     * Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+     */
+    symmetricStateClose(handle: SymmetricState): void;
+
+    /**
+     * Absorb data into the state.
+     *
+     * - **Hash functions:** adds data to be hashed.
+     * - **MAC functions:** adds data to be authenticated.
+     * - **Tuplehash-like constructions:** adds a new tuple to the state.
+     * - **Key derivation functions:** adds to the IKM or to the subkey information.
+     * - **AEAD constructions:** adds additional data to be authenticated.
+     * - **Stateful hash objects, permutation-based constructions:** absorbs.
+     *
+     * If the chosen algorithm doesn't accept input data, the `invalid_operation` error code is returned.
+     *
+     * If too much data has been fed for the algorithm, `overflow` may be thrown.
+     */
+    symmetricStateAbsorb(handle: SymmetricState, data: WasiPtr, dataLen: Size): void;
+
+    /**
+     * Squeeze bytes from the state.
+     *
+     * - **Hash functions:** this tries to output an `out_len` bytes digest from the absorbed data. The hash function output will be truncated if necessary. If the requested size is too large, the `invalid_len` error code is returned.
+     * - **Key derivation functions:** : outputs an arbitrary-long derived key.
+     * - **RNGs, DRBGs, stream ciphers:**: outputs arbitrary-long data.
+     * - **Stateful hash objects, permutation-based constructions:** squeeze.
+     *
+     * Other kinds of algorithms may return `invalid_operation` instead.
+     *
+     * For password-stretching functions, the function may return `in_progress`.
+     * In that case, the guest should retry with the same parameters until the function completes.
+     */
+    symmetricStateSqueeze(handle: SymmetricState, out: WasiPtr, outLen: Size): void;
+
+    /**
+     * Compute and return a tag for all the data injected into the state so far.
+     *
+     * - **MAC functions**: returns a tag authenticating the absorbed data.
+     * - **Tuplehash-like constructions:** returns a tag authenticating all the absorbed tuples.
+     * - **Password-hashing functions:** returns a standard string containing all the required parameters for password verification.
+     *
+     * Other kinds of algorithms may return `invalid_operation` instead.
+     *
+     * For password-stretching functions, the function may return `in_progress`.
+     * In that case, the guest should retry with the same parameters until the function completes.
+     */
+    symmetricStateSqueezeTag(handle: SymmetricState): SymmetricTag;
+
+    /**
+     * Use the current state to produce a key for a target algorithm.
+     *
+     * For extract-then-expand constructions, this returns the PRK.
+     * For session-base authentication encryption, this returns a key that can be used to resume a session without storing a nonce.
+     *
+     * `invalid_operation` is returned for algorithms not supporting this operation.
+     */
+    symmetricStateSqueezeKey(handle: SymmetricState, algStr: string): SymmetricKey;
+
+    /**
+     * Return the maximum length of an authentication tag for the current algorithm.
+     *
+     * This allows guests to compute the size required to store a ciphertext along with its authentication tag.
+     *
+     * The returned length may include the encryption mode's padding requirements in addition to the actual tag.
+     *
+     * For an encryption operation, the size of the output buffer should be `input_len + symmetric_state_max_tag_len()`.
+     *
+     * For a decryption operation, the size of the buffer that will store the decrypted data must be `ciphertext_len - symmetric_state_max_tag_len()`.
+     */
+    symmetricStateMaxTagLen(handle: SymmetricState): Size;
+
+    /**
+     * Encrypt data with an attached tag.
+     *
+     * - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+     * - **AEAD:** encrypts `data` into `out`, including the authentication tag to the output. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The `symmetric_state_max_tag_len()` function can be used to retrieve the overhead of adding the tag, as well as padding if necessary.
+     * - **SHOE, Xoodyak, Strobe:** encrypts data, squeezes a tag and appends it to the output.
+     *
+     * If `out` and `data` are the same address, encryption may happen in-place.
+     *
+     * The function returns the actual size of the ciphertext along with the tag.
+     *
+     * `invalid_operation` is returned for algorithms not supporting encryption.
+     */
+    symmetricStateEncrypt(handle: SymmetricState, out: WasiPtr, outLen: Size, data: WasiPtr, dataLen: Size): Size;
+
+    /**
+     * Encrypt data, with a detached tag.
+     *
+     * - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+     * - **AEAD:** encrypts `data` into `out` and returns the tag separately. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The output and input buffers must be of the same length.
+     * - **SHOE, Xoodyak, Strobe:** encrypts data and squeezes a tag.
+     *
+     * If `out` and `data` are the same address, encryption may happen in-place.
+     *
+     * The function returns the tag.
+     *
+     * `invalid_operation` is returned for algorithms not supporting encryption.
+     */
+    symmetricStateEncryptDetached(handle: SymmetricState, out: WasiPtr, outLen: Size, data: WasiPtr, dataLen: Size): SymmetricTag;
+
+    /**
+     * - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+     * - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+     * - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the one that was appended to the ciphertext.
+     *
+     * If `out` and `data` are the same address, decryption may happen in-place.
+     *
+     * `out_len` must be exactly `data_len` + `max_tag_len` bytes.
+     *
+     * The function returns the actual size of the decrypted message, which can be smaller than `out_len` for modes that requires padding.
+     *
+     * `invalid_tag` is returned if the tag didn't verify.
+     *
+     * `invalid_operation` is returned for algorithms not supporting encryption.
+     */
+    symmetricStateDecrypt(handle: SymmetricState, out: WasiPtr, outLen: Size, data: WasiPtr, dataLen: Size): Size;
+
+    /**
+     * - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+     * - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+     * - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the expected one.
+     *
+     * `raw_tag` is the expected tag, as raw bytes.
+     *
+     * `out` and `data` be must have the same length.
+     * If they also share the same address, decryption may happen in-place.
+     *
+     * The function returns the actual size of the decrypted message.
+     *
+     * `invalid_tag` is returned if the tag verification failed.
+     *
+     * `invalid_operation` is returned for algorithms not supporting encryption.
+     */
+    symmetricStateDecryptDetached(handle: SymmetricState, out: WasiPtr, outLen: Size, data: WasiPtr, dataLen: Size, rawTag: WasiPtr, rawTagLen: Size): Size;
+
+    /**
+     * Make it impossible to recover the previous state.
+     *
+     * This operation is supported by some systems keeping a rolling state over an entire session, for forward security.
+     *
+     * `invalid_operation` is returned for algorithms not supporting ratcheting.
+     */
+    symmetricStateRatchet(handle: SymmetricState): void;
+
+    /**
+     * Return the length of an authentication tag.
+     *
+     * This function can be used by a guest to allocate the correct buffer size to copy a computed authentication tag.
+     */
+    symmetricTagLen(symmetricTag: SymmetricTag): Size;
+
+    /**
+     * Copy an authentication tag into a guest-allocated buffer.
+     *
+     * The handle automatically becomes invalid after this operation. Manually closing it is not required.
+     *
+     * Example usage:
+     *
+     * ```rust
+     * let mut raw_tag = [0u8; 16];
+     * ctx.symmetric_tag_pull(raw_tag_handle, &mut raw_tag)?;
+     * ```
+     *
+     * The function returns `overflow` if the supplied buffer is too small to copy the tag.
+     *
+     * Otherwise, it returns the number of bytes that have been copied.
+     */
+    symmetricTagPull(symmetricTag: SymmetricTag, buf: WasiPtr, bufLen: Size): Size;
+
+    /**
+     * Verify that a computed authentication tag matches the expected value, in constant-time.
+     *
+     * The expected tag must be provided as a raw byte string.
+     *
+     * The function returns `invalid_tag` if the tags don't match.
+     *
+     * Example usage:
+     *
+     * ```rust
+     * let key_handle = ctx.symmetric_key_import("HMAC/SHA-256", b"key")?;
+     * let state_handle = ctx.symmetric_state_open("HMAC/SHA-256", Some(key_handle), None)?;
+     * ctx.symmetric_state_absorb(state_handle, b"data")?;
+     * let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+     * ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+     * ```
+     */
+    symmetricTagVerify(symmetricTag: SymmetricTag, expectedRawTagPtr: WasiPtr, expectedRawTagLen: Size): void;
+
+    /**
+     * Explicitly destroy an unused authentication tag.
+     *
+     * This is usually not necessary, as `symmetric_tag_pull()` automatically closes a tag after it has been copied.
+     *
+     * Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+     */
+    symmetricTagClose(symmetricTag: SymmetricTag): void;
+}
+
+/**
+ * Returns the imports of the [wasi_ephemeral_crypto_symmetric] module, calling the functions of `impl`
+ *
+ * `mem` must be bound to the instance once it has been created.
+ */
+export function wasiEphemeralCryptoSymmetricImports(impl: WasiEphemeralCryptoSymmetric, mem: GuestMemory): WebAssembly.Imports {
+    return {
+        "wasi_ephemeral_crypto_symmetric": {
+            "symmetric_key_generate": (
+                algorithmPtr: number,
+                algorithmLen: number,
+                options: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricKeyGenerate(
+                        mem.readString(algorithmPtr, algorithmLen),
+                        OptOptions.read(mem, options),
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_key_import": (
+                algorithmPtr: number,
+                algorithmLen: number,
+                raw: number,
+                rawLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricKeyImport(
+                        mem.readString(algorithmPtr, algorithmLen),
+                        raw >>> 0,
+                        rawLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_key_export": (
+                symmetricKey: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricKeyExport(
+                        symmetricKey,
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_key_close": (
+                symmetricKey: number
+            ): number => {
+                try {
+                    impl.symmetricKeyClose(
+                        symmetricKey,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_key_generate_managed": (
+                secretsManager: number,
+                algorithmPtr: number,
+                algorithmLen: number,
+                options: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricKeyGenerateManaged(
+                        secretsManager,
+                        mem.readString(algorithmPtr, algorithmLen),
+                        OptOptions.read(mem, options),
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_key_store_managed": (
+                secretsManager: number,
+                symmetricKey: number,
+                symmetricKeyId: number,
+                symmetricKeyIdMaxLen: number
+            ): number => {
+                try {
+                    impl.symmetricKeyStoreManaged(
+                        secretsManager,
+                        symmetricKey,
+                        symmetricKeyId >>> 0,
+                        symmetricKeyIdMaxLen >>> 0,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_key_replace_managed": (
+                secretsManager: number,
+                symmetricKeyOld: number,
+                symmetricKeyNew: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricKeyReplaceManaged(
+                        secretsManager,
+                        symmetricKeyOld,
+                        symmetricKeyNew,
+                    );
+                    const view = mem.view();
+                    view.setBigUint64(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_key_id": (
+                symmetricKey: number,
+                symmetricKeyId: number,
+                symmetricKeyIdMaxLen: number,
+                result0Ptr: number,
+                result1Ptr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricKeyId(
+                        symmetricKey,
+                        symmetricKeyId >>> 0,
+                        symmetricKeyIdMaxLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setUint32(result0Ptr, result[0], true);
+                    view.setBigUint64(result1Ptr, result[1], true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_key_from_id": (
+                secretsManager: number,
+                symmetricKeyId: number,
+                symmetricKeyIdLen: number,
+                symmetricKeyVersion: bigint,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricKeyFromId(
+                        secretsManager,
+                        symmetricKeyId >>> 0,
+                        symmetricKeyIdLen >>> 0,
+                        BigInt.asUintN(64, symmetricKeyVersion),
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_open": (
+                algorithmPtr: number,
+                algorithmLen: number,
+                key: number,
+                options: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateOpen(
+                        mem.readString(algorithmPtr, algorithmLen),
+                        OptSymmetricKey.read(mem, key),
+                        OptOptions.read(mem, options),
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_options_get": (
+                handle: number,
+                namePtr: number,
+                nameLen: number,
+                value: number,
+                valueMaxLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateOptionsGet(
+                        handle,
+                        mem.readString(namePtr, nameLen),
+                        value >>> 0,
+                        valueMaxLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_options_get_u64": (
+                handle: number,
+                namePtr: number,
+                nameLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateOptionsGetU64(
+                        handle,
+                        mem.readString(namePtr, nameLen),
+                    );
+                    const view = mem.view();
+                    view.setBigUint64(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_close": (
+                handle: number
+            ): number => {
+                try {
+                    impl.symmetricStateClose(
+                        handle,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_absorb": (
+                handle: number,
+                data: number,
+                dataLen: number
+            ): number => {
+                try {
+                    impl.symmetricStateAbsorb(
+                        handle,
+                        data >>> 0,
+                        dataLen >>> 0,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_squeeze": (
+                handle: number,
+                out: number,
+                outLen: number
+            ): number => {
+                try {
+                    impl.symmetricStateSqueeze(
+                        handle,
+                        out >>> 0,
+                        outLen >>> 0,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_squeeze_tag": (
+                handle: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateSqueezeTag(
+                        handle,
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_squeeze_key": (
+                handle: number,
+                algStrPtr: number,
+                algStrLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateSqueezeKey(
+                        handle,
+                        mem.readString(algStrPtr, algStrLen),
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_max_tag_len": (
+                handle: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateMaxTagLen(
+                        handle,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_encrypt": (
+                handle: number,
+                out: number,
+                outLen: number,
+                data: number,
+                dataLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateEncrypt(
+                        handle,
+                        out >>> 0,
+                        outLen >>> 0,
+                        data >>> 0,
+                        dataLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_encrypt_detached": (
+                handle: number,
+                out: number,
+                outLen: number,
+                data: number,
+                dataLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateEncryptDetached(
+                        handle,
+                        out >>> 0,
+                        outLen >>> 0,
+                        data >>> 0,
+                        dataLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setInt32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_decrypt": (
+                handle: number,
+                out: number,
+                outLen: number,
+                data: number,
+                dataLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateDecrypt(
+                        handle,
+                        out >>> 0,
+                        outLen >>> 0,
+                        data >>> 0,
+                        dataLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_decrypt_detached": (
+                handle: number,
+                out: number,
+                outLen: number,
+                data: number,
+                dataLen: number,
+                rawTag: number,
+                rawTagLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricStateDecryptDetached(
+                        handle,
+                        out >>> 0,
+                        outLen >>> 0,
+                        data >>> 0,
+                        dataLen >>> 0,
+                        rawTag >>> 0,
+                        rawTagLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_state_ratchet": (
+                handle: number
+            ): number => {
+                try {
+                    impl.symmetricStateRatchet(
+                        handle,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_tag_len": (
+                symmetricTag: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricTagLen(
+                        symmetricTag,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_tag_pull": (
+                symmetricTag: number,
+                buf: number,
+                bufLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.symmetricTagPull(
+                        symmetricTag,
+                        buf >>> 0,
+                        bufLen >>> 0,
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_tag_verify": (
+                symmetricTag: number,
+                expectedRawTagPtr: number,
+                expectedRawTagLen: number
+            ): number => {
+                try {
+                    impl.symmetricTagVerify(
+                        symmetricTag,
+                        expectedRawTagPtr >>> 0,
+                        expectedRawTagLen >>> 0,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+            "symmetric_tag_close": (
+                symmetricTag: number
+            ): number => {
+                try {
+                    impl.symmetricTagClose(
+                        symmetricTag,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return CryptoErrno.SUCCESS;
+            },
+        },
+    };
+}
+
@@ -0,0 +1,337 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/**
+ * Pointer to the data of an `ArrayBuffer` or of a typed array
+ */
+export function wasiDataPtr<B>(buf: B): usize {
+    if (buf instanceof ArrayBuffer) {
+        return changetype<usize>(buf);
+    } else {
+        // @ts-ignore: ArrayBufferView
+        return buf.dataStart;
+    }
+}
+
+/**
+ * The value returned by a function, or the error code it returned instead
+ */
+export class WasiResult<T, E> {
+    error: E;
+    value: T;
+
+    constructor(error: E, value: T) {
+        this.error = error;
+        this.value = value;
+    }
+
+    static ok<T, E>(value: T): WasiResult<T, E> {
+        return new WasiResult<T, E>(<E>0, value);
+    }
+
+    static err<T, E>(error: E): WasiResult<T, E> {
+        if (isReference<T>()) {
+            return new WasiResult<T, E>(error, changetype<T>(0));
+        } else {
+            return new WasiResult<T, E>(error, <T>0);
+        }
+    }
+
+    isOk(): bool {
+        return this.error == <E>0;
+    }
+
+    isErr(): bool {
+        return this.error != <E>0;
+    }
+
+    /**
+     * Return the value, or abort if the function returned an error
+     */
+    unwrap(): T {
+        if (this.isErr()) {
+            throw new Error("WASI error " + this.error.toString());
+        }
+        return this.value;
+    }
+}
+
+/*
+ * ---------------------- Module: [wasi_experimental_http] ----------------------
+ */
+
+export type HttpError = u32;
+
+export namespace HttpError {
+    export const SUCCESS: HttpError = 0;
+    export const INVALID_HANDLE: HttpError = 1;
+    export const MEMORY_NOT_FOUND: HttpError = 2;
+    export const MEMORY_ACCESS_ERROR: HttpError = 3;
+    export const BUFFER_TOO_SMALL: HttpError = 4;
+    export const HEADER_NOT_FOUND: HttpError = 5;
+    export const UTF_8_ERROR: HttpError = 6;
+    export const DESTINATION_NOT_ALLOWED: HttpError = 7;
+    export const INVALID_METHOD: HttpError = 8;
+    export const INVALID_ENCODING: HttpError = 9;
+    export const INVALID_URL: HttpError = 10;
+    export const REQUEST_ERROR: HttpError = 11;
+    export const RUNTIME_ERROR: HttpError = 12;
+    export const TOO_MANY_SESSIONS: HttpError = 13;
+}
+
+/**
+ * HTTP status code
+ */
+export type StatusCode = u16;
+
+/**
+ * An HTTP body being sent
+ */
+export type OutgoingBody = WasiSlice<u8>;
+
+/**
+ * Buffer for an HTTP body being received
+ */
+export type IncomingBody = WasiMutSlice<u8>;
+
+/**
+ * A response handle
+ */
+export type ResponseHandle = WasiHandle;
+
+/**
+ * Buffer to store a header value
+ */
+export type HeaderValueBuf = WasiMutSlice<u8>;
+
+/**
+ * Number of bytes having been written
+ */
+export type WrittenBytes = usize;
+
+/**
+ * Send a request
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_experimental_http", "req")
+export declare function req(
+    url_ptr: WasiPtr<Char8>,
+    url_len: usize,
+    method_ptr: WasiPtr<Char8>,
+    method_len: usize,
+    headers_ptr: WasiPtr<Char8>,
+    headers_len: usize,
+    body_ptr: WasiPtr<u8>,
+    body_len: usize,
+    result_0_ptr: WasiMutPtr<StatusCode>,
+    result_1_ptr: WasiMutPtr<ResponseHandle>
+): HttpError;
+
+/**
+ * Close a request handle
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_experimental_http", "close")
+export declare function close(
+    response_handle: ResponseHandle
+): HttpError;
+
+/**
+ * Get the value associated with a header
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_experimental_http", "header_get")
+export declare function headerGet(
+    response_handle: ResponseHandle,
+    header_name_ptr: WasiPtr<Char8>,
+    header_name_len: usize,
+    header_value_buf_ptr: WasiMutPtr<u8>,
+    header_value_buf_len: usize,
+    result_ptr: WasiMutPtr<WrittenBytes>
+): HttpError;
+
+/**
+ * Fill a buffer with the streamed content of a response body
+ */
+// @ts-ignore: decorator
+@unsafe
+// @ts-ignore: decorator
+@external("wasi_experimental_http", "body_read")
+export declare function bodyRead(
+    response_handle: ResponseHandle,
+    body_buf_ptr: WasiMutPtr<u8>,
+    body_buf_len: usize,
+    result_ptr: WasiMutPtr<WrittenBytes>
+): HttpError;
+
+/**
+ * Values returned by `WasiExperimentalHttp.req()`
+ */
+export class ReqResults {
+    v0: StatusCode;
+    v1: ResponseHandle;
+
+    constructor(v0: StatusCode, v1: ResponseHandle) {
+        this.v0 = v0;
+        this.v1 = v1;
+    }
+}
+
+/**
+ * High-level functions of the `wasi_experimental_http` module
+ */
+export class WasiExperimentalHttp {
+    /**
+     * Send a request
+     */
+    static req<B0>(
+        url: string,
+        method: string,
+        headers: string,
+        body: B0
+    ): WasiResult<ReqResults, HttpError> {
+        const url_buf = String.UTF8.encode(url, false);
+        const method_buf = String.UTF8.encode(method, false);
+        const headers_buf = String.UTF8.encode(headers, false);
+        const result0_buf = new ArrayBuffer(2);
+        const result1_buf = new ArrayBuffer(4);
+        const res = req(
+            changetype<usize>(url_buf),
+            url_buf.byteLength,
+            changetype<usize>(method_buf),
+            method_buf.byteLength,
+            changetype<usize>(headers_buf),
+            headers_buf.byteLength,
+            wasiDataPtr(body),
+            body.byteLength,
+            changetype<usize>(result0_buf),
+            changetype<usize>(result1_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<ReqResults, HttpError>(res);
+        }
+        const values = new ReqResults(
+            load<StatusCode>(changetype<usize>(result0_buf)),
+            load<ResponseHandle>(changetype<usize>(result1_buf))
+        );
+        return WasiResult.ok<ReqResults, HttpError>(values);
+    }
+
+    /**
+     * Close a request handle
+     */
+    static close(response_handle: ResponseHandle): HttpError {
+        const res = close(response_handle);
+        return res;
+    }
+
+    /**
+     * Get the value associated with a header
+     */
+    static headerGet<B0>(
+        response_handle: ResponseHandle,
+        header_name: string,
+        header_value_buf: B0
+    ): WasiResult<WrittenBytes, HttpError> {
+        const header_name_buf = String.UTF8.encode(header_name, false);
+        const result_buf = new ArrayBuffer(4);
+        const res = headerGet(
+            response_handle,
+            changetype<usize>(header_name_buf),
+            header_name_buf.byteLength,
+            wasiDataPtr(header_value_buf),
+            header_value_buf.byteLength,
+            changetype<usize>(result_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<WrittenBytes, HttpError>(res);
+        }
+        return WasiResult.ok<WrittenBytes, HttpError>(
+            load<WrittenBytes>(changetype<usize>(result_buf))
+        );
+    }
+
+    /**
+     * Fill a buffer with the streamed content of a response body
+     */
+    static bodyRead<B0>(
+        response_handle: ResponseHandle,
+        body_buf: B0
+    ): WasiResult<WrittenBytes, HttpError> {
+        const result_buf = new ArrayBuffer(4);
+        const res = bodyRead(
+            response_handle,
+            wasiDataPtr(body_buf),
+            body_buf.byteLength,
+            changetype<usize>(result_buf)
+        );
+        if (res != 0) {
+            return WasiResult.err<WrittenBytes, HttpError>(res);
+        }
+        return WasiResult.ok<WrittenBytes, HttpError>(
+            load<WrittenBytes>(changetype<usize>(result_buf))
+        );
+    }
+}
+
@@ -0,0 +1,182 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <stdbool.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+/*
+ * Checks that `len` elements of `elem_size` bytes starting at `ptr` are in the guest memory,
+ * and translates `ptr` to a native pointer. Raises an exception in the guest otherwise.
+ */
+static inline bool
+wasi_guest_range(wasm_exec_env_t exec_env, uint32_t ptr, uint32_t len, uint32_t elem_size,
+                 void **native)
+{
+    wasm_module_inst_t inst = wasm_runtime_get_module_inst(exec_env);
+    uint64_t size = (uint64_t) len * elem_size;
+
+    if (size > UINT32_MAX || !wasm_runtime_validate_app_addr(inst, ptr, (uint32_t) size)) {
+        wasm_runtime_set_exception(inst, "out of bounds memory access");
+        return false;
+    }
+    *native = wasm_runtime_addr_app_to_native(inst, ptr);
+    return true;
+}
+
+/* ---------------------- Module: [wasi_experimental_http] ---------------------- */
+
+#include "wasi_experimental_http_host.h"
+
+static uint32_t
+wasi_experimental_http_req_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t url_ptr,
+    uint32_t url_len,
+    uint32_t method_ptr,
+    uint32_t method_len,
+    uint32_t headers_ptr,
+    uint32_t headers_len,
+    uint32_t body_ptr,
+    uint32_t body_len,
+    uint32_t result_0_ptr,
+    uint32_t result_1_ptr)
+{
+    const wasi_experimental_http_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *url_ptr_native = NULL;
+    void *method_ptr_native = NULL;
+    void *headers_ptr_native = NULL;
+    void *body_ptr_native = NULL;
+    void *result_0_ptr_native = NULL;
+    void *result_1_ptr_native = NULL;
+
+    if (host->req == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_experimental_http.req is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, url_ptr, url_len, 1, &url_ptr_native) ||
+        !wasi_guest_range(exec_env, method_ptr, method_len, 1, &method_ptr_native) ||
+        !wasi_guest_range(exec_env, headers_ptr, headers_len, 1, &headers_ptr_native) ||
+        !wasi_guest_range(exec_env, body_ptr, body_len, 1, &body_ptr_native) ||
+        !wasi_guest_range(exec_env, result_0_ptr, 1, 2, &result_0_ptr_native) ||
+        !wasi_guest_range(exec_env, result_1_ptr, 1, 4, &result_1_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->req(
+        exec_env,
+        (const char *) url_ptr_native,
+        url_len,
+        (const char *) method_ptr_native,
+        method_len,
+        (const char *) headers_ptr_native,
+        headers_len,
+        (const uint8_t *) body_ptr_native,
+        body_len,
+        (status_code_t *) result_0_ptr_native,
+        (response_handle_t *) result_1_ptr_native);
+}
+
+static uint32_t
+wasi_experimental_http_close_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t response_handle)
+{
+    const wasi_experimental_http_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+
+    if (host->close == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_experimental_http.close is not implemented");
+        return 0;
+    }
+    return (uint32_t) host->close(
+        exec_env,
+        (response_handle_t) response_handle);
+}
+
+static uint32_t
+wasi_experimental_http_header_get_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t response_handle,
+    uint32_t header_name_ptr,
+    uint32_t header_name_len,
+    uint32_t header_value_buf_ptr,
+    uint32_t header_value_buf_len,
+    uint32_t result_ptr)
+{
+    const wasi_experimental_http_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *header_name_ptr_native = NULL;
+    void *header_value_buf_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->header_get == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_experimental_http.header_get is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, header_name_ptr, header_name_len, 1, &header_name_ptr_native) ||
+        !wasi_guest_range(exec_env, header_value_buf_ptr, header_value_buf_len, 1, &header_value_buf_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->header_get(
+        exec_env,
+        (response_handle_t) response_handle,
+        (const char *) header_name_ptr_native,
+        header_name_len,
+        (uint8_t *) header_value_buf_ptr_native,
+        header_value_buf_len,
+        (written_bytes_t *) result_ptr_native);
+}
+
+static uint32_t
+wasi_experimental_http_body_read_wrapper(
+    wasm_exec_env_t exec_env,
+    uint32_t response_handle,
+    uint32_t body_buf_ptr,
+    uint32_t body_buf_len,
+    uint32_t result_ptr)
+{
+    const wasi_experimental_http_host_t *host = wasm_runtime_get_function_attachment(exec_env);
+    void *body_buf_ptr_native = NULL;
+    void *result_ptr_native = NULL;
+
+    if (host->body_read == NULL) {
+        wasm_runtime_set_exception(wasm_runtime_get_module_inst(exec_env),
+            "wasi_experimental_http.body_read is not implemented");
+        return 0;
+    }
+    if (!wasi_guest_range(exec_env, body_buf_ptr, body_buf_len, 1, &body_buf_ptr_native) ||
+        !wasi_guest_range(exec_env, result_ptr, 1, 4, &result_ptr_native)) {
+        return 0;
+    }
+    return (uint32_t) host->body_read(
+        exec_env,
+        (response_handle_t) response_handle,
+        (uint8_t *) body_buf_ptr_native,
+        body_buf_len,
+        (written_bytes_t *) result_ptr_native);
+}
+
+static NativeSymbol wasi_experimental_http_native_symbols[] = {
+    { "req", (void *) wasi_experimental_http_req_wrapper, "(iiiiiiiiii)i", NULL },
+    { "close", (void *) wasi_experimental_http_close_wrapper, "(i)i", NULL },
+    { "header_get", (void *) wasi_experimental_http_header_get_wrapper, "(iiiiii)i", NULL },
+    { "body_read", (void *) wasi_experimental_http_body_read_wrapper, "(iiii)i", NULL },
+};
+
+bool
+wasi_experimental_http_register_natives(const wasi_experimental_http_host_t *host)
+{
+    const size_t n = sizeof wasi_experimental_http_native_symbols / sizeof wasi_experimental_http_native_symbols[0];
+    size_t i;
+
+    /* The callbacks are found through the attachments of the symbols */
+    for (i = 0; i < n; i++) {
+        wasi_experimental_http_native_symbols[i].attachment = (void *) host;
+    }
+    return wasm_runtime_register_natives("wasi_experimental_http", wasi_experimental_http_native_symbols, (uint32_t) n);
+}
+
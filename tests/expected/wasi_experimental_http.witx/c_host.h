@@ -0,0 +1,118 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#include "wasm_export.h"
+
+#ifndef WITX_CODEGEN_HOST_TYPES
+#define WITX_CODEGEN_HOST_TYPES
+
+typedef int32_t wasi_handle_t;
+typedef uint32_t wasi_size_t;
+
+/* A pointer in the guest memory */
+typedef uint32_t wasi_guest_ptr_t;
+
+/* A list stored in the guest memory */
+typedef struct wasi_guest_slice {
+    wasi_guest_ptr_t ptr;
+    wasi_size_t len;
+} wasi_guest_slice_t;
+
+#endif
+
+/* ---------------------- Module: [wasi_experimental_http] ---------------------- */
+
+typedef uint32_t http_error_t;
+
+#define HTTP_ERROR_SUCCESS ((http_error_t) 0)
+#define HTTP_ERROR_INVALID_HANDLE ((http_error_t) 1)
+#define HTTP_ERROR_MEMORY_NOT_FOUND ((http_error_t) 2)
+#define HTTP_ERROR_MEMORY_ACCESS_ERROR ((http_error_t) 3)
+#define HTTP_ERROR_BUFFER_TOO_SMALL ((http_error_t) 4)
+#define HTTP_ERROR_HEADER_NOT_FOUND ((http_error_t) 5)
+#define HTTP_ERROR_UTF_8_ERROR ((http_error_t) 6)
+#define HTTP_ERROR_DESTINATION_NOT_ALLOWED ((http_error_t) 7)
+#define HTTP_ERROR_INVALID_METHOD ((http_error_t) 8)
+#define HTTP_ERROR_INVALID_ENCODING ((http_error_t) 9)
+#define HTTP_ERROR_INVALID_URL ((http_error_t) 10)
+#define HTTP_ERROR_REQUEST_ERROR ((http_error_t) 11)
+#define HTTP_ERROR_RUNTIME_ERROR ((http_error_t) 12)
+#define HTTP_ERROR_TOO_MANY_SESSIONS ((http_error_t) 13)
+
+/** HTTP status code */
+typedef uint16_t status_code_t;
+
+/** An HTTP body being sent */
+typedef wasi_guest_slice_t outgoing_body_t;
+
+/** Buffer for an HTTP body being received */
+typedef wasi_guest_slice_t incoming_body_t;
+
+/** A response handle */
+typedef wasi_handle_t response_handle_t;
+
+/** Buffer to store a header value */
+typedef wasi_guest_slice_t header_value_buf_t;
+
+/** Number of bytes having been written */
+typedef wasi_size_t written_bytes_t;
+
+/**
+ * Host implementation of the [wasi_experimental_http] module
+ *
+ * Guest pointers are checked and translated to native pointers before the callbacks are
+ * called. Callbacks left to `NULL` raise an exception in the guest.
+ */
+typedef struct wasi_experimental_http_host {
+    /** Send a request */
+    http_error_t (*req)(
+        wasm_exec_env_t exec_env,
+        const char *url_ptr,
+        uint32_t url_len,
+        const char *method_ptr,
+        uint32_t method_len,
+        const char *headers_ptr,
+        uint32_t headers_len,
+        const uint8_t *body_ptr,
+        uint32_t body_len,
+        status_code_t *result_0_ptr,
+        response_handle_t *result_1_ptr);
+
+    /** Close a request handle */
+    http_error_t (*close)(
+        wasm_exec_env_t exec_env,
+        response_handle_t response_handle);
+
+    /** Get the value associated with a header */
+    http_error_t (*header_get)(
+        wasm_exec_env_t exec_env,
+        response_handle_t response_handle,
+        const char *header_name_ptr,
+        uint32_t header_name_len,
+        uint8_t *header_value_buf_ptr,
+        uint32_t header_value_buf_len,
+        written_bytes_t *result_ptr);
+
+    /** Fill a buffer with the streamed content of a response body */
+    http_error_t (*body_read)(
+        wasm_exec_env_t exec_env,
+        response_handle_t response_handle,
+        uint8_t *body_buf_ptr,
+        uint32_t body_buf_len,
+        written_bytes_t *result_ptr);
+} wasi_experimental_http_host_t;
+
+/**
+ * Registers the native symbols of the [wasi_experimental_http] module, calling the callbacks of `host`
+ *
+ * `host` must remain valid as long as the runtime is used.
+ */
+bool wasi_experimental_http_register_natives(const wasi_experimental_http_host_t *host);
+
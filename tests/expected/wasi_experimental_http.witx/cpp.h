@@ -0,0 +1,128 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+#include <cstdint>
+#include <cstring>
+#include <tuple>
+#include <cstddef>
+#include <variant>
+
+// namespace WitxCodegenHeader {
+using WasiHandle = int32_t;
+template <typename T> using WasiPtr = T *const;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<unsigned char>;
+
+template <typename R, typename E> using Expected = std::variant<R, E>;
+
+using WasiStringBytesPtr = WasiPtr<unsigned char>;
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t length;
+};
+
+template<typename T>
+struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t length;
+};
+
+template<typename T>
+struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t length;
+};
+
+// }
+
+// ---------------------- Module: [wasi_experimental_http] ----------------------
+
+enum class HttpError : uint32_t {
+    SUCCESS = 0,
+    INVALID_HANDLE = 1,
+    MEMORY_NOT_FOUND = 2,
+    MEMORY_ACCESS_ERROR = 3,
+    BUFFER_TOO_SMALL = 4,
+    HEADER_NOT_FOUND = 5,
+    UTF_8_ERROR = 6,
+    DESTINATION_NOT_ALLOWED = 7,
+    INVALID_METHOD = 8,
+    INVALID_ENCODING = 9,
+    INVALID_URL = 10,
+    REQUEST_ERROR = 11,
+    RUNTIME_ERROR = 12,
+    TOO_MANY_SESSIONS = 13,
+};
+
+/**
+* HTTP status code
+**/
+using StatusCode = uint16_t;
+
+/**
+* An HTTP body being sent
+**/
+using OutgoingBody = WasiSlice<uint8_t>;
+
+/**
+* Buffer for an HTTP body being received
+**/
+using IncomingBody = WasiMutSlice<uint8_t>;
+
+/**
+* A response handle
+**/
+enum class ResponseHandle : WasiHandle {};
+
+/**
+* Buffer to store a header value
+**/
+using HeaderValueBuf = WasiMutSlice<uint8_t>;
+
+/**
+* Number of bytes having been written
+**/
+using WrittenBytes = size_t;
+
+/**
+* Send a request
+**/
+Expected<std::tuple<StatusCode, ResponseHandle>, HttpError> req(
+    WasiPtr<unsigned char> url_ptr,
+    size_t url_len,
+    WasiPtr<unsigned char> method_ptr,
+    size_t method_len,
+    WasiPtr<unsigned char> headers_ptr,
+    size_t headers_len,
+    WasiPtr<uint8_t> body_ptr,
+    size_t body_len
+);
+
+/**
+* Close a request handle
+**/
+Expected<std::monostate, HttpError> close(
+    ResponseHandle response_handle
+);
+
+/**
+* Get the value associated with a header
+**/
+Expected<WrittenBytes, HttpError> header_get(
+    ResponseHandle response_handle,
+    WasiPtr<unsigned char> header_name_ptr,
+    size_t header_name_len,
+    WasiMutPtr<uint8_t> header_value_buf_ptr,
+    size_t header_value_buf_len
+);
+
+/**
+* Fill a buffer with the streamed content of a response body
+**/
+Expected<WrittenBytes, HttpError> body_read(
+    ResponseHandle response_handle,
+    WasiMutPtr<uint8_t> body_buf_ptr,
+    size_t body_buf_len
+);
+
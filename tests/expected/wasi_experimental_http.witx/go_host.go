@@ -0,0 +1,295 @@
+// Code generated by witx-codegen. DO NOT EDIT.
+
+package wasiexperimentalhttp
+
+import (
+	"context"
+	"errors"
+	"fmt"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiSlice is a list of values in the guest memory
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiSize
+}
+
+// WasiError is returned by a host implementation to return an error code to the guest
+type WasiError struct {
+	Errno uint32
+}
+
+func (e *WasiError) Error() string {
+	return fmt.Sprintf("WASI error %d", e.Errno)
+}
+
+// errno returns the error code of a WasiError, and panics on other errors, so that the
+// guest traps
+func errno(err error) uint64 {
+	var wasiErr *WasiError
+	if errors.As(err, &wasiErr) {
+		return api.EncodeU32(wasiErr.Errno)
+	}
+	panic(err)
+}
+
+func boolToU8(v bool) uint8 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// errOutOfBounds is raised when the guest passes a pointer outside of its memory
+var errOutOfBounds = errors.New("out of bounds memory access")
+
+// GuestAllocator allocates size bytes aligned to align in the memory of the guest module,
+// and returns their address
+type GuestAllocator func(ctx context.Context, mod api.Module, size, align uint32) (uint32, error)
+
+// guestMemory accesses the memory of the guest, and panics on invalid pointers
+type guestMemory struct {
+	api.Memory
+	// alloc is only set in functions returning strings or byte slices
+	alloc func(size, align uint32) uint32
+}
+
+// allocatingMemory returns a guestMemory that can allocate memory in the guest, and
+// panics if the allocation fails
+func allocatingMemory(ctx context.Context, mod api.Module, alloc GuestAllocator) guestMemory {
+	return guestMemory{mod.Memory(), func(size, align uint32) uint32 {
+		ptr, err := alloc(ctx, mod, size, align)
+		if err != nil {
+			panic(err)
+		}
+		return ptr
+	}}
+}
+
+func (m guestMemory) check(ok bool) {
+	if !ok {
+		panic(errOutOfBounds)
+	}
+}
+
+func (m guestMemory) u8(ptr uint32) uint8 {
+	v, ok := m.ReadByte(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU8(ptr uint32, v uint8) {
+	m.check(m.WriteByte(ptr, v))
+}
+
+func (m guestMemory) u16(ptr uint32) uint16 {
+	v, ok := m.ReadUint16Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU16(ptr uint32, v uint16) {
+	m.check(m.WriteUint16Le(ptr, v))
+}
+
+func (m guestMemory) u32(ptr uint32) uint32 {
+	v, ok := m.ReadUint32Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU32(ptr uint32, v uint32) {
+	m.check(m.WriteUint32Le(ptr, v))
+}
+
+func (m guestMemory) u64(ptr uint32) uint64 {
+	v, ok := m.ReadUint64Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putU64(ptr uint32, v uint64) {
+	m.check(m.WriteUint64Le(ptr, v))
+}
+
+func (m guestMemory) f32(ptr uint32) float32 {
+	v, ok := m.ReadFloat32Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putF32(ptr uint32, v float32) {
+	m.check(m.WriteFloat32Le(ptr, v))
+}
+
+func (m guestMemory) f64(ptr uint32) float64 {
+	v, ok := m.ReadFloat64Le(ptr)
+	m.check(ok)
+	return v
+}
+
+func (m guestMemory) putF64(ptr uint32, v float64) {
+	m.check(m.WriteFloat64Le(ptr, v))
+}
+
+// bytes returns a view of the guest memory
+func (m guestMemory) bytes(ptr, len uint32) []byte {
+	b, ok := m.Read(ptr, len)
+	m.check(ok)
+	return b
+}
+
+func (m guestMemory) copyBytes(ptr, len uint32) []byte {
+	return append([]byte(nil), m.bytes(ptr, len)...)
+}
+
+func (m guestMemory) string(ptr, len uint32) string {
+	return string(m.bytes(ptr, len))
+}
+
+// putBytes copies data to memory allocated in the guest, and stores its address and
+// length at ptr
+func (m guestMemory) putBytes(ptr uint32, data []byte) {
+	dataPtr := m.alloc(uint32(len(data)), 1)
+	m.check(m.Write(dataPtr, data))
+	m.putU32(ptr, dataPtr)
+	m.putU32(ptr+4, uint32(len(data)))
+}
+
+// ---------------------- Module: [wasi_experimental_http] ----------------------
+
+type HttpError uint32
+
+const (
+	HttpErrorSuccess               HttpError = 0
+	HttpErrorInvalidHandle         HttpError = 1
+	HttpErrorMemoryNotFound        HttpError = 2
+	HttpErrorMemoryAccessError     HttpError = 3
+	HttpErrorBufferTooSmall        HttpError = 4
+	HttpErrorHeaderNotFound        HttpError = 5
+	HttpErrorUtf8Error             HttpError = 6
+	HttpErrorDestinationNotAllowed HttpError = 7
+	HttpErrorInvalidMethod         HttpError = 8
+	HttpErrorInvalidEncoding       HttpError = 9
+	HttpErrorInvalidUrl            HttpError = 10
+	HttpErrorRequestError          HttpError = 11
+	HttpErrorRuntimeError          HttpError = 12
+	HttpErrorTooManySessions       HttpError = 13
+)
+
+// HTTP status code
+type StatusCode = uint16
+
+// An HTTP body being sent
+type OutgoingBody = []byte
+
+// Buffer for an HTTP body being received
+type IncomingBody = []byte
+
+// A response handle
+type ResponseHandle = WasiHandle
+
+// Buffer to store a header value
+type HeaderValueBuf = []byte
+
+// Number of bytes having been written
+type WrittenBytes = WasiSize
+
+// WasiExperimentalHttp is the host implementation of the [wasi_experimental_http] module
+//
+// The methods receive the parameters decoded from the guest memory, and return the
+// results. Errors are returned to the guest by returning a *WasiError.
+type WasiExperimentalHttp interface {
+	// Send a request
+	Req(ctx context.Context, url string, method string, headers string, body OutgoingBody) (StatusCode, ResponseHandle, error)
+
+	// Close a request handle
+	Close(ctx context.Context, responseHandle ResponseHandle) error
+
+	// Get the value associated with a header
+	HeaderGet(ctx context.Context, responseHandle ResponseHandle, headerName string, headerValueBuf HeaderValueBuf) (WrittenBytes, error)
+
+	// Fill a buffer with the streamed content of a response body
+	BodyRead(ctx context.Context, responseHandle ResponseHandle, bodyBuf IncomingBody) (WrittenBytes, error)
+}
+
+// Instantiate adds the [wasi_experimental_http] module to the runtime, calling the methods of impl
+func Instantiate(ctx context.Context, r wazero.Runtime, impl WasiExperimentalHttp) (api.Module, error) {
+	b := r.NewHostModuleBuilder("wasi_experimental_http")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v0, v1, err := impl.Req(
+				ctx,
+				m.string(api.DecodeU32(stack[0]), api.DecodeU32(stack[1])),
+				m.string(api.DecodeU32(stack[2]), api.DecodeU32(stack[3])),
+				m.string(api.DecodeU32(stack[4]), api.DecodeU32(stack[5])),
+				m.copyBytes(api.DecodeU32(stack[6]), api.DecodeU32(stack[7])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU16(api.DecodeU32(stack[8]), uint16(v0))
+			m.putU32(api.DecodeU32(stack[9]), uint32(v1))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("url_ptr", "url_len", "method_ptr", "method_len", "headers_ptr", "headers_len", "body_ptr", "body_len", "result0_ptr", "result1_ptr").
+		Export("req")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			err := impl.Close(ctx, ResponseHandle(api.DecodeU32(stack[0])))
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("response_handle").
+		Export("close")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.HeaderGet(
+				ctx,
+				ResponseHandle(api.DecodeU32(stack[0])),
+				m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+				m.bytes(api.DecodeU32(stack[3]), api.DecodeU32(stack[4])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[5]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("response_handle", "header_name_ptr", "header_name_len", "header_value_buf_ptr", "header_value_buf_len", "result_ptr").
+		Export("header_get")
+	b.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			m := guestMemory{Memory: mod.Memory()}
+			v, err := impl.BodyRead(
+				ctx,
+				ResponseHandle(api.DecodeU32(stack[0])),
+				m.bytes(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),
+			)
+			if err != nil {
+				stack[0] = errno(err)
+				return
+			}
+			m.putU32(api.DecodeU32(stack[3]), uint32(v))
+			stack[0] = 0
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("response_handle", "body_buf_ptr", "body_buf_len", "result_ptr").
+		Export("body_read")
+	return b.Instantiate(ctx)
+}
@@ -0,0 +1,293 @@
+
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+import ctypes
+import enum
+
+import wasmtime
+
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host implementation to return an error code to the guest"""
+
+    def __init__(self, errno):
+        super().__init__(errno)
+        self.errno = errno
+
+
+def read_bytes(caller, ptr, length):
+    """Copies length bytes from the guest memory"""
+    ptr &= 0xFFFFFFFF
+    return bytes(caller["memory"].read(caller, ptr, ptr + length))
+
+
+def write_bytes(caller, ptr, data):
+    """Copies data to the guest memory"""
+    caller["memory"].write(caller, data, ptr & 0xFFFFFFFF)
+
+
+def read_string(caller, ptr, length):
+    """Decodes an UTF-8 string from the guest memory"""
+    return read_bytes(caller, ptr, length).decode("utf-8")
+
+
+def read_value(caller, ptr, ctype):
+    """Reads a value of the given ctypes type from the guest memory"""
+    value = ctype.from_buffer_copy(read_bytes(caller, ptr, ctypes.sizeof(ctype)))
+    if isinstance(value, (ctypes.Structure, ctypes.Union)):
+        return value
+    return value.value
+
+
+def read_list(caller, ptr, length, ctype):
+    """Reads an array of values of the given ctypes type from the guest memory"""
+    array = (ctype * length).from_buffer_copy(
+        read_bytes(caller, ptr, ctypes.sizeof(ctype) * length)
+    )
+    return list(array)
+
+
+def write_value(caller, ptr, ctype, value):
+    """Writes a value of the given ctypes type to the guest memory"""
+    if not isinstance(value, ctype):
+        value = ctype(value)
+    write_bytes(caller, ptr, bytes(value))
+
+
+def write_allocated_bytes(caller, alloc, ptr, data):
+    """Copies data to memory allocated in the guest, and stores its address and
+    length at ptr"""
+    data_ptr = alloc(caller, len(data), 1)
+    write_bytes(caller, data_ptr, data)
+    write_value(caller, ptr, WasiSlice, WasiSlice(data_ptr, len(data)))
+
+
+def write_string(caller, alloc, ptr, value):
+    """Copies an UTF-8 string to memory allocated in the guest, and stores its
+    address and length at ptr"""
+    write_allocated_bytes(caller, alloc, ptr, value.encode("utf-8"))
+
+
+class GuestBuffer:
+    """A buffer in the guest memory, that the host can write to"""
+
+    def __init__(self, caller, ptr, length):
+        self.caller = caller
+        self.ptr = ptr
+        self.length = length
+
+    def __len__(self):
+        return self.length
+
+    def read(self):
+        return read_bytes(self.caller, self.ptr, self.length)
+
+    def write(self, data, offset=0):
+        if offset + len(data) > self.length:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        write_bytes(self.caller, self.ptr + offset, data)
+
+
+# ---------------------- Module: [wasi_experimental_http] ----------------------
+
+
+class HttpError(enum.IntEnum):
+    SUCCESS = 0
+    INVALID_HANDLE = 1
+    MEMORY_NOT_FOUND = 2
+    MEMORY_ACCESS_ERROR = 3
+    BUFFER_TOO_SMALL = 4
+    HEADER_NOT_FOUND = 5
+    UTF_8_ERROR = 6
+    DESTINATION_NOT_ALLOWED = 7
+    INVALID_METHOD = 8
+    INVALID_ENCODING = 9
+    INVALID_URL = 10
+    REQUEST_ERROR = 11
+    RUNTIME_ERROR = 12
+    TOO_MANY_SESSIONS = 13
+
+
+# HTTP status code
+StatusCode = ctypes.c_uint16
+
+
+# An HTTP body being sent
+OutgoingBody = WasiSlice
+
+
+# Buffer for an HTTP body being received
+IncomingBody = WasiSlice
+
+
+# A response handle
+ResponseHandle = WasiHandle
+
+
+# Buffer to store a header value
+HeaderValueBuf = WasiSlice
+
+
+# Number of bytes having been written
+WrittenBytes = WasiSize
+
+
+class WasiExperimentalHttpHost:
+    """Host implementation of the [wasi_experimental_http] module
+
+    The methods receive the parameters decoded from the guest memory, and return
+    the results. Errors are returned to the guest by raising a WasiError.
+    """
+
+    def req(self, url, method, headers, body):
+        """Send a request"""
+        raise NotImplementedError("req")
+
+    def close(self, response_handle):
+        """Close a request handle"""
+        raise NotImplementedError("close")
+
+    def header_get(self, response_handle, header_name, header_value_buf):
+        """Get the value associated with a header"""
+        raise NotImplementedError("header_get")
+
+    def body_read(self, response_handle, body_buf):
+        """Fill a buffer with the streamed content of a response body"""
+        raise NotImplementedError("body_read")
+
+
+def add_wasi_experimental_http_to_linker(linker, host):
+    """Defines the functions of the [wasi_experimental_http] module in a wasmtime.Linker
+
+    The functions decode their parameters from the memory exported by the guest,
+    and call the methods of host.
+    """
+
+    i32 = wasmtime.ValType.i32()
+    i64 = wasmtime.ValType.i64()
+    f32 = wasmtime.ValType.f32()
+    f64 = wasmtime.ValType.f64()
+
+    def req(
+        caller,
+        url_ptr,
+        url_len,
+        method_ptr,
+        method_len,
+        headers_ptr,
+        headers_len,
+        body_ptr,
+        body_len,
+        result_0_ptr,
+        result_1_ptr,
+    ):
+        try:
+            result = host.req(
+                read_string(caller, url_ptr, url_len),
+                read_string(caller, method_ptr, method_len),
+                read_string(caller, headers_ptr, headers_len),
+                read_bytes(caller, body_ptr, body_len),
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_0_ptr, StatusCode, result[0])
+        write_value(caller, result_1_ptr, ResponseHandle, result[1])
+        return 0
+
+    linker.define_func(
+        "wasi_experimental_http",
+        "req",
+        wasmtime.FuncType([i32, i32, i32, i32, i32, i32, i32, i32, i32, i32], [i32]),
+        req,
+        access_caller=True,
+    )
+
+    def close(caller, response_handle):
+        try:
+            host.close(
+                ResponseHandle(response_handle).value,
+            )
+        except WasiError as e:
+            return e.errno
+        return 0
+
+    linker.define_func(
+        "wasi_experimental_http",
+        "close",
+        wasmtime.FuncType([i32], [i32]),
+        close,
+        access_caller=True,
+    )
+
+    def header_get(
+        caller,
+        response_handle,
+        header_name_ptr,
+        header_name_len,
+        header_value_buf_ptr,
+        header_value_buf_len,
+        result_ptr,
+    ):
+        try:
+            result = host.header_get(
+                ResponseHandle(response_handle).value,
+                read_string(caller, header_name_ptr, header_name_len),
+                GuestBuffer(caller, header_value_buf_ptr, header_value_buf_len),
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_ptr, WrittenBytes, result)
+        return 0
+
+    linker.define_func(
+        "wasi_experimental_http",
+        "header_get",
+        wasmtime.FuncType([i32, i32, i32, i32, i32, i32], [i32]),
+        header_get,
+        access_caller=True,
+    )
+
+    def body_read(
+        caller,
+        response_handle,
+        body_buf_ptr,
+        body_buf_len,
+        result_ptr,
+    ):
+        try:
+            result = host.body_read(
+                ResponseHandle(response_handle).value,
+                GuestBuffer(caller, body_buf_ptr, body_buf_len),
+            )
+        except WasiError as e:
+            return e.errno
+        write_value(caller, result_ptr, WrittenBytes, result)
+        return 0
+
+    linker.define_func(
+        "wasi_experimental_http",
+        "body_read",
+        wasmtime.FuncType([i32, i32, i32, i32], [i32]),
+        body_read,
+        access_caller=True,
+    )
+
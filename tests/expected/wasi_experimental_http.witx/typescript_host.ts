@@ -0,0 +1,261 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = number;
+export type WasiPtr = number;
+export type WasiSize = number;
+export type Char8 = number;
+export type Char32 = number;
+
+export interface WasiSlice {
+    ptr: WasiPtr;
+    len: WasiSize;
+}
+
+/**
+ * Thrown by a host implementation to return an error code to the guest
+ */
+export class WasiError<E = number> extends Error {
+    constructor(readonly errno: E) {
+        super(`WASI error ${errno}`);
+    }
+}
+
+/**
+ * Allocates `size` bytes aligned to `align` in the guest memory, and returns their address
+ */
+export type GuestAllocator = (size: number, align: number) => number;
+
+const textDecoder = new TextDecoder("utf-8", { fatal: true });
+const textEncoder = new TextEncoder();
+
+/**
+ * The memory of a guest, that must be bound to the instance once it has been created
+ */
+export class GuestMemory {
+    private memory: WebAssembly.Memory | null = null;
+
+    bind(instance: WebAssembly.Instance): void {
+        this.memory = instance.exports.memory as WebAssembly.Memory;
+    }
+
+    view(): DataView {
+        return new DataView(this.buffer());
+    }
+
+    bytes(ptr: number, len: number): Uint8Array {
+        return new Uint8Array(this.buffer(), ptr >>> 0, len >>> 0);
+    }
+
+    readString(ptr: number, len: number): string {
+        return textDecoder.decode(this.bytes(ptr, len));
+    }
+
+    /**
+     * Copies bytes to memory allocated in the guest, and stores their address and length at `ptr`
+     */
+    writeBytes(alloc: GuestAllocator, ptr: number, bytes: Uint8Array): void {
+        const dataPtr = alloc(bytes.length, 1) >>> 0;
+        this.bytes(dataPtr, bytes.length).set(bytes);
+        const view = this.view();
+        view.setUint32(ptr, dataPtr, true);
+        view.setUint32(ptr + 4, bytes.length, true);
+    }
+
+    writeString(alloc: GuestAllocator, ptr: number, value: string): void {
+        this.writeBytes(alloc, ptr, textEncoder.encode(value));
+    }
+
+    private buffer(): ArrayBuffer {
+        if (this.memory === null) {
+            throw new Error("The guest memory is not bound to an instance");
+        }
+        return this.memory.buffer;
+    }
+}
+
+/*
+ * ---------------------- Module: [wasi_experimental_http] ----------------------
+ */
+
+export type HttpError = number;
+
+export namespace HttpError {
+    export const SUCCESS: HttpError = 0;
+    export const INVALID_HANDLE: HttpError = 1;
+    export const MEMORY_NOT_FOUND: HttpError = 2;
+    export const MEMORY_ACCESS_ERROR: HttpError = 3;
+    export const BUFFER_TOO_SMALL: HttpError = 4;
+    export const HEADER_NOT_FOUND: HttpError = 5;
+    export const UTF_8_ERROR: HttpError = 6;
+    export const DESTINATION_NOT_ALLOWED: HttpError = 7;
+    export const INVALID_METHOD: HttpError = 8;
+    export const INVALID_ENCODING: HttpError = 9;
+    export const INVALID_URL: HttpError = 10;
+    export const REQUEST_ERROR: HttpError = 11;
+    export const RUNTIME_ERROR: HttpError = 12;
+    export const TOO_MANY_SESSIONS: HttpError = 13;
+}
+
+/**
+ * HTTP status code
+ */
+export type StatusCode = number;
+
+/**
+ * An HTTP body being sent
+ */
+export type OutgoingBody = Uint8Array;
+
+/**
+ * Buffer for an HTTP body being received
+ */
+export type IncomingBody = Uint8Array;
+
+/**
+ * A response handle
+ */
+export type ResponseHandle = WasiHandle;
+
+/**
+ * Buffer to store a header value
+ */
+export type HeaderValueBuf = Uint8Array;
+
+/**
+ * Number of bytes having been written
+ */
+export type WrittenBytes = WasiSize;
+
+/**
+ * Host implementation of the [wasi_experimental_http] module
+ *
+ * The functions receive the parameters decoded from the guest memory, and return
+ * the results. Errors are returned to the guest by throwing a `WasiError`.
+ */
+export interface WasiExperimentalHttp {
+    /**
+     * Send a request
+     */
+    req(url: string, method: string, headers: string, body: OutgoingBody): [StatusCode, ResponseHandle];
+
+    /**
+     * Close a request handle
+     */
+    close(responseHandle: ResponseHandle): void;
+
+    /**
+     * Get the value associated with a header
+     */
+    headerGet(responseHandle: ResponseHandle, headerName: string, headerValueBuf: HeaderValueBuf): WrittenBytes;
+
+    /**
+     * Fill a buffer with the streamed content of a response body
+     */
+    bodyRead(responseHandle: ResponseHandle, bodyBuf: IncomingBody): WrittenBytes;
+}
+
+/**
+ * Returns the imports of the [wasi_experimental_http] module, calling the functions of `impl`
+ *
+ * `mem` must be bound to the instance once it has been created.
+ */
+export function wasiExperimentalHttpImports(impl: WasiExperimentalHttp, mem: GuestMemory): WebAssembly.Imports {
+    return {
+        "wasi_experimental_http": {
+            "req": (
+                urlPtr: number,
+                urlLen: number,
+                methodPtr: number,
+                methodLen: number,
+                headersPtr: number,
+                headersLen: number,
+                bodyPtr: number,
+                bodyLen: number,
+                result0Ptr: number,
+                result1Ptr: number
+            ): number => {
+                try {
+                    const result = impl.req(
+                        mem.readString(urlPtr, urlLen),
+                        mem.readString(methodPtr, methodLen),
+                        mem.readString(headersPtr, headersLen),
+                        mem.bytes(bodyPtr, bodyLen).slice(),
+                    );
+                    const view = mem.view();
+                    view.setUint16(result0Ptr, result[0], true);
+                    view.setInt32(result1Ptr, result[1], true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return HttpError.SUCCESS;
+            },
+            "close": (
+                responseHandle: number
+            ): number => {
+                try {
+                    impl.close(
+                        responseHandle,
+                    );
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return HttpError.SUCCESS;
+            },
+            "header_get": (
+                responseHandle: number,
+                headerNamePtr: number,
+                headerNameLen: number,
+                headerValueBufPtr: number,
+                headerValueBufLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.headerGet(
+                        responseHandle,
+                        mem.readString(headerNamePtr, headerNameLen),
+                        mem.bytes(headerValueBufPtr, headerValueBufLen),
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return HttpError.SUCCESS;
+            },
+            "body_read": (
+                responseHandle: number,
+                bodyBufPtr: number,
+                bodyBufLen: number,
+                resultPtr: number
+            ): number => {
+                try {
+                    const result = impl.bodyRead(
+                        responseHandle,
+                        mem.bytes(bodyBufPtr, bodyBufLen),
+                    );
+                    const view = mem.view();
+                    view.setUint32(resultPtr, result, true);
+                } catch (e) {
+                    if (e instanceof WasiError) {
+                        return e.errno;
+                    }
+                    throw e;
+                }
+                return HttpError.SUCCESS;
+            },
+        },
+    };
+}
+
@@ -0,0 +1,57 @@
+
+;;
+;; This file was automatically generated by witx-codegen - Do not edit manually.
+;;
+
+(module
+  ;; ---------------------- Module: [wasi_experimental_http] ----------------------
+
+  ;; Send a request
+  (import "wasi_experimental_http" "req"
+    (func $wasi_experimental_http.req
+      (param $url_ptr i32)
+      (param $url_len i32)
+      (param $method_ptr i32)
+      (param $method_len i32)
+      (param $headers_ptr i32)
+      (param $headers_len i32)
+      (param $body_ptr i32)
+      (param $body_len i32)
+      (param $result0_ptr i32)
+      (param $result1_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; Close a request handle
+  (import "wasi_experimental_http" "close"
+    (func $wasi_experimental_http.close
+      (param $response_handle i32)
+      (result i32)
+    )
+  )
+
+  ;; Get the value associated with a header
+  (import "wasi_experimental_http" "header_get"
+    (func $wasi_experimental_http.header_get
+      (param $response_handle i32)
+      (param $header_name_ptr i32)
+      (param $header_name_len i32)
+      (param $header_value_buf_ptr i32)
+      (param $header_value_buf_len i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+
+  ;; Fill a buffer with the streamed content of a response body
+  (import "wasi_experimental_http" "body_read"
+    (func $wasi_experimental_http.body_read
+      (param $response_handle i32)
+      (param $body_buf_ptr i32)
+      (param $body_buf_len i32)
+      (param $result_ptr i32)
+      (result i32)
+    )
+  )
+)
@@ -0,0 +1,136 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [wasi_experimental_http] ----------------------
+
+pub const HttpError = enum(u32) {
+    SUCCESS = 0,
+    INVALID_HANDLE = 1,
+    MEMORY_NOT_FOUND = 2,
+    MEMORY_ACCESS_ERROR = 3,
+    BUFFER_TOO_SMALL = 4,
+    HEADER_NOT_FOUND = 5,
+    UTF_8_ERROR = 6,
+    DESTINATION_NOT_ALLOWED = 7,
+    INVALID_METHOD = 8,
+    INVALID_ENCODING = 9,
+    INVALID_URL = 10,
+    REQUEST_ERROR = 11,
+    RUNTIME_ERROR = 12,
+    TOO_MANY_SESSIONS = 13,
+};
+
+/// HTTP status code
+pub const StatusCode = u16;
+
+/// An HTTP body being sent
+pub const OutgoingBody = WasiSlice(u8);
+
+/// Buffer for an HTTP body being received
+pub const IncomingBody = WasiMutSlice(u8);
+
+/// A response handle
+pub const ResponseHandle = enum(WasiHandle) { _ };
+
+/// Buffer to store a header value
+pub const HeaderValueBuf = WasiMutSlice(u8);
+
+/// Number of bytes having been written
+pub const WrittenBytes = usize;
+
+pub const WasiExperimentalHttp = struct {
+    /// Send a request
+    pub extern "wasi_experimental_http" fn req(
+        url_ptr: WasiPtr(Char8),
+        url_len: usize,
+        method_ptr: WasiPtr(Char8),
+        method_len: usize,
+        headers_ptr: WasiPtr(Char8),
+        headers_len: usize,
+        body_ptr: WasiPtr(u8),
+        body_len: usize,
+        result_0_ptr: WasiMutPtr(StatusCode),
+        result_1_ptr: WasiMutPtr(ResponseHandle),
+    ) callconv(.C) HttpError;
+
+    /// Close a request handle
+    pub extern "wasi_experimental_http" fn close(
+        response_handle: ResponseHandle,
+    ) callconv(.C) HttpError;
+
+    /// Get the value associated with a header
+    pub extern "wasi_experimental_http" fn header_get(
+        response_handle: ResponseHandle,
+        header_name_ptr: WasiPtr(Char8),
+        header_name_len: usize,
+        header_value_buf_ptr: WasiMutPtr(u8),
+        header_value_buf_len: usize,
+        result_ptr: WasiMutPtr(WrittenBytes),
+    ) callconv(.C) HttpError;
+
+    /// Fill a buffer with the streamed content of a response body
+    pub extern "wasi_experimental_http" fn body_read(
+        response_handle: ResponseHandle,
+        body_buf_ptr: WasiMutPtr(u8),
+        body_buf_len: usize,
+        result_ptr: WasiMutPtr(WrittenBytes),
+    ) callconv(.C) HttpError;
+
+};
+
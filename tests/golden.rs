@@ -0,0 +1,117 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use witx_codegen::{generate, Config, OutputType};
+
+const SOURCES: &[&str] = &[
+    "test_module.witx",
+    "wasi_ephemeral_crypto_common.witx",
+    "wasi_ephemeral_crypto_symmetric.witx",
+    "wasi_experimental_http.witx",
+    "test_module.wit",
+];
+
+const OUTPUTS: &[(OutputType, &str)] = &[
+    (OutputType::AssemblyScript, "assemblyscript.ts"),
+    (OutputType::Rust, "rust.rs"),
+    (OutputType::Zig, "zig.zig"),
+    (OutputType::Overview, "overview.txt"),
+    (OutputType::Doc, "markdown.md"),
+    (OutputType::Cpp, "cpp.h"),
+    (OutputType::Wit, "wit.wit"),
+    (OutputType::Wat, "wat.wat"),
+    (OutputType::PythonHost, "python_host.py"),
+    (OutputType::TypescriptHost, "typescript_host.ts"),
+    (OutputType::GoHost, "go_host.go"),
+    (OutputType::CHostHeader, "c_host.h"),
+    (OutputType::CHostSource, "c_host.c"),
+];
+
+/// Set this environment variable to overwrite the expectation files with the current output
+const BLESS_ENV: &str = "WITX_CODEGEN_BLESS";
+
+const WITX_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn generate_to_string(source: &str, output_type: OutputType) -> String {
+    let output_file =
+        Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("golden-{}-{}", source, output_type));
+    let c = Config {
+        output_type,
+        output_file: Some(output_file.to_str().unwrap().to_string()),
+        witx_files: vec![format!("{}/tests/{}", WITX_DIR, source)],
+        ..Default::default()
+    };
+    generate(&c).unwrap();
+    let output = fs::read_to_string(&output_file).unwrap();
+    fs::remove_file(&output_file).unwrap();
+    output
+}
+
+fn expectation_path(source: &str, file_name: &str) -> PathBuf {
+    Path::new(WITX_DIR)
+        .join("tests")
+        .join("expected")
+        .join(source)
+        .join(file_name)
+}
+
+/// Describe the first line that differs between the expected and the actual output
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for line_number in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(expected_line), Some(actual_line)) if expected_line == actual_line => continue,
+            (expected_line, actual_line) => {
+                return format!(
+                    "line {}:\n  expected: {}\n  actual:   {}",
+                    line_number,
+                    expected_line.unwrap_or("<end of file>"),
+                    actual_line.unwrap_or("<end of file>")
+                )
+            }
+        }
+    }
+    unreachable!()
+}
+
+#[test]
+fn golden_outputs() {
+    let bless = std::env::var_os(BLESS_ENV).is_some();
+    let mut failures = vec![];
+
+    for source in SOURCES {
+        for (output_type, file_name) in OUTPUTS {
+            println!("Generate {} from {}", output_type, source);
+
+            let actual = generate_to_string(source, *output_type);
+            let path = expectation_path(source, file_name);
+            let expected = fs::read_to_string(&path).ok();
+
+            match expected {
+                Some(expected) if expected == actual => {}
+                Some(expected) if !bless => failures.push(format!(
+                    "{} doesn't match the expected output, {}",
+                    path.display(),
+                    first_difference(&expected, &actual)
+                )),
+                None if !bless => failures.push(format!("{} is missing", path.display())),
+                _ => {
+                    // Blessed outputs have to be reviewed before being committed
+                    println!("Writing {}", path.display());
+                    fs::create_dir_all(path.parent().unwrap()).unwrap();
+                    fs::write(&path, &actual).unwrap();
+                }
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "{}\n\nIf these changes are expected, run the tests again with {}=1 to update or \
+             create the expectation files.",
+            failures.join("\n\n"),
+            BLESS_ENV
+        );
+    }
+}
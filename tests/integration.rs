@@ -1,4 +1,5 @@
-use witx_codegen::{generate, Config, OutputType};
+use structopt::StructOpt;
+use witx_codegen::{generate, generate_to_writer, Config, Error, OutputType};
 
 const WITX_SOURCES: &[&str] = &[
     "test_module.witx",
@@ -7,6 +8,8 @@ const WITX_SOURCES: &[&str] = &[
     "wasi_experimental_http.witx",
 ];
 
+const WIT_SOURCES: &[&str] = &["test_module.wit"];
+
 const WITX_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
 #[test]
@@ -109,3 +112,916 @@ fn generate_cpp() {
         generate(&c).unwrap();
     }
 }
+
+#[test]
+fn generate_wit() {
+    let mut c = Config {
+        output_type: OutputType::Wit,
+        output_file: Some("/dev/null".to_string()),
+        ..Default::default()
+    };
+
+    for s in WITX_SOURCES {
+        println!("Generate {}", s);
+
+        let p = format!("{}/tests/{}", WITX_DIR, s);
+        c.witx_files = vec![p];
+
+        generate(&c).unwrap();
+    }
+}
+
+#[test]
+fn name_collisions() {
+    for output_type in [
+        OutputType::AssemblyScript,
+        OutputType::Rust,
+        OutputType::Zig,
+        OutputType::Cpp,
+    ] {
+        for s in ["name_collision.witx", "shadowed_param.witx"] {
+            let c = Config {
+                output_type,
+                output_file: Some("/dev/null".to_string()),
+                witx_files: vec![format!("{}/tests/{}", WITX_DIR, s)],
+                ..Default::default()
+            };
+
+            println!("Generate {} with colliding names from {}", output_type, s);
+
+            assert!(generate(&c).is_err());
+        }
+    }
+}
+
+#[test]
+fn unwritable_output() {
+    let c = Config {
+        output_type: OutputType::Rust,
+        output_file: Some(format!(
+            "{}/missing/dir/out.rs",
+            env!("CARGO_TARGET_TMPDIR")
+        )),
+        witx_files: vec![format!("{}/tests/test_module.witx", WITX_DIR)],
+        ..Default::default()
+    };
+
+    assert!(matches!(generate(&c), Err(Error::Io(_))));
+}
+
+#[test]
+fn lint() {
+    let mut c = Config {
+        output_type: OutputType::Lint,
+        output_file: Some("/dev/null".to_string()),
+        ..Default::default()
+    };
+
+    for s in WITX_SOURCES {
+        println!("Lint {}", s);
+
+        let p = format!("{}/tests/{}", WITX_DIR, s);
+        c.witx_files = vec![p];
+
+        generate(&c).unwrap();
+    }
+}
+
+#[test]
+fn lint_levels() {
+    let witx_file = format!("{}/tests/test_module.witx", WITX_DIR);
+    let lint = |levels: &[&str]| {
+        let output_file = format!(
+            "{}/lint{}.txt",
+            env!("CARGO_TARGET_TMPDIR"),
+            levels.join("")
+        );
+        let args = ["witx-codegen", "-t", "lint", "-o", &output_file, &witx_file];
+        let c = Config::from_iter(args.iter().chain(levels));
+        let res = generate(&c);
+        (res, std::fs::read_to_string(&output_file).unwrap())
+    };
+
+    // Diagnostics point at the definition of the member, or else of the type
+    let (res, output) = lint(&[]);
+    assert!(res.is_ok());
+    assert!(output.contains(&format!(
+        "{}:41:65: warning: type `test_struct` has 2 byte(s) of padding after member \
+         `a_byte` [padding]",
+        witx_file
+    )));
+    assert!(output.contains(&format!(
+        "{}:56:141: warning: case `empty_choice` of `test_tagged_union` is not documented \
+         [undocumented]",
+        witx_file
+    )));
+    assert!(output.contains(&format!("{}: 6 warning(s), 0 error(s) emitted", witx_file)));
+
+    let (res, output) = lint(&["-D", "padding"]);
+    assert!(matches!(res, Err(Error::Lint(2))));
+    assert!(output.contains(&format!(
+        "{}:44:13: error: tuple `test_tuple` has 3 byte(s) of padding after member #0 \
+         [padding]",
+        witx_file
+    )));
+    assert!(output.contains(&format!("{}: 4 warning(s), 2 error(s) emitted", witx_file)));
+
+    let (res, output) = lint(&["-A", "undocumented"]);
+    assert!(res.is_ok());
+    assert!(!output.contains("[undocumented]"));
+    assert!(output.contains(&format!("{}: 2 warning(s), 0 error(s) emitted", witx_file)));
+
+    let (res, output) = lint(&["-A", "padding", "-A", "undocumented"]);
+    assert!(res.is_ok());
+    assert!(output.is_empty());
+
+    // Definitions lowered from WIT have no location
+    let wit_file = format!("{}/tests/test_module.wit", WITX_DIR);
+    let c = Config::from_iter(["witx-codegen", "-t", "lint", &wit_file]);
+    let mut output = vec![];
+    generate_to_writer(&c, &mut output).unwrap();
+    assert!(String::from_utf8(output).unwrap().contains(&format!(
+        "{}: warning: type `test_resource` is not documented [undocumented]",
+        wit_file
+    )));
+}
+
+#[test]
+fn lint_reserved_words() {
+    let witx_file = format!("{}/reserved_words.witx", env!("CARGO_TARGET_TMPDIR"));
+    std::fs::write(
+        &witx_file,
+        r#"(module $reserved_words
+    (typename $header (record (field $async u32)))
+    (@interface func (export "delete")
+        (param $type u32)
+    )
+)
+"#,
+    )
+    .unwrap();
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "lint",
+        "-A",
+        "undocumented",
+        &witx_file,
+    ]);
+    let mut output = vec![];
+    generate_to_writer(&c, &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains(&format!(
+        "{}:2:38: warning: member `async` becomes `async`, which is a reserved word in Zig \
+         [reserved-word]",
+        witx_file
+    )));
+    assert!(output.contains(&format!(
+        "{}:3:30: warning: function `delete` becomes `delete`, which is a reserved word in \
+         AssemblyScript [reserved-word]",
+        witx_file
+    )));
+    assert!(output.contains(&format!(
+        "{}:3:30: warning: parameter `type` becomes `type`, which is a reserved word in Rust \
+         [reserved-word]",
+        witx_file
+    )));
+}
+
+#[test]
+fn generate_from_wit() {
+    for output_type in [
+        OutputType::AssemblyScript,
+        OutputType::Rust,
+        OutputType::Zig,
+        OutputType::Overview,
+        OutputType::Doc,
+        OutputType::Cpp,
+        OutputType::Wit,
+        OutputType::Wat,
+        OutputType::PythonHost,
+        OutputType::TypescriptHost,
+        OutputType::GoHost,
+        OutputType::CHostHeader,
+        OutputType::CHostSource,
+    ] {
+        let mut c = Config {
+            output_type,
+            output_file: Some("/dev/null".to_string()),
+            ..Default::default()
+        };
+
+        for s in WIT_SOURCES {
+            println!("Generate {} from {}", output_type, s);
+
+            let p = format!("{}/tests/{}", WITX_DIR, s);
+            c.witx_files = vec![p];
+
+            generate(&c).unwrap();
+        }
+    }
+}
+
+#[test]
+fn wit_round_trip() {
+    let wit_dir = format!("{}/wit_round_trip", env!("CARGO_TARGET_TMPDIR"));
+    std::fs::create_dir_all(&wit_dir).unwrap();
+    let wit_file = format!("{}/test_module.wit", wit_dir);
+    let c = Config {
+        output_type: OutputType::Wit,
+        output_file: Some(wit_file.clone()),
+        witx_files: vec![format!("{}/tests/test_module.wit", WITX_DIR)],
+        ..Default::default()
+    };
+    generate(&c).unwrap();
+
+    // The aliases generated for the function results are already defined in the input
+    let c = Config {
+        output_type: OutputType::Wit,
+        output_file: Some(format!("{}/round_trip.wit", wit_dir)),
+        witx_files: vec![wit_file],
+        ..Default::default()
+    };
+    generate(&c).unwrap();
+}
+
+#[test]
+fn wit_option() {
+    let wit_dir = format!("{}/wit_option", env!("CARGO_TARGET_TMPDIR"));
+    std::fs::create_dir_all(&wit_dir).unwrap();
+    let wit_file = format!("{}/option.wit", wit_dir);
+    std::fs::write(
+        &wit_file,
+        "package test:options;\n\ninterface options {\n  enum errno { success, failure }\n  record r { a: option<u32>, b: list<option<string>> }\n  type maybe = option<option<u64>>;\n  f: func(a: r) -> result<option<r>, errno>;\n}\n",
+    )
+    .unwrap();
+    let output_file = format!("{}/option.wit", env!("CARGO_TARGET_TMPDIR"));
+    let c = Config {
+        output_type: OutputType::Wit,
+        output_file: Some(output_file.clone()),
+        witx_files: vec![wit_file],
+        ..Default::default()
+    };
+    generate(&c).unwrap();
+    let wit = std::fs::read_to_string(&output_file).unwrap();
+
+    // Anonymous options are named after their first use, innermost first
+    assert!(wit.contains("    variant r-a {\n        absent,\n        present(u32),\n    }\n"));
+    assert!(wit.contains("        b: list<r-b-item>,\n"));
+    assert!(
+        wit.contains("    variant maybe {\n        absent,\n        present(maybe-some),\n    }\n")
+    );
+    assert!(wit.contains("    f: func(a: r) -> result<f-result, errno>;\n"));
+}
+
+#[test]
+fn wit_unsupported() {
+    for (case, definitions) in [
+        (
+            "result_in_record",
+            "record r { a: result<u32, errno> }\n  f: func(a: r) -> result<_, errno>;",
+        ),
+        ("result_without_error", "f: func() -> result;"),
+        ("result_without_error_type", "f: func() -> result<u32>;"),
+        (
+            "list_of_tuples",
+            "f: func(a: list<tuple<u32, u64>>) -> result<_, errno>;",
+        ),
+        ("constructor", "resource x { constructor(a: u32); }"),
+    ] {
+        let wit_dir = format!("{}/wit_unsupported/{}", env!("CARGO_TARGET_TMPDIR"), case);
+        std::fs::create_dir_all(&wit_dir).unwrap();
+        let wit_file = format!("{}/{}.wit", wit_dir, case);
+        std::fs::write(
+            &wit_file,
+            format!(
+                "package test:unsupported;\n\ninterface unsupported {{\n  enum errno {{ success, failure }}\n  {}\n}}\n",
+                definitions
+            ),
+        )
+        .unwrap();
+        let c = Config {
+            output_type: OutputType::Rust,
+            output_file: Some("/dev/null".to_string()),
+            witx_files: vec![wit_file],
+            ..Default::default()
+        };
+
+        println!("Lower unsupported WIT: {}", case);
+
+        assert!(matches!(generate(&c), Err(Error::Wit(_))));
+    }
+}
+
+#[test]
+fn naming_policy() {
+    for (output_type, expected, unexpected) in [
+        (
+            "cpp",
+            "struct __attribute__((packed)) test_struct_t {",
+            "TestStruct",
+        ),
+        (
+            "assemblyscript",
+            "export const GuestError: TestErrno = 1;",
+            "GUEST_ERROR",
+        ),
+        (
+            "rust",
+            "#[link_name = \"a_function_that_returns_multiple_values\"]",
+            "pub fn a_function_that_returns_multiple_values(",
+        ),
+        (
+            "zig",
+            "pub fn aFunctionThatReturnsMultipleValues(",
+            "pub extern \"test_module\" fn a_function_that_returns_multiple_values(",
+        ),
+    ] {
+        println!("Generate {} with a naming policy", output_type);
+
+        let output_file = format!("{}/naming.{}", env!("CARGO_TARGET_TMPDIR"), output_type);
+        let c = Config::from_iter([
+            "witx-codegen",
+            "--naming",
+            &format!("{}/tests/naming.toml", WITX_DIR),
+            "-t",
+            output_type,
+            "-o",
+            &output_file,
+            &format!("{}/tests/test_module.witx", WITX_DIR),
+        ]);
+        generate(&c).unwrap();
+
+        let output = std::fs::read_to_string(&output_file).unwrap();
+        assert!(output.contains(expected));
+        assert!(!output.contains(unexpected));
+    }
+}
+
+#[test]
+fn project() {
+    let project_dir = format!("{}/project", env!("CARGO_TARGET_TMPDIR"));
+    std::fs::create_dir_all(&project_dir).unwrap();
+    let project_file = format!("{}/witx-codegen.toml", project_dir);
+    std::fs::write(
+        &project_file,
+        format!(
+            r#"
+[[job]]
+inputs = ["{dir}/tests/test_module.witx"]
+output-type = "rust"
+output = "test_module.rs"
+module-name = "renamed_module"
+
+[[job]]
+inputs = ["{dir}/tests/test_module.witx"]
+output-type = "zig"
+output = "test_module.zig"
+skip-header = true
+
+[job.naming.types]
+prefix = "Wasi"
+"#,
+            dir = WITX_DIR
+        ),
+    )
+    .unwrap();
+
+    let project = witx_codegen::Project::load(&project_file).unwrap();
+    assert_eq!(project.jobs.len(), 2);
+    project.generate().unwrap();
+
+    let rust = std::fs::read_to_string(format!("{}/test_module.rs", project_dir)).unwrap();
+    assert!(rust.contains("wasm_import_module = \"renamed_module\""));
+    let zig = std::fs::read_to_string(format!("{}/test_module.zig", project_dir)).unwrap();
+    assert!(zig.contains("pub const WasiTestStruct = "));
+    assert!(!zig.contains("automatically generated"));
+
+    std::fs::write(
+        &project_file,
+        "[[job]]\ninputs = []\noutput-type = \"cobol\"\n",
+    )
+    .unwrap();
+    assert!(witx_codegen::Project::load(&project_file).is_err());
+}
+
+#[test]
+fn builder() {
+    let output_file = format!("{}/builder.rs", env!("CARGO_TARGET_TMPDIR"));
+    let symmetric = format!("{}/tests/wasi_ephemeral_crypto_symmetric.witx", WITX_DIR);
+    let builder = witx_codegen::Builder::new()
+        .witx(&symmetric)
+        .rust()
+        .out_file(&output_file)
+        .rerun_if_changed(false);
+
+    let dependencies = builder.dependencies().unwrap();
+    assert_eq!(
+        dependencies,
+        vec![
+            std::path::PathBuf::from(&symmetric),
+            std::path::PathBuf::from(format!(
+                "{}/tests/wasi_ephemeral_crypto_common.witx",
+                WITX_DIR
+            )),
+        ]
+    );
+
+    builder.generate().unwrap();
+    let rust = std::fs::read_to_string(&output_file).unwrap();
+    assert!(rust.contains("pub fn symmetric_key_generate("));
+}
+
+#[test]
+fn trace() {
+    let witx_file = format!("{}/tests/wasi_experimental_http.witx", WITX_DIR);
+    let generate = |output_type: &str| {
+        let output_file = format!("{}/trace.{}", env!("CARGO_TARGET_TMPDIR"), output_type);
+        let c = Config::from_iter([
+            "witx-codegen",
+            "-t",
+            output_type,
+            "--trace",
+            "-o",
+            &output_file,
+            &witx_file,
+        ]);
+        generate(&c).unwrap();
+        std::fs::read_to_string(&output_file).unwrap()
+    };
+
+    let assemblyscript = generate("assemblyscript");
+    assert!(assemblyscript.contains("declare function closeRaw("));
+    assert!(assemblyscript.contains("export function close("));
+    assert!(assemblyscript.contains(
+        "console.log(\"[wasi_experimental_http] close(response_handle=\" + response_handle.toString() + \") -> \" + res.toString());"
+    ));
+
+    let zig = generate("zig");
+    assert!(zig.contains("pub fn close("));
+    assert!(zig.contains("extern \"wasi_experimental_http\" fn close("));
+    assert!(zig.contains(
+        "@import(\"std\").debug.print(\"[wasi_experimental_http] close(response_handle={any}) -> {any}\\n\", .{ response_handle, res });"
+    ));
+
+    // Backends that can't log the calls reject the option
+    let c = Config::from_iter(["witx-codegen", "-t", "cpp", "--trace", &witx_file]);
+    assert!(matches!(
+        generate_to_writer(&c, &mut std::io::sink()),
+        Err(Error::UnsupportedOption { .. })
+    ));
+}
+
+#[test]
+fn wat() {
+    let output_file = format!("{}/test.wat", env!("CARGO_TARGET_TMPDIR"));
+    for s in WITX_SOURCES {
+        let witx_file = format!("{}/tests/{}", WITX_DIR, s);
+        for stub_args in [&[][..], &["--stub-errno", "8"][..]] {
+            let mut args = vec!["witx-codegen", "-t", "wat", "-o", &output_file, &witx_file];
+            args.extend_from_slice(stub_args);
+            generate(&Config::from_iter(args)).unwrap();
+
+            let wat = std::fs::read_to_string(&output_file).unwrap();
+            let wasm = wat::parse_str(&wat).unwrap();
+            wasmparser::validate(&wasm).unwrap();
+            assert_eq!(wat.contains("i32.const 8"), !stub_args.is_empty());
+        }
+    }
+
+    // Multiple input files share a single module
+    let witx_files: Vec<_> = WITX_SOURCES
+        .iter()
+        .map(|s| format!("{}/tests/{}", WITX_DIR, s))
+        .collect();
+    for stub_args in [&[][..], &["--stub-errno", "8"][..]] {
+        let mut args = vec!["witx-codegen", "-t", "wat", "-o", &output_file];
+        args.extend(witx_files.iter().map(String::as_str));
+        args.extend_from_slice(stub_args);
+        generate(&Config::from_iter(args)).unwrap();
+
+        let wat = std::fs::read_to_string(&output_file).unwrap();
+        assert_eq!(wat.matches("(module").count(), 1);
+        let wasm = wat::parse_str(&wat).unwrap();
+        wasmparser::validate(&wasm).unwrap();
+    }
+
+    // Stubs of different modules can't be exported under the same name
+    let other_witx_file = format!("{}/other.witx", env!("CARGO_TARGET_TMPDIR"));
+    std::fs::write(
+        &other_witx_file,
+        r#"
+(module $other
+  (typename $errno (enum (@witx tag u16) $success $badf))
+  (@interface func (export "close")
+    (result $error (expected (error $errno))))
+)
+"#,
+    )
+    .unwrap();
+    let http_witx_file = format!("{}/tests/wasi_experimental_http.witx", WITX_DIR);
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "wat",
+        "--stub-errno",
+        "8",
+        &http_witx_file,
+        &other_witx_file,
+    ]);
+    assert!(matches!(
+        generate_to_writer(&c, &mut std::io::sink()),
+        Err(Error::NameCollision { .. })
+    ));
+
+    // The error code is returned as an i32
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "wat",
+        "--stub-errno",
+        "2147483648",
+        &http_witx_file,
+    ]);
+    assert!(matches!(
+        generate_to_writer(&c, &mut std::io::sink()),
+        Err(Error::InvalidOption { .. })
+    ));
+}
+
+#[test]
+fn python_host() {
+    let output_file = format!("{}/python_host.py", env!("CARGO_TARGET_TMPDIR"));
+    let witx_file = format!("{}/tests/test_module.witx", WITX_DIR);
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "python_host",
+        "-o",
+        &output_file,
+        &witx_file,
+    ]);
+    generate(&c).unwrap();
+    let python = std::fs::read_to_string(&output_file).unwrap();
+
+    // Padding from the computed layout is explicit
+    assert!(python.contains(
+        "class TestStruct(ctypes.Structure):\n    _pack_ = 1\n    _fields_ = [\n        (\"a_boolean\", ctypes.c_bool),\n        (\"a_byte\", ctypes.c_uint8),\n        (\"__pad2\", ctypes.c_uint8 * 2),\n"
+    ));
+    assert!(python.contains("class TestErrno(enum.IntEnum):"));
+    assert!(python.contains("class TestBigFlags(enum.IntFlag):"));
+    assert!(python.contains("read_string(caller, str_ptr, str_len),"));
+    assert!(python.contains("write_value(caller, result_1_ptr, TestBigInt, result[1])"));
+
+    // Strings are copied to memory allocated by the guest
+    assert!(python.contains("def add_test_module_to_linker(linker, host, alloc):"));
+    assert!(python.contains("write_string(caller, alloc, result_ptr, result)"));
+}
+
+#[test]
+fn typescript_host() {
+    let output_file = format!("{}/typescript_host.ts", env!("CARGO_TARGET_TMPDIR"));
+    let witx_file = format!("{}/tests/test_module.witx", WITX_DIR);
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "typescript_host",
+        "-o",
+        &output_file,
+        &witx_file,
+    ]);
+    generate(&c).unwrap();
+    let typescript = std::fs::read_to_string(&output_file).unwrap();
+
+    // Fields are read at the offsets of the computed layout
+    assert!(typescript.contains("aByte: view.getUint8(ptr + 1),"));
+    assert!(typescript.contains("view.getBigUint64(ptr + 8, true),"));
+    assert!(typescript.contains("return { tag: 2, thirdChoice: view.getFloat32(ptr + 4, true) };"));
+    assert!(typescript.contains(
+        "export function testModuleImports(impl: TestModule, mem: GuestMemory, alloc: GuestAllocator): WebAssembly.Imports {"
+    ));
+    assert!(typescript.contains("mem.readString(strPtr, strLen),"));
+    assert!(typescript.contains("return TestErrno.SUCCESS;"));
+
+    // Strings are copied to memory allocated by the guest, after the other members
+    assert!(typescript.contains("mem.writeString(alloc, resultPtr, result);"));
+    assert!(typescript.contains(
+        "view.setUint8(ptr + 1, value.aByte);\n        mem.writeString(alloc, ptr + 4, value.aString);\n"
+    ));
+    assert!(typescript.contains("TestTaggedUnion.write(mem, resultPtr, result, alloc);"));
+    assert!(!typescript.contains("unwritable"));
+}
+
+#[test]
+fn go_host() {
+    let output_file = format!("{}/go_host.go", env!("CARGO_TARGET_TMPDIR"));
+    let witx_file = format!("{}/tests/test_module.witx", WITX_DIR);
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "go_host",
+        "-o",
+        &output_file,
+        &witx_file,
+    ]);
+    generate(&c).unwrap();
+    let go = std::fs::read_to_string(&output_file).unwrap();
+
+    // Fields are read at the offsets of the computed layout
+    assert!(go.contains("\t\tAByte:    uint8(m.u8(ptr+1)),\n"));
+    assert!(go.contains("\t\tV2: TestBigInt(m.u64(ptr+8)),\n"));
+    assert!(go.contains("\t\tv.ThirdChoice = float32(m.f32(ptr+4))\n"));
+    assert!(go.contains(
+        "func Instantiate(ctx context.Context, r wazero.Runtime, impl TestModule, alloc GuestAllocator) (api.Module, error) {"
+    ));
+    assert!(go.contains("m.string(api.DecodeU32(stack[1]), api.DecodeU32(stack[2])),"));
+    assert!(go.contains("\t\tExport(\"a_function_that_returns_nothing\")\n"));
+
+    // Strings are copied to memory allocated by the guest
+    assert!(go.contains("\t\t\tm := allocatingMemory(ctx, mod, alloc)\n\t\t\tv, err := impl.AFunctionThatGetsAndReturnsAString("));
+    assert!(go.contains("\t\t\tm.putBytes(api.DecodeU32(stack[2]), []byte(v))\n"));
+    assert!(go.contains("\tm.putBytes(ptr+4, []byte(v.AString))\n"));
+    assert!(!go.contains("unwritable"));
+}
+
+#[test]
+fn c_host() {
+    let witx_file = format!("{}/tests/test_module.witx", WITX_DIR);
+    let mut outputs = vec![];
+    for output_type in ["c_host_header", "c_host_source"] {
+        let output_file = format!("{}/{}.txt", env!("CARGO_TARGET_TMPDIR"), output_type);
+        let c = Config::from_iter([
+            "witx-codegen",
+            "-t",
+            output_type,
+            "-o",
+            &output_file,
+            &witx_file,
+        ]);
+        generate(&c).unwrap();
+        outputs.push(std::fs::read_to_string(&output_file).unwrap());
+    }
+    let (header, source) = (&outputs[0], &outputs[1]);
+
+    // Records are packed, with explicit padding checked against the computed layout
+    assert!(header.contains("    uint8_t a_byte;\n    uint8_t __pad0[2];\n"));
+    assert!(header.contains("_Static_assert(sizeof(test_tagged_union_t) == 12, \"Error layout\");"));
+    assert!(header.contains("        const char *str_ptr,\n        uint32_t str_len,\n"));
+    assert!(header.contains("bool test_module_register_natives(const test_module_host_t *host);"));
+
+    // Guest pointers are checked with the size of the values they point to
+    assert!(source.contains("#include \"test_module_host.h\""));
+    assert!(
+        source.contains("!wasi_guest_range(exec_env, result_ptr, 1, 12, &result_ptr_native)) {")
+    );
+    assert!(source.contains(
+        "{ \"a_function_that_returns_multiple_values\", (void *) test_module_a_function_that_returns_multiple_values_wrapper, \"(Iiiii)i\", NULL },"
+    ));
+}
+
+#[test]
+fn assemblyscript_wrappers() {
+    let output_file = format!("{}/wrappers.ts", env!("CARGO_TARGET_TMPDIR"));
+    let witx_file = format!("{}/tests/wasi_experimental_http.witx", WITX_DIR);
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "assemblyscript",
+        "-o",
+        &output_file,
+        &witx_file,
+    ]);
+    generate(&c).unwrap();
+    let assemblyscript = std::fs::read_to_string(&output_file).unwrap();
+
+    // The raw imports are still declared
+    assert!(assemblyscript.contains("export declare function req("));
+    assert!(assemblyscript.contains("export class WasiExperimentalHttp {"));
+    // Strings and buffers are accepted as-is
+    assert!(assemblyscript.contains("    static req<B0>(\n        url: string,\n"));
+    assert!(assemblyscript.contains("const url_buf = String.UTF8.encode(url, false);"));
+    assert!(assemblyscript.contains("wasiDataPtr(body),\n            body.byteLength,\n"));
+    // Values are returned as typed results
+    assert!(assemblyscript.contains("): WasiResult<ReqResults, HttpError> {"));
+    assert!(assemblyscript.contains("load<StatusCode>(changetype<usize>(result0_buf)),"));
+    assert!(assemblyscript.contains("return WasiResult.err<WrittenBytes, HttpError>(res);"));
+    // Functions with no values return the error code
+    assert!(assemblyscript.contains("static close(response_handle: ResponseHandle): HttpError {"));
+}
+
+#[test]
+fn assemblyscript_records() {
+    let output_file = format!("{}/records.ts", env!("CARGO_TARGET_TMPDIR"));
+    let witx_file = format!("{}/tests/test_module.witx", WITX_DIR);
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "assemblyscript",
+        "-o",
+        &output_file,
+        &witx_file,
+    ]);
+    generate(&c).unwrap();
+    let assemblyscript = std::fs::read_to_string(&output_file).unwrap();
+
+    // The storage has the exact size of the record, strings included
+    assert!(assemblyscript.contains(
+        "export class TestStruct {\n    private __pad64_0: u64;\n    private __pad32_0: u32;\n\n"
+    ));
+    assert!(
+        assemblyscript.contains("constructor(a_boolean: bool, a_byte: u8, a_string: WasiString) {")
+    );
+    assert!(assemblyscript.contains("static at(ptr: usize): TestStruct {"));
+    // Members are accessed through their offsets
+    assert!(assemblyscript.contains("return load<u8>(changetype<usize>(this), 1);"));
+    assert!(assemblyscript.contains("store<TestBigInt>(changetype<usize>(this), val, 8);"));
+    assert!(assemblyscript.contains("return changetype<WasiString>(changetype<usize>(this) + 4);"));
+    assert!(assemblyscript
+        .contains("memory.copy(changetype<usize>(this) + 4, changetype<usize>(val), 8);"));
+}
+
+#[test]
+fn raii() {
+    let witx_file = format!("{}/tests/wasi_experimental_http.witx", WITX_DIR);
+    let generate = |output_type: &str, witx_file: &str| {
+        let output_file = format!("{}/raii.{}", env!("CARGO_TARGET_TMPDIR"), output_type);
+        let c = Config::from_iter([
+            "witx-codegen",
+            "-t",
+            output_type,
+            "--raii",
+            "-o",
+            &output_file,
+            witx_file,
+        ]);
+        generate(&c).unwrap();
+        std::fs::read_to_string(&output_file).unwrap()
+    };
+
+    let rust = generate("rust", &witx_file);
+    assert!(rust.contains("pub struct ResponseHandle(pub WasiHandle);"));
+    assert!(rust.contains("impl Drop for OwnedResponseHandle {"));
+    assert!(rust.contains("let _ = close(self.0);"));
+
+    let cpp = generate("cpp", &witx_file);
+    assert!(cpp.contains("enum class ResponseHandle : WasiHandle {};"));
+    assert!(cpp.contains("~OwnedResponseHandle() {"));
+    assert!(cpp.contains("(void) close(handle_);"));
+
+    let zig = generate("zig", &witx_file);
+    assert!(zig.contains("pub const ResponseHandle = enum(WasiHandle) { _ };"));
+    assert!(zig.contains("_ = WasiExperimentalHttp.close(self.handle);"));
+
+    // An annotation takes precedence over the naming convention
+    let witx_file = format!("{}/raii.witx", env!("CARGO_TARGET_TMPDIR"));
+    std::fs::write(
+        &witx_file,
+        r#"
+(module $raii
+  (typename $errno (enum (@witx tag u16) $success $badf))
+  (resource $file)
+  (typename $fd (handle $file))
+  (@interface func (export "fd_close")
+    (param $fd $fd)
+    (result $error (expected (error $errno))))
+  ;;; Release the descriptor
+  ;;; @close
+  (@interface func (export "fd_release")
+    (param $fd $fd)
+    (result $error (expected (error $errno))))
+)
+"#,
+    )
+    .unwrap();
+    let rust = generate("rust", &witx_file);
+    assert!(rust.contains("let _ = fd_release(self.0);"));
+    assert!(!rust.contains("let _ = fd_close(self.0);"));
+}
+
+#[test]
+fn methods() {
+    let generate = |output_type: &str, witx_file: &str| {
+        let output_file = format!("{}/methods.{}", env!("CARGO_TARGET_TMPDIR"), output_type);
+        let witx_file = format!("{}/tests/{}", WITX_DIR, witx_file);
+        let c = Config::from_iter([
+            "witx-codegen",
+            "-t",
+            output_type,
+            "--methods",
+            "-o",
+            &output_file,
+            &witx_file,
+        ]);
+        generate(&c).unwrap();
+        std::fs::read_to_string(&output_file).unwrap()
+    };
+
+    let rust = generate("rust", "wasi_experimental_http.witx");
+    // Free functions are still generated
+    assert!(rust.contains("pub fn header_get(\n    response_handle: ResponseHandle,\n"));
+    assert!(rust.contains("impl ResponseHandle {"));
+    assert!(rust.contains("    pub fn header_get(\n        self,\n        header_name_ptr:"));
+    assert!(rust.contains("        body_read(self, body_buf_ptr, body_buf_len)\n"));
+
+    // The handle type prefix is removed from method names
+    let rust = generate("rust", "wasi_ephemeral_crypto_symmetric.witx");
+    assert!(rust.contains("impl SymmetricState {"));
+    assert!(rust.contains("    pub fn absorb(\n        self,\n"));
+    // A parameter named like the function doesn't shadow it
+    assert!(rust.contains("self::symmetric_key_id(self, symmetric_key_id, "));
+
+    let zig = generate("zig", "wasi_experimental_http.witx");
+    assert!(zig.contains("pub const ResponseHandle = enum(WasiHandle) {\n    _,\n"));
+    assert!(zig.contains("        return WasiExperimentalHttp.close(self);\n"));
+
+    // C++ methods are defined on the classes owning the handles
+    let witx_file = format!("{}/tests/wasi_ephemeral_crypto_symmetric.witx", WITX_DIR);
+    let mut cpp = vec![];
+    let c = Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "cpp",
+        "--raii",
+        "--methods",
+        &witx_file,
+    ]);
+    generate_to_writer(&c, &mut cpp).unwrap();
+    let cpp = String::from_utf8(cpp).unwrap();
+    assert!(cpp.contains("class OwnedSymmetricState {"));
+    assert!(cpp.contains("    Expected<std::monostate, CryptoErrno> absorb(\n"));
+    assert!(cpp.contains("        return ::symmetric_state_absorb(handle_, data, data_len);\n"));
+    assert!(!cpp.contains("return ::symmetric_state_close("));
+    // `export` is a keyword
+    assert!(cpp.contains("    Expected<ArrayOutput, CryptoErrno> symmetric_key_export() const {\n"));
+
+    // Other backends, and C++ without the owning classes, reject the option
+    for output_args in [&["-t", "cpp"][..], &["-t", "go_host", "--raii"]] {
+        let mut args = vec!["witx-codegen", "--methods", &witx_file];
+        args.extend(output_args);
+        let c = Config::from_iter(args);
+        assert!(matches!(
+            generate_to_writer(&c, &mut std::io::sink()),
+            Err(Error::UnsupportedOption { .. })
+        ));
+    }
+}
+
+#[test]
+fn derive() {
+    let generate = |derive: &[&str]| {
+        let output_file = format!("{}/derive.rs", env!("CARGO_TARGET_TMPDIR"));
+        let witx_file = format!("{}/tests/test_module.witx", WITX_DIR);
+        let mut args = vec!["witx-codegen", "-t", "rust", "-o", &output_file, &witx_file];
+        args.extend(derive);
+        generate(&Config::from_iter(args)).unwrap();
+        std::fs::read_to_string(&output_file).unwrap()
+    };
+
+    // All traits by default, except `Eq` and `Hash` for types with floats
+    let rust = generate(&[]);
+    assert!(rust.contains("impl std::fmt::Debug for TestStruct {"));
+    assert!(rust.contains("            .field(\"a_byte\", &{ self.a_byte })\n"));
+    assert!(rust.contains("impl std::hash::Hash for TestStruct {"));
+    assert!(rust.contains("impl Default for TestTuple {"));
+    assert!(rust.contains("impl PartialEq for TestTaggedUnion {"));
+    assert!(!rust.contains("impl Eq for TestTaggedUnion {}"));
+    assert!(
+        rust.contains("#[derive(Copy, Clone, Debug, PartialEq)]\npub enum TestTaggedUnionValue {")
+    );
+
+    let rust = generate(&["--derive", "debug,eq"]);
+    assert!(rust.contains("impl std::fmt::Debug for TestTaggedUnion {"));
+    assert!(rust.contains("impl PartialEq for TestStruct {"));
+    assert!(rust.contains("impl Eq for TestStruct {}"));
+    assert!(!rust.contains("impl std::hash::Hash for"));
+    assert!(!rust.contains("impl Default for"));
+
+    let rust = generate(&["--derive"]);
+    assert!(!rust.contains("impl std::fmt::Debug for"));
+    assert!(rust.contains("#[derive(Copy, Clone)]\npub enum TestTaggedUnionValue {"));
+}
+
+#[test]
+fn rust_layout() {
+    let output_file = format!("{}/layout.rs", env!("CARGO_TARGET_TMPDIR"));
+    let witx_file = format!("{}/tests/test_module.witx", WITX_DIR);
+    generate(&Config::from_iter([
+        "witx-codegen",
+        "-t",
+        "rust",
+        "-o",
+        &output_file,
+        &witx_file,
+    ]))
+    .unwrap();
+    let rust = std::fs::read_to_string(&output_file).unwrap();
+
+    // The natural layout matches the WebAssembly one, so records don't have to be packed
+    assert!(!rust.contains("packed"));
+    assert!(rust.contains("#[repr(C)]\n#[derive(Copy, Clone)]\npub struct TestTuple { // -- Tuple\n    pub v0: TestBool,\n    __pad8_0: u8,\n    __pad16_0: u16,\n"));
+    // Types without pointers are also checked on 64-bit targets
+    assert!(rust.contains("#[cfg(any(target_arch = \"wasm32\", target_pointer_width = \"64\"))]\nconst _: () = assert!(std::mem::size_of::<TestTuple>() == 16);\n"));
+    assert!(rust.contains("#[cfg(target_arch = \"wasm32\")]\nconst _: () = assert!(std::mem::size_of::<TestStruct>() == 12);\n"));
+    assert!(rust.contains("const _: () = assert!(std::mem::align_of::<TestTuple>() == 8);\n"));
+    assert!(rust.contains("const _: () = assert!(std::mem::align_of::<TestTaggedUnion>() == 4);\n"));
+}
@@ -0,0 +1,386 @@
+//! Cross-checks the layout computed for random WITX types against a reference C layout.
+
+use witx_codegen::astype::*;
+
+const ITERATIONS: usize = 1000;
+const MAX_DEPTH: usize = 3;
+
+/// Set this environment variable to a number to use a different seed
+const SEED_ENV: &str = "WITX_CODEGEN_LAYOUT_SEED";
+
+/// A small xorshift generator, so that failures can be reproduced from the seed alone
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// Random types, and the WITX syntax they are rendered to
+#[derive(Debug)]
+enum Ty {
+    Builtin(&'static str, usize),
+    List(&'static str),
+    Record(Vec<Ty>),
+    Tuple(Vec<Ty>),
+    Variant(VariantSyntax, usize, Vec<Option<Ty>>),
+}
+
+/// How a variant is written in WITX. `option` and `expected` are shorthands for variants
+/// with a `u8` tag and two cases.
+#[derive(Debug)]
+enum VariantSyntax {
+    Tagged(&'static str),
+    Option,
+    Expected,
+}
+
+const BUILTINS: &[(&str, usize)] = &[
+    ("u8", 1),
+    ("u16", 2),
+    ("u32", 4),
+    ("u64", 8),
+    ("s8", 1),
+    ("s16", 2),
+    ("s32", 4),
+    ("s64", 8),
+    ("f32", 4),
+    ("f64", 8),
+];
+
+const TAGS: &[(&str, usize)] = &[("u8", 1), ("u16", 2), ("u32", 4), ("u64", 8)];
+
+impl Ty {
+    fn random(rng: &mut Rng, depth: usize) -> Ty {
+        let kinds = if depth >= MAX_DEPTH { 2 } else { 5 };
+        match rng.below(kinds) {
+            0 => {
+                let (name, size) = BUILTINS[rng.below(BUILTINS.len())];
+                Ty::Builtin(name, size)
+            }
+            1 => Ty::List(["string", "(list u8)"][rng.below(2)]),
+            2 => Ty::Record(Self::random_members(rng, depth)),
+            3 => Ty::Tuple(Self::random_members(rng, depth)),
+            _ => Self::random_variant(rng, depth),
+        }
+    }
+
+    fn random_members(rng: &mut Rng, depth: usize) -> Vec<Ty> {
+        (0..1 + rng.below(5))
+            .map(|_| Ty::random(rng, depth + 1))
+            .collect()
+    }
+
+    fn random_variant(rng: &mut Rng, depth: usize) -> Ty {
+        match rng.below(4) {
+            0 => {
+                let payload = Ty::random(rng, depth + 1);
+                return Ty::Variant(VariantSyntax::Option, 1, vec![None, Some(payload)]);
+            }
+            1 => {
+                let cases = (0..2)
+                    .map(|_| match rng.below(3) {
+                        0 => None,
+                        _ => Some(Ty::random(rng, depth + 1)),
+                    })
+                    .collect();
+                return Ty::Variant(VariantSyntax::Expected, 1, cases);
+            }
+            _ => {}
+        }
+        let (tag, tag_size) = TAGS[rng.below(TAGS.len())];
+        let mut cases: Vec<_> = (0..1 + rng.below(5))
+            .map(|_| match rng.below(3) {
+                0 => None,
+                _ => Some(Ty::random(rng, depth + 1)),
+            })
+            .collect();
+        // Variants without any payload are lowered to enumerations
+        if cases.iter().all(Option::is_none) {
+            cases[0] = Some(Ty::random(rng, depth + 1));
+        }
+        Ty::Variant(VariantSyntax::Tagged(tag), tag_size, cases)
+    }
+
+    /// WITX syntax for the type. Records cannot be anonymous, so nested records are
+    /// added to `typenames` and referenced by name.
+    fn to_witx(&self, typenames: &mut Vec<String>) -> String {
+        match self {
+            Ty::Builtin(name, _) | Ty::List(name) => name.to_string(),
+            Ty::Record(members) => {
+                let fields: Vec<_> = members
+                    .iter()
+                    .enumerate()
+                    .map(|(i, member)| format!("(field $f{} {})", i, member.to_witx(typenames)))
+                    .collect();
+                let name = format!("$t{}", typenames.len());
+                typenames.push(format!("(typename {} (record {}))", name, fields.join(" ")));
+                name
+            }
+            Ty::Tuple(members) => {
+                let members: Vec<_> = members
+                    .iter()
+                    .map(|member| member.to_witx(typenames))
+                    .collect();
+                format!("(tuple {})", members.join(" "))
+            }
+            Ty::Variant(VariantSyntax::Option, _, cases) => {
+                let payload = cases[1].as_ref().unwrap();
+                format!("(option {})", payload.to_witx(typenames))
+            }
+            Ty::Variant(VariantSyntax::Expected, _, cases) => {
+                let mut witx = "(expected".to_string();
+                if let Some(ok) = &cases[0] {
+                    witx.push_str(&format!(" {}", ok.to_witx(typenames)));
+                }
+                witx.push_str(" (error");
+                if let Some(err) = &cases[1] {
+                    witx.push_str(&format!(" {}", err.to_witx(typenames)));
+                }
+                witx.push_str("))");
+                witx
+            }
+            Ty::Variant(VariantSyntax::Tagged(tag), _, cases) => {
+                let cases: Vec<_> = cases
+                    .iter()
+                    .enumerate()
+                    .map(|(i, case)| match case {
+                        None => format!("(case $c{})", i),
+                        Some(payload) => {
+                            format!("(case $c{} {})", i, payload.to_witx(typenames))
+                        }
+                    })
+                    .collect();
+                format!("(variant (@witx tag {}) {})", tag, cases.join(" "))
+            }
+        }
+    }
+
+    /// Size and alignment of the equivalent C type on a 32-bit target
+    fn c_size_align(&self) -> (usize, usize) {
+        match self {
+            Ty::Builtin(_, size) => (*size, *size),
+            Ty::List(_) => (8, 4),
+            Ty::Record(members) | Ty::Tuple(members) => {
+                let offsets = c_struct_offsets(members);
+                let align = members
+                    .iter()
+                    .map(|member| member.c_size_align().1)
+                    .max()
+                    .unwrap_or(1);
+                let end = match (members.last(), offsets.last()) {
+                    (Some(member), Some(offset)) => offset + member.c_size_align().0,
+                    _ => 0,
+                };
+                (align_to(end, align), align)
+            }
+            Ty::Variant(_, tag_size, cases) => {
+                let (payload_offset, payload_size, payload_align) =
+                    c_variant_payload(*tag_size, cases);
+                let align = payload_align.max(*tag_size);
+                (align_to(payload_offset + payload_size, align), align)
+            }
+        }
+    }
+
+    /// WITX computes the size of a variant case by case, with each payload right after
+    /// the tag. When payloads have different alignments, this can be smaller than the
+    /// size of the equivalent C tagged union, whose payloads all share the same offset.
+    fn has_c_layout(&self) -> bool {
+        match self {
+            Ty::Builtin(..) | Ty::List(_) => true,
+            Ty::Record(members) | Ty::Tuple(members) => members.iter().all(Ty::has_c_layout),
+            Ty::Variant(_, tag_size, cases) => {
+                let mut witx_size = 0;
+                for case in cases {
+                    let (mut case_size, mut case_align) = (*tag_size, *tag_size);
+                    if let Some(payload) = case {
+                        let (payload_size, payload_align) = payload.c_size_align();
+                        case_size = align_to(case_size, payload_align) + payload_size;
+                        case_align = case_align.max(payload_align);
+                    }
+                    witx_size = witx_size.max(align_to(case_size, case_align));
+                }
+                witx_size == self.c_size_align().0 && cases.iter().flatten().all(Ty::has_c_layout)
+            }
+        }
+    }
+}
+
+fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+/// Offsets of the members of a C structure
+fn c_struct_offsets(members: &[Ty]) -> Vec<usize> {
+    let mut offset = 0;
+    members
+        .iter()
+        .map(|member| {
+            let (size, align) = member.c_size_align();
+            let member_offset = align_to(offset, align);
+            offset = member_offset + size;
+            member_offset
+        })
+        .collect()
+}
+
+/// Offset, size and alignment of the union following the tag of a C tagged union
+fn c_variant_payload(tag_size: usize, cases: &[Option<Ty>]) -> (usize, usize, usize) {
+    let (mut size, mut align) = (0, 1);
+    for payload in cases.iter().flatten() {
+        let (payload_size, payload_align) = payload.c_size_align();
+        size = size.max(payload_size);
+        align = align.max(payload_align);
+    }
+    (align_to(tag_size, align), size, align)
+}
+
+fn check_members(
+    ctx: &str,
+    members: &[Ty],
+    layout: &[(usize, usize, &ASType)],
+    expected_size: usize,
+) {
+    assert_eq!(layout.len(), members.len(), "{}: member count", ctx);
+    let offsets = c_struct_offsets(members);
+    let mut total = 0;
+    for (i, (member, (offset, padding, type_))) in members.iter().zip(layout).enumerate() {
+        let (size, align) = member.c_size_align();
+        assert_eq!(*offset, offsets[i], "{}: offset of member #{}", ctx, i);
+        assert_eq!(offset % align, 0, "{}: alignment of member #{}", ctx, i);
+        assert_eq!(*offset, total, "{}: padding before member #{}", ctx, i);
+        total += size + padding;
+        check(ctx, member, type_);
+    }
+    assert_eq!(total, expected_size, "{}: members and padding size", ctx);
+}
+
+fn check(ctx: &str, ty: &Ty, type_: &ASType) {
+    let (size, _) = ty.c_size_align();
+    match (ty, type_.leaf()) {
+        (Ty::Builtin(..), _) => {}
+        (Ty::List(_), ASType::String(_)) | (Ty::List(_), ASType::Slice(_)) => {}
+        (Ty::Record(members), ASType::Struct(layout)) => {
+            let layout: Vec<_> = layout
+                .iter()
+                .map(|member| (member.offset, member.padding, member.type_.as_ref()))
+                .collect();
+            check_members(ctx, members, &layout, size);
+        }
+        (Ty::Tuple(members), ASType::Tuple(layout)) => {
+            let layout: Vec<_> = layout
+                .iter()
+                .map(|member| (member.offset, member.padding, member.type_.as_ref()))
+                .collect();
+            check_members(ctx, members, &layout, size);
+        }
+        (Ty::Variant(_, tag_size, cases), ASType::Union(union_)) => {
+            let (payload_offset, _, payload_align) = c_variant_payload(*tag_size, cases);
+            assert_eq!(union_.members.len(), cases.len(), "{}: case count", ctx);
+            assert_eq!(
+                union_.member_offset, payload_offset,
+                "{}: payload offset",
+                ctx
+            );
+            assert_eq!(
+                union_.member_offset % payload_align,
+                0,
+                "{}: payload alignment",
+                ctx
+            );
+            assert_eq!(
+                tag_size + union_.padding_after_tag,
+                union_.member_offset,
+                "{}: padding after the tag",
+                ctx
+            );
+            assert_eq!(
+                union_.member_offset + union_.max_member_size,
+                size,
+                "{}: variant size",
+                ctx
+            );
+            for (case, member) in cases.iter().zip(&union_.members) {
+                match case {
+                    None => assert_eq!(member.type_.as_ref(), &ASType::Void, "{}", ctx),
+                    Some(payload) => check(ctx, payload, &member.type_),
+                }
+            }
+        }
+        (Ty::Variant(VariantSyntax::Option, tag_size, cases), ASType::Option(option)) => {
+            let (payload_offset, _, _) = c_variant_payload(*tag_size, cases);
+            assert_eq!(option.offset, payload_offset, "{}: payload offset", ctx);
+            assert_eq!(type_.mem_size(), size, "{}: option size", ctx);
+            check(ctx, cases[1].as_ref().unwrap(), &option.type_);
+        }
+        (Ty::Variant(VariantSyntax::Expected, tag_size, cases), ASType::Result(result)) => {
+            let (payload_offset, _, _) = c_variant_payload(*tag_size, cases);
+            assert_eq!(
+                result.result_offset, payload_offset,
+                "{}: payload offset",
+                ctx
+            );
+            assert_eq!(type_.mem_size(), size, "{}: result size", ctx);
+            for (case, type_) in cases.iter().zip([&result.ok_type, &result.error_type]) {
+                match case {
+                    None => assert_eq!(type_.as_ref(), &ASType::Void, "{}", ctx),
+                    Some(payload) => check(ctx, payload, type_),
+                }
+            }
+        }
+        _ => panic!("{}: unexpected lowering {:?} for {:?}", ctx, type_, ty),
+    }
+}
+
+#[test]
+fn layout_matches_c() {
+    let seed = match std::env::var(SEED_ENV) {
+        Ok(seed) => seed.parse().expect("invalid seed"),
+        Err(_) => 0x5eed_1a70_u64,
+    };
+    let mut rng = Rng(seed.max(1));
+    let mut skipped = 0;
+
+    for iteration in 0..ITERATIONS {
+        let ty = match rng.below(3) {
+            0 => Ty::Record(Ty::random_members(&mut rng, 0)),
+            1 => Ty::Tuple(Ty::random_members(&mut rng, 0)),
+            _ => Ty::random_variant(&mut rng, 0),
+        };
+        if !ty.has_c_layout() {
+            skipped += 1;
+            continue;
+        }
+        let mut typenames = vec![];
+        let type_witx = ty.to_witx(&mut typenames);
+        if !type_witx.starts_with('$') {
+            typenames.push(format!("(typename $t {})", type_witx));
+        }
+        let witx_source = format!("(module $layout {})", typenames.join(" "));
+        let ctx = format!(
+            "seed {}, iteration {}, types [{}]",
+            seed, iteration, witx_source
+        );
+
+        let module_witx = witx::parse(&witx_source).unwrap_or_else(|e| panic!("{}: {:?}", ctx, e));
+        let type_witx = module_witx.typenames().last().unwrap();
+        let type_ = match &type_witx.tref {
+            witx::TypeRef::Name(_) => panic!("{}: not a value type", ctx),
+            witx::TypeRef::Value(type_witx) => ASType::from(type_witx.as_ref()),
+        };
+        check(&ctx, &ty, &type_);
+    }
+    println!(
+        "{} type(s) skipped, as WITX doesn't lay them out like C",
+        skipped
+    );
+}
@@ -0,0 +1,112 @@
+//! `include_witx!` expands to the Rust code generated from WITX files:
+//!
+//! ```ignore
+//! witx_codegen_macros::include_witx!("witx/wasi_ephemeral_crypto_symmetric.witx");
+//! ```
+//!
+//! Paths are relative to the directory of the crate manifest. WIT files and WIT package
+//! directories are also accepted.
+//!
+//! The macro can't be re-exported by `witx-codegen`, since this crate depends on it to
+//! generate the code, and Cargo rejects dependency cycles, including optional ones.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{LitStr, Token};
+use witx_codegen::Builder;
+
+#[proc_macro]
+pub fn include_witx(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let witx_files = Punctuated::<LitStr, Token![,]>::parse_terminated.parse2(input)?;
+    if witx_files.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "Expected the path of at least one WITX file",
+        ));
+    }
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new(Span::call_site(), "CARGO_MANIFEST_DIR is not set"))?;
+
+    let paths = witx_files
+        .iter()
+        .map(|witx_file| Path::new(&manifest_dir).join(witx_file.value()))
+        .collect::<Vec<_>>();
+    let mut builder = Builder::new().rust();
+    for (witx_file, path) in witx_files.iter().zip(&paths) {
+        if !path.exists() {
+            return Err(syn::Error::new(
+                witx_file.span(),
+                format!("[{}] doesn't exist", path.display()),
+            ));
+        }
+        builder = builder.witx(path);
+    }
+    // Errors are reported at the first path that can't be generated on its own
+    let error = |e: witx_codegen::Error| {
+        let span = witx_files
+            .iter()
+            .zip(&paths)
+            .find(|(_, path)| {
+                Builder::new()
+                    .rust()
+                    .witx(path)
+                    .generate_to_string()
+                    .is_err()
+            })
+            .map_or_else(|| witx_files[0].span(), |(witx_file, _)| witx_file.span());
+        match e {
+            witx_codegen::Error::Witx(e) => syn::Error::new(span, e.report()),
+            e => syn::Error::new(span, e),
+        }
+    };
+    let code = builder.generate_to_string().map_err(error)?;
+    let code = TokenStream::from_str(&code).map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("The generated code couldn't be parsed: {}", e),
+        )
+    })?;
+
+    // Including the input files makes the compiler track them, so that
+    // the code is generated again after they change
+    let mut tracked_files = vec![];
+    for dependency in builder.dependencies().map_err(error)? {
+        tracked_files.extend(source_files(&dependency));
+    }
+    let tracked_files = tracked_files
+        .iter()
+        .map(|path| path.to_string_lossy().to_string());
+
+    Ok(quote! {
+        #(const _: &[u8] = include_bytes!(#tracked_files);)*
+        #code
+    })
+}
+
+/// The files of a WIT package directory, or the file itself
+fn source_files(path: &Path) -> Vec<PathBuf> {
+    if !path.is_dir() {
+        return vec![path.to_path_buf()];
+    }
+    let mut files: Vec<_> = fs::read_dir(path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| matches!(path.extension(), Some(ext) if ext == "wit"))
+        .collect();
+    files.sort();
+    files
+}
@@ -0,0 +1,11 @@
+#[allow(clippy::all)]
+mod http {
+    witx_codegen_macros::include_witx!("../tests/wasi_experimental_http.witx");
+}
+
+#[test]
+fn include_witx() {
+    let status_code: http::StatusCode = 200;
+    assert_eq!(std::mem::size_of_val(&status_code), 2);
+    assert_eq!(http::HTTP_ERROR::INVALID_HANDLE, 1);
+}